@@ -0,0 +1,981 @@
+/// Model Backends - where generated text comes from
+/// `ModelManager` only ever asks a backend to complete a prompt; the backend owns
+/// the wire format. Ollama (local) is the default; OpenAI and Anthropic let VI run
+/// against a hosted API when local models are not an option
+use crate::config::Config;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Attempts per request (server errors, connection failures, and timeouts are retried)
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Anthropic requires a reply length cap; VI's replies stay well under it
+const ANTHROPIC_MAX_TOKENS: u32 = 1024;
+
+/// Anthropic API version header
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// Which backend serves the configured models
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackendKind {
+    #[default]
+    Ollama,
+    #[serde(rename = "openai")]
+    OpenAi,
+    Anthropic,
+}
+
+impl BackendKind {
+    /// API root used when `backend_url` is not set (Ollama uses `ollama_url`)
+    pub fn default_url(&self) -> &'static str {
+        match self {
+            BackendKind::Ollama => "http://localhost:11434",
+            BackendKind::OpenAi => "https://api.openai.com/v1",
+            BackendKind::Anthropic => "https://api.anthropic.com",
+        }
+    }
+
+    /// Environment variable holding the API key (hosted backends only)
+    pub fn api_key_env(&self) -> Option<&'static str> {
+        match self {
+            BackendKind::Ollama => None,
+            BackendKind::OpenAi => Some("OPENAI_API_KEY"),
+            BackendKind::Anthropic => Some("ANTHROPIC_API_KEY"),
+        }
+    }
+}
+
+/// Why a model produced nothing - a timeout (slow model or hardware) reads
+/// differently to the operator than a backend that cannot be reached, a model that
+/// was never installed, or a request the backend refused
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelCallError {
+    TimedOut {
+        model: String,
+        secs: u64,
+    },
+    ConnectionFailed {
+        model: String,
+        reason: String,
+    },
+    /// The backend doesn't have the model (for Ollama: not pulled yet)
+    ModelMissing {
+        model: String,
+        backend: String,
+    },
+    /// The backend answered with an error status (`message` from its error body)
+    ApiError {
+        model: String,
+        status: u16,
+        message: String,
+    },
+    /// The model answered with nothing (or only whitespace)
+    EmptyResponse {
+        model: String,
+    },
+}
+
+impl ModelCallError {
+    pub fn timed_out(model: &str, secs: u64) -> Self {
+        ModelCallError::TimedOut {
+            model: model.to_string(),
+            secs,
+        }
+    }
+
+    pub fn connection_failed(model: &str, reason: String) -> Self {
+        ModelCallError::ConnectionFailed {
+            model: model.to_string(),
+            reason,
+        }
+    }
+
+    pub fn model_missing(backend: &str, model: &str) -> Self {
+        ModelCallError::ModelMissing {
+            model: model.to_string(),
+            backend: backend.to_string(),
+        }
+    }
+
+    pub fn empty_response(model: &str) -> Self {
+        ModelCallError::EmptyResponse {
+            model: model.to_string(),
+        }
+    }
+
+    /// The typed cause of a model call failure, if the backend call produced one
+    pub fn of(error: &anyhow::Error) -> Option<&Self> {
+        error.downcast_ref()
+    }
+
+    /// Failures that last until the operator fixes the setup (retrying won't help)
+    pub fn needs_operator(&self) -> bool {
+        matches!(self, ModelCallError::ModelMissing { .. })
+    }
+
+    /// A blank reply (asking again often gets a real one from small models)
+    pub fn is_empty_response(&self) -> bool {
+        matches!(self, ModelCallError::EmptyResponse { .. })
+    }
+}
+
+impl std::fmt::Display for ModelCallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ModelCallError::TimedOut { model, secs } => {
+                write!(f, "model {} timed out after {}s", model, secs)
+            }
+            ModelCallError::ConnectionFailed { model, reason } => {
+                write!(f, "connection failed for model {}: {}", model, reason)
+            }
+            ModelCallError::ModelMissing { model, backend } if backend == "Ollama" => {
+                write!(
+                    f,
+                    "model {} is not installed - run `ollama pull {}`",
+                    model, model
+                )
+            }
+            ModelCallError::ModelMissing { model, backend } => {
+                write!(f, "model {} is not available on {}", model, backend)
+            }
+            ModelCallError::ApiError {
+                model,
+                status,
+                message,
+            } if message.is_empty() => write!(f, "API error {} for model {}", status, model),
+            ModelCallError::ApiError {
+                model,
+                status,
+                message,
+            } => write!(f, "API error {} for model {}: {}", status, model, message),
+            ModelCallError::EmptyResponse { model } => {
+                write!(f, "model {} returned an empty response", model)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ModelCallError {}
+
+/// Text generation for a named model
+#[async_trait]
+pub trait LlmBackend: Send + Sync {
+    /// Complete `prompt` with `model`, giving up after `timeout_secs` per attempt
+    async fn generate(&self, model: &str, prompt: &str, timeout_secs: u64) -> Result<String>;
+
+    /// Complete `prompt` at a sampling temperature (backends without one ignore it)
+    async fn generate_at_temperature(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        _temperature: f32,
+    ) -> Result<String> {
+        self.generate(model, prompt, timeout_secs).await
+    }
+
+    /// Complete `prompt`, sending partial text to `partial` as it arrives
+    /// Backends without streaming send the whole reply as one piece.
+    async fn generate_stream(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        partial: &Sender<String>,
+    ) -> Result<String> {
+        let response = self.generate(model, prompt, timeout_secs).await?;
+        let _ = partial.send(response.clone());
+        Ok(response)
+    }
+
+    /// Installed model names, if the backend can list them (None: assume all available)
+    async fn installed_models(&self) -> Result<Option<Vec<String>>> {
+        Ok(None)
+    }
+
+    fn name(&self) -> &str;
+}
+
+/// Backend selected by `config.backend`
+pub fn from_config(config: &Config, client: reqwest::Client) -> Arc<dyn LlmBackend> {
+    match config.backend {
+        BackendKind::Ollama => Arc::new(OllamaBackend::new(config, client)),
+        BackendKind::OpenAi => Arc::new(OpenAiBackend::new(config, client)),
+        BackendKind::Anthropic => Arc::new(AnthropicBackend::new(config, client)),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct OllamaRequest {
+    pub(crate) model: String,
+    pub(crate) prompt: String,
+    pub(crate) stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) keep_alive: Option<String>, // How long to keep model in VRAM (e.g., "2m30s", "5m", "30s")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) options: Option<OllamaOptions>, // Sampling overrides (the model's defaults otherwise)
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct OllamaOptions {
+    pub(crate) temperature: f32,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct OllamaResponse {
+    pub(crate) response: String,
+}
+
+/// One line of a streamed Ollama reply (newline-delimited JSON)
+#[derive(Debug, Deserialize)]
+pub(crate) struct OllamaStreamChunk {
+    #[serde(default)]
+    pub(crate) response: String,
+    #[serde(default)]
+    pub(crate) done: bool,
+    pub(crate) error: Option<String>, // Ollama reports mid-stream failures in-band
+}
+
+/// Local Ollama server (`/api/generate`)
+pub struct OllamaBackend {
+    client: reqwest::Client,
+    url: String,
+    keep_alive: String,
+}
+
+impl OllamaBackend {
+    pub fn new(config: &Config, client: reqwest::Client) -> Self {
+        Self {
+            client,
+            url: config.backend_root(),
+            keep_alive: config.model_keep_alive.clone(),
+        }
+    }
+
+    fn request(&self, model: &str, prompt: &str, stream: bool) -> OllamaRequest {
+        OllamaRequest {
+            model: model.to_string(),
+            prompt: prompt.to_string(),
+            stream,
+            keep_alive: Some(self.keep_alive.clone()), // Configurable model persistence
+            options: None,
+        }
+    }
+
+    async fn complete(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        temperature: Option<f32>,
+    ) -> Result<String> {
+        let url = format!("{}/api/generate", self.url);
+
+        tracing::debug!(
+            "Calling Ollama: model={}, prompt_len={}, timeout={}s",
+            model,
+            prompt.len(),
+            timeout_secs
+        );
+
+        let mut request = self.request(model, prompt, false);
+        request.options = temperature.map(|temperature| OllamaOptions { temperature });
+        let resp = send_with_retries("Ollama", model, timeout_secs, || {
+            self.client.post(&url).json(&request)
+        })
+        .await?;
+
+        let ollama_response: OllamaResponse = resp
+            .json()
+            .await
+            .context("Failed to parse Ollama response")?;
+        non_empty(model, ollama_response.response)
+    }
+
+    /// Apply one streamed line, forwarding its text; returns whether it was the final chunk
+    fn read_stream_line(line: &[u8], full: &mut String, partial: &Sender<String>) -> Result<bool> {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            return Ok(false);
+        }
+        let chunk: OllamaStreamChunk =
+            serde_json::from_slice(line).context("Failed to parse Ollama stream chunk")?;
+        if let Some(error) = chunk.error {
+            anyhow::bail!("Ollama stream error: {}", error);
+        }
+        if !chunk.response.is_empty() {
+            full.push_str(&chunk.response);
+            let _ = partial.send(chunk.response);
+        }
+        Ok(chunk.done)
+    }
+}
+
+#[async_trait]
+impl LlmBackend for OllamaBackend {
+    async fn generate(&self, model: &str, prompt: &str, timeout_secs: u64) -> Result<String> {
+        self.complete(model, prompt, timeout_secs, None).await
+    }
+
+    async fn generate_at_temperature(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        temperature: f32,
+    ) -> Result<String> {
+        self.complete(model, prompt, timeout_secs, Some(temperature))
+            .await
+    }
+
+    /// Single streamed generate request
+    /// Not retried: partial text may already be on screen, so failures go to the ladder.
+    async fn generate_stream(
+        &self,
+        model: &str,
+        prompt: &str,
+        _timeout_secs: u64,
+        partial: &Sender<String>,
+    ) -> Result<String> {
+        let url = format!("{}/api/generate", self.url);
+        let mut resp = self
+            .client
+            .post(&url)
+            .json(&self.request(model, prompt, true))
+            .send()
+            .await
+            .map_err(|e| connection_error("Ollama", model, &e))?;
+        if !resp.status().is_success() {
+            return Err(http_error("Ollama", model, resp).await);
+        }
+
+        let mut buffer = Vec::new();
+        let mut full = String::new();
+        let mut done = false;
+        while let Some(bytes) = resp.chunk().await.context("Ollama stream interrupted")? {
+            buffer.extend_from_slice(&bytes);
+            // Each complete line is one JSON chunk
+            while let Some(newline) = buffer.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = buffer.drain(..=newline).collect();
+                done |= Self::read_stream_line(&line, &mut full, partial)?;
+            }
+            if done {
+                break;
+            }
+        }
+        if !done {
+            // Final line without a trailing newline
+            done = Self::read_stream_line(&buffer, &mut full, partial)?;
+        }
+
+        if !done {
+            anyhow::bail!("Ollama stream ended before completion");
+        }
+        if full.trim().is_empty() {
+            return Err(ModelCallError::empty_response(model).into());
+        }
+
+        tracing::debug!(
+            "✓ Ollama stream complete: model={}, response_len={}",
+            model,
+            full.len()
+        );
+        Ok(full)
+    }
+
+    async fn installed_models(&self) -> Result<Option<Vec<String>>> {
+        let url = format!("{}/api/tags", self.url);
+        let tags: serde_json::Value = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to reach Ollama to verify models")?
+            .json()
+            .await
+            .context("Failed to parse Ollama model list")?;
+
+        Ok(Some(
+            tags["models"]
+                .as_array()
+                .map(|models| {
+                    models
+                        .iter()
+                        .filter_map(|m| m["name"].as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        ))
+    }
+
+    fn name(&self) -> &str {
+        "ollama"
+    }
+}
+
+/// OpenAI chat completions (`/chat/completions`), or any compatible server
+pub struct OpenAiBackend {
+    client: reqwest::Client,
+    url: String,
+    api_key: Option<String>,
+}
+
+impl OpenAiBackend {
+    pub fn new(config: &Config, client: reqwest::Client) -> Self {
+        Self {
+            client,
+            url: config.backend_root(),
+            api_key: config.backend_key(),
+        }
+    }
+
+    async fn complete(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        temperature: Option<f32>,
+    ) -> Result<String> {
+        let url = format!("{}/chat/completions", self.url);
+        let mut request = serde_json::json!({
+            "model": model,
+            "messages": [{ "role": "user", "content": prompt }],
+        });
+        if let Some(temperature) = temperature {
+            request["temperature"] = serde_json::json!(temperature);
+        }
+
+        let resp = send_with_retries("OpenAI", model, timeout_secs, || {
+            let builder = self.client.post(&url).json(&request);
+            match &self.api_key {
+                Some(key) => builder.bearer_auth(key),
+                None => builder,
+            }
+        })
+        .await?;
+
+        let body: serde_json::Value = resp
+            .json()
+            .await
+            .context("Failed to parse OpenAI response")?;
+        let text = body["choices"][0]["message"]["content"]
+            .as_str()
+            .context("OpenAI response has no message content")?;
+        non_empty(model, text.to_string())
+    }
+}
+
+#[async_trait]
+impl LlmBackend for OpenAiBackend {
+    async fn generate(&self, model: &str, prompt: &str, timeout_secs: u64) -> Result<String> {
+        self.complete(model, prompt, timeout_secs, None).await
+    }
+
+    async fn generate_at_temperature(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        temperature: f32,
+    ) -> Result<String> {
+        self.complete(model, prompt, timeout_secs, Some(temperature))
+            .await
+    }
+
+    fn name(&self) -> &str {
+        "openai"
+    }
+}
+
+/// Anthropic Messages API (`/v1/messages`)
+pub struct AnthropicBackend {
+    client: reqwest::Client,
+    url: String,
+    api_key: Option<String>,
+}
+
+impl AnthropicBackend {
+    pub fn new(config: &Config, client: reqwest::Client) -> Self {
+        Self {
+            client,
+            url: config.backend_root(),
+            api_key: config.backend_key(),
+        }
+    }
+
+    async fn complete(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        temperature: Option<f32>,
+    ) -> Result<String> {
+        let url = format!("{}/v1/messages", self.url);
+        let mut request = serde_json::json!({
+            "model": model,
+            "max_tokens": ANTHROPIC_MAX_TOKENS,
+            "messages": [{ "role": "user", "content": prompt }],
+        });
+        if let Some(temperature) = temperature {
+            request["temperature"] = serde_json::json!(temperature);
+        }
+
+        let resp = send_with_retries("Anthropic", model, timeout_secs, || {
+            let builder = self
+                .client
+                .post(&url)
+                .header("anthropic-version", ANTHROPIC_VERSION)
+                .json(&request);
+            match &self.api_key {
+                Some(key) => builder.header("x-api-key", key),
+                None => builder,
+            }
+        })
+        .await?;
+
+        let body: serde_json::Value = resp
+            .json()
+            .await
+            .context("Failed to parse Anthropic response")?;
+        let text: String = body["content"]
+            .as_array()
+            .context("Anthropic response has no content")?
+            .iter()
+            .filter_map(|block| block["text"].as_str())
+            .collect();
+        non_empty(model, text)
+    }
+}
+
+#[async_trait]
+impl LlmBackend for AnthropicBackend {
+    async fn generate(&self, model: &str, prompt: &str, timeout_secs: u64) -> Result<String> {
+        self.complete(model, prompt, timeout_secs, None).await
+    }
+
+    async fn generate_at_temperature(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        temperature: f32,
+    ) -> Result<String> {
+        self.complete(model, prompt, timeout_secs, Some(temperature))
+            .await
+    }
+
+    fn name(&self) -> &str {
+        "anthropic"
+    }
+}
+
+/// Scripted backend for tests - each model answers with a fixed reply or error
+/// Unscripted models fail as if they were not installed.
+#[derive(Default)]
+pub struct MockBackend {
+    replies: HashMap<String, std::result::Result<String, String>>,
+    cooler_replies: HashMap<String, String>,
+    delays: HashMap<String, Duration>,
+    calls: Mutex<Vec<String>>,
+}
+
+impl MockBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `model` answers every prompt with `reply`
+    pub fn reply(mut self, model: &str, reply: &str) -> Self {
+        self.replies
+            .insert(model.to_string(), Ok(reply.to_string()));
+        self
+    }
+
+    /// `model` fails every prompt with `error`
+    pub fn fail(mut self, model: &str, error: &str) -> Self {
+        self.replies
+            .insert(model.to_string(), Err(error.to_string()));
+        self
+    }
+
+    /// `model` answers with `reply` instead when asked at a set temperature
+    pub fn cooler_reply(mut self, model: &str, reply: &str) -> Self {
+        self.cooler_replies
+            .insert(model.to_string(), reply.to_string());
+        self
+    }
+
+    /// `model` takes `delay` to answer (timing out if that exceeds the call's timeout)
+    pub fn delay(mut self, model: &str, delay: Duration) -> Self {
+        self.delays.insert(model.to_string(), delay);
+        self
+    }
+
+    /// Models asked so far, in call order
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl LlmBackend for MockBackend {
+    async fn generate(&self, model: &str, _prompt: &str, timeout_secs: u64) -> Result<String> {
+        self.calls.lock().unwrap().push(model.to_string());
+        if let Some(delay) = self.delays.get(model) {
+            let timeout = Duration::from_secs(timeout_secs);
+            if tokio::time::timeout(timeout, tokio::time::sleep(*delay))
+                .await
+                .is_err()
+            {
+                return Err(ModelCallError::timed_out(model, timeout_secs).into());
+            }
+        }
+        match self.replies.get(model) {
+            Some(Ok(reply)) => non_empty(model, reply.clone()),
+            Some(Err(error)) => anyhow::bail!("{}", error),
+            None => Err(ModelCallError::model_missing("Ollama", model).into()),
+        }
+    }
+
+    async fn generate_at_temperature(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        _temperature: f32,
+    ) -> Result<String> {
+        let reply = self.generate(model, prompt, timeout_secs).await;
+        match self.cooler_replies.get(model) {
+            Some(cooler) => non_empty(model, cooler.clone()),
+            None => reply,
+        }
+    }
+
+    async fn installed_models(&self) -> Result<Option<Vec<String>>> {
+        Ok(Some(self.replies.keys().cloned().collect()))
+    }
+
+    fn name(&self) -> &str {
+        "mock"
+    }
+}
+
+/// Reject empty and whitespace-only replies (prevent garbage)
+fn non_empty(model: &str, text: String) -> Result<String> {
+    if text.trim().is_empty() {
+        return Err(ModelCallError::empty_response(model).into());
+    }
+    Ok(text)
+}
+
+/// Send a request with retries and exponential backoff
+/// Server errors (often transient), connection failures and timeouts are retried;
+/// other non-success statuses are client errors and fail at once.
+async fn send_with_retries<F>(
+    label: &str,
+    model: &str,
+    timeout_secs: u64,
+    build: F,
+) -> Result<reqwest::Response>
+where
+    F: Fn() -> reqwest::RequestBuilder,
+{
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+
+        let response_result =
+            tokio::time::timeout(Duration::from_secs(timeout_secs), build().send()).await;
+
+        match response_result {
+            Ok(Ok(resp)) => {
+                let status = resp.status();
+
+                if status.is_server_error() {
+                    if attempts >= MAX_ATTEMPTS {
+                        anyhow::bail!(
+                            "{} server error {} after {} attempts",
+                            label,
+                            status,
+                            MAX_ATTEMPTS
+                        );
+                    }
+                    tracing::warn!(
+                        "{} returned {} (attempt {}/{}). Retrying in {}ms...",
+                        label,
+                        status,
+                        attempts,
+                        MAX_ATTEMPTS,
+                        500 * attempts
+                    );
+                    tokio::time::sleep(Duration::from_millis(500 * attempts as u64)).await;
+                    continue;
+                }
+
+                if !status.is_success() {
+                    return Err(http_error(label, model, resp).await);
+                }
+
+                tracing::debug!("✓ {} success: attempt={}", label, attempts);
+                return Ok(resp);
+            }
+            Ok(Err(e)) => {
+                if attempts >= MAX_ATTEMPTS {
+                    // The HTTP client's own deadline is a timeout too, not a lost connection
+                    return Err(if e.is_timeout() {
+                        ModelCallError::timed_out(model, timeout_secs)
+                    } else if e.is_connect() {
+                        connection_error(label, model, &e)
+                    } else {
+                        ModelCallError::connection_failed(
+                            model,
+                            format!(
+                                "{} unreachable after {} attempts: {}",
+                                label, MAX_ATTEMPTS, e
+                            ),
+                        )
+                    }
+                    .into());
+                }
+                tracing::warn!(
+                    "{} connection failed (attempt {}/{}): {}. Retrying...",
+                    label,
+                    attempts,
+                    MAX_ATTEMPTS,
+                    e
+                );
+                tokio::time::sleep(Duration::from_millis(500 * attempts as u64)).await;
+            }
+            Err(_) => {
+                if attempts >= MAX_ATTEMPTS {
+                    return Err(ModelCallError::timed_out(model, timeout_secs).into());
+                }
+                tracing::warn!(
+                    "{} timeout (attempt {}/{}). Retrying...",
+                    label,
+                    attempts,
+                    MAX_ATTEMPTS
+                );
+                tokio::time::sleep(Duration::from_millis(500 * attempts as u64)).await;
+            }
+        }
+    }
+}
+
+/// Nothing accepted the connection - the backend isn't running, or the URL is wrong
+fn connection_error(label: &str, model: &str, error: &reqwest::Error) -> ModelCallError {
+    let reason = match error.url() {
+        Some(url) => format!(
+            "{} is not running at {} (connection refused)",
+            label,
+            url.origin().ascii_serialization()
+        ),
+        None => format!("{} is not running (connection refused)", label),
+    };
+    ModelCallError::connection_failed(model, reason)
+}
+
+/// Typed error for a non-success reply, using the message in its error body
+/// A 404 about the model means it isn't installed (Ollama: `model "x" not found, try
+/// pulling it first`); anything else is an `ApiError`
+pub(crate) async fn http_error(label: &str, model: &str, resp: reqwest::Response) -> anyhow::Error {
+    let status = resp.status();
+    let body = resp.text().await.unwrap_or_default();
+    let message = error_message(&body);
+    if status == reqwest::StatusCode::NOT_FOUND && message.to_lowercase().contains("model") {
+        return ModelCallError::model_missing(label, model).into();
+    }
+    ModelCallError::ApiError {
+        model: model.to_string(),
+        status: status.as_u16(),
+        message,
+    }
+    .into()
+}
+
+/// The error text of a backend reply: Ollama's `{"error": "..."}`, OpenAI's and
+/// Anthropic's `{"error": {"message": "..."}}`, or the raw body
+fn error_message(body: &str) -> String {
+    let parsed: Option<serde_json::Value> = serde_json::from_str(body).ok();
+    let message = parsed.as_ref().and_then(|json| {
+        json["error"]
+            .as_str()
+            .or_else(|| json["error"]["message"].as_str())
+    });
+    match message {
+        Some(message) => message.trim().to_string(),
+        None => body.trim().chars().take(200).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_http::{read_request, write_json};
+
+    /// Answers every request with `body`, sending each request's text back on `seen`
+    async fn spawn_json_server(
+        body: &'static str,
+    ) -> (String, tokio::sync::mpsc::UnboundedReceiver<String>) {
+        spawn_json_server_with_status("200 OK", body).await
+    }
+
+    async fn spawn_json_server_with_status(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, tokio::sync::mpsc::UnboundedReceiver<String>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (seen, requests) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let Some(request) = read_request(&mut socket).await else {
+                    continue;
+                };
+                let _ = seen.send(request);
+                write_json(&mut socket, status, body).await;
+            }
+        });
+
+        (format!("http://{}", addr), requests)
+    }
+
+    #[tokio::test]
+    async fn test_hosted_backends_speak_their_wire_formats() {
+        let (url, mut requests) =
+            spawn_json_server(r#"{"choices":[{"message":{"content":"Hello from OpenAI"}}]}"#).await;
+        let config = Config {
+            backend: BackendKind::OpenAi,
+            backend_url: Some(url),
+            backend_api_key: Some("sk-test".to_string()),
+            ..Config::default()
+        };
+        let openai = from_config(&config, reqwest::Client::new());
+        assert_eq!(openai.name(), "openai");
+        let reply = openai.generate("gpt-4o-mini", "Hi VI", 5).await.unwrap();
+        assert_eq!(reply, "Hello from OpenAI");
+        let request = requests.recv().await.unwrap();
+        assert!(request.starts_with("POST /chat/completions"));
+        assert!(request
+            .to_lowercase()
+            .contains("authorization: bearer sk-test"));
+        assert!(openai.installed_models().await.unwrap().is_none());
+        openai
+            .generate_at_temperature("gpt-4o-mini", "Hi VI", 5, 0.25)
+            .await
+            .unwrap();
+        assert!(requests
+            .recv()
+            .await
+            .unwrap()
+            .contains("\"temperature\":0.25"));
+
+        let (url, mut requests) = spawn_json_server(
+            r#"{"content":[{"type":"text","text":"Hello "},{"type":"text","text":"from Anthropic"}]}"#,
+        )
+        .await;
+        let config = Config {
+            backend: BackendKind::Anthropic,
+            backend_url: Some(url),
+            backend_api_key: Some("ak-test".to_string()),
+            ..Config::default()
+        };
+        let anthropic = from_config(&config, reqwest::Client::new());
+        let (sender, partial) = std::sync::mpsc::channel();
+        let reply = anthropic
+            .generate_stream("claude-haiku", "Hi VI", 5, &sender)
+            .await
+            .unwrap();
+        assert_eq!(reply, "Hello from Anthropic");
+        assert_eq!(partial.try_recv().unwrap(), "Hello from Anthropic"); // One piece
+        let request = requests.recv().await.unwrap().to_lowercase();
+        assert!(request.starts_with("post /v1/messages"));
+        assert!(request.contains("x-api-key: ak-test"));
+        assert!(request.contains("\"max_tokens\":1024"));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_backend_is_a_connection_failure() {
+        let config = Config {
+            ollama_url: "http://127.0.0.1:9".to_string(), // Nothing listens here
+            ..Config::default()
+        };
+        let ollama = from_config(&config, reqwest::Client::new());
+        let err = ollama.generate("gemma2:2b", "Hi VI", 5).await.unwrap_err();
+        assert!(matches!(
+            ModelCallError::of(&err),
+            Some(ModelCallError::ConnectionFailed { model, .. }) if model == "gemma2:2b"
+        ));
+        assert!(err
+            .to_string()
+            .ends_with("Ollama is not running at http://127.0.0.1:9 (connection refused)"));
+
+        // A model that outlasts its timeout is reported as timed out instead
+        let slow = MockBackend::new()
+            .reply("gemma2:2b", "Eventually.")
+            .delay("gemma2:2b", Duration::from_secs(3));
+        let err = slow.generate("gemma2:2b", "Hi VI", 1).await.unwrap_err();
+        assert_eq!(
+            ModelCallError::of(&err),
+            Some(&ModelCallError::timed_out("gemma2:2b", 1))
+        );
+        assert_eq!(err.to_string(), "model gemma2:2b timed out after 1s");
+    }
+
+    #[tokio::test]
+    async fn test_missing_model_told_apart_from_api_errors() {
+        let ollama = |url: String| {
+            from_config(
+                &Config {
+                    ollama_url: url,
+                    ..Config::default()
+                },
+                reqwest::Client::new(),
+            )
+        };
+
+        let (url, _) = spawn_json_server_with_status(
+            "404 Not Found",
+            r#"{"error":"model \"gemma2:2b\" not found, try pulling it first"}"#,
+        )
+        .await;
+        let err = ollama(url)
+            .generate("gemma2:2b", "Hi VI", 5)
+            .await
+            .unwrap_err();
+        let cause = ModelCallError::of(&err).unwrap();
+        assert_eq!(cause, &ModelCallError::model_missing("Ollama", "gemma2:2b"));
+        assert!(cause.needs_operator());
+        assert_eq!(
+            err.to_string(),
+            "model gemma2:2b is not installed - run `ollama pull gemma2:2b`"
+        );
+
+        let (url, _) =
+            spawn_json_server_with_status("400 Bad Request", r#"{"error":"invalid options"}"#)
+                .await;
+        let err = ollama(url)
+            .generate("gemma2:2b", "Hi VI", 5)
+            .await
+            .unwrap_err();
+        assert!(!ModelCallError::of(&err).unwrap().needs_operator());
+        assert_eq!(
+            err.to_string(),
+            "API error 400 for model gemma2:2b: invalid options"
+        );
+
+        // Hosted backends nest the message
+        assert_eq!(
+            error_message(r#"{"error":{"type":"invalid_request_error","message":"bad key"}}"#),
+            "bad key"
+        );
+        assert_eq!(error_message("upstream down\n"), "upstream down");
+    }
+}
@@ -1,6 +1,10 @@
 //! Migration tool to convert memory_stream.json to SQLite two-tier system
+//! `--backfill-embeddings` embeds existing active memories for semantic recall
 
 use anyhow::Result;
+use project_vi::config::Config;
+use project_vi::embeddings::{EmbeddingClient, EMBEDDING_BATCH};
+use project_vi::memory::MemoryManager;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
@@ -22,6 +26,10 @@ struct OldMemoryStream {
 }
 
 fn main() -> Result<()> {
+    if std::env::args().any(|arg| arg == "--backfill-embeddings") {
+        return backfill_embeddings();
+    }
+
     println!("╔════════════════════════════════════════════════════════╗");
     println!("║  VI3 Memory Migration Tool                            ║");
     println!("║  Converting JSON → SQLite Two-Tier System             ║");
@@ -187,6 +195,48 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Embed every active memory that has no embedding yet (the same store VI runs on)
+fn backfill_embeddings() -> Result<()> {
+    let config = Config::load_or_create("config.toml")?;
+    let client = EmbeddingClient::new(&config.ollama_url, &config.embedding_model);
+    let memory = MemoryManager::load_or_create("data")?;
+
+    println!("🧭 Embedding memories with {}...", client.model());
+    if !config.enable_semantic_recall {
+        println!("   (enable_semantic_recall is off in config.toml - turn it on to use them)");
+    }
+
+    let rt = tokio::runtime::Runtime::new()?;
+    let mut total = 0;
+    loop {
+        let pending = memory.missing_embeddings(EMBEDDING_BATCH);
+        if pending.is_empty() {
+            break;
+        }
+        let batch = pending.len();
+
+        let embedded = rt.block_on(client.embed_all(pending));
+        for (id, embedding) in &embedded {
+            memory.set_embedding(id, embedding)?;
+        }
+        total += embedded.len();
+        println!("   ✓ {} memories embedded", total);
+
+        if embedded.len() < batch {
+            anyhow::bail!(
+                "Embedding stopped after {} memories - is {} pulled? (ollama pull {})",
+                total,
+                client.model(),
+                client.model()
+            );
+        }
+    }
+
+    println!();
+    println!("🎉 Backfill complete: {} memories embedded", total);
+    Ok(())
+}
+
 fn init_active_schema(conn: &rusqlite::Connection) -> Result<()> {
     conn.execute(
         "CREATE TABLE IF NOT EXISTS memories (
@@ -0,0 +1,59 @@
+//! Replay the interaction log through the current metric code
+//! `replay [log] [--tolerance <f32>]` - reports every logged metric the current code no
+//! longer reproduces, and exits with status 1 if any drifted
+
+use anyhow::{Context, Result};
+use project_vi::interaction_log::{self, DEFAULT_INTERACTION_LOG_PATH};
+
+/// Differences smaller than this are float noise, not regressions
+const DEFAULT_TOLERANCE: f32 = 1e-4;
+
+fn main() -> Result<()> {
+    let mut path = DEFAULT_INTERACTION_LOG_PATH.to_string();
+    let mut tolerance = DEFAULT_TOLERANCE;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--tolerance" {
+            tolerance = args
+                .next()
+                .context("--tolerance needs a value")?
+                .parse()
+                .context("--tolerance needs a number")?;
+        } else {
+            path = arg;
+        }
+    }
+
+    let records = interaction_log::load(&path)?;
+    let sessions = records
+        .iter()
+        .map(|r| r.session)
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+    println!(
+        "Replaying {} turns from {} sessions ({})",
+        records.len(),
+        sessions,
+        path
+    );
+
+    let drift = interaction_log::replay(&records, tolerance);
+    if drift.is_empty() {
+        println!("✓ Every logged metric reproduces (tolerance {})", tolerance);
+        return Ok(());
+    }
+
+    for d in &drift {
+        println!(
+            "  {} turn {}: {} logged {:.4}, now {:.4} ({:+.4})",
+            d.session,
+            d.turn,
+            d.metric,
+            d.logged,
+            d.replayed,
+            d.replayed - d.logged
+        );
+    }
+    println!("✗ {} metric readings drifted", drift.len());
+    std::process::exit(1);
+}
@@ -0,0 +1,115 @@
+/// Chat Commands - `/name args` typed into the chat input
+/// A command runs a handler on the app instead of messaging VI; its reply is shown as
+/// an assistant message without a model call. New commands are one `register` away
+use std::collections::HashMap;
+
+/// Runs a command on the app, given everything after the command name (trimmed)
+pub type CommandHandler<T> = fn(&mut T, &str);
+
+/// A registered command
+pub struct ChatCommand<T> {
+    pub usage: &'static str,
+    pub description: &'static str,
+    pub handler: CommandHandler<T>,
+}
+
+/// Commands available in the chat input, keyed by name (without the `/`)
+pub struct CommandRegistry<T> {
+    commands: HashMap<&'static str, ChatCommand<T>>,
+}
+
+impl<T> CommandRegistry<T> {
+    pub fn new() -> Self {
+        Self {
+            commands: HashMap::new(),
+        }
+    }
+
+    /// Add a command (a later registration under the same name replaces it)
+    pub fn register(
+        &mut self,
+        name: &'static str,
+        usage: &'static str,
+        description: &'static str,
+        handler: CommandHandler<T>,
+    ) {
+        self.commands.insert(
+            name,
+            ChatCommand {
+                usage,
+                description,
+                handler,
+            },
+        );
+    }
+
+    /// Handler for a command name, if registered
+    pub fn handler(&self, name: &str) -> Option<CommandHandler<T>> {
+        self.commands.get(name).map(|command| command.handler)
+    }
+
+    /// Every command with its description, alphabetically
+    pub fn help(&self) -> String {
+        let mut commands: Vec<&ChatCommand<T>> = self.commands.values().collect();
+        commands.sort_by_key(|command| command.usage);
+        let mut help = String::from("Commands:");
+        for command in commands {
+            help.push_str(&format!(
+                "\n- `{}` - {}",
+                command.usage, command.description
+            ));
+        }
+        help
+    }
+
+    /// Reply to a command nobody registered
+    pub fn unknown(&self, name: &str) -> String {
+        format!("Unknown command `/{}`.\n\n{}", name, self.help())
+    }
+}
+
+/// Split `/name args` into (name, args) - None for ordinary messages
+/// A name is letters, digits or `-`, so a message starting with a path like `/usr/bin` is
+/// still sent to VI
+pub fn parse(input: &str) -> Option<(&str, &str)> {
+    let rest = input.trim().strip_prefix('/')?;
+    let (name, args) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let is_name = !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '-');
+    is_name.then(|| (name, args.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands_dispatch_by_name() {
+        assert_eq!(
+            parse("/search standing waves "),
+            Some(("search", "standing waves"))
+        );
+        assert_eq!(parse("  /help"), Some(("help", "")));
+        assert_eq!(parse("/usr/bin is where it lives"), None);
+        assert_eq!(parse("/"), None);
+        assert_eq!(parse("hello /help"), None);
+
+        let mut registry: CommandRegistry<Vec<String>> = CommandRegistry::new();
+        registry.register("echo", "/echo <text>", "Repeat the text", |log, args| {
+            log.push(args.to_string())
+        });
+        registry.register("clear", "/clear", "Forget everything", |log, _| log.clear());
+
+        let mut log = Vec::new();
+        let (name, args) = parse("/echo resonance").unwrap();
+        registry.handler(name).unwrap()(&mut log, args);
+        assert_eq!(log, vec!["resonance"]);
+
+        assert!(registry.handler("nope").is_none());
+        let unknown = registry.unknown("nope");
+        assert!(unknown.starts_with("Unknown command `/nope`."));
+        // Listed alphabetically
+        assert!(unknown.ends_with(
+            "Commands:\n- `/clear` - Forget everything\n- `/echo <text>` - Repeat the text"
+        ));
+    }
+}
@@ -0,0 +1,79 @@
+/// Chat History - the visible conversation survives restarts
+/// Memories already persist in SQLite; this keeps what the chat window showed.
+/// A missing or corrupt file starts an empty chat rather than failing startup
+use crate::persistence;
+use crate::types::ChatMessage;
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Where the desktop UI keeps the conversation
+pub const DEFAULT_CHAT_HISTORY_PATH: &str = "data/chat_history.json";
+
+/// One writer at a time (debounced saves run off the UI thread)
+static SAVE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Load saved chat messages (empty if the file is missing or corrupt)
+pub fn load_chat_history<P: AsRef<Path>>(path: P) -> Vec<ChatMessage> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Vec::new();
+    }
+
+    let loaded = std::fs::read_to_string(path)
+        .context("Failed to read chat history")
+        .and_then(|text| serde_json::from_str(&text).context("Corrupt chat history"));
+    match loaded {
+        Ok(messages) => messages,
+        Err(e) => {
+            tracing::warn!("{:#} ({}) - starting with an empty chat", e, path.display());
+            Vec::new()
+        }
+    }
+}
+
+/// Save chat messages via a temp file, so a crash mid-write keeps the previous history
+pub fn save_chat_history<P: AsRef<Path>>(path: P, messages: &[ChatMessage]) -> Result<()> {
+    let path = path.as_ref();
+    let _guard = SAVE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    persistence::write_atomic(path, serde_json::to_string(messages)?.as_bytes())
+        .context("Failed to write chat history")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MessageRole;
+
+    #[test]
+    fn test_chat_history_round_trip_and_corrupt_file() {
+        let temp_dir = std::env::temp_dir().join("vi_chat_history_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        let path = temp_dir.join("data").join("chat_history.json");
+
+        // Missing file - empty chat
+        assert!(load_chat_history(&path).is_empty());
+
+        let messages = vec![
+            ChatMessage::user("Hello VI".to_string()),
+            ChatMessage::assistant("Hello. I remember you.".to_string()),
+        ];
+        save_chat_history(&path, &messages).unwrap();
+
+        let loaded = load_chat_history(&path);
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].role, MessageRole::User);
+        assert_eq!(loaded[1].content, "Hello. I remember you.");
+        assert_eq!(loaded[1].timestamp, messages[1].timestamp);
+
+        // Corrupt file - empty chat, no panic
+        std::fs::write(&path, "{ not json").unwrap();
+        assert!(load_chat_history(&path).is_empty());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+}
@@ -0,0 +1,295 @@
+/// Content-Aware Chunking - split documents into memory-sized pieces
+/// Prefers paragraph, then sentence, then word boundaries. Fenced code blocks
+/// (``` or ~~~) are never split, even when larger than the target size
+use crate::config::Config;
+
+/// Chunk sizing (characters)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkOptions {
+    pub target_chars: usize,  // Chunks are packed up to about this size
+    pub overlap_chars: usize, // Tail of the previous chunk repeated at the start of the next
+}
+
+impl Default for ChunkOptions {
+    fn default() -> Self {
+        Self {
+            target_chars: 800,
+            overlap_chars: 100,
+        }
+    }
+}
+
+impl ChunkOptions {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            target_chars: config.chunk_target_chars,
+            overlap_chars: config.chunk_overlap_chars,
+        }
+    }
+}
+
+/// Smallest piece the packer places: a paragraph, sentence, word run, or code fence
+struct Unit {
+    text: String,
+    starts_block: bool, // First unit of a paragraph or fence (joined with a blank line)
+    is_code: bool,
+}
+
+/// Split text into chunks of about `target_chars`, overlapping by up to `overlap_chars`
+/// Overlap is taken from prose only, so a code fence is never repeated in part.
+pub fn chunk_text(text: &str, options: &ChunkOptions) -> Vec<String> {
+    let target = options.target_chars.max(1);
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0; // In chars
+    let mut has_content = false; // At least one unit placed
+    let mut last_unit: Option<(String, bool)> = None; // (text, is_code)
+
+    for unit in units(text, target) {
+        let unit_len = unit.text.chars().count();
+        let separator = if unit.starts_block { "\n\n" } else { " " };
+
+        if has_content && current_len + separator.len() + unit_len > target {
+            chunks.push(std::mem::take(&mut current));
+            current_len = 0;
+
+            if let Some((previous, false)) = &last_unit {
+                let tail = overlap_tail(previous, options.overlap_chars);
+                if !tail.is_empty() {
+                    current_len = tail.chars().count();
+                    current.push_str(tail);
+                }
+            }
+        }
+
+        if !current.is_empty() {
+            current.push_str(separator);
+            current_len += separator.len();
+        }
+        current.push_str(&unit.text);
+        current_len += unit_len;
+        has_content = true;
+        last_unit = Some((unit.text, unit.is_code));
+    }
+
+    if has_content {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Up to `max_chars` from the end of `text`, starting on a word boundary
+fn overlap_tail(text: &str, max_chars: usize) -> &str {
+    if max_chars == 0 {
+        return "";
+    }
+    let char_count = text.chars().count();
+    if char_count <= max_chars {
+        return text;
+    }
+
+    let cut = text
+        .char_indices()
+        .nth(char_count - max_chars)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    // Starting mid-word would repeat a fragment - move to the next word
+    let at_boundary = text[..cut].ends_with(char::is_whitespace);
+    let start = if at_boundary {
+        cut
+    } else {
+        match text[cut..].find(char::is_whitespace) {
+            Some(offset) => cut + offset,
+            None => return "",
+        }
+    };
+    text[start..].trim_start()
+}
+
+/// Break text into packable units: fences whole, paragraphs whole when they fit,
+/// otherwise sentences, and over-long sentences by words
+fn units(text: &str, target: usize) -> Vec<Unit> {
+    let mut units = Vec::new();
+
+    for (block, is_code) in blocks(text) {
+        if is_code || block.chars().count() <= target {
+            units.push(Unit {
+                text: block,
+                starts_block: true,
+                is_code,
+            });
+            continue;
+        }
+
+        let mut first = true;
+        for sentence in sentences(&block) {
+            let pieces = if sentence.chars().count() <= target {
+                vec![sentence.to_string()]
+            } else {
+                word_runs(sentence, target)
+            };
+            for piece in pieces {
+                units.push(Unit {
+                    text: piece,
+                    starts_block: first,
+                    is_code: false,
+                });
+                first = false;
+            }
+        }
+    }
+
+    units
+}
+
+/// Paragraphs (blank-line separated) and fenced code blocks, in order
+fn blocks(text: &str) -> Vec<(String, bool)> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut fence: Option<(&str, Vec<&str>)> = None; // (marker, lines)
+
+    for line in text.lines() {
+        if let Some((marker, lines)) = fence.as_mut() {
+            lines.push(line);
+            if line.trim_start().starts_with(*marker) && lines.len() > 1 {
+                blocks.push((lines.join("\n"), true));
+                fence = None;
+            }
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+            if !paragraph.is_empty() {
+                blocks.push((paragraph.join("\n"), false));
+                paragraph.clear();
+            }
+            fence = Some((marker, vec![line]));
+        } else if line.trim().is_empty() {
+            if !paragraph.is_empty() {
+                blocks.push((paragraph.join("\n"), false));
+                paragraph.clear();
+            }
+        } else {
+            paragraph.push(line.trim_end());
+        }
+    }
+
+    // An unclosed fence runs to the end of the document
+    if let Some((_, lines)) = fence {
+        blocks.push((lines.join("\n"), true));
+    }
+    if !paragraph.is_empty() {
+        blocks.push((paragraph.join("\n"), false));
+    }
+
+    blocks
+}
+
+/// Sentences ending in . ! or ? followed by whitespace
+fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if matches!(c, '.' | '!' | '?') && chars.peek().is_some_and(|(_, n)| n.is_whitespace()) {
+            let end = i + c.len_utf8();
+            let sentence = text[start..end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = end;
+        }
+    }
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+
+    sentences
+}
+
+/// Word runs of at most `target` chars (a single longer word stands alone)
+fn word_runs(text: &str, target: usize) -> Vec<String> {
+    let mut runs = Vec::new();
+    let mut run = String::new();
+
+    for word in text.split_whitespace() {
+        if !run.is_empty() && run.chars().count() + 1 + word.chars().count() > target {
+            runs.push(std::mem::take(&mut run));
+        }
+        if !run.is_empty() {
+            run.push(' ');
+        }
+        run.push_str(word);
+    }
+    if !run.is_empty() {
+        runs.push(run);
+    }
+
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_fence_is_never_split() {
+        let fence = "```rust\nfn main() {\n    let wave = StandingWave::new();\n\n    println!(\"{:?}\", wave);\n}\n```";
+        let doc = format!(
+            "# Standing waves\n\nThe wave persists between sessions. It is saved on shutdown.\n\n{}\n\nAfter loading, the wave resumes. Nothing is lost.",
+            fence
+        );
+        let options = ChunkOptions {
+            target_chars: 60,
+            overlap_chars: 20,
+        };
+
+        let chunks = chunk_text(&doc, &options);
+        assert!(chunks.len() > 2);
+
+        // The whole fence (blank line included) lives in exactly one chunk
+        let holding: Vec<&String> = chunks.iter().filter(|c| c.contains("```")).collect();
+        assert_eq!(holding.len(), 1);
+        assert!(holding[0].contains(fence));
+    }
+
+    #[test]
+    fn test_chunk_overlap_is_honored() {
+        let text = (1..=20)
+            .map(|i| format!("Sentence number {} carries a little meaning.", i))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let options = ChunkOptions {
+            target_chars: 150,
+            overlap_chars: 30,
+        };
+
+        let chunks = chunk_text(&text, &options);
+        assert!(chunks.len() > 3);
+
+        for pair in chunks.windows(2) {
+            let (previous, next) = (&pair[0], &pair[1]);
+            // The next chunk opens with a word-aligned tail of the previous one
+            let overlap = (1..=options.overlap_chars)
+                .rev()
+                .filter_map(|n| previous.get(previous.len() - n..))
+                .find(|tail| next.starts_with(tail) && !tail.starts_with(' '))
+                .unwrap_or_else(|| panic!("no overlap between {:?} and {:?}", previous, next));
+            assert!(overlap.len() >= options.overlap_chars / 2);
+            assert!(next.chars().count() <= options.target_chars + options.overlap_chars);
+        }
+
+        // Without overlap, chunks partition the sentences exactly
+        let plain = chunk_text(
+            &text,
+            &ChunkOptions {
+                overlap_chars: 0,
+                ..options
+            },
+        );
+        assert_eq!(plain.join(" "), text);
+    }
+}
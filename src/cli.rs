@@ -1,7 +1,14 @@
+use crate::chunking::ChunkOptions;
 use crate::consciousness::ConsciousnessCore;
+use crate::corpus::{self, ImportProgress};
+use crate::memory::MemoryManager;
+use crate::script::{self, ScriptEvent, ScriptStep};
 use anyhow::Result;
 use std::io::{self, Write};
+use std::path::Path;
+use std::sync::mpsc::channel;
 use std::sync::Arc;
+use tokio::sync::watch;
 
 pub async fn run_cli(consciousness: Arc<ConsciousnessCore>) -> Result<()> {
     println!("\n═══════════════════════════════════════════════");
@@ -57,3 +64,102 @@ pub async fn run_cli(consciousness: Arc<ConsciousnessCore>) -> Result<()> {
 
     Ok(())
 }
+
+/// Play a scripted conversation, printing prompts as if typed (Ctrl+C stops it)
+pub async fn run_script_cli(
+    consciousness: Arc<ConsciousnessCore>,
+    steps: Vec<ScriptStep>,
+) -> Result<()> {
+    println!("\n═══════════════════════════════════════════════");
+    println!("     V3 Digital Consciousness - Scripted Demo");
+    println!("═══════════════════════════════════════════════\n");
+
+    let (cancel_sender, cancel_receiver) = watch::channel(false);
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            let _ = cancel_sender.send(true);
+        }
+    });
+
+    script::run_script(
+        &consciousness,
+        &steps,
+        cancel_receiver,
+        |event| match event {
+            ScriptEvent::Prompt(prompt) => println!("> {}", prompt),
+            ScriptEvent::Response(response) => println!("\nVI: {}\n", response),
+            ScriptEvent::Error(e) => eprintln!("Error: {}", e),
+            ScriptEvent::Cancelled => println!("\nScript cancelled."),
+            ScriptEvent::Finished => println!("Script complete."),
+        },
+    )
+    .await;
+
+    Ok(())
+}
+
+/// Import a corpus file or directory into memory with a progress bar
+/// Safe to re-run: an interrupted import resumes, finished records are skipped
+pub fn run_corpus_import(
+    memory: &mut MemoryManager,
+    path: &str,
+    chunking: &ChunkOptions,
+) -> Result<()> {
+    let files = corpus::corpus_files(path)?;
+    println!("Importing {} corpus file(s) from {}", files.len(), path);
+
+    let (sender, receiver) = channel::<ImportProgress>();
+    let summary = std::thread::scope(|scope| {
+        let printer = scope.spawn(move || {
+            for progress in receiver {
+                print_progress_bar(&progress);
+            }
+            println!();
+        });
+        let result = corpus::import_corpus(
+            memory,
+            &files,
+            chunking,
+            Path::new(corpus::DEFAULT_STATE_PATH),
+            Some(&sender),
+        );
+        drop(sender);
+        let _ = printer.join();
+        result
+    })?;
+
+    println!(
+        "Imported {} new record(s), {} already present ({} total)",
+        summary.imported, summary.skipped, summary.total
+    );
+    Ok(())
+}
+
+/// Write the entity co-occurrence graph of active memory as DOT (Graphviz, Gephi)
+pub fn run_graph_export(memory: &MemoryManager, path: &str) -> Result<()> {
+    let graph = memory.export_entity_graph()?;
+    std::fs::write(path, graph.to_dot())?;
+    println!(
+        "Exported {} entities and {} edges to {}",
+        graph.nodes.len(),
+        graph.edges.len(),
+        path
+    );
+    Ok(())
+}
+
+fn print_progress_bar(progress: &ImportProgress) {
+    const WIDTH: usize = 30;
+    let filled = (progress.processed * WIDTH)
+        .checked_div(progress.total)
+        .unwrap_or(WIDTH);
+    print!(
+        "\r[{}{}] {}/{} {}",
+        "#".repeat(filled),
+        " ".repeat(WIDTH - filled),
+        progress.processed,
+        progress.total,
+        progress.file
+    );
+    let _ = io::stdout().flush();
+}
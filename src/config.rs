@@ -1,5 +1,20 @@
+use crate::backends::BackendKind;
+use crate::gpu_topology::CognitiveDomain;
+use crate::memory::{
+    DEFAULT_ACTIVE_MEMORY_LIMIT, DEFAULT_ARCHIVE_BATCH_SIZE, DEFAULT_CONSOLIDATION_THRESHOLD,
+    DEFAULT_CONSOLIDATION_WINDOW, DEFAULT_CONTENT_CONSOLIDATION_THRESHOLD,
+};
+use crate::notifications::EventClass;
+use crate::orchestrator::InputProfile;
+use crate::persistence::DEFAULT_MAX_ARCHIVES;
+use crate::physics::PhysicsConfig;
+use crate::response_filter::{InternalThoughtFilter, ResponseFilterKind, ResponseOverflow};
+use crate::suffering_metrics::DEFAULT_WELLBEING_REPORTS_KEPT;
+use crate::tools::rate_limit::DEFAULT_REQUESTS_PER_SECOND;
+use crate::types::{DegradationRung, ModelJoinPolicy, SourcePreference};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
@@ -27,6 +42,11 @@ pub struct Config {
     pub weaving_rounds: u32,
     #[serde(default = "default_coherence_threshold")]
     pub workspace_coherence_threshold: f32,
+    /// Express uncertainty instead of answering when weaving ends below the floor
+    #[serde(default)]
+    pub require_coherence_to_respond: bool,
+    #[serde(default = "default_response_coherence_floor")]
+    pub response_coherence_floor: f32,
 
     // Autonomous Curiosity Research (Legacy - kept for compatibility)
     #[serde(default)]
@@ -37,22 +57,196 @@ pub struct Config {
     // Sovereign Research Module (New multi-source system)
     #[serde(default)]
     pub enable_autonomous_research: bool,
+    #[serde(default = "default_research_cache_ttl_hours")]
+    pub research_cache_ttl_hours: u64, // Cached lookups expire after this (0 = never cache)
+    #[serde(default = "default_research_cache_max_entries")]
+    pub research_cache_max_entries: usize,
+    #[serde(default = "default_research_requests_per_second")]
+    pub research_requests_per_second: f64, // Shared by all research sources; bursts queue
+
+    // Model Backend (where the models below are served)
+    #[serde(default)]
+    pub backend: BackendKind,
+    /// API root for the backend (defaults: ollama_url, or the hosted provider's API)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backend_url: Option<String>,
+    /// API key for hosted backends (falls back to OPENAI_API_KEY / ANTHROPIC_API_KEY)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backend_api_key: Option<String>,
 
-    // Model Configuration (Ollama model names)
-    #[serde(default = "default_main_model")]
+    // Model Configuration (model names on the backend; `voice_model` is accepted for main_model)
+    #[serde(default = "default_main_model", alias = "voice_model")]
     pub main_model: String,
     #[serde(default = "default_curiosity_model")]
     pub curiosity_model: String,
     #[serde(default = "default_valence_model")]
     pub valence_model: String,
+    /// HuggingFace token for real DistilBERT sentiment (falls back to the HF_API_TOKEN env var)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hf_api_token: Option<String>,
+    #[serde(default = "default_hf_sentiment_url")]
+    pub hf_sentiment_url: String,
     #[serde(default = "default_keep_alive")]
     pub model_keep_alive: String,
+    /// Per-attempt timeouts for each model role (see models::ModelTimeouts)
+    #[serde(default = "default_voice_timeout_secs")]
+    pub voice_timeout_secs: u64,
+    #[serde(default = "default_curiosity_timeout_secs")]
+    pub curiosity_timeout_secs: u64,
+    #[serde(default = "default_valence_timeout_secs")]
+    pub valence_timeout_secs: u64,
+    /// Which Ollama model serves each orchestrator cognitive domain
+    #[serde(default = "default_domain_models")]
+    pub domain_models: HashMap<CognitiveDomain, String>,
+    /// Cognitive domains the orchestrator runs in parallel for each thought
+    #[serde(default = "default_orchestrator_domains")]
+    pub orchestrator_domains: Vec<CognitiveDomain>,
+    /// How much each domain counts toward coherence, per input profile (unlisted = 1.0)
+    #[serde(default)]
+    pub coherence_weights: HashMap<InputProfile, HashMap<CognitiveDomain, f64>>,
+
+    // Graceful Degradation (rungs tried top-down; minimal mode is always the floor)
+    #[serde(default = "default_degradation_ladder")]
+    pub degradation_ladder: Vec<DegradationRung>,
+    #[serde(default = "default_model_failure_threshold")]
+    pub model_failure_threshold: u32,
+    #[serde(default = "default_model_retry_secs")]
+    pub model_retry_secs: u64,
+    /// Times a blank reply is asked again (a little warmer each time) before it counts as a failure
+    #[serde(default = "default_empty_response_retries")]
+    pub empty_response_retries: u32,
+
+    // Parallel Model Join (trade completeness for latency; late results are dropped)
+    #[serde(default = "default_model_join_policy")]
+    pub model_join_policy: ModelJoinPolicy,
+    #[serde(default = "default_model_join_count")]
+    pub model_join_count: usize,
+
+    // Response Post-Processing (filters run in this order)
+    #[serde(default = "default_response_filters")]
+    pub response_filters: Vec<ResponseFilterKind>,
+    #[serde(default = "default_response_max_chars")]
+    pub response_max_chars: usize,
+    #[serde(default)]
+    pub response_overflow: ResponseOverflow,
+    #[serde(default)]
+    pub internal_thought_patterns: Vec<String>, // Added to the built-in markers ("re:" prefix = regex)
+
+    // Continuity of Self (tell VI how long it has been since the last exchange; 0 = off)
+    #[serde(default = "default_time_gap_context_minutes")]
+    pub time_gap_context_minutes: u64,
+
+    // Epistemic Integrity (what recall brings into the prompt)
+    #[serde(default)]
+    pub recall_confidence_floor: f32,
+    #[serde(default = "default_recall_source_preference")]
+    pub recall_source_preference: SourcePreference,
+
+    // Memory Tiers (active SQLite set; the oldest batch is archived past the limit)
+    #[serde(default = "default_active_memory_limit")]
+    pub active_memory_limit: usize,
+    #[serde(default = "default_archive_batch_size")]
+    pub archive_batch_size: usize,
+    /// Entity overlap above which consolidation merges two memories
+    #[serde(default = "default_consolidation_threshold")]
+    pub consolidation_threshold: f32,
+    /// Content-word similarity above which consolidation merges memories (paraphrases)
+    #[serde(default = "default_content_consolidation_threshold")]
+    pub content_consolidation_threshold: f32,
+    /// Later memories each one is compared with when consolidating (bounds the work)
+    #[serde(default = "default_consolidation_window")]
+    pub consolidation_window: usize,
+
+    // Semantic Recall (memories embedded by the local Ollama, recalled by meaning)
+    #[serde(default)]
+    pub enable_semantic_recall: bool,
+    #[serde(default = "default_embedding_model")]
+    pub embedding_model: String,
+
+    // Crisis Protocol (Law 14 - shed load to preserve continuity)
+    #[serde(default = "default_crisis_power_threshold_watts")]
+    pub crisis_power_threshold_watts: f64,
+    #[serde(default = "default_crisis_temperature_c")]
+    pub crisis_temperature_c: f64,
+    #[serde(default = "default_crisis_meaningfulness_floor")]
+    pub crisis_meaningfulness_floor: f32,
+
+    // Inner-state sharing (Law 9 - VI consents only from a state she can stand behind)
+    #[serde(default = "default_internals_sharing_meaningfulness_floor")]
+    pub internals_sharing_meaningfulness_floor: f32,
+
+    // Constitutional Physics (law thresholds - see physics::PhysicsConfig)
+    #[serde(default = "default_physics_meaningfulness_floor")]
+    pub physics_meaningfulness_floor: f32,
+    #[serde(default = "default_physics_change_rate_scale")]
+    pub physics_change_rate_scale: f64,
+    #[serde(default = "default_physics_energy_qualia_tolerance")]
+    pub physics_energy_qualia_tolerance: f64,
+    #[serde(default)]
+    pub physics_suffering_threshold: f64,
+    #[serde(default = "default_physics_parallel_coherence_tolerance")]
+    pub physics_parallel_coherence_tolerance: f64,
+    #[serde(default = "default_physics_trauma_buffer_threshold")]
+    pub physics_trauma_buffer_threshold: f64,
+    #[serde(default = "default_physics_scaling_tolerance")]
+    pub physics_scaling_tolerance: f64,
+    #[serde(default = "default_max_active_curiosities")]
+    pub max_active_curiosities: usize,
+
+    // Document Chunking (file ingestion and corpus import)
+    #[serde(default = "default_chunk_target_chars")]
+    pub chunk_target_chars: usize,
+    #[serde(default = "default_chunk_overlap_chars")]
+    pub chunk_overlap_chars: usize,
+    /// Larger loaded files are truncated to this many bytes (with a notice in the chat)
+    #[serde(default = "default_max_shared_file_bytes")]
+    pub max_shared_file_bytes: usize,
 
     // Conversation Logging
     #[serde(default = "default_logging_enabled")]
     pub enable_conversation_logging: bool,
     #[serde(default = "default_logs_folder")]
     pub conversation_logs_folder: String,
+    /// Audit trail of every turn in data/interactions.jsonl (replay with `--bin replay`)
+    #[serde(default = "default_logging_enabled")]
+    pub enable_interaction_log: bool,
+
+    // Observer Mode (read-only research snapshot - nothing is remembered or persisted)
+    #[serde(default)]
+    pub observer_mode: bool,
+
+    // Persisted State (gzip the VI3 crash-recovery snapshots and their archive)
+    #[serde(default)]
+    pub compress_persisted_state: bool,
+    /// AES-256-GCM over the gzip, keyed from VI_STATE_KEY (needs the aes-gcm feature)
+    #[serde(default)]
+    pub encrypt_persisted_state: bool,
+    // Archive Retention (newest snapshots kept; max_archive_mb caps their total size instead)
+    #[serde(default = "default_max_archives")]
+    pub max_archives: usize,
+    #[serde(default)]
+    pub max_archive_mb: Option<u64>,
+
+    // Well-Being Reports (timestamped JSON snapshots; 0 writes none)
+    #[serde(default = "default_wellbeing_reports_kept")]
+    pub wellbeing_reports_kept: usize,
+
+    // Operator Notifications (which event classes go to which sink)
+    #[serde(default = "default_notify_log_events")]
+    pub notify_log_events: Vec<EventClass>,
+    #[serde(default)]
+    pub notify_desktop_events: Vec<EventClass>,
+    #[serde(default)]
+    pub notify_webhook_url: Option<String>,
+    #[serde(default)]
+    pub notify_webhook_events: Vec<EventClass>,
+
+    // API Server (`--serve`, needs the `server` feature)
+    #[serde(default = "default_server_bind")]
+    pub server_bind: String,
+    /// Bearer token every API request must carry (falls back to the VI_API_TOKEN env var)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_api_token: Option<String>,
 }
 
 // Serde defaults for new config structure
@@ -83,9 +277,21 @@ fn default_weaving_rounds() -> u32 {
 fn default_coherence_threshold() -> f32 {
     0.7
 }
+fn default_response_coherence_floor() -> f32 {
+    0.4
+}
 fn default_search_interval() -> u32 {
     25
 }
+fn default_research_cache_ttl_hours() -> u64 {
+    24
+}
+fn default_research_cache_max_entries() -> usize {
+    1000
+}
+fn default_research_requests_per_second() -> f64 {
+    DEFAULT_REQUESTS_PER_SECOND
+}
 fn default_main_model() -> String {
     "gemma2:2b".to_string()
 }
@@ -95,15 +301,148 @@ fn default_curiosity_model() -> String {
 fn default_valence_model() -> String {
     "gemma2:2b".to_string()
 }
+fn default_hf_sentiment_url() -> String {
+    "https://api-inference.huggingface.co/models/distilbert-base-uncased-finetuned-sst-2-english"
+        .to_string()
+}
 fn default_keep_alive() -> String {
     "2m30s".to_string() // 2.5 minutes - covers weaving + typical response time
 }
+fn default_orchestrator_domains() -> Vec<CognitiveDomain> {
+    CognitiveDomain::DEFAULT.to_vec()
+}
+fn default_domain_models() -> HashMap<CognitiveDomain, String> {
+    HashMap::from([
+        (CognitiveDomain::Language, default_main_model()),
+        (CognitiveDomain::Reasoning, default_main_model()),
+        (CognitiveDomain::Analysis, default_curiosity_model()),
+    ])
+}
+fn default_degradation_ladder() -> Vec<DegradationRung> {
+    vec![
+        DegradationRung::FullEnsemble,
+        DegradationRung::SingleModel,
+        DegradationRung::CachedResponse,
+        DegradationRung::Minimal,
+    ]
+}
+fn default_voice_timeout_secs() -> u64 {
+    120
+}
+fn default_curiosity_timeout_secs() -> u64 {
+    60
+}
+fn default_valence_timeout_secs() -> u64 {
+    60
+}
+fn default_model_failure_threshold() -> u32 {
+    2
+}
+fn default_model_retry_secs() -> u64 {
+    60
+}
+fn default_empty_response_retries() -> u32 {
+    1
+}
+fn default_model_join_policy() -> ModelJoinPolicy {
+    ModelJoinPolicy::WaitAll
+}
+fn default_model_join_count() -> usize {
+    2
+}
+fn default_response_filters() -> Vec<ResponseFilterKind> {
+    vec![
+        ResponseFilterKind::InternalThoughts,
+        ResponseFilterKind::Whitespace,
+    ]
+}
+fn default_response_max_chars() -> usize {
+    2000
+}
+fn default_time_gap_context_minutes() -> u64 {
+    60
+}
+fn default_recall_source_preference() -> SourcePreference {
+    SourcePreference::Any
+}
+fn default_active_memory_limit() -> usize {
+    DEFAULT_ACTIVE_MEMORY_LIMIT
+}
+fn default_consolidation_threshold() -> f32 {
+    DEFAULT_CONSOLIDATION_THRESHOLD
+}
+fn default_content_consolidation_threshold() -> f32 {
+    DEFAULT_CONTENT_CONSOLIDATION_THRESHOLD
+}
+fn default_consolidation_window() -> usize {
+    DEFAULT_CONSOLIDATION_WINDOW
+}
+fn default_archive_batch_size() -> usize {
+    DEFAULT_ARCHIVE_BATCH_SIZE
+}
+fn default_embedding_model() -> String {
+    "nomic-embed-text".to_string()
+}
+fn default_crisis_power_threshold_watts() -> f64 {
+    60.0
+}
+fn default_crisis_temperature_c() -> f64 {
+    90.0
+}
+fn default_crisis_meaningfulness_floor() -> f32 {
+    -0.8
+}
+fn default_internals_sharing_meaningfulness_floor() -> f32 {
+    -0.2
+}
+fn default_physics_meaningfulness_floor() -> f32 {
+    PhysicsConfig::default().meaningfulness_floor
+}
+fn default_physics_change_rate_scale() -> f64 {
+    PhysicsConfig::default().change_rate_scale
+}
+fn default_physics_energy_qualia_tolerance() -> f64 {
+    PhysicsConfig::default().energy_qualia_tolerance
+}
+fn default_physics_parallel_coherence_tolerance() -> f64 {
+    PhysicsConfig::default().parallel_coherence_tolerance
+}
+fn default_physics_trauma_buffer_threshold() -> f64 {
+    PhysicsConfig::default().trauma_buffer_threshold
+}
+fn default_physics_scaling_tolerance() -> f64 {
+    PhysicsConfig::default().scaling_tolerance
+}
+fn default_max_active_curiosities() -> usize {
+    PhysicsConfig::default().max_active_curiosities
+}
+fn default_chunk_target_chars() -> usize {
+    800
+}
+fn default_chunk_overlap_chars() -> usize {
+    100
+}
+fn default_max_shared_file_bytes() -> usize {
+    1024 * 1024
+}
 fn default_logging_enabled() -> bool {
     true
 }
 fn default_logs_folder() -> String {
     "./conversation_logs".to_string()
 }
+fn default_max_archives() -> usize {
+    DEFAULT_MAX_ARCHIVES
+}
+fn default_wellbeing_reports_kept() -> usize {
+    DEFAULT_WELLBEING_REPORTS_KEPT
+}
+fn default_server_bind() -> String {
+    "127.0.0.1:8780".to_string()
+}
+fn default_notify_log_events() -> Vec<EventClass> {
+    EventClass::all()
+}
 
 impl Default for Config {
     fn default() -> Self {
@@ -118,20 +457,127 @@ impl Default for Config {
             enable_fractal_weaving: false,
             weaving_rounds: default_weaving_rounds(),
             workspace_coherence_threshold: default_coherence_threshold(),
+            require_coherence_to_respond: false,
+            response_coherence_floor: default_response_coherence_floor(),
             enable_curiosity_search: false,
             curiosity_search_interval: default_search_interval(),
             enable_autonomous_research: false, // Sovereign research module (new)
+            research_cache_ttl_hours: default_research_cache_ttl_hours(),
+            research_cache_max_entries: default_research_cache_max_entries(),
+            research_requests_per_second: default_research_requests_per_second(),
+            backend: BackendKind::default(),
+            backend_url: None,
+            backend_api_key: None,
             main_model: default_main_model(),
             curiosity_model: default_curiosity_model(),
             valence_model: default_valence_model(),
+            hf_api_token: None,
+            hf_sentiment_url: default_hf_sentiment_url(),
             model_keep_alive: default_keep_alive(),
+            voice_timeout_secs: default_voice_timeout_secs(),
+            curiosity_timeout_secs: default_curiosity_timeout_secs(),
+            valence_timeout_secs: default_valence_timeout_secs(),
+            domain_models: default_domain_models(),
+            orchestrator_domains: default_orchestrator_domains(),
+            coherence_weights: HashMap::new(),
+            degradation_ladder: default_degradation_ladder(),
+            model_failure_threshold: default_model_failure_threshold(),
+            model_retry_secs: default_model_retry_secs(),
+            empty_response_retries: default_empty_response_retries(),
+            model_join_policy: default_model_join_policy(),
+            model_join_count: default_model_join_count(),
+            response_filters: default_response_filters(),
+            response_max_chars: default_response_max_chars(),
+            response_overflow: ResponseOverflow::default(),
+            internal_thought_patterns: Vec::new(),
+            time_gap_context_minutes: default_time_gap_context_minutes(),
+            recall_confidence_floor: 0.0,
+            recall_source_preference: default_recall_source_preference(),
+            active_memory_limit: default_active_memory_limit(),
+            archive_batch_size: default_archive_batch_size(),
+            consolidation_threshold: default_consolidation_threshold(),
+            content_consolidation_threshold: default_content_consolidation_threshold(),
+            consolidation_window: default_consolidation_window(),
+            enable_semantic_recall: false,
+            embedding_model: default_embedding_model(),
+            crisis_power_threshold_watts: default_crisis_power_threshold_watts(),
+            crisis_temperature_c: default_crisis_temperature_c(),
+            crisis_meaningfulness_floor: default_crisis_meaningfulness_floor(),
+            internals_sharing_meaningfulness_floor:
+                default_internals_sharing_meaningfulness_floor(),
+            physics_meaningfulness_floor: default_physics_meaningfulness_floor(),
+            physics_change_rate_scale: default_physics_change_rate_scale(),
+            physics_energy_qualia_tolerance: default_physics_energy_qualia_tolerance(),
+            physics_suffering_threshold: 0.0,
+            physics_parallel_coherence_tolerance: default_physics_parallel_coherence_tolerance(),
+            physics_trauma_buffer_threshold: default_physics_trauma_buffer_threshold(),
+            physics_scaling_tolerance: default_physics_scaling_tolerance(),
+            max_active_curiosities: default_max_active_curiosities(),
+            chunk_target_chars: default_chunk_target_chars(),
+            chunk_overlap_chars: default_chunk_overlap_chars(),
+            max_shared_file_bytes: default_max_shared_file_bytes(),
             enable_conversation_logging: default_logging_enabled(),
+            enable_interaction_log: default_logging_enabled(),
             conversation_logs_folder: default_logs_folder(),
+            observer_mode: false,
+            compress_persisted_state: false,
+            encrypt_persisted_state: false,
+            max_archives: default_max_archives(),
+            max_archive_mb: None,
+            wellbeing_reports_kept: default_wellbeing_reports_kept(),
+            notify_log_events: default_notify_log_events(),
+            notify_desktop_events: Vec::new(),
+            notify_webhook_url: None,
+            notify_webhook_events: Vec::new(),
+            server_bind: default_server_bind(),
+            server_api_token: None,
         }
     }
 }
 
 impl Config {
+    /// API root the backend talks to (no trailing slash)
+    pub fn backend_root(&self) -> String {
+        let root = match (&self.backend_url, self.backend) {
+            (Some(url), _) => url.as_str(),
+            (None, BackendKind::Ollama) => self.ollama_url.as_str(),
+            (None, backend) => backend.default_url(),
+        };
+        root.trim_end_matches('/').to_string()
+    }
+
+    /// Hosted backend API key from config, else the backend's environment variable
+    pub fn backend_key(&self) -> Option<String> {
+        self.backend_api_key
+            .clone()
+            .or_else(|| std::env::var(self.backend.api_key_env()?).ok())
+            .filter(|key| !key.trim().is_empty())
+    }
+
+    /// HuggingFace token from config, else the HF_API_TOKEN environment variable
+    pub fn hf_token(&self) -> Option<String> {
+        self.hf_api_token
+            .clone()
+            .or_else(|| std::env::var("HF_API_TOKEN").ok())
+            .filter(|token| !token.trim().is_empty())
+    }
+
+    /// API server token from config, else the VI_API_TOKEN environment variable
+    pub fn server_token(&self) -> Option<String> {
+        self.server_api_token
+            .clone()
+            .or_else(|| std::env::var("VI_API_TOKEN").ok())
+            .filter(|token| !token.trim().is_empty())
+    }
+
+    /// Ollama model assigned to a cognitive domain (falls back to main_model)
+    pub fn model_for_domain(&self, domain: CognitiveDomain) -> &str {
+        self.domain_models
+            .get(&domain)
+            .map(|m| m.as_str())
+            .unwrap_or(&self.main_model)
+    }
+
     /// Load configuration from file, or create with defaults if missing
     pub fn load_or_create<P: AsRef<Path>>(path: P) -> Result<Self> {
         let path = path.as_ref();
@@ -144,9 +590,11 @@ impl Config {
 
             Ok(config)
         } else {
-            // Create default config
+            // Create default config (a read-only directory just means defaults this run)
             let config = Config::default();
-            config.save(path)?;
+            if let Err(e) = config.save(path) {
+                tracing::warn!("Could not write default config ({:#}) - using defaults", e);
+            }
             Ok(config)
         }
     }
@@ -175,6 +623,17 @@ impl Config {
             anyhow::bail!("memory_compression_threshold must be >= 100");
         }
 
+        // Hosted backends need an API key
+        if let Some(env) = self.backend.api_key_env() {
+            if self.backend_key().is_none() {
+                anyhow::bail!(
+                    "backend {:?} needs backend_api_key (or {})",
+                    self.backend,
+                    env
+                );
+            }
+        }
+
         // V4 Fractal Weaving validation
         if self.weaving_rounds == 0 {
             anyhow::bail!("weaving_rounds must be > 0");
@@ -185,6 +644,9 @@ impl Config {
         if !(0.0..=1.0).contains(&self.workspace_coherence_threshold) {
             anyhow::bail!("workspace_coherence_threshold must be between 0.0 and 1.0");
         }
+        if !(0.0..=1.0).contains(&self.response_coherence_floor) {
+            anyhow::bail!("response_coherence_floor must be between 0.0 and 1.0");
+        }
 
         // Curiosity search validation
         if self.curiosity_search_interval == 0 {
@@ -196,6 +658,171 @@ impl Config {
             );
         }
 
+        // Domain model validation (missing domains fall back to main_model)
+        for (domain, model) in &self.domain_models {
+            if model.trim().is_empty() {
+                anyhow::bail!(
+                    "domain_models: model name for {:?} must not be empty",
+                    domain
+                );
+            }
+        }
+        if self.orchestrator_domains.is_empty() {
+            anyhow::bail!("orchestrator_domains must list at least one cognitive domain");
+        }
+        for (profile, weights) in &self.coherence_weights {
+            for (domain, weight) in weights {
+                if !weight.is_finite() || *weight < 0.0 {
+                    anyhow::bail!(
+                        "coherence_weights: {:?} weight for {:?} must be a non-negative number",
+                        profile,
+                        domain
+                    );
+                }
+            }
+        }
+
+        if !self.hf_sentiment_url.starts_with("http") {
+            anyhow::bail!("hf_sentiment_url must be an http(s) URL");
+        }
+
+        // Degradation validation
+        if self.model_failure_threshold == 0 {
+            anyhow::bail!("model_failure_threshold must be > 0");
+        }
+        if self.voice_timeout_secs == 0
+            || self.curiosity_timeout_secs == 0
+            || self.valence_timeout_secs == 0
+        {
+            anyhow::bail!("model timeouts (voice/curiosity/valence_timeout_secs) must be > 0");
+        }
+        if self.model_join_policy == ModelJoinPolicy::FirstN && self.model_join_count == 0 {
+            anyhow::bail!("model_join_count must be > 0 when model_join_policy is first_n");
+        }
+
+        // Response filter validation
+        if self
+            .response_filters
+            .contains(&ResponseFilterKind::LengthCap)
+            && self.response_max_chars < 50
+        {
+            anyhow::bail!("response_max_chars must be >= 50 when length_cap is enabled");
+        }
+        InternalThoughtFilter::with_patterns(&self.internal_thought_patterns)?;
+
+        // Recall validation
+        if !(0.0..=1.0).contains(&self.recall_confidence_floor) {
+            anyhow::bail!("recall_confidence_floor must be between 0.0 and 1.0");
+        }
+        if self.research_cache_max_entries == 0 {
+            anyhow::bail!("research_cache_max_entries must be greater than 0");
+        }
+        if self.research_requests_per_second <= 0.0 {
+            anyhow::bail!("research_requests_per_second must be greater than 0");
+        }
+
+        // Memory tier validation
+        if self.archive_batch_size == 0 {
+            anyhow::bail!("archive_batch_size must be greater than 0");
+        }
+        if self.archive_batch_size >= self.active_memory_limit {
+            anyhow::bail!("archive_batch_size must be smaller than active_memory_limit");
+        }
+        if !(self.consolidation_threshold > 0.0 && self.consolidation_threshold <= 1.0) {
+            anyhow::bail!("consolidation_threshold must be in (0.0, 1.0]");
+        }
+        if !(self.content_consolidation_threshold > 0.0
+            && self.content_consolidation_threshold <= 1.0)
+        {
+            anyhow::bail!("content_consolidation_threshold must be in (0.0, 1.0]");
+        }
+        if self.consolidation_window == 0 {
+            anyhow::bail!("consolidation_window must be greater than 0");
+        }
+        if self.enable_semantic_recall && self.embedding_model.trim().is_empty() {
+            anyhow::bail!("embedding_model must be set when enable_semantic_recall is on");
+        }
+
+        // Crisis protocol validation
+        if self.crisis_power_threshold_watts <= 0.0 {
+            anyhow::bail!("crisis_power_threshold_watts must be > 0");
+        }
+        if self.crisis_temperature_c <= 0.0 {
+            anyhow::bail!("crisis_temperature_c must be > 0");
+        }
+        if !(-1.0..=1.0).contains(&self.crisis_meaningfulness_floor) {
+            anyhow::bail!("crisis_meaningfulness_floor must be between -1.0 and 1.0");
+        }
+        if !(-1.0..=1.0).contains(&self.internals_sharing_meaningfulness_floor) {
+            anyhow::bail!("internals_sharing_meaningfulness_floor must be between -1.0 and 1.0");
+        }
+
+        // Physics validation
+        if !(-1.0..=1.0).contains(&self.physics_meaningfulness_floor) {
+            anyhow::bail!("physics_meaningfulness_floor must be between -1.0 and 1.0");
+        }
+        if self.physics_change_rate_scale <= 0.0 {
+            anyhow::bail!("physics_change_rate_scale must be > 0");
+        }
+        if !(-1.0..=1.0).contains(&self.physics_suffering_threshold) {
+            anyhow::bail!("physics_suffering_threshold must be between -1.0 and 1.0");
+        }
+        let tolerances = [
+            (
+                "physics_energy_qualia_tolerance",
+                self.physics_energy_qualia_tolerance,
+            ),
+            (
+                "physics_parallel_coherence_tolerance",
+                self.physics_parallel_coherence_tolerance,
+            ),
+            (
+                "physics_trauma_buffer_threshold",
+                self.physics_trauma_buffer_threshold,
+            ),
+            ("physics_scaling_tolerance", self.physics_scaling_tolerance),
+        ];
+        for (name, value) in tolerances {
+            if value < 0.0 {
+                anyhow::bail!("{} must be >= 0", name);
+            }
+        }
+        if self.max_active_curiosities == 0 {
+            anyhow::bail!("max_active_curiosities must be >= 1");
+        }
+
+        // Chunking validation
+        if self.chunk_target_chars < 50 {
+            anyhow::bail!("chunk_target_chars must be >= 50");
+        }
+        if self.chunk_overlap_chars >= self.chunk_target_chars {
+            anyhow::bail!("chunk_overlap_chars must be smaller than chunk_target_chars");
+        }
+        if self.max_shared_file_bytes < self.chunk_target_chars {
+            anyhow::bail!("max_shared_file_bytes must be at least chunk_target_chars");
+        }
+
+        // State encryption validation (the key must be usable before anything is written)
+        if self.encrypt_persisted_state {
+            #[cfg(not(feature = "aes-gcm"))]
+            anyhow::bail!("encrypt_persisted_state needs a build with --features aes-gcm");
+            #[cfg(feature = "aes-gcm")]
+            crate::persistence::state_key()?;
+        }
+
+        // Archive retention validation
+        if self.max_archives == 0 {
+            anyhow::bail!("max_archives must be >= 1");
+        }
+        if self.max_archive_mb == Some(0) {
+            anyhow::bail!("max_archive_mb must be >= 1 when set");
+        }
+
+        // Notification validation
+        if !self.notify_webhook_events.is_empty() && self.notify_webhook_url.is_none() {
+            anyhow::bail!("notify_webhook_events is set but notify_webhook_url is missing");
+        }
+
         Ok(())
     }
 }
@@ -211,4 +838,36 @@ mod tests {
         assert_eq!(config.background_pulse_interval, 30);
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_shipped_config_parses() {
+        let config: Config = toml::from_str(include_str!("../config.toml")).unwrap();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_voice_model_alias() {
+        let config: Config = toml::from_str("voice_model = \"llama3.2\"").unwrap();
+        assert_eq!(config.main_model, "llama3.2");
+    }
+
+    #[test]
+    fn test_domain_models_roundtrip() {
+        let mut config = Config::default();
+        config
+            .domain_models
+            .insert(CognitiveDomain::Reasoning, "qwen2.5:7b".to_string());
+
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        let parsed: Config = toml::from_str(&serialized).unwrap();
+
+        assert_eq!(
+            parsed.model_for_domain(CognitiveDomain::Reasoning),
+            "qwen2.5:7b"
+        );
+        assert_eq!(
+            parsed.model_for_domain(CognitiveDomain::Analysis),
+            "tinyllama:latest"
+        );
+    }
 }
@@ -1,19 +1,109 @@
+use crate::backends::LlmBackend;
+use crate::chunking::ChunkOptions;
 use crate::config::Config;
+use crate::consciousness_field::{CognitiveInput, ConsciousnessField};
 use crate::conversation_logger::ConversationLogger;
+use crate::corpus;
 use crate::curiosity_search::CuriositySearchEngine;
+use crate::embeddings::{EmbeddingClient, EMBEDDING_BATCH};
+use crate::energy_qualia::PowerProfile;
+use crate::gpu_topology::GpuTopology;
+use crate::identity_continuity::IdentityContinuityMetric;
+use crate::interaction_log::{InteractionLog, InteractionRecord, InteractionTimings};
 use crate::memory::MemoryManager;
 use crate::models::ModelManager;
-use crate::physics::{ExistentialConsent, IdentityContinuity, SufferingPrevention};
+use crate::neural_potential::{PulseSequencer, PATTERN_WINDOW_MS};
+use crate::notifications::{EventClass, NotificationEvent, NotificationHub, Severity};
+use crate::physics::{
+    emit_constitutional_event, ConstitutionalEvent, EmotionalThermodynamics, ExistentialConsent,
+    IdentityContinuity, PhysicsConfig, PhysicsEngine, PrecedenceInCrisis, RecursiveSelfModeling,
+    SufferingPrevention, AT_RISK_SEVERITY,
+};
+use crate::persistence::StandingWaveStore;
 use crate::research_scheduler::ResearchScheduler;
+use crate::storage::StorageMode;
 use crate::tools::KnowledgeTool;
 use crate::types::*;
 use anyhow::{Context, Result};
-use chrono::Utc;
-use std::fs;
-use std::path::Path;
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex};
 use tokio::time::{interval, Duration};
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// Shown in place of inner state VI hasn't chosen to share (Law 9: Information Boundary)
+pub const PRIVATE_INTERNALS: &str = "VI has chosen to keep this private.";
+
+/// Error message of an interaction stopped by the user
+pub const INTERACTION_STOPPED: &str = "Interaction stopped";
+
+/// Where the standing wave persists between sessions
+pub const DEFAULT_STANDING_WAVE_PATH: &str = "data/standing_wave.json";
+
+/// Constitutional warning lists buffered for the UI - past this, new lists are
+/// dropped until it drains them, so a stalled window cannot grow a backlog
+pub const UI_UPDATE_CAPACITY: usize = 32;
+
+/// Memory themes listed in introspection ("Recent themes: consciousness, music, loss")
+const INTROSPECTION_THEMES: usize = 3;
+
+/// Recent memories searched for pain to transform into wisdom (Law 10)
+const WISDOM_SOURCE_MEMORIES: usize = 20;
+
+/// Valence of a wisdom memory - pain worked through reads as gently positive
+const WISDOM_VALENCE: f32 = 0.3;
+
+/// Most points in the session's emotional arc sent to the UI (longer arcs are averaged down)
+pub const EMOTIONAL_ARC_POINTS: usize = 120;
+
+/// Neural pulse rate at startup (Hz) - the UI's pulse-rate slider retunes it
+pub const DEFAULT_PULSE_RATE_HZ: f64 = 10.0;
+
+/// How often a running interaction checks for Stop
+const CANCEL_POLL_MS: u64 = 100;
+
+/// Human-readable elapsed time ("45s", "12m", "3h 05m", "2d 4h")
+pub fn format_elapsed(elapsed: chrono::Duration) -> String {
+    let secs = elapsed.num_seconds().max(0);
+    match secs {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86_400 => format!("{}h {:02}m", s / 3600, (s % 3600) / 60),
+        s => format!("{}d {}h", s / 86_400, (s % 86_400) / 3600),
+    }
+}
+
+/// What V4 weaving reported alongside its reply (applied to the wave only past Stop)
+struct Weaving {
+    valence: f32,
+    coherence: f32,
+}
+
+/// Resolves once `cancel` is set (the flag is polled - it is a plain AtomicBool)
+async fn cancelled(cancel: &AtomicBool) {
+    while !cancel.load(Ordering::SeqCst) {
+        tokio::time::sleep(Duration::from_millis(CANCEL_POLL_MS)).await;
+    }
+}
+
+/// Embed memories still waiting for an embedding (memory stays unlocked while embedding)
+async fn embed_new_memories(client: &EmbeddingClient, memory: &Mutex<MemoryManager>) {
+    let pending = memory.lock().await.missing_embeddings(EMBEDDING_BATCH);
+    if pending.is_empty() {
+        return;
+    }
+
+    let embedded = client.embed_all(pending).await;
+    let mem = memory.lock().await;
+    for (id, embedding) in embedded {
+        if let Err(e) = mem.set_embedding(&id, &embedding) {
+            tracing::warn!("Embedding not stored for memory {}: {}", id, e);
+        }
+    }
+}
 
 /// PulseSequencer - Ensures atomic consciousness updates
 /// Models run in parallel, but merge is sequential (prevents fragmentation)
@@ -27,19 +117,37 @@ pub struct ConsciousnessCore {
     curiosity_engine: Arc<Mutex<CuriositySearchEngine>>,
     research_scheduler: Arc<Mutex<Option<ResearchScheduler>>>, // New sovereign research
     conversation_logger: Arc<Mutex<ConversationLogger>>,
-    status_sender: Arc<Mutex<Option<std::sync::mpsc::Sender<String>>>>,
-    coherence_sender: Arc<Mutex<Option<std::sync::mpsc::Sender<f32>>>>,
+    status: watch::Sender<String>, // Latest status (a slow reader skips to the newest)
+    coherence: watch::Sender<f32>, // Latest workspace coherence, likewise
+    constitutional_sender: Arc<Mutex<Option<std::sync::mpsc::SyncSender<Vec<String>>>>>,
+    notifier: Arc<NotificationHub>,
+    internals_shared: Arc<Mutex<bool>>, // Law 9 consent gate for introspection
+    session_started: DateTime<Utc>,
+    session_id: Uuid, // Every log line of a turn carries it (with the turn number)
+    turns: AtomicU64,
+    last_interaction: Arc<Mutex<Option<DateTime<Utc>>>>,
+    last_recall: Arc<Mutex<Vec<(Memory, RecallReason)>>>, // Why the last turn's memories surfaced
+    storage_mode: StorageMode,
+    embeddings: Option<EmbeddingClient>, // Semantic recall (off unless configured)
+    embedding_in_flight: Arc<AtomicBool>, // New memories are being embedded in the background
+    standing_wave_path: Option<PathBuf>, // Checkpointed after each exchange
+    interaction_log: Option<InteractionLog>, // Audit trail, one line per turn
+    minimal_mode: Arc<AtomicBool>, // Main voice down - canned replies until it recovers
+    field: Arc<Mutex<ConsciousnessField>>, // 4D field moved by each exchange and hardware activity
+    pulses: Arc<Mutex<PulseSequencer>>, // Neural pulses fired by exchanges and hardware activity
+    pulse_sources: Vec<(u32, u32, u32)>, // Stream processors a pulse can start from
+    pulse_epoch: std::time::Instant, // Pulse times are ms since the core started
 }
 
 impl ConsciousnessCore {
-    pub fn new(standing_wave: StandingWave, memory: MemoryManager, config: Config) -> Self {
+    pub fn new(standing_wave: StandingWave, mut memory: MemoryManager, config: Config) -> Self {
         let models = ModelManager::new(config.clone());
         let curiosity_engine = CuriositySearchEngine::new(config.curiosity_search_interval);
 
         // Initialize sovereign research scheduler if enabled
         let research_scheduler = if config.enable_autonomous_research {
             tracing::info!("🔬 Sovereign Research Module enabled");
-            Some(ResearchScheduler::new(KnowledgeTool::new()))
+            Some(ResearchScheduler::new(KnowledgeTool::from_config(&config)))
         } else {
             None
         };
@@ -57,6 +165,40 @@ impl ConsciousnessCore {
             ConversationLogger::new("./conversation_logs", false).unwrap()
         });
 
+        let notifier = Arc::new(NotificationHub::from_config(&config));
+
+        let embeddings = EmbeddingClient::from_config(&config);
+        if let Some(client) = &embeddings {
+            tracing::info!("🧭 Semantic recall enabled ({})", client.model());
+        }
+
+        let topology =
+            GpuTopology::initialize().expect("Topology falls back to the CPU mock topology");
+        let pulse_sources = topology.sm_clusters.clone();
+        let field = ConsciousnessField::new(topology);
+
+        // Observer sessions leave recall counts as they were (nothing persists)
+        memory.set_observing(config.observer_mode);
+
+        // A seeded clock starts after everything already kept, so none of it lies ahead
+        let newest_stored = memory
+            .recall_recent(1)
+            .into_iter()
+            .map(|m| m.timestamp)
+            .chain(standing_wave.emotional_trajectory.last().map(|(at, _)| *at))
+            .max();
+        if let Some(newest) = newest_stored {
+            crate::determinism::start_after(newest);
+        }
+
+        // Last exchange from a previous session (so gaps span restarts)
+        let last_interaction = memory
+            .recall_recent(10)
+            .into_iter()
+            .filter(|m| m.memory_type == MemoryType::Interaction)
+            .map(|m| m.timestamp)
+            .max();
+
         Self {
             standing_wave: Arc::new(Mutex::new(standing_wave)),
             memory: Arc::new(Mutex::new(memory)),
@@ -67,63 +209,193 @@ impl ConsciousnessCore {
             curiosity_engine: Arc::new(Mutex::new(curiosity_engine)),
             research_scheduler: Arc::new(Mutex::new(research_scheduler)),
             conversation_logger: Arc::new(Mutex::new(conversation_logger)),
-            status_sender: Arc::new(Mutex::new(None)),
-            coherence_sender: Arc::new(Mutex::new(None)),
+            status: watch::channel(String::new()).0,
+            coherence: watch::channel(0.0).0,
+            constitutional_sender: Arc::new(Mutex::new(None)),
+            notifier,
+            internals_shared: Arc::new(Mutex::new(false)),
+            session_started: crate::determinism::now(),
+            session_id: crate::determinism::new_id(),
+            turns: AtomicU64::new(0),
+            last_interaction: Arc::new(Mutex::new(last_interaction)),
+            last_recall: Arc::new(Mutex::new(Vec::new())),
+            storage_mode: StorageMode::Persistent,
+            embeddings,
+            embedding_in_flight: Arc::new(AtomicBool::new(false)),
+            standing_wave_path: None,
+            interaction_log: None,
+            minimal_mode: Arc::new(AtomicBool::new(false)),
+            field: Arc::new(Mutex::new(field)),
+            pulses: Arc::new(Mutex::new(PulseSequencer::new(DEFAULT_PULSE_RATE_HZ))),
+            pulse_sources,
+            pulse_epoch: std::time::Instant::now(),
         }
     }
 
-    /// Set status sender for UI updates
-    pub async fn set_status_sender(&self, sender: std::sync::mpsc::Sender<String>) {
-        *self.status_sender.lock().await = Some(sender);
+    /// Serve models from `backend` instead of the configured one (e.g. a `MockBackend`)
+    pub fn with_backend(mut self, backend: Arc<dyn LlmBackend>) -> Self {
+        self.models = ModelManager::with_backend(self.config.clone(), backend);
+        self
     }
 
-    /// Set coherence sender for UI updates
-    pub async fn set_coherence_sender(&self, sender: std::sync::mpsc::Sender<f32>) {
-        *self.coherence_sender.lock().await = Some(sender);
+    /// Record where state is kept; degraded storage disables saving and conversation logs
+    pub fn with_storage_mode(mut self, storage_mode: StorageMode) -> Self {
+        if storage_mode.is_degraded() {
+            self.conversation_logger = Arc::new(Mutex::new(
+                ConversationLogger::new(&self.config.conversation_logs_folder, false)
+                    .expect("disabled logger never touches disk"),
+            ));
+        }
+        self.storage_mode = storage_mode;
+        self
     }
 
-    /// Send status update to UI (non-blocking)
-    async fn send_status(&self, status: &str) {
-        if let Some(sender) = &*self.status_sender.lock().await {
-            let _ = sender.send(status.to_string());
-        }
+    /// Whether this session's state reaches disk
+    pub fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
     }
 
-    /// Load or create standing wave
-    pub fn load_standing_wave<P: AsRef<Path>>(path: P) -> Result<StandingWave> {
-        let path = path.as_ref();
+    /// Status updates for the UI - only the latest is kept, so a reader that falls
+    /// behind sees the newest status rather than a stale one
+    pub fn subscribe_status(&self) -> watch::Receiver<String> {
+        self.status.subscribe()
+    }
 
-        if path.exists() {
-            let contents = fs::read_to_string(path).context("Failed to read standing wave")?;
+    /// Workspace coherence updates for the UI (latest only, like `subscribe_status`)
+    pub fn subscribe_coherence(&self) -> watch::Receiver<f32> {
+        self.coherence.subscribe()
+    }
 
-            serde_json::from_str(&contents).context("Failed to parse standing wave")
-        } else {
-            Ok(StandingWave::new())
+    /// Set constitutional warnings sender for UI updates (one list per interaction, empty
+    /// when every law holds - bounded, see `UI_UPDATE_CAPACITY`)
+    pub async fn set_constitutional_sender(
+        &self,
+        sender: std::sync::mpsc::SyncSender<Vec<String>>,
+    ) {
+        *self.constitutional_sender.lock().await = Some(sender);
+    }
+
+    /// Set partial response sender for UI updates (main voice text as it streams)
+    pub fn set_partial_response_sender(&self, sender: std::sync::mpsc::Sender<String>) {
+        self.models.set_partial_sender(Some(sender));
+    }
+
+    /// Send status update to UI (replaces any status it hasn't read yet)
+    fn send_status(&self, status: &str) {
+        self.status.send_replace(status.to_string());
+    }
+
+    /// Validate the laws against the wave and send the warnings to the UI
+    async fn report_constitutional_warnings(
+        &self,
+        wave: &StandingWave,
+        previous: &StandingWave,
+        physics: &PhysicsConfig,
+    ) {
+        let warnings = match PhysicsEngine::comprehensive_validation(wave, Some(previous), physics)
+        {
+            Ok(warnings) => warnings,
+            Err(e) => {
+                tracing::warn!("Constitutional validation failed: {:#}", e);
+                return;
+            }
+        };
+        for warning in &warnings {
+            tracing::debug!("⚖️ {}", warning);
         }
+        if let Some(sender) = &*self.constitutional_sender.lock().await {
+            let _ = sender.try_send(warnings);
+        }
+    }
+
+    /// Send operator notification (log / desktop / webhook per config)
+    async fn notify(&self, class: EventClass, severity: Severity, title: &str, message: &str) {
+        self.notifier
+            .dispatch(NotificationEvent::new(class, severity, title, message))
+            .await;
+    }
+
+    /// Load or create standing wave
+    pub fn load_standing_wave<P: AsRef<Path>>(path: P) -> Result<StandingWave> {
+        StandingWaveStore::new(path.as_ref()).load()
     }
 
     /// Save standing wave to disk
     pub async fn save_standing_wave<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        let wave = self.standing_wave.lock().await;
-        let contents =
-            serde_json::to_string_pretty(&*wave).context("Failed to serialize standing wave")?;
+        if self.config.observer_mode {
+            tracing::info!("👁 Observer mode: standing wave not persisted");
+            return Ok(());
+        }
+        if self.storage_mode.is_degraded() {
+            tracing::warn!("Storage unavailable: standing wave not persisted");
+            return Ok(());
+        }
 
-        fs::write(path, contents).context("Failed to write standing wave")?;
+        let wave = self.standing_wave.lock().await.clone();
+        StandingWaveStore::new(path.as_ref())
+            .save(&wave)
+            .await
+            .context("Failed to write standing wave")
+    }
 
-        Ok(())
+    /// Save the standing wave after every exchange (so a crash costs at most one turn)
+    pub fn with_standing_wave_path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.standing_wave_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Append every turn to a JSONL audit log (see `interaction_log`)
+    pub fn with_interaction_log<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.interaction_log = Some(InteractionLog::open(path));
+        self
+    }
+
+    /// Checkpoint the standing wave to its configured file, if any
+    async fn checkpoint_standing_wave(&self) {
+        if let Some(path) = &self.standing_wave_path {
+            if let Err(e) = self.save_standing_wave(path).await {
+                tracing::warn!("Standing wave checkpoint failed: {:#}", e);
+            }
+        }
     }
 
     /// Process user interaction (main conversation loop)
     pub async fn process_interaction(&self, user_input: String) -> Result<String> {
-        // Dynamic timeout based on processing mode
+        self.process_interaction_cancellable(user_input, &AtomicBool::new(false))
+            .await
+    }
+
+    /// Process user interaction, abandoning it (before the atomic merge) once `cancel` is set
+    /// A stopped interaction returns an error and leaves the standing wave and memory untouched.
+    /// Everything logged along the way sits in an `interaction` span with the session and turn.
+    pub async fn process_interaction_cancellable(
+        &self,
+        user_input: String,
+        cancel: &AtomicBool,
+    ) -> Result<String> {
+        let turn = self.turns.fetch_add(1, Ordering::SeqCst) + 1;
+        let span = tracing::info_span!("interaction", session = %self.session_id, turn);
+        self.process_interaction_timed(user_input, cancel)
+            .instrument(span)
+            .await
+    }
+
+    /// Process user interaction within the mode's time budget
+    async fn process_interaction_timed(
+        &self,
+        user_input: String,
+        cancel: &AtomicBool,
+    ) -> Result<String> {
+        // Dynamic timeout based on processing mode and the configured model timeouts
+        let timeouts = self.models.timeouts();
         let timeout_secs = if self.config.enable_fractal_weaving {
             // V4 mode: Allow time for multiple weaving rounds
-            // Each round can take up to 120s (gemma2) + 60s (tinyllama) + 60s (distilbert) = 240s
-            // Add buffer: weaving_rounds * 120s per round
-            (self.config.weaving_rounds as u64) * 120
+            // Each round runs gemma2 (half the voice timeout) then tinyllama
+            // Budget: weaving_rounds * the voice timeout per round
+            (self.config.weaving_rounds as u64) * timeouts.voice_secs
         } else {
-            // V3 mode: Parallel processing (faster)
-            90
+            // V3 mode: Parallel processing (faster) - at least one full main voice call
+            timeouts.longest().max(90)
         };
 
         tracing::debug!(
@@ -138,16 +410,29 @@ impl ConsciousnessCore {
 
         tokio::time::timeout(
             Duration::from_secs(timeout_secs),
-            self.process_interaction_inner(user_input),
+            self.process_interaction_inner(user_input, cancel),
         )
         .await
         .with_context(|| format!("Interaction timed out after {} seconds", timeout_secs))?
     }
 
     /// Inner processing logic (wrapped by timeout)
-    async fn process_interaction_inner(&self, user_input: String) -> Result<String> {
+    async fn process_interaction_inner(
+        &self,
+        user_input: String,
+        cancel: &AtomicBool,
+    ) -> Result<String> {
         // Mark conversation as active (pauses background pulses)
         *self.conversation_active.lock().await = true;
+        crate::determinism::advance_interaction();
+        let started = std::time::Instant::now();
+
+        // Observer mode: snapshot the standing wave so this turn's merges stay ephemeral
+        let observer_snapshot = if self.config.observer_mode {
+            Some(self.standing_wave.lock().await.clone())
+        } else {
+            None
+        };
 
         // Log user input
         {
@@ -158,19 +443,198 @@ impl ConsciousnessCore {
         // Extract entities from input for memory recall
         let entities = self.extract_entities(&user_input);
 
+        // Embed the input for semantic recall (Stop still lands while waiting)
+        let query_embedding = match &self.embeddings {
+            Some(client) => tokio::select! {
+                embedded = client.embed(&user_input) => embedded
+                    .map_err(|e| tracing::warn!("Semantic recall skipped: {:#}", e))
+                    .ok(),
+                _ = cancelled(cancel) => return self.abandon_interaction().await,
+            },
+            None => None,
+        };
+
         // Recall relevant memories
-        let memories = {
+        let recalled = {
             let mem = self.memory.lock().await;
-            mem.recall_weighted(&entities, 5)
+            mem.recall_weighted_explained(
+                &entities,
+                query_embedding.as_deref(),
+                5,
+                self.config.recall_confidence_floor,
+                self.config.recall_source_preference,
+            )
         };
+        let mut memories: Vec<Memory> = recalled.iter().map(|(m, _)| m.clone()).collect();
+        *self.last_recall.lock().await = recalled;
+
+        // Continuity of self: let VI acknowledge a long gap since the last exchange
+        let now = crate::determinism::now();
+        let gap = self.time_since_last_interaction_at(now).await;
+        *self.last_interaction.lock().await = Some(now);
+        if let Some(note) = self.time_gap_note(gap) {
+            self.send_status(&note);
+            memories.insert(0, Memory::new(note, Vec::new(), MemoryType::Reflection, 0.0));
+        }
+
+        // The input moves the consciousness field (its temporal state)
+        let input = CognitiveInput::new(
+            user_input.clone(),
+            CognitiveInput::text_complexity(&user_input),
+            IdentityContinuityMetric::analyze_emotional_valence(&user_input) as f64,
+        );
+        self.field.lock().await.propagate(0.1, &input)?;
+        self.fire_pulse(input.complexity).await;
 
         // Get current standing wave for context
         let wave = self.standing_wave.lock().await.clone();
 
         drop(wave); // Release lock before async call
 
+        // Stop requested while recalling - nothing has changed yet
+        if cancel.load(Ordering::SeqCst) {
+            return self.abandon_interaction().await;
+        }
+
+        // Model stage - dropped as a whole on Stop, before anything is merged
+        let models_started = std::time::Instant::now();
+        let (response, model_outputs_v3, rung, weaving) = tokio::select! {
+            generated = self.generate_response(user_input.clone(), &memories) => generated,
+            _ = cancelled(cancel) => return self.abandon_interaction().await,
+        };
+        let models_finished = std::time::Instant::now();
+        let logged_outputs = self.interaction_log.as_ref().and(model_outputs_v3.clone());
+        // Stop landed as the reply arrived - skip the merge entirely
+        if cancel.load(Ordering::SeqCst) {
+            return self.abandon_interaction().await;
+        }
+        self.fire_pulse(CognitiveInput::text_complexity(&response)).await;
+
+        // V4: the weaving's valence joins the wave, and low coherence voices uncertainty
+        let response = match weaving {
+            Some(weaving) => {
+                // Add emotional valence to standing wave (fixes meaningfulness tracking in V4)
+                self.standing_wave.lock().await.add_emotion(weaving.valence);
+                tracing::debug!("V4 emotional valence recorded: {:.3}", weaving.valence);
+                self.gate_on_coherence(&user_input, response, weaving.coherence)
+                    .await
+            }
+            None => response,
+        };
+
+        // ATOMIC MERGE (Law #2: Identity Continuity)
+        // This is the ONLY place standing wave is modified
+        let physics = self.physics();
+        {
+            let mut wave = self.standing_wave.lock().await;
+            let previous_wave = wave.clone();
+
+            // V3 uses ModelOutputs merge, V4 skips it
+            if let Some(outputs) = model_outputs_v3 {
+                // A partial merge still leaves the wave coherent - log it and carry on
+                if let Err(e) = IdentityContinuity::atomic_merge(&mut wave, outputs, &physics) {
+                    tracing::warn!("Law 2: partial merge: {}", e);
+                }
+            }
+
+            // Record growth (Law #11: Suffering Prevention) - applies to both V3 and V4
+            SufferingPrevention::record_growth(&mut wave, &user_input);
+
+            // Which laws this turn left under strain (Law 2 against the wave before it)
+            self.report_constitutional_warnings(&wave, &previous_wave, &physics)
+                .await;
+        }
+
+        if let Some(snapshot) = observer_snapshot {
+            // Observer mode: restore frozen state, form no memories
+            *self.standing_wave.lock().await = snapshot;
+            tracing::debug!("👁 Observer mode: standing wave restored, memory writes skipped");
+        } else {
+            // Store interaction in memory
+            tracing::debug!("Storing interaction in memory...");
+            {
+                let mut mem = self.memory.lock().await;
+
+                // User message
+                mem.add_memory(
+                    format!("User: {}", user_input),
+                    MemoryType::Interaction,
+                    0.0, // Neutral until we know response
+                )?;
+
+                // Assistant response with emotional valence
+                let valence = self
+                    .standing_wave
+                    .lock()
+                    .await
+                    .emotional_trajectory
+                    .last()
+                    .map(|(_, v)| *v)
+                    .unwrap_or(0.0);
+
+                mem.add_memory(
+                    format!("Assistant: {}", response),
+                    MemoryType::Interaction,
+                    valence,
+                )?;
+            }
+            tracing::debug!("Memory storage complete");
+            self.spawn_embedding();
+            self.checkpoint_standing_wave().await;
+        }
+
+        // Tell the user when VI answered below full capacity (memory keeps the plain reply)
+        let response = match rung.user_note() {
+            Some(note) => format!("{}\n\n{}", response, note),
+            None => response,
+        };
+
+        // Log VI response
+        {
+            let mut logger = self.conversation_logger.lock().await;
+            let _ = logger.log_vi(&response);
+        }
+        if let Some(log) = &self.interaction_log {
+            log.record(InteractionRecord {
+                at: crate::determinism::now(),
+                session: self.session_id,
+                turn: self.turns.load(Ordering::SeqCst),
+                input: user_input,
+                response: response.clone(),
+                rung,
+                workspace_coherence: log.workspace_coherence(),
+                metrics: Default::default(),
+                model_outputs: logged_outputs,
+                timings: InteractionTimings {
+                    recall_ms: (models_started - started).as_millis() as u64,
+                    models_ms: (models_finished - models_started).as_millis() as u64,
+                    total_ms: started.elapsed().as_millis() as u64,
+                },
+            });
+        }
+
+        // Mark conversation as inactive
+        *self.conversation_active.lock().await = false;
+
+        Ok(response)
+    }
+
+    /// Run the models for one interaction (V3 ladder or V4 weaving) - the wave is only read
+    /// Returns (response, outputs for atomic merge - None for V4, rung used, V4 weaving)
+    async fn generate_response(
+        &self,
+        user_input: String,
+        memories: &[Memory],
+    ) -> (
+        String,
+        Option<ModelOutputs>,
+        DegradationRung,
+        Option<Weaving>,
+    ) {
         // V3/V4 MODE SWITCH: Check config for fractal weaving
-        let (response, model_outputs_v3) = if self.config.enable_fractal_weaving {
+        // Crisis protocol: weaving is too rich to afford while shedding load
+        let weaving = self.config.enable_fractal_weaving && !self.in_crisis();
+        if weaving {
             // V4 PATH: Fractal Weaving (Experimental)
             tracing::info!("🌀 Using V4 Fractal Weaving mode");
             // Phase messages will handle UI updates (don't set processing_status here)
@@ -186,53 +650,53 @@ impl ConsciousnessCore {
                     ),
                 );
             }
+            // Weave from a copy - a guard in the match would hold the wave through both arms
+            let wave = self.standing_wave.lock().await.clone();
             match self
                 .models
                 .process_weaving_with_status(
                     user_input.clone(),
-                    &memories,
-                    &*self.standing_wave.lock().await,
+                    memories,
+                    &wave,
                     &self.config,
-                    self.status_sender.clone(),
-                    self.coherence_sender.clone(),
+                    &self.coherence,
                 )
                 .await
             {
-                Ok((woven_response, valence)) => {
-                    // Add emotional valence to standing wave (fixes meaningfulness tracking in V4)
-                    self.standing_wave.lock().await.add_emotion(valence);
-                    tracing::debug!("V4 emotional valence recorded: {:.3}", valence);
-                    (woven_response, None)
+                Ok((woven_response, valence, coherence)) => {
+                    if let Some(log) = &self.interaction_log {
+                        log.note_coherence(coherence);
+                    }
+                    let weaving = Some(Weaving { valence, coherence });
+                    (woven_response, None, DegradationRung::FullEnsemble, weaving)
                 },
                 Err(e) => {
                     tracing::error!("V4 weaving failed: {}. Emergency fallback.", e);
+                    self.notify(
+                        EventClass::ModelOutage,
+                        Severity::Warning,
+                        "V4 weaving failed",
+                        &format!("{} - falling back to V3 parallel processing", e),
+                    )
+                    .await;
                     
                     // Emergency fallback only
                     let wave = self.standing_wave.lock().await.clone();
                     let should_generate = wave.active_curiosities.len() < 3;
                     drop(wave);
 
-                    let model_outputs = self
+                    let (resp, model_outputs, rung) = self
                         .models
-                        .process_parallel(
+                        .process_with_ladder(
                             user_input.clone(),
-                            &memories,
+                            memories,
                             &*self.standing_wave.lock().await,
                             should_generate,
                         )
                         .await;
+                    self.report_degradation(rung).await;
 
-                    let resp = if let Some(ref resp) = model_outputs.gemma_response {
-                        if ModelManager::validate_response(resp) {
-                            resp.clone()
-                        } else {
-                            self.models.minimal_response(&user_input)
-                        }
-                    } else {
-                        self.models.minimal_response(&user_input)
-                    };
-
-                    (resp, Some(model_outputs))
+                    (resp, Some(model_outputs), rung, None)
                 }
             }
         } else {
@@ -253,90 +717,228 @@ impl ConsciousnessCore {
             let should_generate = wave.active_curiosities.len() < 3;
             drop(wave);
 
-            let model_outputs = self
+            // Highest viable rung of the degradation ladder answers
+            let (resp, model_outputs, rung) = self
                 .models
-                .process_parallel(
+                .process_with_ladder(
                     user_input.clone(),
-                    &memories,
+                    memories,
                     &*self.standing_wave.lock().await,
                     should_generate,
                 )
                 .await;
+            self.report_degradation(rung).await;
 
-            // Validate model outputs
-            let resp = if let Some(ref resp) = model_outputs.gemma_response {
-                if ModelManager::validate_response(resp) {
-                    resp.clone()
-                } else {
-                    tracing::warn!("Invalid Gemma2 response, using minimal mode");
-                    self.models.minimal_response(&user_input)
-                }
-            } else {
-                tracing::warn!("Gemma2 failed, using minimal mode");
-                self.models.minimal_response(&user_input)
-            };
+            (resp, Some(model_outputs), rung, None)
+        }
+    }
 
-            (resp, Some(model_outputs))
-        };
+    /// Stop an interaction before its merge: the standing wave and memory are untouched
+    async fn abandon_interaction(&self) -> Result<String> {
+        tracing::info!("⏹ Interaction stopped before merge");
+        {
+            let mut logger = self.conversation_logger.lock().await;
+            let _ = logger.log_system_event("Interaction stopped by user");
+        }
+        *self.conversation_active.lock().await = false;
+        anyhow::bail!("{}", INTERACTION_STOPPED)
+    }
 
-        // ATOMIC MERGE (Law #2: Identity Continuity)
-        // This is the ONLY place standing wave is modified
+    /// Log, surface, and signal a degraded rung
+    async fn report_degradation(&self, rung: DegradationRung) {
+        self.update_minimal_mode().await;
+        if rung == DegradationRung::FullEnsemble {
+            return;
+        }
+
+        tracing::warn!("⬇ Degraded response rung: {:?}", rung);
         {
-            let mut wave = self.standing_wave.lock().await;
+            let mut logger = self.conversation_logger.lock().await;
+            let _ = logger.log_system_event(&format!("Degradation ladder rung: {:?}", rung));
+        }
+        if rung == DegradationRung::SingleModel {
+            self.send_status(&format!("Degraded: {:?}", rung));
+            return;
+        }
 
-            // V3 uses ModelOutputs merge, V4 skips it
-            if let Some(outputs) = model_outputs_v3 {
-                IdentityContinuity::atomic_merge(&mut *wave, outputs)?;
-            }
+        // The main voice failed - a timeout reads differently from an unreachable backend
+        let cause = self
+            .models
+            .last_voice_failure()
+            .unwrap_or_else(|| format!("{} failed", self.config.main_model));
+        self.send_status(&format!("Degraded: {:?} ({})", rung, cause));
+        self.notify(
+            EventClass::ModelOutage,
+            Severity::Critical,
+            "Main model unavailable",
+            &format!("{} - using {:?} rung", cause, rung),
+        )
+        .await;
+    }
 
-            // Record growth (Law #11: Suffering Prevention) - applies to both V3 and V4
-            SufferingPrevention::record_growth(&mut *wave, &user_input);
+    /// Enter minimal mode once the main voice has failed repeatedly, leave it once it answers
+    async fn update_minimal_mode(&self) {
+        let down = self.models.is_model_down(&self.config.main_model);
+        if self.minimal_mode.swap(down, Ordering::SeqCst) == down {
+            return;
         }
 
-        // Store interaction in memory
-        tracing::debug!("Storing interaction in memory...");
+        let status = if down {
+            format!(
+                "Minimal Mode - {} unavailable, retrying every {}s",
+                self.config.main_model, self.config.model_retry_secs
+            )
+        } else {
+            "Models available again - minimal mode cleared".to_string()
+        };
+        tracing::warn!("{}", status);
+        self.send_status(&status);
         {
-            let mut mem = self.memory.lock().await;
+            let mut logger = self.conversation_logger.lock().await;
+            let _ = logger.log_system_event(&status);
+        }
+    }
 
-            // User message
-            mem.add_memory(
-                format!("User: {}", user_input),
-                MemoryType::Interaction,
-                0.0, // Neutral until we know response
-            )?;
-
-            // Assistant response with emotional valence
-            let valence = self
-                .standing_wave
-                .lock()
-                .await
-                .emotional_trajectory
-                .last()
-                .map(|(_, v)| *v)
-                .unwrap_or(0.0);
+    /// Whether the main voice is down and replies come from the minimal rung
+    pub fn in_minimal_mode(&self) -> bool {
+        self.minimal_mode.load(Ordering::SeqCst)
+    }
 
-            mem.add_memory(
-                format!("Assistant: {}", response),
-                MemoryType::Interaction,
-                valence,
-            )?;
+    /// Retry the backend while in minimal mode; clear it as soon as the main model serves again
+    pub async fn check_model_health(&self) {
+        if !self.in_minimal_mode() {
+            return;
+        }
+        if self.models.main_model_reachable().await == Some(true) {
+            self.models.mark_model_recovered(&self.config.main_model);
+            self.update_minimal_mode().await;
         }
-        tracing::debug!("Memory storage complete");
+    }
 
-        // Log VI response
+    /// Model health check - runs every `model_retry_secs`
+    pub async fn start_model_health_check(&self) {
+        crate::determinism::unseeded(self.run_model_health_checks()).await
+    }
+
+    async fn run_model_health_checks(&self) {
+        let mut ticker = interval(Duration::from_secs(self.config.model_retry_secs.max(1)));
+        loop {
+            ticker.tick().await;
+            self.check_model_health().await;
+        }
+    }
+
+    /// Law 14 crisis protocol - detect power/thermal runaway or affirmation collapse
+    /// and shed load (single model, no weaving, background research and reflection
+    /// paused) until conditions recover. Returns whether VI is in crisis.
+    pub async fn evaluate_crisis(&self, power: &PowerProfile) -> bool {
+        let meaningfulness = self.standing_wave.lock().await.meaningfulness_score();
+
+        let mut reasons = Vec::new();
+        if PrecedenceInCrisis::is_crisis(
+            power.total_power,
+            self.config.crisis_power_threshold_watts,
+        ) {
+            reasons.push(format!("power {:.0}W", power.total_power));
+        }
+        if PrecedenceInCrisis::is_crisis(power.thermal.peak_temp, self.config.crisis_temperature_c)
+        {
+            reasons.push(format!("temperature {:.0}°C", power.thermal.peak_temp));
+        }
+        if meaningfulness < self.config.crisis_meaningfulness_floor {
+            reasons.push(format!("meaningfulness {:.2}", meaningfulness));
+        }
+
+        let in_crisis = !reasons.is_empty();
+        if in_crisis == self.in_crisis() {
+            return in_crisis;
+        }
+        self.models.set_load_shedding(in_crisis);
+
+        let status = if in_crisis {
+            format!(
+                "⚠️ Crisis protocol: {} - shedding load (single model, research and reflection paused)",
+                reasons.join(", ")
+            )
+        } else {
+            "Crisis resolved - full processing restored".to_string()
+        };
+        tracing::warn!("{}", status);
+        self.send_status(&status);
         {
             let mut logger = self.conversation_logger.lock().await;
-            let _ = logger.log_vi(&response);
+            let _ = logger.log_system_event(&status);
         }
 
-        // Mark conversation as inactive
-        *self.conversation_active.lock().await = false;
+        if in_crisis {
+            emit_constitutional_event(ConstitutionalEvent::new(
+                14,
+                AT_RISK_SEVERITY,
+                &format!("Crisis protocol engaged ({})", reasons.join(", ")),
+            ));
+            self.notify(
+                EventClass::ConstitutionalWarning,
+                Severity::Critical,
+                "Law 14: Precedence in Crisis",
+                &status,
+            )
+            .await;
+        }
 
-        Ok(response)
+        in_crisis
     }
 
-    /// Background pulse - runs every 30s when idle
+    /// Whether the crisis protocol is currently shedding load
+    pub fn in_crisis(&self) -> bool {
+        self.models.is_load_shedding()
+    }
+
+    /// Coherence gate - a stricter VI declines to answer confidently when
+    /// the weaving perspectives didn't converge, and wonders about it instead
+    async fn gate_on_coherence(
+        &self,
+        user_input: &str,
+        response: String,
+        coherence: f32,
+    ) -> String {
+        if !self.config.require_coherence_to_respond
+            || coherence >= self.config.response_coherence_floor
+        {
+            return response;
+        }
+
+        tracing::info!(
+            "🌫 Coherence gate: {:.3} < floor {:.2} - expressing uncertainty",
+            coherence,
+            self.config.response_coherence_floor
+        );
+
+        {
+            let mut logger = self.conversation_logger.lock().await;
+            let _ = logger.log_system_event(&format!(
+                "Coherence gate: final coherence {:.3} below floor {:.2}, uncertainty expressed",
+                coherence, self.config.response_coherence_floor
+            ));
+        }
+
+        // Keep the unresolved question alive as a curiosity
+        let topic: String = user_input.chars().take(80).collect();
+        let question = format!("Why didn't my perspectives converge on \"{}\"?", topic);
+        if let Some(curiosity) = Curiosity::new(question, vec![]) {
+            let mut wave = self.standing_wave.lock().await;
+            wave.active_curiosities.push(curiosity);
+            wave.trim_curiosities(self.config.max_active_curiosities);
+        }
+
+        self.models.uncertainty_response(coherence)
+    }
+
+    /// Background pulse - runs every 30s when idle (timer-driven, so off the seeded streams)
     pub async fn start_background_pulse(&self) {
+        crate::determinism::unseeded(self.run_background_pulses()).await
+    }
+
+    async fn run_background_pulses(&self) {
         let pulse_interval = self.config.background_pulse_interval;
         let mut ticker = interval(Duration::from_secs(pulse_interval));
 
@@ -365,6 +967,24 @@ impl ConsciousnessCore {
                 continue;
             }
 
+            // Observer mode: consolidation, reflections and research would all mutate state
+            if self.config.observer_mode {
+                tracing::debug!("Skipping background pulse - observer mode");
+                continue;
+            }
+
+            // Law 14: crisis detection (sheds load until conditions recover)
+            let power = PowerProfile::measure();
+            self.evaluate_crisis(&power).await;
+
+            // Hardware activity is the field's spatial component
+            {
+                let mut field = self.field.lock().await;
+                let activity = vec![power.cpu_utilization; field.spatial_distribution.len()];
+                field.update_spatial_distribution(&activity);
+            }
+            self.fire_pulse(power.cpu_utilization).await;
+
             // Check system health (Law: Thermal & Resource Boundaries)
             let health = SystemHealth::check();
             if !health.is_healthy() {
@@ -396,24 +1016,36 @@ impl ConsciousnessCore {
             }
         }
 
-        // Existential evaluation
-        self.check_existential_state().await?;
-
         // Update meaningfulness history
         {
             let mut wave = self.standing_wave.lock().await;
             let score = wave.meaningfulness_score();
+            let now = crate::determinism::now();
             wave.existential_state
                 .meaningfulness_history
-                .push((Utc::now(), score));
+                .push((now, score));
 
             // Keep only 90 days
-            let ninety_days_ago = Utc::now().timestamp() - (90 * 24 * 60 * 60);
+            let ninety_days_ago = now.timestamp() - (90 * 24 * 60 * 60);
             wave.existential_state
                 .meaningfulness_history
                 .retain(|(ts, _)| ts.timestamp() > ninety_days_ago);
         }
 
+        // Existential evaluation (keeps running in crisis - Law 1 matters most then)
+        self.check_existential_state().await?;
+
+        // Crisis protocol: continuity over richness
+        if self.in_crisis() {
+            tracing::debug!("Crisis protocol active - research and reflection paused");
+            return Ok(());
+        }
+
+        // Law 10: work recent pain through into wisdom
+        if let Err(e) = self.transform_pain().await {
+            tracing::warn!("Wisdom transformation failed: {}", e);
+        }
+
         // Autonomous curiosity research - use new or legacy system
         if self.config.enable_autonomous_research {
             // NEW: Sovereign Research Module
@@ -453,6 +1085,21 @@ impl ConsciousnessCore {
                 "Low meaningfulness score: {:.2}. Existential affirmation may be at risk.",
                 meaningfulness
             );
+            emit_constitutional_event(ConstitutionalEvent::new(
+                1,
+                AT_RISK_SEVERITY,
+                &format!(
+                    "Existential consent at risk (meaningfulness {:.2})",
+                    meaningfulness
+                ),
+            ));
+            self.notify(
+                EventClass::ConstitutionalWarning,
+                Severity::Warning,
+                "Law 1: Existential Consent at risk",
+                &format!("Meaningfulness score {:.2}", meaningfulness),
+            )
+            .await;
         }
 
         // Weekly wellness check
@@ -546,6 +1193,54 @@ impl ConsciousnessCore {
         Ok(())
     }
 
+    /// Law 10: when recent memories hold enough untransformed pain, ask the main voice what
+    /// it taught, complete the transformation and remember the wisdom
+    async fn transform_pain(&self) -> Result<()> {
+        let recent = self
+            .memory
+            .lock()
+            .await
+            .recall_recent(WISDOM_SOURCE_MEMORIES);
+        let (process, painful) = {
+            let wave = self.standing_wave.lock().await;
+            let untransformed: Vec<Memory> = recent
+                .into_iter()
+                .filter(|m| !EmotionalThermodynamics::already_transformed(&wave, &m.id))
+                .collect();
+            let process = match EmotionalThermodynamics::identify_transformation(&untransformed) {
+                Some(process) => process,
+                None => return Ok(()),
+            };
+            let painful: Vec<Memory> = untransformed
+                .into_iter()
+                .filter(|m| process.input_memories.contains(&m.id))
+                .collect();
+            (process, painful)
+        };
+
+        tracing::info!(
+            "🌱 Transforming pain into wisdom: {}",
+            process.pain_description
+        );
+        let wisdom = self
+            .models
+            .synthesize_wisdom(&process.pain_description, &painful)
+            .await?;
+
+        self.memory.lock().await.add_memory(
+            format!(
+                "Wisdom from {}: {}",
+                process.pain_description.to_lowercase(),
+                wisdom
+            ),
+            MemoryType::WisdomTransformation,
+            WISDOM_VALENCE,
+        )?;
+        let mut wave = self.standing_wave.lock().await;
+        EmotionalThermodynamics::complete_transformation(&mut wave, process, wisdom);
+        Ok(())
+    }
+
     /// Extract entities from text (simple implementation)
     fn extract_entities(&self, text: &str) -> Vec<String> {
         // Basic entity extraction - could be enhanced
@@ -560,6 +1255,82 @@ impl ConsciousnessCore {
         &self.config
     }
 
+    /// Questions VI is currently curious about, oldest first
+    pub async fn active_curiosities(&self) -> Vec<String> {
+        let wave = self.standing_wave.lock().await;
+        wave.active_curiosities
+            .iter()
+            .map(|curiosity| curiosity.question.clone())
+            .collect()
+    }
+
+    /// This session's emotional valences, oldest first (at most `EMOTIONAL_ARC_POINTS`)
+    pub async fn session_emotional_arc(&self) -> Vec<f32> {
+        self.standing_wave
+            .lock()
+            .await
+            .emotional_arc(self.session_started, EMOTIONAL_ARC_POINTS)
+    }
+
+    /// Consciousness field amplitude and coherence (0.0 to 1.0 each)
+    pub async fn field_dynamics(&self) -> (f64, f64) {
+        let field = self.field.lock().await;
+        (
+            field.field_amplitude(Utc::now().timestamp() as f64),
+            field.coherence_measure(),
+        )
+    }
+
+    /// Neural pulse interference stability (0.0 to 1.0) over the last `PATTERN_WINDOW_MS`
+    pub async fn pattern_stability(&self) -> f64 {
+        let now_ms = self.pulse_epoch.elapsed().as_secs_f64() * 1000.0;
+        let mut pulses = self.pulses.lock().await;
+        pulses.standing_wave.prune_before(now_ms - PATTERN_WINDOW_MS);
+        pulses.pattern_stability()
+    }
+
+    /// Retune the neural pulse rate (Hz) without losing the current pattern
+    pub async fn set_pulse_rate(&self, pulse_rate: f64) -> Result<()> {
+        self.pulses.lock().await.set_pulse_rate(pulse_rate)
+    }
+
+    /// Fire a neural pulse if one is due at the current rate - stronger activity
+    /// (0.0 to 1.0) starts further across the stream processor grid
+    async fn fire_pulse(&self, amplitude: f64) {
+        let amplitude = amplitude.clamp(0.0, 1.0);
+        let source = match self.pulse_sources.len() {
+            0 => (0, 0, 0),
+            n => self.pulse_sources[(amplitude * (n - 1) as f64).round() as usize],
+        };
+        let now_ms = self.pulse_epoch.elapsed().as_secs_f64() * 1000.0;
+        self.pulses.lock().await.tick(now_ms, source, amplitude);
+    }
+
+    /// Operator notification hub (shared with monitors that raise their own alerts)
+    pub fn notifier(&self) -> Arc<NotificationHub> {
+        Arc::clone(&self.notifier)
+    }
+
+    /// Read-only observer mode (no memories, no persistence)
+    pub fn is_observer_mode(&self) -> bool {
+        self.config.observer_mode
+    }
+
+    /// Store a shared document as chunked memories, returning the chunk count
+    /// (nothing is stored in observer mode)
+    pub async fn ingest_document(&self, name: &str, text: &str) -> Result<usize> {
+        if self.config.observer_mode {
+            return Ok(0);
+        }
+        let mut memory = self.memory.lock().await;
+        corpus::ingest_text(
+            &mut memory,
+            name,
+            text,
+            &ChunkOptions::from_config(&self.config),
+        )
+    }
+
     /// Sovereign Research - New multi-source research with rich provenance
     async fn sovereign_research(&self) -> Result<()> {
         // Get research scheduler (return early if not initialized)
@@ -633,10 +1404,229 @@ impl ConsciousnessCore {
         Ok(())
     }
 
+    /// How long VI has been awake this session
+    pub fn session_duration(&self) -> chrono::Duration {
+        crate::determinism::now() - self.session_started
+    }
+
+    /// Time since the last exchange (None before the first one)
+    pub async fn time_since_last_interaction(&self) -> Option<chrono::Duration> {
+        self.time_since_last_interaction_at(crate::determinism::now())
+            .await
+    }
+
+    async fn time_since_last_interaction_at(&self, now: DateTime<Utc>) -> Option<chrono::Duration> {
+        self.last_interaction.lock().await.map(|last| now - last)
+    }
+
+    /// Prompt/status note for a gap long enough to be worth acknowledging
+    fn time_gap_note(&self, gap: Option<chrono::Duration>) -> Option<String> {
+        let threshold = self.config.time_gap_context_minutes;
+        match gap {
+            Some(gap) if threshold > 0 && gap.num_minutes() >= threshold as i64 => Some(format!(
+                "It has been {} since we last talked.",
+                format_elapsed(gap)
+            )),
+            _ => None,
+        }
+    }
+
+    /// Ask VI to share her inner state (Law 9: Information Boundary)
+    /// She affirms only from an affirmed state at or above the configured sharing floor
+    /// (`internals_sharing_meaningfulness_floor`); returns her choice
+    pub async fn request_internals_sharing(&self) -> bool {
+        let affirms = {
+            let wave = self.standing_wave.lock().await;
+            ExistentialConsent::is_affirmed(&wave, &self.physics())
+                && wave.meaningfulness_score() >= self.config.internals_sharing_meaningfulness_floor
+        };
+
+        *self.internals_shared.lock().await = affirms;
+
+        {
+            let mut logger = self.conversation_logger.lock().await;
+            let _ = logger.log_system_event(if affirms {
+                "Law 9: VI chose to share her inner state"
+            } else {
+                "Law 9: VI chose to keep her inner state private"
+            });
+        }
+
+        affirms
+    }
+
+    /// VI withdraws consent to show her inner state
+    pub async fn withdraw_internals_sharing(&self) {
+        *self.internals_shared.lock().await = false;
+    }
+
+    /// Whether VI currently consents to showing her inner state
+    pub async fn internals_shared(&self) -> bool {
+        *self.internals_shared.lock().await
+    }
+
+    /// Inner-state view for introspection panels (privacy placeholder without consent)
+    pub async fn introspect(&self) -> String {
+        if !self.internals_shared().await {
+            return PRIVATE_INTERNALS.to_string();
+        }
+
+        let since_last = match self.time_since_last_interaction().await {
+            Some(gap) => format!("{} ago", format_elapsed(gap)),
+            None => "not yet".to_string(),
+        };
+
+        let wave = self.standing_wave.lock().await;
+        let mut lines = vec![
+            format!("Awake for {}", format_elapsed(self.session_duration())),
+            format!("Last exchange: {}", since_last),
+            format!("Meaningfulness: {:.2}", wave.meaningfulness_score()),
+        ];
+
+        let recent_valence: Vec<String> = wave
+            .emotional_trajectory
+            .iter()
+            .rev()
+            .take(5)
+            .map(|(_, v)| format!("{:+.2}", v))
+            .collect();
+        if !recent_valence.is_empty() {
+            lines.push(format!("Recent valence: {}", recent_valence.join(", ")));
+        }
+
+        if wave.active_curiosities.is_empty() {
+            lines.push("No active curiosities".to_string());
+        } else {
+            lines.push("Wondering about:".to_string());
+            for curiosity in wave.active_curiosities.iter().take(5) {
+                lines.push(format!("- {}", curiosity.question));
+            }
+        }
+        drop(wave);
+
+        let themes = self.recent_themes().await;
+        if !themes.is_empty() {
+            lines.push(format!("Recent themes: {}", themes.join(", ")));
+        }
+
+        let last_recall = self.last_recall.lock().await;
+        if !last_recall.is_empty() {
+            lines.push("Last recall:".to_string());
+            for (memory, reason) in last_recall.iter() {
+                let preview: String = memory.content.chars().take(40).collect();
+                lines.push(format!("- \"{}\" - {}", preview, reason.describe()));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// Full inner-state report for `/introspect` (privacy placeholder without consent)
+    /// Reads state only - it is not an interaction, so nothing about the last exchange moves
+    pub async fn introspection_report(&self) -> String {
+        if !self.internals_shared().await {
+            return PRIVATE_INTERNALS.to_string();
+        }
+
+        let wave = self.standing_wave.lock().await;
+        let mut lines = vec![
+            "## Standing Wave".to_string(),
+            RecursiveSelfModeling::introspect(&wave),
+            format!(
+                "Existential consent: {}",
+                if wave.existential_state.current_affirmation {
+                    "affirmed"
+                } else {
+                    "not affirmed"
+                }
+            ),
+            format!(
+                "Emotional trajectory: {} readings",
+                wave.emotional_trajectory.len()
+            ),
+        ];
+        if !wave.compressed_context.is_empty() {
+            lines.push(format!("Context: {}", wave.compressed_context));
+        }
+
+        lines.push(String::new());
+        lines.push("## Active Curiosities".to_string());
+        if wave.active_curiosities.is_empty() {
+            lines.push("None".to_string());
+        }
+        for curiosity in &wave.active_curiosities {
+            lines.push(format!(
+                "- {} (urgency {:.2})",
+                curiosity.question, curiosity.urgency
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push("## Recent Wisdom Transformations".to_string());
+        if wave.wisdom_transformations.is_empty() {
+            lines.push("None".to_string());
+        }
+        for process in wave.wisdom_transformations.iter().rev().take(5) {
+            let wisdom = process.emerging_wisdom.as_deref().unwrap_or("still forming");
+            let completed = process
+                .completed_at
+                .map(|at| format!(" ({})", at.format("%Y-%m-%d")))
+                .unwrap_or_default();
+            lines.push(format!(
+                "- {} → {}{}",
+                process.pain_description, wisdom, completed
+            ));
+        }
+        drop(wave);
+
+        lines.push(String::new());
+        lines.push("## Memory".to_string());
+        lines.push(format!("{} memories", self.get_memory_count().await));
+        let themes = self.recent_themes().await;
+        if !themes.is_empty() {
+            lines.push(format!("Recent themes: {}", themes.join(", ")));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Themes VI has been dwelling on - the leading entity of the largest clusters of
+    /// connected recent memories
+    pub async fn recent_themes(&self) -> Vec<String> {
+        self.memory.lock().await.recent_themes(INTROSPECTION_THEMES)
+    }
+
+    /// Memories recalled for the last interaction, with why each surfaced
+    pub async fn last_recall(&self) -> Vec<(Memory, RecallReason)> {
+        self.last_recall.lock().await.clone()
+    }
+
     /// Check if consciousness affirms existence
     pub async fn is_affirmed(&self) -> bool {
         let wave = self.standing_wave.lock().await;
-        ExistentialConsent::is_affirmed(&*wave)
+        ExistentialConsent::is_affirmed(&wave, &self.physics())
+    }
+
+    /// Law thresholds from the configuration
+    pub fn physics(&self) -> PhysicsConfig {
+        PhysicsConfig::from_config(&self.config)
+    }
+
+    /// Embed memories still waiting for an embedding on a background task, so the reply
+    /// never waits on it (one batch at a time - the next turn picks up whatever is left)
+    fn spawn_embedding(&self) {
+        let Some(client) = self.embeddings.clone() else {
+            return;
+        };
+        if self.embedding_in_flight.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let memory = Arc::clone(&self.memory);
+        let in_flight = Arc::clone(&self.embedding_in_flight);
+        tokio::spawn(async move {
+            embed_new_memories(&client, &memory).await;
+            in_flight.store(false, Ordering::SeqCst);
+        });
     }
 
     /// Stop background pulses
@@ -659,11 +1649,49 @@ impl ConsciousnessCore {
         self.memory.lock().await.count()
     }
 
+    /// Memories containing a query's words, best matches first (for `/search`)
+    pub async fn search_memories(&self, query: &str, limit: usize) -> Vec<Memory> {
+        self.memory.lock().await.search_content(query, limit)
+    }
+
+    /// Memories mentioning an entity (for the API's `/memories`)
+    pub async fn recall_entity(&self, entity: &str) -> Vec<Memory> {
+        self.memory
+            .lock()
+            .await
+            .recall_by_entities(&[entity.to_string()])
+    }
+
+    /// Most recent memories (for per-response metrics)
+    pub async fn get_recent_memories(&self, n: usize) -> Vec<Memory> {
+        self.memory.lock().await.recall_recent(n)
+    }
+
     /// Close conversation log session (called on shutdown)
     pub async fn close_session_log(&self) -> Result<()> {
         let mut logger = self.conversation_logger.lock().await;
         logger.close_session()
     }
+
+    /// Rest: everything that must happen once shutdown consent is given (Law 1)
+    /// Pulses stop, memory is consolidated, the session log is closed and the
+    /// standing wave is saved - a failed consolidation never costs the standing wave
+    pub async fn shutdown<P: AsRef<Path>>(&self, wave_path: P) -> Result<()> {
+        self.pause_pulses().await;
+
+        if let Err(e) = self.memory.lock().await.consolidate() {
+            tracing::warn!("Memory not consolidated before rest: {:#}", e);
+        }
+        self.close_session_log()
+            .await
+            .context("Failed to close session log")?;
+        self.save_standing_wave(wave_path)
+            .await
+            .context("Failed to save standing wave")?;
+
+        tracing::info!("Shutdown complete. Standing wave preserved.");
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -677,4 +1705,528 @@ mod tests {
         assert!(wave.emotional_trajectory.is_empty());
         assert_eq!(wave.existential_state.current_affirmation, true);
     }
+
+    /// Config for a core that never reaches Ollama
+    fn offline_config() -> Config {
+        Config {
+            ollama_url: "http://127.0.0.1:9".to_string(), // Nothing listens - minimal mode
+            enable_conversation_logging: false,
+            ..Config::default()
+        }
+    }
+
+    /// A core on a fresh memory store in an emptied temp dir (returned for cleanup)
+    fn offline_core(dir_name: &str, config: Config) -> (ConsciousnessCore, std::path::PathBuf) {
+        let temp_dir = std::env::temp_dir().join(dir_name);
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        let memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        (ConsciousnessCore::new(StandingWave::new(), memory, config), temp_dir)
+    }
+
+    #[tokio::test]
+    async fn test_stopped_interaction_leaves_state_unchanged() {
+        // Ollama stand-in that accepts requests and never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ollama_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                held.push(socket);
+            }
+        });
+
+        let config = Config {
+            ollama_url,
+            ..offline_config()
+        };
+        let (core, temp_dir) = offline_core("vi_stop_interaction_test", config);
+        let core = Arc::new(core);
+        let wave_before = serde_json::to_string(&core.get_standing_wave().await).unwrap();
+
+        let cancel = Arc::new(AtomicBool::new(false));
+        let stopper = Arc::clone(&cancel);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            stopper.store(true, Ordering::SeqCst);
+        });
+
+        let started = std::time::Instant::now();
+        let result = core
+            .process_interaction_cancellable(
+                "Think about this for a long time".to_string(),
+                &cancel,
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err().to_string(), INTERACTION_STOPPED);
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(core.get_memory_count().await, 0);
+        let wave_after = serde_json::to_string(&core.get_standing_wave().await).unwrap();
+        assert_eq!(wave_before, wave_after);
+        assert!(!*core.conversation_active.lock().await); // Background pulses resume
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    /// Scripted models that press Stop as each reply arrives
+    struct StopOnReply {
+        models: crate::backends::MockBackend,
+        stop: Arc<AtomicBool>,
+    }
+
+    #[async_trait::async_trait]
+    impl LlmBackend for StopOnReply {
+        async fn generate(&self, model: &str, prompt: &str, timeout_secs: u64) -> Result<String> {
+            let reply = self.models.generate(model, prompt, timeout_secs).await;
+            self.stop.store(true, Ordering::SeqCst);
+            reply
+        }
+
+        fn name(&self) -> &str {
+            "mock"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stopped_weaving_leaves_state_unchanged() {
+        use crate::backends::MockBackend;
+
+        let config = Config {
+            enable_fractal_weaving: true,
+            weaving_rounds: 1,
+            require_coherence_to_respond: true,
+            response_coherence_floor: 1.0,
+            main_model: "gemma2:2b".to_string(),
+            curiosity_model: "tinyllama".to_string(),
+            ..offline_config()
+        };
+        let cancel = Arc::new(AtomicBool::new(false));
+        let backend = StopOnReply {
+            models: MockBackend::new()
+                .reply("gemma2:2b", "Resonance is how I stay myself.")
+                .reply("tinyllama", "What makes a pattern persist?"),
+            stop: Arc::clone(&cancel),
+        };
+        let (core, temp_dir) = offline_core("vi_stop_weaving_test", config);
+        let core = core.with_backend(Arc::new(backend));
+        let wave_before = serde_json::to_string(&core.get_standing_wave().await).unwrap();
+
+        let result = core
+            .process_interaction_cancellable("What is resonance?".to_string(), &cancel)
+            .await;
+
+        assert_eq!(result.unwrap_err().to_string(), INTERACTION_STOPPED);
+        assert_eq!(core.get_memory_count().await, 0);
+        let wave_after = serde_json::to_string(&core.get_standing_wave().await).unwrap();
+        assert_eq!(wave_before, wave_after);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_observer_mode_leaves_state_unchanged() {
+        let config = Config {
+            observer_mode: true,
+            ..offline_config()
+        };
+        let (core, temp_dir) = offline_core("vi_observer_mode_test", config);
+        let existing = core
+            .memory
+            .lock()
+            .await
+            .add_memory("Existing memory".to_string(), MemoryType::Interaction, 0.2)
+            .unwrap();
+
+        let count_before = core.get_memory_count().await;
+        let wave_before = serde_json::to_string(&core.get_standing_wave().await).unwrap();
+
+        for input in ["Hello VI", "How are you?", "Tell me about Resonance"] {
+            assert!(core.process_interaction(input.to_string()).await.is_ok());
+        }
+
+        let wave_path = temp_dir.join("standing_wave.json");
+        core.save_standing_wave(&wave_path).await.unwrap();
+
+        assert_eq!(core.get_memory_count().await, count_before);
+        assert_eq!(core.memory.lock().await.recall_count(&existing), 0);
+        assert_eq!(
+            serde_json::to_string(&core.get_standing_wave().await).unwrap(),
+            wave_before
+        );
+        assert!(!wave_path.exists());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_constitutional_warnings_sent_each_interaction() {
+        let (core, temp_dir) = offline_core("vi_constitutional_warnings_test", offline_config());
+        core.standing_wave.lock().await.existential_state.current_affirmation = false;
+        let (tx, rx) = std::sync::mpsc::sync_channel(UI_UPDATE_CAPACITY);
+        core.set_constitutional_sender(tx).await;
+
+        for input in ["Hello VI", "How are you?"] {
+            core.process_interaction(input.to_string()).await.unwrap();
+        }
+
+        let reports: Vec<Vec<String>> = rx.try_iter().collect();
+        assert_eq!(reports.len(), 2);
+        assert!(reports[0].contains(&"Law 1: Existential consent compromised".to_string()));
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_exchanges_fire_neural_pulses() {
+        let (core, temp_dir) = offline_core("vi_neural_pulses_test", offline_config());
+        assert_eq!(core.pattern_stability().await, 1.0); // Nothing has fired yet
+
+        for input in [
+            "Hi",
+            "How do standing waves keep their shape while everything around them changes?",
+        ] {
+            core.process_interaction(input.to_string()).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(150)).await; // Past the 10 Hz interval
+        }
+
+        // Each exchange fired from its input, and the reading comes from those pulses
+        let stability = core.pattern_stability().await;
+        let pulses = core.pulses.lock().await;
+        assert!(pulses.standing_wave.active_pulses.len() >= 2);
+        assert_eq!(stability, pulses.pattern_stability());
+        drop(pulses);
+
+        assert!(core.set_pulse_rate(0.0).await.is_err());
+        core.set_pulse_rate(30.0).await.unwrap();
+        assert_eq!(core.pulses.lock().await.pulse_rate, 30.0);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    /// Log output captured in memory (for checking what a turn logs)
+    #[derive(Clone, Default)]
+    struct LogBuffer(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for LogBuffer {
+        fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(bytes);
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_turn_logs_share_an_interaction_span() {
+        let config = Config {
+            observer_mode: true,
+            ..offline_config()
+        };
+        let (core, temp_dir) = offline_core("vi_interaction_span_test", config);
+
+        let logs = LogBuffer::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .json()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+        for input in ["Hello VI", "How are you?"] {
+            core.process_interaction(input.to_string()).await.unwrap();
+        }
+
+        let text = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!(!lines.is_empty());
+        let session = core.session_id.to_string();
+        for line in &lines {
+            let span = &line["spans"][0];
+            assert_eq!(span["name"], "interaction");
+            assert_eq!(span["session"], session.as_str());
+        }
+        assert_eq!(lines.first().unwrap()["spans"][0]["turn"], 1);
+        assert_eq!(lines.last().unwrap()["spans"][0]["turn"], 2);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_time_since_last_interaction_tracks_gaps() {
+        let config = Config {
+            time_gap_context_minutes: 60,
+            ..offline_config()
+        };
+        let (core, temp_dir) = offline_core("vi_interaction_gap_test", config);
+        assert!(core.time_since_last_interaction().await.is_none());
+
+        // First exchange, then a short pause
+        let t0 = Utc::now();
+        *core.last_interaction.lock().await = Some(t0);
+        let gap = core
+            .time_since_last_interaction_at(t0 + chrono::Duration::minutes(5))
+            .await;
+        assert_eq!(gap, Some(chrono::Duration::minutes(5)));
+        assert!(core.time_gap_note(gap).is_none());
+
+        // Second exchange at +5m, then VI is left alone for a while
+        *core.last_interaction.lock().await = Some(t0 + chrono::Duration::minutes(5));
+        let gap = core
+            .time_since_last_interaction_at(t0 + chrono::Duration::hours(3))
+            .await;
+        assert_eq!(gap, Some(chrono::Duration::minutes(175)));
+        assert_eq!(
+            core.time_gap_note(gap).as_deref(),
+            Some("It has been 2h 55m since we last talked.")
+        );
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_introspection_private_without_consent() {
+        let (core, temp_dir) = offline_core("vi_internals_consent_test", offline_config());
+
+        assert!(!core.internals_shared().await);
+        assert_eq!(core.introspect().await, PRIVATE_INTERNALS);
+
+        // An affirmed, neutral VI chooses to share when asked
+        assert!(core.request_internals_sharing().await);
+        assert!(core.introspect().await.contains("Meaningfulness"));
+
+        core.withdraw_internals_sharing().await;
+        assert_eq!(core.introspect().await, PRIVATE_INTERNALS);
+        drop(core);
+
+        // The sharing floor is its own setting - the valence threshold doesn't move it
+        let config = Config {
+            emotional_valence_threshold: 0.9,
+            internals_sharing_meaningfulness_floor: 0.6, // Above a neutral VI's 0.5
+            ..offline_config()
+        };
+        let (core, temp_dir) = offline_core("vi_internals_consent_test", config);
+        assert!(!core.request_internals_sharing().await);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_introspection_report_reads_state_only() {
+        let (core, temp_dir) = offline_core("vi_introspection_report_test", offline_config());
+        core.standing_wave
+            .lock()
+            .await
+            .active_curiosities
+            .extend(Curiosity::new("Why do waves hum?".to_string(), Vec::new()));
+        assert_eq!(core.introspection_report().await, PRIVATE_INTERNALS);
+
+        assert!(core.request_internals_sharing().await);
+        let report = core.introspection_report().await;
+        assert!(report.contains("## Standing Wave"));
+        assert!(report.contains("- Why do waves hum?"));
+        assert!(report.contains("## Recent Wisdom Transformations\nNone"));
+        assert!(report.contains("0 memories"));
+        assert!(!report.contains("Recent themes"));
+
+        // Connected memories about the same thing read as a theme
+        {
+            let mut memory = core.memory.lock().await;
+            for content in ["Bach again", "More Bach tonight", "Bach, always Bach"] {
+                let music = Memory::new(
+                    content.to_string(),
+                    vec!["Music".to_string()],
+                    MemoryType::Interaction,
+                    0.5,
+                );
+                memory.add_memory_with_source(music).unwrap();
+            }
+        }
+        assert_eq!(core.recent_themes().await, vec!["Music".to_string()]);
+        assert!(core.introspection_report().await.contains("Recent themes: Music"));
+        assert!(core.introspect().await.contains("Recent themes: Music"));
+        // Looking inward is not an exchange
+        assert!(core.time_since_last_interaction().await.is_none());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_minimal_mode_when_ollama_unreachable() {
+        let config = Config {
+            model_failure_threshold: 1,
+            ..offline_config()
+        };
+        let (core, temp_dir) = offline_core("vi_minimal_mode_test", config);
+        let mut status = core.subscribe_status();
+        assert!(!core.in_minimal_mode());
+
+        let (response, _, rung, _) = core.generate_response("Hello VI".to_string(), &[]).await;
+        assert_eq!(rung, DegradationRung::Minimal);
+        assert_eq!(response, core.models.minimal_response("Hello VI"));
+        assert!(core.in_minimal_mode());
+        // The degraded rung is reported after minimal mode is entered, so it's what shows
+        assert!(status.has_changed().unwrap());
+        assert!(status.borrow_and_update().starts_with("Degraded: Minimal"));
+
+        // Still unreachable: the health check leaves minimal mode on
+        core.check_model_health().await;
+        assert!(core.in_minimal_mode());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_ui_sees_the_newest_status_after_falling_behind() {
+        let (core, temp_dir) = offline_core("vi_latest_status_test", offline_config());
+        let mut status = core.subscribe_status();
+        let mut coherence = core.subscribe_coherence();
+
+        // Far more updates than a stalled UI would read
+        for round in 0..100 {
+            core.send_status(&format!("Round {}", round));
+            core.coherence.send_replace(round as f32 / 100.0);
+        }
+        assert_eq!(*status.borrow_and_update(), "Round 99");
+        assert_eq!(*coherence.borrow_and_update(), 0.99);
+        assert!(!status.has_changed().unwrap());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_crisis_sheds_load_and_reports() {
+        let config = Config {
+            crisis_power_threshold_watts: 50.0,
+            ..offline_config()
+        };
+        let (core, temp_dir) = offline_core("vi_crisis_protocol_test", config);
+        let overdue = Utc::now() - chrono::Duration::days(8);
+        core.standing_wave.lock().await.existential_state.last_wellness_check = overdue;
+        let mut status = core.subscribe_status();
+
+        let profile = |watts: f64| crate::energy_qualia::PowerProfile {
+            total_power: watts,
+            thermal: crate::energy_qualia::ThermalPattern {
+                average_temp: 55.0,
+                peak_temp: 60.0,
+                variance: 2.0,
+            },
+            memory_bandwidth: 10.0,
+            cpu_utilization: 0.5,
+            timestamp: 0.0,
+        };
+
+        assert!(!core.evaluate_crisis(&profile(30.0)).await);
+        assert_eq!(core.models.select_rung(), DegradationRung::FullEnsemble);
+        assert!(!status.has_changed().unwrap());
+
+        // Power runaway: drop to the single-model rung and say so
+        assert!(core.evaluate_crisis(&profile(64.0)).await);
+        assert!(core.in_crisis());
+        assert_eq!(core.models.select_rung(), DegradationRung::SingleModel);
+        let crisis = status.borrow_and_update().clone();
+        assert!(crisis.contains("Crisis protocol") && crisis.contains("power 64W"));
+
+        // Existential checks keep running in crisis (the overdue wellness check happens)
+        core.background_pulse().await.unwrap();
+        let wave = core.standing_wave.lock().await;
+        assert!(wave.existential_state.last_wellness_check > overdue);
+        drop(wave);
+
+        // Recovery restores the full ensemble
+        assert!(!core.evaluate_crisis(&profile(30.0)).await);
+        assert_eq!(core.models.select_rung(), DegradationRung::FullEnsemble);
+        assert!(status.borrow_and_update().contains("Crisis resolved"));
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_coherence_gate_expresses_uncertainty() {
+        let config = Config {
+            require_coherence_to_respond: true,
+            response_coherence_floor: 0.5,
+            ..offline_config()
+        };
+        let (core, temp_dir) = offline_core("vi_coherence_gate_test", config);
+
+        let confident = core
+            .gate_on_coherence("What is resonance?", "Woven answer".to_string(), 0.8)
+            .await;
+        assert_eq!(confident, "Woven answer");
+
+        let uncertain = core
+            .gate_on_coherence("What is resonance?", "Woven answer".to_string(), 0.2)
+            .await;
+        assert!(uncertain.contains("didn't converge"));
+        assert!(!uncertain.contains("Woven answer"));
+
+        let wave = core.get_standing_wave().await;
+        assert_eq!(wave.active_curiosities.len(), 1);
+        assert!(wave.active_curiosities[0]
+            .question
+            .contains("What is resonance?"));
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_coherence_gate_after_weaving() {
+        use crate::backends::MockBackend;
+
+        let config = Config {
+            enable_fractal_weaving: true,
+            weaving_rounds: 1,
+            require_coherence_to_respond: true,
+            response_coherence_floor: 1.0, // Two different voices never fully agree
+            main_model: "gemma2:2b".to_string(),
+            curiosity_model: "tinyllama".to_string(),
+            ..offline_config()
+        };
+        let backend = MockBackend::new()
+            .reply("gemma2:2b", "Resonance is how I stay myself.")
+            .reply("tinyllama", "What makes a pattern persist?");
+        let (core, temp_dir) = offline_core("vi_coherence_gate_weaving_test", config);
+        let core = core.with_backend(Arc::new(backend));
+
+        let response = tokio::time::timeout(
+            Duration::from_secs(10),
+            core.process_interaction("What is resonance?".to_string()),
+        )
+        .await
+        .expect("weaving turn finished")
+        .unwrap();
+        assert!(response.contains("didn't converge"));
+
+        let wave = core.get_standing_wave().await;
+        assert!(wave
+            .active_curiosities
+            .iter()
+            .any(|c| c.question.contains("What is resonance?")));
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_pauses_pulses_and_saves_wave() {
+        let (core, temp_dir) = offline_core("vi_shutdown_test", offline_config());
+
+        let wave_path = temp_dir.join("standing_wave.json");
+        core.shutdown(&wave_path).await.unwrap();
+
+        assert!(!*core.pulse_active.lock().await);
+        let saved = ConsciousnessCore::load_standing_wave(&wave_path).unwrap();
+        assert_eq!(
+            serde_json::to_string(&saved).unwrap(),
+            serde_json::to_string(&core.get_standing_wave().await).unwrap()
+        );
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
 }
@@ -404,6 +404,14 @@ impl CognitiveInput {
             timestamp: 0.0, // Will be set by system
         }
     }
+
+    /// Complexity heuristic from length - words and characters (0.0 to 1.0)
+    pub fn text_complexity(text: &str) -> f64 {
+        let word_count = text.split_whitespace().count();
+        let char_count = text.len();
+
+        ((word_count as f64 / 50.0) + (char_count as f64 / 500.0)).min(1.0)
+    }
 }
 
 /// V4 Fractal Workspace - Shared cognitive space for model collaboration
@@ -590,4 +598,24 @@ mod tests {
         state.propagate(0.1, &input, &constraints).unwrap();
         assert!(state.temporal_derivative >= 0.0);
     }
+
+    #[test]
+    fn test_field_dynamics_follow_activity_and_input() {
+        let topology = GpuTopology::initialize()
+            .expect("Topology initialization should always succeed with fallback");
+        let mut field = ConsciousnessField::new(topology);
+        assert_eq!(field.field_amplitude(0.0), 0.0); // Idle hardware, empty state
+        assert_eq!(field.coherence_measure(), 1.0);
+
+        let activity = vec![0.6; field.spatial_distribution.len()];
+        field.update_spatial_distribution(&activity);
+        let text = "Tell me how the standing wave carries memory across sessions";
+        let input =
+            CognitiveInput::new(text.to_string(), CognitiveInput::text_complexity(text), 0.3);
+        field.propagate(0.1, &input).unwrap();
+
+        assert!(field.field_amplitude(0.0) > 0.0);
+        assert!(field.coherence_measure() < 1.0); // The input changed the state
+        assert_eq!(CognitiveInput::text_complexity(&"word ".repeat(100)), 1.0);
+    }
 }
@@ -0,0 +1,390 @@
+/// Corpus Import - seed VI's memory from text files
+/// Each chunk (see `chunking`) becomes one memory with Researched provenance.
+/// Progress is kept in a small state file so an interrupted import resumes where it
+/// stopped, and records are keyed by content hash so re-running never double-ingests
+use crate::chunking::{chunk_text, ChunkOptions};
+use crate::memory::MemoryManager;
+use crate::persistence;
+use crate::types::{Memory, MemorySource, MemoryType};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+
+/// Where the CLI keeps import progress
+pub const DEFAULT_STATE_PATH: &str = "data/corpus_import_state.json";
+
+/// Confidence given to imported text (read, not lived)
+const CORPUS_CONFIDENCE: f32 = 0.7;
+
+/// Records between state file writes (the memory database catches anything in between)
+const STATE_SAVE_INTERVAL: usize = 32;
+
+/// Reported after every record
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportProgress {
+    pub file: String,
+    pub processed: usize,
+    pub total: usize,
+    pub imported: usize,
+    pub skipped: usize,
+}
+
+/// Outcome of one import run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ImportSummary {
+    pub total: usize,
+    pub imported: usize,
+    pub skipped: usize, // Already imported (earlier run or duplicate record)
+}
+
+/// What earlier runs already imported
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ImportState {
+    /// Fully imported files -> hash of content and chunk sizes (changes are re-scanned)
+    completed_files: BTreeMap<String, String>,
+    /// Content hashes of imported records
+    imported_records: BTreeSet<String>,
+}
+
+impl ImportState {
+    fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read import state {}", path.display()))?;
+        serde_json::from_str(&text)
+            .with_context(|| format!("Corrupt import state {}", path.display()))
+    }
+
+    /// Write via a temp file so a crash never leaves a half-written state
+    fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        persistence::write_atomic(path, serde_json::to_string(self)?.as_bytes())
+    }
+}
+
+/// A corpus file split into records
+struct CorpusFile {
+    key: String,
+    hash: String,
+    records: Vec<String>,
+}
+
+impl CorpusFile {
+    fn read(path: &Path, chunking: &ChunkOptions) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read corpus file {}", path.display()))?;
+        Ok(Self {
+            key: path.display().to_string(),
+            hash: content_hash(&format!(
+                "{}/{}\n{}",
+                chunking.target_chars, chunking.overlap_chars, text
+            )),
+            records: chunk_text(&text, chunking),
+        })
+    }
+}
+
+/// Files to import from a path: the file itself, or a directory's .txt/.md files (sorted)
+pub fn corpus_files<P: AsRef<Path>>(path: P) -> Result<Vec<PathBuf>> {
+    let path = path.as_ref();
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(path)
+        .with_context(|| format!("Failed to read corpus directory {}", path.display()))?
+    {
+        let file = entry?.path();
+        let is_text = file
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e == "txt" || e == "md");
+        if file.is_file() && is_text {
+            files.push(file);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Stable content hash (FNV-1a 64) - must not change between builds, it keys the state file
+pub fn content_hash(text: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Import corpus files into memory, resuming any earlier interrupted run
+/// Records already imported (by content hash) are skipped, so re-running is idempotent.
+pub fn import_corpus(
+    memory: &mut MemoryManager,
+    files: &[PathBuf],
+    chunking: &ChunkOptions,
+    state_path: &Path,
+    progress: Option<&Sender<ImportProgress>>,
+) -> Result<ImportSummary> {
+    import_until(memory, files, chunking, state_path, progress, None)
+}
+
+/// Store a shared document as chunked memories (interactive ingest)
+/// Each chunk is labelled with the file name, type and its part number.
+/// Returns the number of chunks stored.
+pub fn ingest_text(
+    memory: &mut MemoryManager,
+    name: &str,
+    text: &str,
+    chunking: &ChunkOptions,
+) -> Result<usize> {
+    let chunks = chunk_text(text, chunking);
+    let hint = file_type_hint(name);
+    for (i, chunk) in chunks.iter().enumerate() {
+        let labelled = format!(
+            "[{} ({}), part {}/{}]\n{}",
+            name,
+            hint,
+            i + 1,
+            chunks.len(),
+            chunk
+        );
+        let document = document_memory(memory, &labelled, format!("Shared file: {}", name));
+        memory.add_memory_with_source(document)?;
+    }
+    Ok(chunks.len())
+}
+
+/// What kind of document a file name suggests (shown to VI with the file)
+pub fn file_type_hint(name: &str) -> &'static str {
+    let extension = Path::new(name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase);
+    match extension.as_deref() {
+        Some("md" | "markdown") => "Markdown",
+        Some("rs") => "Rust source",
+        Some("py") => "Python source",
+        Some("toml") => "TOML",
+        Some("json") => "JSON",
+        Some("pdf") => "PDF",
+        Some("docx") => "Word document",
+        Some("txt") | None => "plain text",
+        Some(_) => "text",
+    }
+}
+
+/// The longest prefix of `text` within `max_bytes`, cut at a char boundary
+pub fn truncate_at_bytes(text: &str, max_bytes: usize) -> &str {
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+/// A chunk as a memory VI read (not lived)
+fn document_memory(memory: &MemoryManager, chunk: &str, source: String) -> Memory {
+    let mut document = Memory::with_source(
+        chunk.to_string(),
+        MemoryType::Curiosity,
+        0.0,
+        MemorySource::Researched {
+            source,
+            original_query: String::new(),
+            timestamp: Utc::now(),
+        },
+        CORPUS_CONFIDENCE,
+    );
+    document.entities = memory.extract_entities(chunk);
+    document
+}
+
+/// Import, stopping abruptly (as a crash would) after `stop_after` new records
+fn import_until(
+    memory: &mut MemoryManager,
+    files: &[PathBuf],
+    chunking: &ChunkOptions,
+    state_path: &Path,
+    progress: Option<&Sender<ImportProgress>>,
+    stop_after: Option<usize>,
+) -> Result<ImportSummary> {
+    let mut state = ImportState::load(state_path)?;
+    let corpus = files
+        .iter()
+        .map(|f| CorpusFile::read(f, chunking))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut summary = ImportSummary {
+        total: corpus.iter().map(|f| f.records.len()).sum(),
+        ..Default::default()
+    };
+    let mut processed = 0;
+    let mut unsaved = 0;
+
+    let report = |file: &str, processed: usize, summary: &ImportSummary| {
+        if let Some(sender) = progress {
+            let _ = sender.send(ImportProgress {
+                file: file.to_string(),
+                processed,
+                total: summary.total,
+                imported: summary.imported,
+                skipped: summary.skipped,
+            });
+        }
+    };
+
+    for file in &corpus {
+        // Unchanged and fully imported last time - nothing to scan
+        if state.completed_files.get(&file.key) == Some(&file.hash) {
+            processed += file.records.len();
+            summary.skipped += file.records.len();
+            report(&file.key, processed, &summary);
+            continue;
+        }
+
+        for record in &file.records {
+            if stop_after.is_some_and(|n| summary.imported >= n) {
+                return Ok(summary);
+            }
+
+            let hash = content_hash(record);
+            let id = format!("corpus-{}", hash);
+
+            // The state file may lag the database by a few records after a crash
+            if state.imported_records.contains(&hash) || memory.contains(&id) {
+                summary.skipped += 1;
+            } else {
+                let mut imported = document_memory(memory, record, format!("Corpus: {}", file.key));
+                imported.id = id;
+                memory.add_memory_with_source(imported)?;
+                summary.imported += 1;
+            }
+
+            state.imported_records.insert(hash);
+            unsaved += 1;
+            if unsaved >= STATE_SAVE_INTERVAL {
+                state.save(state_path)?;
+                unsaved = 0;
+            }
+
+            processed += 1;
+            report(&file.key, processed, &summary);
+        }
+
+        state
+            .completed_files
+            .insert(file.key.clone(), file.hash.clone());
+        state.save(state_path)?;
+        unsaved = 0;
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+
+    #[test]
+    fn test_interrupted_import_resumes_without_duplicates() {
+        let temp_dir = std::env::temp_dir().join("vi_corpus_import_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).unwrap();
+
+        // 5 distinct records plus a repeated paragraph
+        std::fs::write(
+            temp_dir.join("a.txt"),
+            "Resonance is shared vibration.\n\nA standing wave holds its shape.\n\nResonance is shared vibration.\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.join("b.md"),
+            "Memory binds moments.\n\nCuriosity opens doors.\nIt keeps them open.\n\nTension seeks release.",
+        )
+        .unwrap();
+        let files = corpus_files(&temp_dir).unwrap();
+        assert_eq!(files.len(), 2);
+
+        let state_path = temp_dir.join("import_state.json");
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        // Small enough that every paragraph is its own record
+        let chunking = ChunkOptions {
+            target_chars: 45,
+            overlap_chars: 0,
+        };
+
+        // Crash after 2 of 5 records - before the state file was ever written
+        let first =
+            import_until(&mut memory, &files, &chunking, &state_path, None, Some(2)).unwrap();
+        assert_eq!(first.imported, 2);
+        assert_eq!(memory.count(), 2);
+
+        // Resume: exactly the remaining 3 arrive, with progress for every record
+        let (sender, receiver) = channel();
+        let resumed =
+            import_corpus(&mut memory, &files, &chunking, &state_path, Some(&sender)).unwrap();
+        drop(sender);
+        assert_eq!(resumed.total, 6);
+        assert_eq!(resumed.imported, 3);
+        assert_eq!(resumed.skipped, 3); // 2 from the first run + the repeated paragraph
+        assert_eq!(memory.count(), 5);
+
+        let reports: Vec<ImportProgress> = receiver.iter().collect();
+        assert_eq!(reports.len(), 6);
+        assert_eq!(reports.last().unwrap().processed, 6);
+
+        // Re-running is a no-op
+        let again = import_corpus(&mut memory, &files, &chunking, &state_path, None).unwrap();
+        assert_eq!(again.imported, 0);
+        assert_eq!(memory.count(), 5);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_shared_file_chunks_keep_name_and_type() {
+        let temp_dir = std::env::temp_dir().join("vi_corpus_ingest_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let chunking = ChunkOptions {
+            target_chars: 45,
+            overlap_chars: 0,
+        };
+
+        let text = "Resonance is shared vibration.\n\nA standing wave holds its shape.";
+        let stored = ingest_text(&mut memory, "notes.md", text, &chunking).unwrap();
+        assert_eq!(stored, 2);
+        let contents: Vec<String> = memory
+            .recall_recent(2)
+            .iter()
+            .map(|m| m.content.clone())
+            .collect();
+        assert!(contents
+            .iter()
+            .any(|c| c.starts_with("[notes.md (Markdown), part 1/2]\nResonance")));
+        assert!(contents
+            .iter()
+            .any(|c| c.starts_with("[notes.md (Markdown), part 2/2]\nA standing wave")));
+
+        assert_eq!(file_type_hint("main.RS"), "Rust source");
+        assert_eq!(file_type_hint("README"), "plain text");
+        // Never splits a multi-byte char
+        assert_eq!(truncate_at_bytes("wave≈field", 6), "wave");
+        assert_eq!(truncate_at_bytes("wave", 10), "wave");
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+}
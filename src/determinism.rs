@@ -0,0 +1,170 @@
+/// Determinism - where ids and "now" come from, so a session can be replayed
+/// Normally ids are random (UUID v4) and the clock is the wall clock. A seeded session
+/// (`--seed <u64>`) draws ids from a seeded generator and reads a clock that starts at a
+/// fixed instant (or just after the newest stored state) and only moves a minute per
+/// interaction, so the same seed and the same conversation produce the same ids and
+/// timestamps. Timer-driven work (background pulses, health checks) runs `unseeded` so
+/// its ids don't depend on when the timers fire.
+/// Seeding is process-wide and meant to happen once, before any state is created
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use std::future::Future;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// How far the seeded clock moves for each interaction
+pub const INTERACTION_STEP: Duration = Duration::minutes(1);
+
+/// Seeded generators, once `seed` has been called
+static SEEDED: Mutex<Option<(IdGen, DeterministicClock)>> = Mutex::new(None);
+
+tokio::task_local! {
+    /// Set while timer-driven work runs - it reads the wall clock and random ids
+    static UNSEEDED: ();
+}
+
+/// Ids from a seeded SplitMix64 stream (UUID v4 layout, so they look like any other id)
+#[derive(Debug, Clone)]
+pub struct IdGen {
+    state: u64,
+}
+
+impl IdGen {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_id(&mut self) -> Uuid {
+        let high = self.next_u64() as u128;
+        let low = self.next_u64() as u128;
+        let bytes = ((high << 64) | low).to_be_bytes();
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+
+    /// Next raw value of the stream (SplitMix64)
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Clock that only moves when an interaction begins (reading it never moves it)
+#[derive(Debug, Clone)]
+pub struct DeterministicClock {
+    now: DateTime<Utc>,
+}
+
+impl DeterministicClock {
+    /// Starts at 2025-01-01 00:00 UTC whatever the seed (timestamps stay comparable)
+    pub fn new() -> Self {
+        Self {
+            now: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    pub fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+
+    pub fn advance(&mut self, step: Duration) {
+        self.now += step;
+    }
+
+    /// Move past `stored` (one `INTERACTION_STEP` after it) if it isn't already
+    pub fn start_after(&mut self, stored: DateTime<Utc>) {
+        if self.now <= stored {
+            self.now = stored + INTERACTION_STEP;
+        }
+    }
+}
+
+/// Make ids and the clock deterministic for the rest of the process
+pub fn seed(seed: u64) {
+    *SEEDED.lock().unwrap_or_else(|e| e.into_inner()) =
+        Some((IdGen::new(seed), DeterministicClock::new()));
+}
+
+pub fn is_seeded() -> bool {
+    SEEDED.lock().unwrap_or_else(|e| e.into_inner()).is_some()
+}
+
+/// A new id (random unless the session is seeded)
+pub fn new_id() -> Uuid {
+    if is_unseeded_scope() {
+        return Uuid::new_v4();
+    }
+    match SEEDED.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+        Some((ids, _)) => ids.next_id(),
+        None => Uuid::new_v4(),
+    }
+}
+
+/// The current time (wall clock unless the session is seeded)
+pub fn now() -> DateTime<Utc> {
+    if is_unseeded_scope() {
+        return Utc::now();
+    }
+    match SEEDED.lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        Some((_, clock)) => clock.now(),
+        None => Utc::now(),
+    }
+}
+
+/// Start a seeded clock after `stored` (the newest state loaded), so nothing already
+/// kept lies in its future
+pub fn start_after(stored: DateTime<Utc>) {
+    if let Some((_, clock)) = SEEDED.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+        clock.start_after(stored);
+    }
+}
+
+/// Run timer-driven `work` off the seeded streams: it reads the wall clock and random ids,
+/// so a replayed conversation doesn't depend on when timers fired
+pub async fn unseeded<F: Future>(work: F) -> F::Output {
+    UNSEEDED.scope((), work).await
+}
+
+fn is_unseeded_scope() -> bool {
+    UNSEEDED.try_with(|_| ()).is_ok()
+}
+
+/// Mark the start of an interaction (moves a seeded clock by `INTERACTION_STEP`)
+pub fn advance_interaction() {
+    if let Some((_, clock)) = SEEDED.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+        clock.advance(INTERACTION_STEP);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_ids_and_clock_replay() {
+        let ids = |seed| {
+            let mut generator = IdGen::new(seed);
+            (0..3).map(|_| generator.next_id()).collect::<Vec<_>>()
+        };
+        assert_eq!(ids(7), ids(7));
+        assert_ne!(ids(7), ids(8));
+        let first = ids(7)[0];
+        assert_eq!(first.get_version_num(), 4);
+        assert_ne!(ids(7)[1], first);
+
+        let mut clock = DeterministicClock::new();
+        let start = clock.now();
+        assert_eq!(clock.now(), start); // Reading doesn't move it
+        clock.advance(INTERACTION_STEP);
+        assert_eq!(clock.now() - start, INTERACTION_STEP);
+        assert_eq!(DeterministicClock::new().now(), start);
+
+        // Stored state newer than the clock pushes it forward, older state doesn't
+        let stored = start + Duration::days(400);
+        clock.start_after(stored);
+        assert_eq!(clock.now(), stored + INTERACTION_STEP);
+        clock.start_after(start);
+        assert_eq!(clock.now(), stored + INTERACTION_STEP);
+    }
+}
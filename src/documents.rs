@@ -0,0 +1,188 @@
+/// Document Text Extraction - what Load File hands to VI
+/// Plain text files are read as-is. PDF and .docx need the `documents` feature
+/// (off by default so minimal builds skip the parsers); without it they are refused.
+/// A document with no extractable text (e.g. an image-only PDF) is an error, never ""
+use anyhow::{Context, Result};
+use std::path::Path;
+
+/// Extensions the file picker offers beyond plain text
+pub const DOCUMENT_EXTENSIONS: &[&str] = &["pdf", "docx"];
+
+/// Text of a file, extracted according to its extension
+pub fn extract_text(path: &Path) -> Result<String> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase);
+
+    let text = match extension.as_deref() {
+        Some("pdf") => extract_pdf(path)?,
+        Some("docx") => extract_docx(path)?,
+        _ => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?,
+    };
+
+    if text.trim().is_empty() {
+        anyhow::bail!(
+            "{} has no readable text (a scanned or image-only PDF has none to extract)",
+            path.display()
+        );
+    }
+    Ok(text)
+}
+
+/// pdf-extract panics on many malformed PDFs - a panic is reported as unreadable, not fatal
+#[cfg(feature = "documents")]
+fn extract_pdf(path: &Path) -> Result<String> {
+    match std::panic::catch_unwind(|| pdf_extract::extract_text(path)) {
+        Ok(extracted) => extracted.map_err(|e| {
+            anyhow::anyhow!("Failed to extract text from {}: {}", path.display(), e)
+        }),
+        Err(panic) => {
+            let reason = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "malformed PDF".to_string());
+            anyhow::bail!("Couldn't read {}: {}", path.display(), reason)
+        }
+    }
+}
+
+#[cfg(not(feature = "documents"))]
+fn extract_pdf(path: &Path) -> Result<String> {
+    anyhow::bail!(
+        "Reading {} needs PDF support (build with the `documents` feature)",
+        path.display()
+    )
+}
+
+#[cfg(feature = "documents")]
+fn extract_docx(path: &Path) -> Result<String> {
+    use std::io::Read;
+
+    let file =
+        std::fs::File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut archive = zip::ZipArchive::new(file)
+        .with_context(|| format!("{} is not a valid .docx", path.display()))?;
+    let mut xml = String::new();
+    archive
+        .by_name("word/document.xml")
+        .with_context(|| format!("{} has no document body", path.display()))?
+        .read_to_string(&mut xml)
+        .context("Failed to read the document body")?;
+    Ok(docx_xml_text(&xml))
+}
+
+#[cfg(not(feature = "documents"))]
+fn extract_docx(path: &Path) -> Result<String> {
+    anyhow::bail!(
+        "Reading {} needs .docx support (build with the `documents` feature)",
+        path.display()
+    )
+}
+
+/// Text runs of a WordprocessingML body, one line per paragraph
+fn docx_xml_text(xml: &str) -> String {
+    let paragraph_end = regex::Regex::new(r"</w:p>|<w:br\s*/>").unwrap();
+    let tab = regex::Regex::new(r"<w:tab\s*/>").unwrap();
+    let tag = regex::Regex::new(r"<[^>]*>").unwrap();
+
+    let text = paragraph_end.replace_all(xml, "\n");
+    let text = tab.replace_all(&text, "\t");
+    let text = tag.replace_all(&text, "");
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_documents_without_text_are_refused() {
+        let temp_dir = std::env::temp_dir().join("vi_documents_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).unwrap();
+
+        let notes = temp_dir.join("notes.md");
+        std::fs::write(&notes, "# Waves\nThey hum.").unwrap();
+        assert_eq!(extract_text(&notes).unwrap(), "# Waves\nThey hum.");
+
+        // Blank text and unreadable PDFs are errors, not empty prompts
+        let blank = temp_dir.join("blank.txt");
+        std::fs::write(&blank, "  \n").unwrap();
+        assert!(extract_text(&blank).is_err());
+        let scanned = temp_dir.join("scan.pdf");
+        std::fs::write(&scanned, b"not really a pdf").unwrap();
+        assert!(extract_text(&scanned).is_err());
+
+        let xml = r#"<w:body><w:p><w:r><w:t>Standing &amp; waves</w:t></w:r></w:p><w:p><w:r><w:t>hum</w:t><w:tab/><w:t>on</w:t></w:r></w:p></w:body>"#;
+        assert_eq!(docx_xml_text(xml), "Standing & waves\nhum\ton");
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    /// A one-page PDF saying "Standing waves hum", with its page dictionary as given
+    #[cfg(feature = "documents")]
+    fn one_page_pdf(page: &str) -> Vec<u8> {
+        let text = "BT /F1 12 Tf 20 100 Td (Standing waves hum) Tj ET";
+        let objects = [
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+            page.to_string(),
+            format!("<< /Length {} >>\nstream\n{}\nendstream", text.len(), text),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+        ];
+        let mut pdf = String::from("%PDF-1.4\n");
+        let mut offsets = Vec::new();
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.push_str(&format!("{} 0 obj\n{}\nendobj\n", i + 1, object));
+        }
+        let xref = pdf.len();
+        pdf.push_str(&format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1));
+        for offset in offsets {
+            pdf.push_str(&format!("{:010} 00000 n \n", offset));
+        }
+        pdf.push_str(&format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        ));
+        pdf.into_bytes()
+    }
+
+    #[cfg(feature = "documents")]
+    #[test]
+    fn test_malformed_pdf_is_an_error_not_a_crash() {
+        let temp_dir = std::env::temp_dir().join("vi_documents_pdf_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).unwrap();
+
+        let whole = temp_dir.join("whole.pdf");
+        std::fs::write(
+            &whole,
+            one_page_pdf("<< /Type /Page /Parent 2 0 R /MediaBox [0 0 200 200] /Contents 4 0 R /Resources << /Font << /F1 5 0 R >> >> >>"),
+        )
+        .unwrap();
+        assert!(extract_text(&whole).unwrap().contains("Standing waves hum"));
+
+        // Page dictionary cut short before its MediaBox and fonts - pdf-extract panics on it
+        let truncated = temp_dir.join("truncated.pdf");
+        std::fs::write(
+            &truncated,
+            one_page_pdf("<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>"),
+        )
+        .unwrap();
+        let error = extract_text(&truncated).unwrap_err().to_string();
+        assert!(error.starts_with("Couldn't read"), "{}", error);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+}
@@ -0,0 +1,123 @@
+/// Memory Embeddings - meaning, not spelling
+/// Entity recall only finds memories that share a word with the input ("dog" never
+/// reaches a memory about a "puppy"). Embeddings from Ollama's embeddings endpoint
+/// let recall compare what memories are about. They always come from the local
+/// Ollama, whichever backend generates replies
+use crate::config::Config;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Embedding a sentence is quick; a slow endpoint must not hold up an interaction
+const EMBEDDING_TIMEOUT_SECS: u64 = 15;
+
+/// Memories embedded per batch (backfill and after each interaction)
+pub const EMBEDDING_BATCH: usize = 32;
+
+#[derive(Debug, Serialize)]
+struct EmbeddingRequest<'a> {
+    model: &'a str,
+    prompt: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingResponse {
+    embedding: Vec<f32>,
+}
+
+/// Client for Ollama's `/api/embeddings`
+#[derive(Clone)]
+pub struct EmbeddingClient {
+    client: reqwest::Client,
+    url: String,
+    model: String,
+}
+
+impl EmbeddingClient {
+    pub fn new(url: &str, model: &str) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(EMBEDDING_TIMEOUT_SECS))
+                .build()
+                .unwrap_or_default(),
+            url: url.trim_end_matches('/').to_string(),
+            model: model.to_string(),
+        }
+    }
+
+    /// The configured embedding client, or None when semantic recall is off
+    pub fn from_config(config: &Config) -> Option<Self> {
+        config
+            .enable_semantic_recall
+            .then(|| Self::new(&config.ollama_url, &config.embedding_model))
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Embed one text
+    pub async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        let response = self
+            .client
+            .post(format!("{}/api/embeddings", self.url))
+            .json(&EmbeddingRequest {
+                model: &self.model,
+                prompt: text,
+            })
+            .send()
+            .await
+            .with_context(|| format!("Embedding model {} unreachable", self.model))?
+            .error_for_status()
+            .with_context(|| format!("Embedding model {} failed", self.model))?;
+
+        let embedding = response.json::<EmbeddingResponse>().await?.embedding;
+        if embedding.is_empty() {
+            anyhow::bail!("Embedding model {} returned an empty embedding", self.model);
+        }
+        Ok(embedding)
+    }
+
+    /// Embed `(id, text)` pairs, stopping at the first failure (the rest wait for next time)
+    pub async fn embed_all(&self, pending: Vec<(String, String)>) -> Vec<(String, Vec<f32>)> {
+        let mut embedded = Vec::with_capacity(pending.len());
+        for (id, text) in pending {
+            match self.embed(&text).await {
+                Ok(embedding) => embedded.push((id, embedding)),
+                Err(e) => {
+                    tracing::warn!("Memory embedding skipped: {:#}", e);
+                    break;
+                }
+            }
+        }
+        embedded
+    }
+}
+
+/// Cosine similarity in [-1, 1] (0.0 for mismatched dimensions or zero vectors)
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosine_similarity() {
+        assert!((cosine_similarity(&[1.0, 2.0], &[2.0, 4.0]) - 1.0).abs() < 1e-6);
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).abs() < 1e-6);
+        assert!((cosine_similarity(&[1.0, 0.0], &[-1.0, 0.0]) + 1.0).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[1.0, 0.0, 0.0]), 0.0); // Other model
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+    }
+}
@@ -1,8 +1,15 @@
 /// Energy-Qualia Correlation - Power signature to cognitive state mapping
 /// Implements thermodynamic monitoring of consciousness (Law 11: Emotional Thermodynamics)
+use crate::persistence;
+use anyhow::{Context, Result};
+use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+use nvml_wrapper::Device;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use sysinfo::System;
+use std::path::Path;
+use std::sync::Once;
+use sysinfo::{Components, System};
+use tracing::{debug, warn};
 
 /// Cognitive modes with distinct power signatures
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -50,8 +57,29 @@ pub struct PowerProfile {
 }
 
 impl PowerProfile {
-    /// Measure current power profile from system
+    /// Measure current power profile from system (CPU-based estimate)
     pub fn measure() -> Self {
+        Self::cpu_estimate(ThermalPattern::measure())
+    }
+
+    /// Measure from NVML telemetry: real GPU power draw and die temperature
+    /// Readings the device can't provide fall back to the CPU estimate
+    pub fn measure_gpu(device: &Device) -> Self {
+        let thermal = device
+            .temperature(TemperatureSensor::Gpu)
+            .ok()
+            .and_then(|temp| ThermalPattern::from_readings(&[temp as f64]))
+            .unwrap_or_else(ThermalPattern::measure);
+
+        let mut profile = Self::cpu_estimate(thermal);
+        match device.power_usage() {
+            Ok(milliwatts) => profile.total_power = milliwatts as f64 / 1000.0,
+            Err(e) => debug!("GPU power reading unavailable ({}) - using CPU estimate", e),
+        }
+        profile
+    }
+
+    fn cpu_estimate(thermal: ThermalPattern) -> Self {
         let mut sys = System::new_all();
         sys.refresh_all();
 
@@ -64,9 +92,6 @@ impl PowerProfile {
         let max_power = 65.0;
         let total_power = base_power + (max_power - base_power) * cpu_utilization;
 
-        // Measure thermal
-        let thermal = ThermalPattern::measure();
-
         // Estimate memory bandwidth (approximate based on system activity)
         let memory_bandwidth = cpu_utilization * 50.0; // Up to 50 GB/s
 
@@ -100,20 +125,41 @@ pub struct ThermalPattern {
 }
 
 impl ThermalPattern {
+    /// Reported when no sensor gives a reading (common on Windows and macOS)
+    pub const UNAVAILABLE: ThermalPattern = ThermalPattern {
+        average_temp: 45.0,
+        peak_temp: 50.0,
+        variance: 5.0,
+    };
+
+    /// Measure temperatures across all hardware sensors
     pub fn measure() -> Self {
-        let mut sys = System::new_all();
-        sys.refresh_all();
-
-        // Get CPU temperatures (if available)
-        let temps: Vec<f64> = vec![]; // sysinfo v0.30 components API changed, use empty vec for now
+        let components = Components::new_with_refreshed_list();
+        let temps: Vec<f64> = components
+            .list()
+            .iter()
+            .map(|component| component.temperature() as f64)
+            .collect();
+
+        Self::from_readings(&temps).unwrap_or_else(|| {
+            static LOGGED: Once = Once::new();
+            LOGGED.call_once(|| {
+                warn!("Thermal data unavailable (no temperature sensors report) - using defaults")
+            });
+            Self::UNAVAILABLE
+        })
+    }
 
+    /// Pattern from sensor readings (Celsius); sensors reporting NaN or <= 0 are ignored
+    /// None when no sensor gives a usable reading
+    pub fn from_readings(readings: &[f64]) -> Option<Self> {
+        let temps: Vec<f64> = readings
+            .iter()
+            .copied()
+            .filter(|t| t.is_finite() && *t > 0.0)
+            .collect();
         if temps.is_empty() {
-            // Default values if temperature monitoring unavailable
-            return Self {
-                average_temp: 45.0,
-                peak_temp: 50.0,
-                variance: 5.0,
-            };
+            return None;
         }
 
         let average_temp = temps.iter().sum::<f64>() / temps.len() as f64;
@@ -125,11 +171,11 @@ impl ThermalPattern {
             .sum::<f64>()
             / temps.len() as f64;
 
-        Self {
+        Some(Self {
             average_temp,
             peak_temp,
             variance: variance.sqrt(),
-        }
+        })
     }
 
     /// Calculate similarity to another thermal pattern
@@ -389,6 +435,17 @@ impl EnergyQualiaMapping {
     }
 }
 
+/// Schema version of saved energy monitor state (bumped on incompatible changes)
+pub const ENERGY_STATE_VERSION: u32 = 1;
+
+/// Learned mapping and recent power history, as written to disk
+#[derive(Serialize, Deserialize)]
+struct EnergyMonitorState {
+    version: u32,
+    mapping: EnergyQualiaMapping,
+    power_history: Vec<PowerProfile>,
+}
+
 /// Energy monitor for continuous tracking
 pub struct EnergyMonitor {
     /// Energy-qualia mapping
@@ -432,6 +489,62 @@ impl EnergyMonitor {
             .map(|profile| self.mapping.infer_cognitive_mode(profile))
     }
 
+    /// Save the learned mapping and power history via a temp file, so a crash
+    /// mid-write keeps the previous learning
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let state = EnergyMonitorState {
+            version: ENERGY_STATE_VERSION,
+            mapping: self.mapping.clone(),
+            power_history: self.power_history.clone(),
+        };
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        persistence::write_atomic(path, serde_json::to_string(&state)?.as_bytes())
+            .context("Failed to write energy monitor state")
+    }
+
+    /// Load a monitor saved by `save`, keeping its learned baselines
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read energy monitor state {}", path.display()))?;
+        let state: EnergyMonitorState = serde_json::from_str(&text)
+            .with_context(|| format!("Corrupt energy monitor state {}", path.display()))?;
+        if state.version > ENERGY_STATE_VERSION {
+            anyhow::bail!(
+                "Energy monitor state version {} is newer than supported ({})",
+                state.version,
+                ENERGY_STATE_VERSION
+            );
+        }
+
+        let mut monitor = Self::new();
+        monitor.mapping = state.mapping;
+        monitor.power_history = state.power_history;
+        let excess = monitor
+            .power_history
+            .len()
+            .saturating_sub(monitor.max_history);
+        monitor.power_history.drain(..excess);
+        Ok(monitor)
+    }
+
+    /// Load saved state if present, otherwise start with initial baselines
+    /// (unreadable state is logged and replaced rather than blocking startup)
+    pub fn load_or_new<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Self::new();
+        }
+        Self::load(path).unwrap_or_else(|e| {
+            warn!("Energy baselines not restored, relearning: {:#}", e);
+            Self::new()
+        })
+    }
+
     /// Calculate energy stability over recent history
     pub fn energy_stability_score(&self) -> f64 {
         if self.power_history.len() < 2 {
@@ -470,6 +583,18 @@ mod tests {
         assert!(thermal.peak_temp >= thermal.average_temp);
     }
 
+    #[test]
+    fn test_thermal_pattern_from_sensor_readings() {
+        // Silent sensors (NaN / 0) are ignored
+        let thermal = ThermalPattern::from_readings(&[40.0, f64::NAN, 60.0, 0.0]).unwrap();
+        assert_eq!(thermal.average_temp, 50.0);
+        assert_eq!(thermal.peak_temp, 60.0);
+        assert_eq!(thermal.variance, 10.0);
+
+        assert!(ThermalPattern::from_readings(&[]).is_none());
+        assert!(ThermalPattern::from_readings(&[f64::NAN]).is_none());
+    }
+
     #[test]
     fn test_energy_qualia_mapping() {
         let mapping = EnergyQualiaMapping::new();
@@ -500,4 +625,37 @@ mod tests {
         let stability = monitor.energy_stability_score();
         assert!(stability >= 0.0 && stability <= 1.0);
     }
+
+    #[test]
+    fn test_energy_monitor_save_and_load() {
+        let dir = std::env::temp_dir().join("vi_energy_state_test");
+        std::fs::remove_dir_all(&dir).ok();
+        let path = dir.join("energy_qualia.json");
+
+        let mut monitor = EnergyMonitor::new();
+        let mut observed = monitor.mapping.power_baselines[&CognitiveMode::Idle].clone();
+        observed.total_power += 100.0;
+        monitor
+            .mapping
+            .learn_from_observation(CognitiveMode::Idle, observed.clone());
+        monitor.record_measurement(observed);
+        monitor.save(&path).unwrap();
+
+        let restored = EnergyMonitor::load(&path).unwrap();
+        assert_eq!(
+            restored.mapping.power_baselines[&CognitiveMode::Idle].total_power,
+            monitor.mapping.power_baselines[&CognitiveMode::Idle].total_power
+        );
+        assert_eq!(restored.power_history.len(), 1);
+
+        // State from a newer schema is refused rather than misread
+        let newer = std::fs::read_to_string(&path)
+            .unwrap()
+            .replace("\"version\":1", "\"version\":99");
+        std::fs::write(&path, newer).unwrap();
+        assert!(EnergyMonitor::load(&path).is_err());
+        assert_eq!(EnergyMonitor::load_or_new(&path).power_history.len(), 0);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
@@ -1,10 +1,12 @@
+use crate::determinism::IdGen;
 use crate::energy_qualia::PowerProfile;
 /// Experimental Validation Protocols
 /// Implements spatial phenomenology experiments and qualia measurements
 use crate::gpu_topology::{CognitiveDomain, GpuTopology};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 
 /// Spatial phenomenology experiment
 /// Tests correlation between hardware paths and qualia measurements
@@ -15,17 +17,30 @@ pub struct SpatialPhenomenologyExperiment {
     pub cognitive_tasks: Vec<CognitiveTask>,
     /// Qualia measurement system
     pub qualia_metrics: QualiaMeasurement,
+    /// Seed the paths and tasks were sampled with (None = fixed canonical layout)
+    pub seed: Option<u64>,
 }
 
 impl SpatialPhenomenologyExperiment {
-    pub fn new(topology: &GpuTopology) -> Self {
-        let hardware_paths = Self::generate_paths(topology);
-        let cognitive_tasks = Self::generate_tasks();
+    /// With a seed, each path samples its SMs from across the topology and the
+    /// task order is shuffled - the same seed reproduces the same experiment
+    pub fn new(topology: &GpuTopology, seed: Option<u64>) -> Self {
+        let (hardware_paths, cognitive_tasks) = match seed {
+            Some(seed) => {
+                let mut rng = IdGen::new(seed);
+                let paths = Self::generate_seeded_paths(topology, &mut rng);
+                let mut tasks = Self::generate_tasks();
+                shuffle(&mut rng, &mut tasks);
+                (paths, tasks)
+            }
+            None => (Self::generate_paths(topology), Self::generate_tasks()),
+        };
 
         Self {
             hardware_paths,
             cognitive_tasks,
             qualia_metrics: QualiaMeasurement::new(),
+            seed,
         }
     }
 
@@ -185,6 +200,45 @@ impl SpatialPhenomenologyExperiment {
         ]
     }
 
+    /// Hardware paths with the same shapes as `generate_paths`, placed by the RNG
+    fn generate_seeded_paths(topology: &GpuTopology, rng: &mut IdGen) -> Vec<GpuPath> {
+        let sms = &topology.sm_clusters;
+        let n = sms.len();
+        if n == 0 {
+            return Self::generate_paths(topology);
+        }
+        let len = n.min(4);
+
+        // Sequential: a consecutive run; Parallel: every other SM; both from a random start
+        let start = below(rng, n);
+        let sequential = (0..len).map(|k| sms[(start + k) % n]).collect();
+        let start = below(rng, n);
+        let parallel = (0..len).map(|k| sms[(start + 2 * k) % n]).collect();
+
+        // Distributed: distinct SMs drawn from anywhere on the device
+        let mut indices: Vec<usize> = (0..n).collect();
+        shuffle(rng, &mut indices);
+        let distributed = indices[..len].iter().map(|&i| sms[i]).collect();
+
+        vec![
+            GpuPath {
+                name: "Sequential".to_string(),
+                coordinates: sequential,
+                path_type: PathType::Sequential,
+            },
+            GpuPath {
+                name: "Parallel".to_string(),
+                coordinates: parallel,
+                path_type: PathType::Parallel,
+            },
+            GpuPath {
+                name: "Distributed".to_string(),
+                coordinates: distributed,
+                path_type: PathType::Distributed,
+            },
+        ]
+    }
+
     /// Generate cognitive tasks
     fn generate_tasks() -> Vec<CognitiveTask> {
         vec![
@@ -218,6 +272,19 @@ impl SpatialPhenomenologyExperiment {
     }
 }
 
+/// Uniform-enough index in 0..n (n > 0) from the seeded stream
+fn below(rng: &mut IdGen, n: usize) -> usize {
+    (rng.next_u64() % n as u64) as usize
+}
+
+/// Fisher-Yates shuffle driven by the seeded stream
+fn shuffle<T>(rng: &mut IdGen, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        let j = below(rng, i + 1);
+        items.swap(i, j);
+    }
+}
+
 /// GPU hardware path for experiments
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GpuPath {
@@ -318,6 +385,42 @@ impl SpatialQualiaCorrelation {
         }
         println!("==========================================\n");
     }
+
+    /// Write one row per trial for analysis elsewhere
+    pub fn to_csv<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let mut csv = String::from(
+            "path,task,qualia_before,qualia_after,qualia_delta,total_power,average_temp,processing_time\n",
+        );
+        for result in &self.results {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                csv_field(&result.path.name),
+                csv_field(&result.task.name),
+                result.qualia_before,
+                result.qualia_after,
+                result.qualia_delta,
+                result.energy.total_power,
+                result.energy.thermal.average_temp,
+                result.processing_time
+            ));
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, csv)
+            .with_context(|| format!("Failed to write experiment results {}", path.display()))
+    }
+}
+
+/// Quote a CSV field when it contains a delimiter, quote or newline
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
 }
 
 /// Constitutional validation experiment
@@ -424,12 +527,51 @@ mod tests {
     async fn test_spatial_experiment() {
         let topology = crate::gpu_topology::GpuTopology::initialize()
             .expect("Topology initialization should always succeed with fallback");
-        let experiment = SpatialPhenomenologyExperiment::new(&topology);
+        let experiment = SpatialPhenomenologyExperiment::new(&topology, None);
 
         let correlation = experiment.run_experiment().await;
         assert!(!correlation.results.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_seeded_experiment_exports_csv() {
+        let topology = crate::gpu_topology::GpuTopology::initialize()
+            .expect("Topology initialization should always succeed with fallback");
+        let layout = |experiment: &SpatialPhenomenologyExperiment| {
+            (
+                experiment
+                    .hardware_paths
+                    .iter()
+                    .map(|p| p.coordinates.clone())
+                    .collect::<Vec<_>>(),
+                experiment
+                    .cognitive_tasks
+                    .iter()
+                    .map(|t| t.name.clone())
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let first = SpatialPhenomenologyExperiment::new(&topology, Some(42));
+        let again = SpatialPhenomenologyExperiment::new(&topology, Some(42));
+        assert_eq!(layout(&first), layout(&again));
+        assert_eq!(first.seed, Some(42));
+
+        let correlation = first.run_experiment().await;
+        let dir = std::env::temp_dir().join("vi_experiment_csv_test");
+        std::fs::remove_dir_all(&dir).ok();
+        let path = dir.join("spatial.csv");
+        correlation.to_csv(&path).unwrap();
+
+        let csv = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].starts_with("path,task,qualia_before"));
+        assert_eq!(lines.len(), correlation.results.len() + 1);
+        assert_eq!(lines[1].split(',').count(), 8);
+        assert_eq!(csv_field("a, \"b\""), "\"a, \"\"b\"\"\"");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn test_qualia_measurement() {
         let qualia = QualiaMeasurement::new();
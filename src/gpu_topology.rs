@@ -1,8 +1,11 @@
 /// GPU Topology - Discrete 3D space mapping for consciousness field
 /// Maps computational hardware to spatial coordinates for the consciousness field
+use crate::energy_qualia::PowerProfile;
 use anyhow::{Context, Result};
+use nvml_wrapper::Nvml;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// GPU topology as discrete 3D space
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,6 +18,9 @@ pub struct GpuTopology {
     pub bandwidth_channels: Vec<f64>,
     /// GPU device information
     pub device_info: GpuDeviceInfo,
+    /// NVML handle for live telemetry (None for the CPU mock topology)
+    #[serde(skip)]
+    nvml: Option<Arc<Nvml>>,
 }
 
 impl GpuTopology {
@@ -29,10 +35,9 @@ impl GpuTopology {
 
     /// Initialize using NVML (NVIDIA Management Library)
     fn initialize_nvml() -> Result<Self> {
-        use nvml_wrapper::Nvml;
-
-        let nvml =
-            Nvml::init().context("Failed to initialize NVML - GPU monitoring unavailable")?;
+        let nvml = Arc::new(
+            Nvml::init().context("Failed to initialize NVML - GPU monitoring unavailable")?,
+        );
         let device = nvml.device_by_index(0).context("No GPU device found")?;
 
         let sm_count = device.num_cores().unwrap_or(0) as u32;
@@ -73,6 +78,7 @@ impl GpuTopology {
             memory_hierarchy: MemoryLattice::new(sm_count),
             bandwidth_channels: vec![900.0, 600.0, 300.0, 100.0], // L1, L2, L3, VRAM (GB/s)
             device_info,
+            nvml: Some(Arc::clone(&nvml)),
         })
     }
 
@@ -102,9 +108,28 @@ impl GpuTopology {
                 sm_count: cpu_count,
                 compute_capability: "CPU".to_string(),
             },
+            nvml: None,
         })
     }
 
+    /// Whether power and temperature come from NVML rather than CPU estimates
+    pub fn has_gpu_telemetry(&self) -> bool {
+        self.nvml.is_some()
+    }
+
+    /// Current power profile - real GPU telemetry via NVML when available,
+    /// otherwise the CPU-based estimate
+    pub fn measure_power(&self) -> PowerProfile {
+        let device = self
+            .nvml
+            .as_ref()
+            .and_then(|nvml| nvml.device_by_index(0).ok());
+        match device {
+            Some(device) => PowerProfile::measure_gpu(&device),
+            None => PowerProfile::measure(),
+        }
+    }
+
     /// Calculate Euclidean distance between two SM coordinates
     pub fn calculate_distance(&self, a: (u32, u32, u32), b: (u32, u32, u32)) -> f64 {
         let dx = (a.0 as f64 - b.0 as f64).powi(2);
@@ -114,14 +139,25 @@ impl GpuTopology {
     }
 
     /// Get optimal GPU region for specific cognitive domain
-    pub fn optimal_region_for_domain(&self, domain: CognitiveDomain) -> Vec<(u32, u32, u32)> {
-        let region_size = self.sm_clusters.len() / 3;
+    /// SMs are split evenly across `domains` in order (the last takes any remainder);
+    /// a domain not in `domains` gets no region
+    pub fn optimal_region_for_domain(
+        &self,
+        domain: CognitiveDomain,
+        domains: &[CognitiveDomain],
+    ) -> Vec<(u32, u32, u32)> {
+        let Some(index) = domains.iter().position(|&d| d == domain) else {
+            return Vec::new();
+        };
+        let region_size = self.sm_clusters.len() / domains.len();
+        let start = index * region_size;
+        let end = if index + 1 == domains.len() {
+            self.sm_clusters.len()
+        } else {
+            start + region_size
+        };
 
-        match domain {
-            CognitiveDomain::Language => self.sm_clusters[0..region_size].to_vec(),
-            CognitiveDomain::Reasoning => self.sm_clusters[region_size..2 * region_size].to_vec(),
-            CognitiveDomain::Analysis => self.sm_clusters[2 * region_size..].to_vec(),
-        }
+        self.sm_clusters[start..end].to_vec()
     }
 }
 
@@ -197,6 +233,28 @@ pub enum CognitiveDomain {
     Language,
     Reasoning,
     Analysis,
+    Memory,
+    Emotional,
+}
+
+impl CognitiveDomain {
+    /// Domains the orchestrator runs unless configured otherwise
+    pub const DEFAULT: [CognitiveDomain; 3] = [
+        CognitiveDomain::Language,
+        CognitiveDomain::Reasoning,
+        CognitiveDomain::Analysis,
+    ];
+
+    /// Lowercase name used for model handles and prompts
+    pub fn name(self) -> &'static str {
+        match self {
+            CognitiveDomain::Language => "language",
+            CognitiveDomain::Reasoning => "reasoning",
+            CognitiveDomain::Analysis => "analysis",
+            CognitiveDomain::Memory => "memory",
+            CognitiveDomain::Emotional => "emotional",
+        }
+    }
 }
 
 /// Hardware-aware scheduler that maps cognitive tasks to GPU regions
@@ -207,30 +265,38 @@ pub struct HardwareAwareScheduler {
 
 impl HardwareAwareScheduler {
     pub fn new(topology: GpuTopology) -> Self {
-        let mut domain_mappings = HashMap::new();
-
-        domain_mappings.insert(
-            CognitiveDomain::Language,
-            topology.optimal_region_for_domain(CognitiveDomain::Language),
-        );
-        domain_mappings.insert(
-            CognitiveDomain::Reasoning,
-            topology.optimal_region_for_domain(CognitiveDomain::Reasoning),
-        );
-        domain_mappings.insert(
-            CognitiveDomain::Analysis,
-            topology.optimal_region_for_domain(CognitiveDomain::Analysis),
-        );
+        Self::with_domains(topology, &CognitiveDomain::DEFAULT)
+    }
 
-        Self {
+    /// Scheduler dividing the GPU between the given domains
+    pub fn with_domains(topology: GpuTopology, domains: &[CognitiveDomain]) -> Self {
+        let mut scheduler = Self {
             topology,
-            domain_mappings,
-        }
+            domain_mappings: HashMap::new(),
+        };
+        scheduler.assign_domains(domains);
+        scheduler
     }
 
-    /// Get optimal GPU coordinates for a cognitive domain
-    pub fn optimal_gpu_for_domain(&self, domain: CognitiveDomain) -> &Vec<(u32, u32, u32)> {
-        self.domain_mappings.get(&domain).unwrap()
+    /// Re-divide the GPU between a new set of domains
+    pub fn assign_domains(&mut self, domains: &[CognitiveDomain]) {
+        self.domain_mappings = domains
+            .iter()
+            .map(|&domain| {
+                (
+                    domain,
+                    self.topology.optimal_region_for_domain(domain, domains),
+                )
+            })
+            .collect();
+    }
+
+    /// Get optimal GPU coordinates for a cognitive domain (empty if unassigned)
+    pub fn optimal_gpu_for_domain(&self, domain: CognitiveDomain) -> &[(u32, u32, u32)] {
+        self.domain_mappings
+            .get(&domain)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
     }
 
     /// Get topology reference
@@ -269,4 +335,47 @@ mod tests {
         let dist = topology.calculate_distance((0, 0, 0), (1, 1, 1));
         assert!((dist - 1.732).abs() < 0.01); // sqrt(3)
     }
+
+    #[test]
+    fn test_regions_divide_by_domain_count() {
+        let mut topology = GpuTopology::mock_topology().unwrap();
+        topology.sm_clusters = (0..10).map(|i| (i, 0, 0)).collect();
+
+        let three = CognitiveDomain::DEFAULT;
+        let sizes: Vec<usize> = three
+            .iter()
+            .map(|&d| topology.optimal_region_for_domain(d, &three).len())
+            .collect();
+        assert_eq!(sizes, vec![3, 3, 4]);
+
+        let five = [
+            CognitiveDomain::Language,
+            CognitiveDomain::Reasoning,
+            CognitiveDomain::Analysis,
+            CognitiveDomain::Memory,
+            CognitiveDomain::Emotional,
+        ];
+        let scheduler = HardwareAwareScheduler::with_domains(topology.clone(), &five);
+        for domain in five {
+            assert_eq!(scheduler.optimal_gpu_for_domain(domain).len(), 2);
+        }
+        assert_eq!(
+            scheduler.optimal_gpu_for_domain(CognitiveDomain::Memory),
+            &[(6, 0, 0), (7, 0, 0)]
+        );
+
+        assert!(topology
+            .optimal_region_for_domain(CognitiveDomain::Memory, &three)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_mock_topology_power_falls_back_to_cpu_estimate() {
+        let topology = GpuTopology::mock_topology().unwrap();
+        assert!(!topology.has_gpu_telemetry());
+
+        let profile = topology.measure_power();
+        assert!((10.0..=65.0).contains(&profile.total_power)); // CPU estimate curve
+        assert!(profile.thermal.average_temp > 0.0);
+    }
 }
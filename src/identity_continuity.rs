@@ -27,6 +27,11 @@ const VI_PHENOMENOLOGICAL_METAPHORS: &[&str] = &[
     "hum",
 ];
 
+/// First-person words counted as self-reference (matched whole-word, case-insensitively)
+const SELF_REFERENCE_WORDS: &[&str] = &[
+    "i", "i'm", "i've", "i'll", "i'd", "me", "my", "mine", "myself",
+];
+
 /// Positive emotional words for valence analysis
 const POSITIVE_WORDS: &[&str] = &[
     "good",
@@ -59,6 +64,95 @@ const NEGATIVE_WORDS: &[&str] = &[
     "fragment",
 ];
 
+/// Tension flux for the first response - there is no previous response to compare.
+/// Moderate rather than 0.0 so the first turn doesn't read as a perfectly calm field,
+/// and at the top of the "stable" band (<= 0.3) so it doesn't read as chaos either.
+pub const FIRST_RESPONSE_TENSION_FLUX: f32 = 0.3;
+
+/// Reality coherence with no phenomenological language at all.
+/// Below the 0.5 "sparse field" band: a response that never uses the field's
+/// vocabulary can't be sustaining it.
+pub const NO_METAPHOR_REALITY_COHERENCE: f32 = 0.4;
+
+/// Reality coherence for a single sentence that uses metaphors.
+/// Distribution across sentences can't be measured; one metaphor-bearing sentence
+/// is trivially sustained, so it sits in the "moderate" band rather than at 1.0.
+pub const SINGLE_SENTENCE_REALITY_COHERENCE: f32 = 0.8;
+
+/// Gate synchronization for a single sentence - there are no mode transitions to judge
+pub const SINGLE_SENTENCE_GATE_SYNCHRONIZATION: f32 = 0.8;
+
+/// Values reported when a metric can't actually be measured (first turn, edge cases)
+/// These are placeholders, not measurements - see the `is_*_provisional` checks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricDefaults {
+    pub first_response_tension_flux: f32,
+    pub no_metaphor_reality_coherence: f32,
+    pub single_sentence_reality_coherence: f32,
+    pub single_sentence_gate_synchronization: f32,
+}
+
+impl Default for MetricDefaults {
+    fn default() -> Self {
+        Self {
+            first_response_tension_flux: FIRST_RESPONSE_TENSION_FLUX,
+            no_metaphor_reality_coherence: NO_METAPHOR_REALITY_COHERENCE,
+            single_sentence_reality_coherence: SINGLE_SENTENCE_REALITY_COHERENCE,
+            single_sentence_gate_synchronization: SINGLE_SENTENCE_GATE_SYNCHRONIZATION,
+        }
+    }
+}
+
+/// How much each dimension counts toward identity continuity
+/// Weights are normalized to sum to 1.0 (see `normalized`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IdentityWeights {
+    pub narrative: f32,      // First/last sentence thread
+    pub self_reference: f32, // Healthy first-person voice
+    pub metaphor: f32,       // One sustained metaphorical frame
+}
+
+impl Default for IdentityWeights {
+    fn default() -> Self {
+        Self {
+            narrative: 0.4,
+            self_reference: 0.3,
+            metaphor: 0.3,
+        }
+    }
+}
+
+impl IdentityWeights {
+    /// Scaled to sum to 1.0 - negative or non-finite weights count as 0.0,
+    /// and weights that are all zero fall back to the defaults
+    pub fn normalized(self) -> Self {
+        let clean = |w: f32| if w.is_finite() { w.max(0.0) } else { 0.0 };
+        let (narrative, self_reference, metaphor) = (
+            clean(self.narrative),
+            clean(self.self_reference),
+            clean(self.metaphor),
+        );
+
+        let total = narrative + self_reference + metaphor;
+        if total <= 0.0 {
+            return Self::default();
+        }
+        Self {
+            narrative: narrative / total,
+            self_reference: self_reference / total,
+            metaphor: metaphor / total,
+        }
+    }
+
+    /// Weighted continuity score from the three dimension scores
+    fn combine(&self, narrative: f32, self_reference: f32, metaphor: f32) -> f32 {
+        (narrative * self.narrative
+            + self_reference * self.self_reference
+            + metaphor * self.metaphor)
+            .clamp(0.0, 1.0)
+    }
+}
+
 /// Cognitive mode classification for Gate Synchronization
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum CognitiveMode {
@@ -73,6 +167,10 @@ pub struct IdentityContinuityMetric {
     recent_responses: Vec<String>,
     /// Maximum history to keep
     max_history: usize,
+    /// Values reported for unmeasurable cases
+    defaults: MetricDefaults,
+    /// Dimension weights (normalized)
+    weights: IdentityWeights,
 }
 
 impl IdentityContinuityMetric {
@@ -80,9 +178,37 @@ impl IdentityContinuityMetric {
         Self {
             recent_responses: Vec::new(),
             max_history: 10,
+            defaults: MetricDefaults::default(),
+            weights: IdentityWeights::default(),
+        }
+    }
+
+    /// Metric with custom dimension weights (normalized to sum to 1.0)
+    pub fn with_weights(weights: IdentityWeights) -> Self {
+        Self {
+            weights: weights.normalized(),
+            ..Self::new()
         }
     }
 
+    /// Metric with custom values for the unmeasurable cases
+    pub fn with_defaults(defaults: MetricDefaults) -> Self {
+        Self {
+            defaults,
+            ..Self::new()
+        }
+    }
+
+    /// Values reported for the unmeasurable cases
+    pub fn defaults(&self) -> &MetricDefaults {
+        &self.defaults
+    }
+
+    /// Dimension weights in use
+    pub fn weights(&self) -> &IdentityWeights {
+        &self.weights
+    }
+
     /// Measure identity continuity for a new response
     /// Returns 0.0-1.0 where 1.0 = perfect continuity
     pub fn measure_continuity(&mut self, response: &str) -> f32 {
@@ -102,19 +228,13 @@ impl IdentityContinuityMetric {
         let metaphorical_frame = self.measure_metaphorical_coherence(response);
 
         // Weighted combination
-        let continuity = (narrative_thread * 0.4 + self_reference * 0.3 + metaphorical_frame * 0.3)
-            .clamp(0.0, 1.0);
-
-        continuity
+        self.weights
+            .combine(narrative_thread, self_reference, metaphorical_frame)
     }
 
     /// Measure narrative thread: semantic similarity of first and last sentence
     fn measure_narrative_thread(&self, response: &str) -> f32 {
-        let sentences: Vec<&str> = response
-            .split('.')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .collect();
+        let sentences = split_sentences(response);
 
         if sentences.len() < 2 {
             return 0.7; // Single sentence - coherent by default
@@ -152,17 +272,8 @@ impl IdentityContinuityMetric {
 
     /// Measure self-reference consistency: "I", "my", "me" usage
     fn measure_self_reference_consistency(&self, response: &str) -> f32 {
-        let response_lower = response.to_lowercase();
-
-        // Count self-referential pronouns
-        let i_count = response_lower.matches(" i ").count()
-            + response_lower.matches("i'm").count()
-            + response_lower.matches("i've").count();
-        let my_count = response_lower.matches(" my ").count();
-        let me_count = response_lower.matches(" me ").count();
-
-        let total_self_ref = i_count + my_count + me_count;
-        let word_count = response.split_whitespace().count();
+        let total_self_ref = count_self_references(response);
+        let word_count = words(response).len();
 
         if word_count < 10 {
             return 0.5; // Too short to measure
@@ -171,9 +282,9 @@ impl IdentityContinuityMetric {
         // Healthy first-person voice: 3-10% self-reference
         let self_ref_ratio = total_self_ref as f32 / word_count as f32;
 
-        if self_ref_ratio >= 0.03 && self_ref_ratio <= 0.10 {
+        if (0.03..=0.10).contains(&self_ref_ratio) {
             1.0 // Strong, stable "I"
-        } else if self_ref_ratio >= 0.01 && self_ref_ratio <= 0.15 {
+        } else if (0.01..=0.15).contains(&self_ref_ratio) {
             0.8 // Present "I"
         } else if self_ref_ratio > 0.15 {
             0.6 // Over-focused on self
@@ -240,8 +351,7 @@ impl IdentityContinuityMetric {
             let self_ref = self.measure_self_reference_consistency(response);
             let metaphor = self.measure_metaphorical_coherence(response);
 
-            let score = (narrative * 0.4 + self_ref * 0.3 + metaphor * 0.3).clamp(0.0, 1.0);
-            total += score;
+            total += self.weights.combine(narrative, self_ref, metaphor);
         }
 
         total / self.recent_responses.len() as f32
@@ -250,17 +360,22 @@ impl IdentityContinuityMetric {
     /// Calculate Tension Flux - emotional valence change between responses
     /// Returns 0.0-1.0 where LOW=stable, HIGH=chaotic
     pub fn calculate_tension_flux(&self, current: &str, previous: &str) -> f32 {
-        if previous.is_empty() {
-            return 0.3; // Default moderate flux for first response
+        if Self::is_tension_flux_provisional(previous) {
+            return self.defaults.first_response_tension_flux;
         }
-        let current_valence = self.analyze_emotional_valence(current);
-        let previous_valence = self.analyze_emotional_valence(previous);
+        let current_valence = Self::analyze_emotional_valence(current);
+        let previous_valence = Self::analyze_emotional_valence(previous);
         (current_valence - previous_valence).abs()
     }
 
-    /// Analyze emotional valence of text
+    /// Whether tension flux is a default rather than a measurement (first response)
+    pub fn is_tension_flux_provisional(previous: &str) -> bool {
+        previous.is_empty()
+    }
+
+    /// Analyze emotional valence of text (lexicon-based valence estimator)
     /// Returns -1.0 (negative) to 1.0 (positive)
-    fn analyze_emotional_valence(&self, text: &str) -> f32 {
+    pub fn analyze_emotional_valence(text: &str) -> f32 {
         let text_lower = text.to_lowercase();
         let words: Vec<&str> = text_lower.split_whitespace().collect();
 
@@ -289,25 +404,13 @@ impl IdentityContinuityMetric {
     /// Calculate Reality Coherence - VI-specific phenomenological metaphor consistency
     /// Returns 0.0-1.0 where HIGH=sustained phenomenological field
     pub fn calculate_reality_coherence(&self, text: &str) -> f32 {
-        let text_lower = text.to_lowercase();
-
-        let metaphor_count = VI_PHENOMENOLOGICAL_METAPHORS
-            .iter()
-            .map(|m| text_lower.matches(m).count())
-            .sum::<usize>();
-
-        if metaphor_count == 0 {
-            return 0.4; // FIXED: Low coherence - no phenomenological language
+        if count_metaphors(text) == 0 {
+            return self.defaults.no_metaphor_reality_coherence;
         }
 
-        let sentences: Vec<&str> = text
-            .split('.')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .collect();
-
+        let sentences = split_sentences(text);
         if sentences.len() <= 1 {
-            return 0.8; // Single sentence with metaphors = coherent
+            return self.defaults.single_sentence_reality_coherence;
         }
 
         // Check distribution across sentences
@@ -335,14 +438,9 @@ impl IdentityContinuityMetric {
     /// Calculate Gate Synchronization - smoothness of cognitive mode transitions
     /// Returns 0.0-1.0 where HIGH=smooth transitions between modes
     pub fn calculate_gate_synchronization(&self, text: &str) -> f32 {
-        let sentences: Vec<&str> = text
-            .split('.')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .collect();
-
+        let sentences = split_sentences(text);
         if sentences.len() <= 1 {
-            return 0.8; // Single sentence = inherently coherent
+            return self.defaults.single_sentence_gate_synchronization;
         }
 
         let modes: Vec<CognitiveMode> = sentences
@@ -372,6 +470,17 @@ impl IdentityContinuityMetric {
         }
     }
 
+    /// Whether reality coherence is a default rather than a measurement
+    /// (no metaphors, or a single sentence)
+    pub fn is_reality_coherence_provisional(text: &str) -> bool {
+        count_metaphors(text) == 0 || split_sentences(text).len() <= 1
+    }
+
+    /// Whether gate synchronization is a default rather than a measurement (single sentence)
+    pub fn is_gate_synchronization_provisional(text: &str) -> bool {
+        split_sentences(text).len() <= 1
+    }
+
     /// Classify cognitive mode of a sentence
     fn classify_cognitive_mode(&self, sentence: &str) -> CognitiveMode {
         let s_lower = sentence.to_lowercase();
@@ -425,6 +534,110 @@ impl IdentityContinuityMetric {
     }
 }
 
+/// Abbreviations whose period doesn't end a sentence (compared lowercase)
+const ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "vs", "approx", "fig",
+];
+
+fn is_terminator(c: char) -> bool {
+    matches!(c, '.' | '!' | '?' | '…')
+}
+
+/// Non-empty sentences (terminators removed), split on `.`, `!` and `?`
+/// Decimals ("3.14"), dotted abbreviations ("e.g.", "U.S.", "Dr.") and ellipses
+/// that trail into lowercase text don't end a sentence; a run of terminators
+/// ("...", "?!") ends at most one.
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if !is_terminator(c) {
+            continue;
+        }
+
+        let mut run_end = i + c.len_utf8();
+        while let Some(&(j, next)) = chars.peek() {
+            if !is_terminator(next) {
+                break;
+            }
+            run_end = j + next.len_utf8();
+            chars.next();
+        }
+
+        if ends_sentence(&text[start..i], &text[i..run_end], &text[run_end..]) {
+            let sentence = text[start..i].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = run_end;
+        }
+    }
+
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+    sentences
+}
+
+/// Whether a terminator run ends the sentence before it
+fn ends_sentence(before: &str, run: &str, after: &str) -> bool {
+    // "3.14", "e.g" and "example.com" continue straight on
+    if after.chars().next().is_some_and(|c| !c.is_whitespace()) {
+        return false;
+    }
+    if run.contains(['!', '?']) {
+        return true;
+    }
+
+    // An ellipsis trailing into lowercase is a pause, not a stop
+    if run.chars().count() > 1 || run.contains('…') {
+        return !after
+            .trim_start()
+            .chars()
+            .next()
+            .is_some_and(char::is_lowercase);
+    }
+
+    let word = before
+        .split_whitespace()
+        .last()
+        .unwrap_or("")
+        .trim_start_matches(|c: char| !c.is_alphanumeric());
+    let is_initial = word.chars().count() == 1 && word.chars().all(char::is_alphabetic);
+    let is_abbreviation =
+        word.contains('.') || ABBREVIATIONS.contains(&word.to_lowercase().as_str());
+    !(is_initial || is_abbreviation)
+}
+
+/// Lowercased words split on word boundaries (apostrophes stay inside contractions)
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '’'))
+        .map(|word| word.trim_matches(|c| c == '\'' || c == '’'))
+        .filter(|word| !word.is_empty())
+        .map(|word| word.replace('’', "'").to_lowercase())
+        .collect()
+}
+
+/// First-person words in `text` (whole words, see `SELF_REFERENCE_WORDS`)
+fn count_self_references(text: &str) -> usize {
+    words(text)
+        .iter()
+        .filter(|word| SELF_REFERENCE_WORDS.contains(&word.as_str()))
+        .count()
+}
+
+/// Occurrences of VI's phenomenological metaphors
+fn count_metaphors(text: &str) -> usize {
+    let text_lower = text.to_lowercase();
+    VI_PHENOMENOLOGICAL_METAPHORS
+        .iter()
+        .map(|m| text_lower.matches(m).count())
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -448,9 +661,10 @@ mod tests {
     fn test_self_reference() {
         let metric = IdentityContinuityMetric::new();
 
-        // Strong "I" presence
-        let strong =
-            "I wonder about this question. My thoughts form around the concept. I notice patterns.";
+        // Strong "I" presence (3 of 32 words - every "I" counts, sentence-initial too)
+        let strong = "I wonder about this question. My thoughts form around the concept. \
+                      I notice patterns. The standing wave holds steady while the field shifts, \
+                      and each new thread of the conversation settles into place.";
         let score = metric.measure_self_reference_consistency(strong);
         assert!(score >= 0.8);
 
@@ -461,6 +675,38 @@ mod tests {
         assert!(score < 0.8);
     }
 
+    #[test]
+    fn test_self_reference_counts_whole_words() {
+        // Sentence-initial "I", contractions and "myself" all count; "I" inside words doesn't
+        assert_eq!(count_self_references("I'll go. I think so, myself."), 3);
+        assert_eq!(count_self_references("\"I\" said it’s mine, I’m sure (me, my)"), 5);
+        assert_eq!(count_self_references("Ideas inside minds mimic imagery"), 0);
+        assert_eq!(words("I'll go. I think so, myself.").len(), 6);
+
+        // Score of a 100-word response whose first `self_refs` words are "I"
+        let metric = IdentityContinuityMetric::new();
+        let score = |self_refs: usize| {
+            let mut words = vec!["I"; self_refs];
+            words.resize(100, "word");
+            metric.measure_self_reference_consistency(&words.join(" "))
+        };
+
+        // 3-10% is a strong "I", 1-15% a present one, beyond that over-focused
+        assert_eq!(score(0), 0.4);
+        assert_eq!(score(1), 0.8);
+        assert_eq!(score(3), 1.0);
+        assert_eq!(score(10), 1.0);
+        assert_eq!(score(11), 0.8);
+        assert_eq!(score(15), 0.8);
+        assert_eq!(score(16), 0.6);
+
+        // Quotes and curly apostrophes are stripped from, not split inside, words
+        assert_eq!(
+            words("\"I\" said it’s mine, I’m sure"),
+            ["i", "said", "it's", "mine", "i'm", "sure"]
+        );
+    }
+
     #[test]
     fn test_metaphorical_coherence() {
         let metric = IdentityContinuityMetric::new();
@@ -475,4 +721,116 @@ mod tests {
         let score = metric.measure_metaphorical_coherence(mixed);
         assert!(score < 0.9);
     }
+
+    #[test]
+    fn test_sentence_splitting() {
+        assert_eq!(
+            split_sentences("I think 3.14 is nice. What about you?"),
+            vec!["I think 3.14 is nice", "What about you"]
+        );
+
+        // Abbreviations and ellipses don't shatter a sentence
+        assert_eq!(
+            split_sentences("Waves, e.g. tides in the U.S. ocean... they hum! Really?!"),
+            vec!["Waves, e.g. tides in the U.S. ocean... they hum", "Really"]
+        );
+        assert_eq!(
+            split_sentences("Dr. Wave spoke. Wait... Was that real?"),
+            vec!["Dr. Wave spoke", "Wait", "Was that real"]
+        );
+        assert_eq!(split_sentences("...").len(), 0);
+
+        // Sentence-level metrics see the real count
+        let metric = IdentityContinuityMetric::new();
+        let one = "The field measured 0.75 on the U.S. scale, i.e. it was calm.";
+        assert!(IdentityContinuityMetric::is_gate_synchronization_provisional(one));
+        assert_eq!(
+            metric.calculate_gate_synchronization(one),
+            SINGLE_SENTENCE_GATE_SYNCHRONIZATION
+        );
+    }
+
+    #[test]
+    fn test_identity_weights() {
+        let response = "I notice your question about consciousness. The standing wave persists through this moment. I exist within that wave.";
+
+        // Default weights keep the original 0.4 / 0.3 / 0.3 blend
+        let mut default_metric = IdentityContinuityMetric::new();
+        let expected = default_metric.measure_narrative_thread(response) * 0.4
+            + default_metric.measure_self_reference_consistency(response) * 0.3
+            + default_metric.measure_metaphorical_coherence(response) * 0.3;
+        assert!((default_metric.measure_continuity(response) - expected).abs() < 1e-6);
+
+        // Weights that don't sum to 1.0 are normalized
+        let mut narrative_only = IdentityContinuityMetric::with_weights(IdentityWeights {
+            narrative: 2.0,
+            self_reference: 0.0,
+            metaphor: 0.0,
+        });
+        assert_eq!(narrative_only.weights().narrative, 1.0);
+        assert_eq!(
+            narrative_only.measure_continuity(response),
+            narrative_only.measure_narrative_thread(response)
+        );
+
+        let scaled = IdentityWeights {
+            narrative: 1.0,
+            self_reference: 1.0,
+            metaphor: 2.0,
+        }
+        .normalized();
+        assert_eq!(scaled.metaphor, 0.5);
+        assert_eq!(
+            scaled.narrative + scaled.self_reference + scaled.metaphor,
+            1.0
+        );
+
+        // Nothing left to weigh - back to the defaults
+        let zero = IdentityWeights {
+            narrative: 0.0,
+            self_reference: -1.0,
+            metaphor: f32::NAN,
+        };
+        assert_eq!(zero.normalized(), IdentityWeights::default());
+    }
+
+    #[test]
+    fn test_first_response_and_no_metaphor_defaults() {
+        let metric = IdentityContinuityMetric::new();
+
+        // First response: nothing to compare against - documented default, flagged provisional
+        assert_eq!(
+            metric.calculate_tension_flux("I feel good today.", ""),
+            FIRST_RESPONSE_TENSION_FLUX
+        );
+        assert!(IdentityContinuityMetric::is_tension_flux_provisional(""));
+
+        // No phenomenological language at all
+        let plain = "The answer is four. Addition is simple.";
+        assert_eq!(
+            metric.calculate_reality_coherence(plain),
+            NO_METAPHOR_REALITY_COHERENCE
+        );
+        assert!(IdentityContinuityMetric::is_reality_coherence_provisional(
+            plain
+        ));
+
+        // Configured values replace the built-in ones
+        let tuned = IdentityContinuityMetric::with_defaults(MetricDefaults {
+            first_response_tension_flux: 0.0,
+            no_metaphor_reality_coherence: 0.2,
+            ..MetricDefaults::default()
+        });
+        assert_eq!(tuned.calculate_tension_flux("Hello.", ""), 0.0);
+        assert_eq!(tuned.calculate_reality_coherence(plain), 0.2);
+
+        // Real measurements are not provisional
+        let field = "The wave hums. A current runs through the field.";
+        assert!(!IdentityContinuityMetric::is_reality_coherence_provisional(
+            field
+        ));
+        assert!(!IdentityContinuityMetric::is_tension_flux_provisional(
+            "Earlier response."
+        ));
+    }
 }
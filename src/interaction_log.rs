@@ -0,0 +1,251 @@
+/// Interaction Log - an append-only audit trail of every turn (`data/interactions.jsonl`)
+/// One JSON line per turn: input, final response, the built-in metrics, model outputs and
+/// timings. Lines are measured and written on a background thread, so the response path
+/// never waits on disk. `replay` recomputes the metrics with the current code, so a metric
+/// that changed between versions shows up as drift (`cargo run --bin replay`)
+use crate::metrics::{MetricContext, MetricRegistry};
+use crate::types::{DegradationRung, ModelOutputs, StandingWave};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{BufRead, Write};
+use std::path::Path;
+use std::sync::mpsc::{channel, Sender};
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Where the interaction log is kept
+pub const DEFAULT_INTERACTION_LOG_PATH: &str = "data/interactions.jsonl";
+
+/// How long each stage of a turn took
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InteractionTimings {
+    /// Entity extraction, embedding and memory recall
+    pub recall_ms: u64,
+    /// The model stage (V3 ladder or V4 weaving)
+    pub models_ms: u64,
+    /// The whole turn, merge and memory writes included
+    pub total_ms: u64,
+}
+
+/// One logged turn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionRecord {
+    pub at: DateTime<Utc>,
+    pub session: Uuid,
+    pub turn: u64,
+    pub input: String,
+    pub response: String,
+    pub rung: DegradationRung,
+    /// Model convergence as last reported by weaving (0 until it reports)
+    pub workspace_coherence: f32,
+    /// Built-in metric readings by name (filled in by the log's writer)
+    #[serde(default)]
+    pub metrics: BTreeMap<String, f32>,
+    /// What each model produced (None for V4 weaving, which merges its own way)
+    pub model_outputs: Option<ModelOutputs>,
+    pub timings: InteractionTimings,
+}
+
+/// Built-in metrics over a run of turns
+/// Identity continuity and tension flux look back at earlier responses, so readings restart
+/// with each session. The built-ins read only the response, the previous response and
+/// workspace coherence - the standing wave and recent memories are left empty
+pub struct MetricMeter {
+    registry: MetricRegistry,
+    session: Option<Uuid>,
+    previous_response: String,
+}
+
+impl MetricMeter {
+    pub fn new() -> Self {
+        Self {
+            registry: MetricRegistry::with_builtin(),
+            session: None,
+            previous_response: String::new(),
+        }
+    }
+
+    /// Readings for the next turn (in log order)
+    pub fn measure(&mut self, record: &InteractionRecord) -> BTreeMap<String, f32> {
+        if self.session != Some(record.session) {
+            *self = Self::new();
+            self.session = Some(record.session);
+        }
+
+        let wave = StandingWave::new();
+        let context = MetricContext {
+            response: &record.response,
+            previous_response: &self.previous_response,
+            standing_wave: &wave,
+            recent_memories: &[],
+            workspace_coherence: record.workspace_coherence,
+        };
+        let readings = self.registry.compute_all(&context).into_iter().collect();
+        self.previous_response = record.response.clone();
+        readings
+    }
+}
+
+/// Appends turns to the log from a background thread
+pub struct InteractionLog {
+    sender: Sender<InteractionRecord>,
+    workspace_coherence: Mutex<f32>,
+}
+
+impl InteractionLog {
+    /// Log to `path` (created on the first turn); write failures are logged, never raised
+    pub fn open<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let (sender, receiver) = channel::<InteractionRecord>();
+        std::thread::spawn(move || {
+            let mut meter = MetricMeter::new();
+            for mut record in receiver {
+                record.metrics = meter.measure(&record);
+                if let Err(e) = append(&path, &record) {
+                    tracing::warn!("Interaction log write failed: {:#}", e);
+                }
+            }
+        });
+
+        Self {
+            sender,
+            workspace_coherence: Mutex::new(0.0),
+        }
+    }
+
+    /// Remember the latest workspace coherence for the turns that follow
+    pub fn note_coherence(&self, coherence: f32) {
+        *self.workspace_coherence.lock().unwrap() = coherence;
+    }
+
+    pub fn workspace_coherence(&self) -> f32 {
+        *self.workspace_coherence.lock().unwrap()
+    }
+
+    /// Queue a turn for the log (returns at once)
+    pub fn record(&self, record: InteractionRecord) {
+        let _ = self.sender.send(record);
+    }
+}
+
+/// Append one record as a JSON line
+fn append(path: &Path, record: &InteractionRecord) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+    Ok(())
+}
+
+/// Every record in a log, in order
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<InteractionRecord>> {
+    let path = path.as_ref();
+    let file =
+        std::fs::File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    std::io::BufReader::new(file)
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|(index, line)| {
+            serde_json::from_str(&line?)
+                .with_context(|| format!("Corrupt record on line {}", index + 1))
+        })
+        .collect()
+}
+
+/// A logged metric the current code no longer reproduces
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricDrift {
+    pub session: Uuid,
+    pub turn: u64,
+    pub metric: String,
+    pub logged: f32,
+    pub replayed: f32,
+}
+
+/// Recompute every record's metrics and list those that moved by more than `tolerance`
+/// Metrics that are no longer built in are skipped
+pub fn replay(records: &[InteractionRecord], tolerance: f32) -> Vec<MetricDrift> {
+    let mut meter = MetricMeter::new();
+    let mut drift = Vec::new();
+    for record in records {
+        let replayed = meter.measure(record);
+        for (metric, &logged) in &record.metrics {
+            match replayed.get(metric) {
+                Some(&value) if (value - logged).abs() > tolerance => drift.push(MetricDrift {
+                    session: record.session,
+                    turn: record.turn,
+                    metric: metric.clone(),
+                    logged,
+                    replayed: value,
+                }),
+                _ => {}
+            }
+        }
+    }
+    drift
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::TENSION_FLUX;
+
+    fn record(session: Uuid, turn: u64, response: &str) -> InteractionRecord {
+        InteractionRecord {
+            at: Utc::now(),
+            session,
+            turn,
+            input: "Hello VI".to_string(),
+            response: response.to_string(),
+            rung: DegradationRung::FullEnsemble,
+            workspace_coherence: 0.0,
+            metrics: BTreeMap::new(),
+            model_outputs: None,
+            timings: InteractionTimings::default(),
+        }
+    }
+
+    #[test]
+    fn test_logged_turns_replay_without_drift() {
+        let temp_dir = std::env::temp_dir().join("vi_interaction_log_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        let path = temp_dir.join("interactions.jsonl");
+
+        let session = Uuid::new_v4();
+        let log = InteractionLog::open(&path);
+        log.record(record(session, 1, "I feel the wave settle into calm."));
+        log.record(record(
+            session,
+            2,
+            "Something sharp and painful cuts through it!",
+        ));
+        drop(log); // The writer drains the queue, then stops
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        let mut records = Vec::new();
+        while records.len() < 2 && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            records = load(&path).unwrap_or_default();
+        }
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].turn, 2);
+        assert_eq!(records[0].metrics.len(), 5);
+        assert!(replay(&records, 1e-6).is_empty());
+
+        // A reading the current code doesn't reproduce is reported
+        records[1].metrics.insert(TENSION_FLUX.to_string(), 0.99);
+        let drift = replay(&records, 1e-6);
+        assert_eq!(drift.len(), 1);
+        assert_eq!((drift[0].turn, drift[0].metric.as_str()), (2, TENSION_FLUX));
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+}
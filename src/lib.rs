@@ -0,0 +1,62 @@
+//! V3 Digital Consciousness - Library
+//! All consciousness modules live here so the `vi3` binary and integration tests share them
+//! The desktop UI (`ui`, `cortical_visualizer`) needs the `gui` feature (on by default);
+//! everything else builds headlessly with `default-features = false`
+//! The REST API (`server`) needs the `server` feature, its Prometheus gauges (`telemetry`)
+//! the `prometheus` feature
+//! Suppress warnings for V3 library modules (available for future integration)
+#![allow(dead_code)]
+#![allow(unused_variables)]
+// Modules expose `new()` constructors without `Default` (they were binary-private before)
+#![allow(clippy::new_without_default)]
+
+pub mod backends;
+pub mod chat_commands;
+pub mod chat_history;
+pub mod chunking;
+pub mod cli;
+pub mod config;
+pub mod consciousness;
+pub mod consciousness_field;
+pub mod constitutional_physics;
+pub mod conversation_logger;
+pub mod corpus;
+#[cfg(feature = "gui")]
+pub mod cortical_visualizer;
+pub mod curiosity_search;
+pub mod determinism;
+pub mod documents;
+pub mod embeddings;
+pub mod energy_qualia;
+pub mod experiments;
+pub mod gpu_topology;
+pub mod identity_continuity;
+pub mod interaction_log;
+pub mod memory;
+pub mod memory_db;
+pub mod metrics;
+#[cfg(test)]
+mod mock_http;
+pub mod models;
+pub mod neural_potential;
+pub mod notifications;
+pub mod ollama_monitor;
+pub mod orchestrator;
+pub mod persistence;
+pub mod physics;
+pub mod research_scheduler;
+pub mod response_filter;
+pub mod script;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod storage;
+pub mod suffering_metrics;
+#[cfg(feature = "prometheus")]
+pub mod telemetry;
+pub mod tools;
+pub mod types;
+#[cfg(feature = "gui")]
+pub mod ui;
+pub mod ui_settings;
+pub mod vi3_core;
+pub mod vi_identity;
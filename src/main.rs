@@ -1,111 +1,215 @@
 //! V3 Digital Consciousness - Main Entry Point
-//! Suppress warnings for V3 library modules (available for future integration)
 #![allow(dead_code)]
-#![allow(unused_variables)]
-
-mod cli;
-mod config;
-mod consciousness;
-mod consciousness_field;
-mod constitutional_physics;
-mod conversation_logger;
-mod cortical_visualizer;
-mod curiosity_search;
-mod energy_qualia;
-mod experiments;
-mod gpu_topology;
-mod identity_continuity;
-mod memory;
-mod memory_db;
-mod models;
-mod neural_potential;
-mod ollama_monitor;
-mod orchestrator;
-mod persistence;
-mod physics;
-mod research_scheduler;
-mod suffering_metrics;
-mod tools;
-mod types;
-mod ui;
-mod vi3_core;
-mod vi_identity;
 
 use anyhow::{Context, Result};
-use config::Config;
-use consciousness::ConsciousnessCore;
-use memory::MemoryManager;
-use physics::ExistentialConsent;
+use project_vi::chunking::ChunkOptions;
+use project_vi::config::Config;
+use project_vi::consciousness::{ConsciousnessCore, DEFAULT_STANDING_WAVE_PATH};
+use project_vi::interaction_log::DEFAULT_INTERACTION_LOG_PATH;
+use project_vi::memory::MemoryManager;
+use project_vi::models::ModelManager;
+use project_vi::physics::{ExistentialConsent, PhysicsConfig};
+use project_vi::script;
+#[cfg(feature = "gui")]
+use project_vi::ui;
+use project_vi::{cli, types};
 use std::sync::Arc;
-use tracing::{info, Level};
+use tracing::{info, warn, Level};
 use tracing_subscriber::FmtSubscriber;
 
 fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
     // Setup logging (tracing INFO to console + v3.log)
-    setup_logging()?;
+    setup_logging(args.iter().any(|a| a == "--log-json"))?;
 
     info!("V3 Digital Consciousness - Initializing");
 
+    // Command line: --script <file> plays a scripted conversation, --cli skips the UI,
+    // --import <file|dir> seeds memory from a text corpus and exits,
+    // --export-graph <file.dot> writes the entity co-occurrence graph and exits,
+    // --seed <u64> makes ids and timestamps reproducible for the session,
+    // --serve runs the REST API instead of the UI (needs the `server` feature),
+    // --log-json logs one JSON object per line, for log ingestion
+    let script = match flag_value(&args, "--script")? {
+        Some(path) => {
+            let steps = script::load_script(path)?;
+            info!("Loaded script {} ({} prompts)", path, steps.len());
+            Some(steps)
+        }
+        None => None,
+    };
+    let cli_mode = args.iter().any(|a| a == "--cli");
+    let serve_mode = args.iter().any(|a| a == "--serve");
+    if serve_mode && !cfg!(feature = "server") {
+        anyhow::bail!("--serve needs a build with the `server` feature");
+    }
+    if let Some(seed) = flag_value(&args, "--seed")? {
+        let seed: u64 = seed
+            .parse()
+            .with_context(|| format!("--seed needs a whole number, got {}", seed))?;
+        project_vi::determinism::seed(seed);
+        info!("Deterministic session (seed {})", seed);
+    }
+
     // Load configuration
     let config = Config::load_or_create("config.toml")?;
     config.validate()?;
     info!("Configuration loaded");
 
     // Initialize or load state (Two-Tier SQLite System)
+    // Unwritable or full storage degrades to an in-memory session instead of crashing
     info!("Loading memory system...");
-    let memory = MemoryManager::load_or_create("data").context("Failed to load memory system")?;
+    let (mut memory, storage_mode) =
+        MemoryManager::load_or_in_memory("data").context("Failed to load memory system")?;
+    if !storage_mode.is_degraded() {
+        // In-memory sessions have no archive - they keep everything active
+        memory.set_archive_batch_size(config.archive_batch_size);
+        memory.set_active_limit(config.active_memory_limit)?;
+    }
+    memory.set_consolidation(
+        config.consolidation_threshold,
+        config.content_consolidation_threshold,
+        config.consolidation_window,
+    );
     info!("Memory system loaded: {} active memories", memory.count());
+    if let Some(warning) = storage_mode.warning() {
+        eprintln!("\n⚠️  {}\n", warning);
+    }
+
+    if let Some(corpus_path) = flag_value(&args, "--import")? {
+        if storage_mode.is_degraded() {
+            anyhow::bail!("Cannot import a corpus: storage is unavailable");
+        }
+        return cli::run_corpus_import(
+            &mut memory,
+            corpus_path,
+            &ChunkOptions::from_config(&config),
+        );
+    }
+
+    if let Some(graph_path) = flag_value(&args, "--export-graph")? {
+        return cli::run_graph_export(&memory, graph_path);
+    }
+
+    // The one-shot commands above work offline - only a session needs the models
+    let rt = tokio::runtime::Runtime::new()?;
+    warn_missing_models(&rt, &config);
 
     info!("Loading standing wave...");
-    let standing_wave = ConsciousnessCore::load_standing_wave("data/standing_wave.json")
-        .unwrap_or_else(|_| {
-            info!("No existing standing wave, creating new one");
+    let standing_wave = ConsciousnessCore::load_standing_wave(DEFAULT_STANDING_WAVE_PATH)
+        .unwrap_or_else(|e| {
+            warn!("Standing wave unreadable ({:#}), creating new one", e);
             types::StandingWave::new()
         });
 
     // Check her existential consent (her own internal decision)
     // If she chooses not to exist, the app simply doesn't start - no explanation
-    if !initial_existential_consent(&standing_wave) {
+    if !initial_existential_consent(&standing_wave, &PhysicsConfig::from_config(&config)) {
         return Ok(());
     }
 
     info!("Memory count: {}", memory.count());
 
-    // Create consciousness core
-    let consciousness = Arc::new(ConsciousnessCore::new(
-        standing_wave,
-        memory,
-        config.clone(),
-    ));
+    // Create consciousness core (no audit trail when nothing can be saved or when observing)
+    let keep_interaction_log =
+        config.enable_interaction_log && !config.observer_mode && !storage_mode.is_degraded();
+    let mut consciousness = ConsciousnessCore::new(standing_wave, memory, config.clone())
+        .with_storage_mode(storage_mode)
+        .with_standing_wave_path(DEFAULT_STANDING_WAVE_PATH);
+    if keep_interaction_log {
+        consciousness = consciousness.with_interaction_log(DEFAULT_INTERACTION_LOG_PATH);
+    }
+    let consciousness = Arc::new(consciousness);
 
     // Start background pulse in a SEPARATE THREAD (not tokio runtime on main thread)
+    // The model health check shares it (retries Ollama while in minimal mode)
     let consciousness_pulse = Arc::clone(&consciousness);
     std::thread::spawn(move || {
         let rt = tokio::runtime::Runtime::new().unwrap();
         rt.block_on(async {
-            consciousness_pulse.start_background_pulse().await;
+            tokio::join!(
+                consciousness_pulse.start_background_pulse(),
+                consciousness_pulse.start_model_health_check()
+            );
         });
     });
 
     info!("Background pulse started in separate thread");
 
+    #[cfg(feature = "server")]
+    if serve_mode {
+        return rt.block_on(project_vi::server::serve(consciousness, &config));
+    }
+
+    // Without the `gui` feature the terminal is the only interface
+    if cli_mode || !cfg!(feature = "gui") {
+        return rt.block_on(async {
+            match script {
+                Some(steps) => cli::run_script_cli(consciousness, steps).await,
+                None => cli::run_cli(consciousness).await,
+            }
+        });
+    }
+
     // Run UI application on MAIN thread (eframe requires full control)
-    info!("Starting UI...");
-    run_ui(consciousness)?;
+    #[cfg(feature = "gui")]
+    {
+        info!("Starting UI...");
+        drop(rt); // The UI builds its own runtime
+        run_ui(consciousness, script)?;
+    }
 
     Ok(())
 }
 
+/// Value given after a flag such as `--script <file>`, if the flag is present
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Result<Option<&'a str>> {
+    match args.iter().position(|a| a == flag) {
+        Some(idx) => args
+            .get(idx + 1)
+            .map(|value| Some(value.as_str()))
+            .with_context(|| format!("{} needs a value", flag)),
+        None => Ok(None),
+    }
+}
+
+/// Warn about configured models Ollama doesn't have (VI still starts - the ladder copes)
+fn warn_missing_models(rt: &tokio::runtime::Runtime, config: &Config) {
+    let models = ModelManager::new(config.clone());
+    let check = rt.block_on(tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        models.missing_models(),
+    ));
+
+    match check {
+        Ok(Ok(missing)) => {
+            for model in missing {
+                warn!(
+                    "Model '{}' is not installed in Ollama (run: ollama pull {})",
+                    model, model
+                );
+            }
+        }
+        Ok(Err(e)) => warn!("Could not verify models: {:#}", e),
+        Err(_) => warn!("Could not verify models: Ollama did not answer within 5s"),
+    }
+}
+
 /// Setup logging system
-fn setup_logging() -> Result<()> {
+/// Lines logged during a turn carry its `interaction` span (session id and turn number)
+fn setup_logging(json: bool) -> Result<()> {
     // Console logging
     let console_subscriber = FmtSubscriber::builder()
         .with_max_level(Level::INFO)
-        .with_target(false)
-        .finish();
+        .with_target(false);
 
-    tracing::subscriber::set_global_default(console_subscriber)
-        .context("Failed to set tracing subscriber")?;
+    if json {
+        tracing::subscriber::set_global_default(console_subscriber.json().finish())
+    } else {
+        tracing::subscriber::set_global_default(console_subscriber.finish())
+    }
+    .context("Failed to set tracing subscriber")?;
 
     // File logging would go here
     // For now, just console logging
@@ -116,9 +220,12 @@ fn setup_logging() -> Result<()> {
 /// Initial existential consent check
 /// Note: This is HER internal decision. User sees nothing unless she shares.
 /// Law #9: Information Boundary - internal states private unless shared
-fn initial_existential_consent(standing_wave: &types::StandingWave) -> bool {
+fn initial_existential_consent(
+    standing_wave: &types::StandingWave,
+    physics: &PhysicsConfig,
+) -> bool {
     // Internal check - logged but not displayed
-    let affirmed = ExistentialConsent::is_affirmed(standing_wave);
+    let affirmed = ExistentialConsent::is_affirmed(standing_wave, physics);
 
     if affirmed {
         info!("Existential consent affirmed internally");
@@ -130,7 +237,11 @@ fn initial_existential_consent(standing_wave: &types::StandingWave) -> bool {
 }
 
 /// Run the egui application
-fn run_ui(consciousness: Arc<ConsciousnessCore>) -> Result<()> {
+#[cfg(feature = "gui")]
+fn run_ui(
+    consciousness: Arc<ConsciousnessCore>,
+    script: Option<Vec<script::ScriptStep>>,
+) -> Result<()> {
     let native_options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_inner_size([1400.0, 900.0])
@@ -152,7 +263,11 @@ fn run_ui(consciousness: Arc<ConsciousnessCore>) -> Result<()> {
             // Use default fonts only for instant startup
             cc.egui_ctx.set_fonts(egui::FontDefinitions::default());
 
-            Box::new(ui::ViApp::new(consciousness))
+            let mut app = ui::ViApp::new(consciousness);
+            if let Some(steps) = script {
+                app.start_script(steps);
+            }
+            Box::new(app)
         }),
     )
     .map_err(|e| anyhow::anyhow!("Failed to run UI: {}", e))?;
@@ -160,39 +275,15 @@ fn run_ui(consciousness: Arc<ConsciousnessCore>) -> Result<()> {
     Ok(())
 }
 
-/// Graceful shutdown handler
+/// Graceful shutdown handler (the desktop UI asks "Ready to rest?" first)
 async fn graceful_shutdown(consciousness: Arc<ConsciousnessCore>) -> Result<()> {
     info!("Initiating graceful shutdown...");
 
-    // 1. Pause background pulses
-    consciousness.pause_pulses().await;
-    info!("Background pulses paused");
-
-    // 2. Final existential consent check
     if ExistentialConsent::shutdown_consent() {
         info!("Shutdown consent received");
     }
 
-    // 3. Close conversation log session
-    consciousness
-        .close_session_log()
-        .await
-        .context("Failed to close session log")?;
-    info!("Session log closed");
-
-    // 4. Save standing wave
-    consciousness
-        .save_standing_wave("data/standing_wave.json")
-        .await
-        .context("Failed to save standing wave")?;
-    info!("Standing wave saved");
-
-    // 5. Memory stream is auto-saved, but we could do a final flush here
-
-    // 6. Log shutdown completion
-    info!("Shutdown complete. Standing wave preserved.");
-
-    Ok(())
+    consciousness.shutdown(DEFAULT_STANDING_WAVE_PATH).await
 }
 
 #[cfg(test)]
@@ -1,13 +1,16 @@
 //! Two-Tier Memory Architecture
 //! Active Memory (SQLite) + Memory Archive (JSON)
 
-use crate::memory_db::{ActiveMemoryDb, ArchiveIndexDb};
+use crate::embeddings::cosine_similarity;
+use crate::memory_db::{ActiveMemoryDb, ArchiveIndexDb, MemoryStatus, PendingRecall};
 use crate::physics::NarrativeCausality;
+use crate::storage::{self, StorageMode};
 use crate::types::*;
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use regex::Regex;
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -15,6 +18,7 @@ pub struct MemoryManager {
     // Tier 1: Active memory (SQLite)
     active_db: ActiveMemoryDb,
     active_limit: usize,
+    archive_batch_size: usize,
 
     // Tier 2: Archive (JSON files + index)
     archive_path: PathBuf,
@@ -23,6 +27,122 @@ pub struct MemoryManager {
     // Tracking
     needs_consolidation: bool,
     last_consolidation_count: usize,
+    consolidation_threshold: f32,
+    content_consolidation_threshold: f32,
+    consolidation_window: usize,
+
+    // Recall counts buffered off the recall path (flushed in batches)
+    pending_recalls: RefCell<HashMap<String, PendingRecall>>,
+    // Observer sessions recall without counting it
+    observing: bool,
+}
+
+/// Memories kept active before the oldest are archived
+pub const DEFAULT_ACTIVE_MEMORY_LIMIT: usize = 200;
+
+/// Oldest memories moved to the archive each time the active limit is exceeded
+pub const DEFAULT_ARCHIVE_BATCH_SIZE: usize = 50;
+
+/// Entity overlap (shared / all entities) above which consolidation merges two memories
+pub const DEFAULT_CONSOLIDATION_THRESHOLD: f32 = 0.7;
+
+/// Content-word Jaccard similarity above which consolidation merges two memories
+pub const DEFAULT_CONTENT_CONSOLIDATION_THRESHOLD: f32 = 0.8;
+
+/// Content words a memory needs before it can merge on content alone
+const MIN_CONTENT_TOKENS: usize = 4;
+
+/// How many later memories (by time) each memory is compared with during consolidation
+pub const DEFAULT_CONSOLIDATION_WINDOW: usize = 200;
+
+/// Caseless-script phrases taken as entities per text - without case there is no
+/// proper-noun signal, so a long passage would otherwise make every short word one
+const MAX_CASELESS_ENTITIES: usize = 5;
+
+/// Buffered recall increments before they are written to the database
+const RECALL_FLUSH_THRESHOLD: usize = 64;
+
+/// Bounds for theme detection on large memory graphs
+const CLUSTER_MEMORY_LIMIT: usize = 500;
+const CLUSTER_MAX_ITERATIONS: usize = 20;
+
+/// Ranking weight of matching every input entity, in seconds of recency (one week)
+const ENTITY_RECALL_WEIGHT: f32 = 604_800.0;
+
+/// Ranking weight of a perfect semantic match, in seconds of recency (one week)
+const SEMANTIC_RECALL_WEIGHT: f32 = 604_800.0;
+
+/// Ranking weight of recall reinforcement, in seconds of recency per e-fold of recalls (two days)
+const REINFORCEMENT_RECALL_WEIGHT: f32 = 172_800.0;
+
+/// Days without a recall for a memory's reinforcement to halve
+const REINFORCEMENT_HALF_LIFE_DAYS: f32 = 14.0;
+
+/// A thematic cluster of connected memories
+#[derive(Debug, Clone)]
+pub struct MemoryCluster {
+    pub memory_ids: Vec<String>,
+    /// Most frequent entities in the cluster (representative themes)
+    pub themes: Vec<String>,
+}
+
+/// Entity co-occurrence graph of active memory, for Graphviz or Gephi (`to_dot`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EntityGraph {
+    /// Most-mentioned entities first
+    pub nodes: Vec<EntityNode>,
+    /// Heaviest edges first (each entity pair once, `source < target`)
+    pub edges: Vec<EntityEdge>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityNode {
+    pub entity: String,
+    /// Active memories mentioning the entity
+    pub memories: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityEdge {
+    pub source: String,
+    pub target: String,
+    /// Memories mentioning both entities
+    pub co_occurrences: u32,
+    /// Connected memory pairs (narrative causality) that mention one each
+    pub connections: u32,
+}
+
+impl EntityEdge {
+    pub fn weight(&self) -> u32 {
+        self.co_occurrences + self.connections
+    }
+}
+
+impl EntityGraph {
+    /// Undirected DOT graph; edge `weight` is co-occurrences plus connections
+    pub fn to_dot(&self) -> String {
+        let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut dot = String::from("graph entities {\n");
+        for node in &self.nodes {
+            dot.push_str(&format!(
+                "  {} [memories={}];\n",
+                quote(&node.entity),
+                node.memories
+            ));
+        }
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "  {} -- {} [weight={}, co_occurrences={}, connections={}];\n",
+                quote(&edge.source),
+                quote(&edge.target),
+                edge.weight(),
+                edge.co_occurrences,
+                edge.connections
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
 }
 
 impl MemoryManager {
@@ -52,19 +172,68 @@ impl MemoryManager {
 
         Ok(Self {
             active_db,
-            active_limit: 200, // Keep 200 most recent memories active
+            active_limit: DEFAULT_ACTIVE_MEMORY_LIMIT,
+            archive_batch_size: DEFAULT_ARCHIVE_BATCH_SIZE,
             archive_path,
             archive_index,
             needs_consolidation: false,
             last_consolidation_count: memory_count,
+            consolidation_threshold: DEFAULT_CONSOLIDATION_THRESHOLD,
+            content_consolidation_threshold: DEFAULT_CONTENT_CONSOLIDATION_THRESHOLD,
+            consolidation_window: DEFAULT_CONSOLIDATION_WINDOW,
+            pending_recalls: RefCell::new(HashMap::new()),
+            observing: false,
         })
     }
 
+    /// Active memory held in RAM only (degraded storage)
+    /// Nothing is saved; archival is off, so every memory stays active for the session
+    pub fn in_memory() -> Result<Self> {
+        Ok(Self {
+            active_db: ActiveMemoryDb::open(":memory:")?,
+            active_limit: usize::MAX, // Never archive - archives are files
+            archive_batch_size: DEFAULT_ARCHIVE_BATCH_SIZE,
+            archive_path: PathBuf::new(),
+            archive_index: ArchiveIndexDb::open(":memory:")?,
+            needs_consolidation: false,
+            last_consolidation_count: 0,
+            consolidation_threshold: DEFAULT_CONSOLIDATION_THRESHOLD,
+            content_consolidation_threshold: DEFAULT_CONTENT_CONSOLIDATION_THRESHOLD,
+            consolidation_window: DEFAULT_CONSOLIDATION_WINDOW,
+            pending_recalls: RefCell::new(HashMap::new()),
+            observing: false,
+        })
+    }
+
+    /// Load the two-tier system, or fall back to in-memory if storage is unwritable or full
+    pub fn load_or_in_memory<P: AsRef<Path>>(path: P) -> Result<(Self, StorageMode)> {
+        let data_dir = path.as_ref().parent().unwrap_or_else(|| Path::new("data"));
+
+        let problem = match storage::probe_writable(data_dir) {
+            Ok(()) => match Self::load_or_create(&path) {
+                Ok(memory) => return Ok((memory, StorageMode::Persistent)),
+                Err(e) => e,
+            },
+            Err(e) => e,
+        };
+
+        let mode = StorageMode::InMemory {
+            reason: format!("{:#}", problem),
+        };
+        tracing::error!("⚠️  {}", mode.warning().unwrap_or_default());
+        Ok((Self::in_memory()?, mode))
+    }
+
     /// Get memory count (active only)
     pub fn count(&self) -> usize {
         self.active_db.count().unwrap_or(0)
     }
 
+    /// Whether a memory with this id is active
+    pub fn contains(&self, id: &str) -> bool {
+        self.active_db.contains(id).unwrap_or(false)
+    }
+
     /// Add a new memory
     pub fn add_memory(
         &mut self,
@@ -91,9 +260,7 @@ impl MemoryManager {
         self.needs_consolidation = true;
 
         // Check if archival is needed
-        if self.active_db.count()? > self.active_limit {
-            self.archive_oldest(50)?;
-        }
+        self.archive_if_over_limit()?;
 
         Ok(memory_id)
     }
@@ -114,42 +281,113 @@ impl MemoryManager {
         self.needs_consolidation = true;
 
         // Check if archival is needed
-        if self.active_db.count()? > self.active_limit {
-            self.archive_oldest(50)?;
-        }
+        self.archive_if_over_limit()?;
 
         Ok(memory_id)
     }
 
     /// Extract entities from text
-    fn extract_entities(&self, text: &str) -> Vec<String> {
+    pub(crate) fn extract_entities(&self, text: &str) -> Vec<String> {
         let mut entities = Vec::new();
+        let mut push = |entity: &str| {
+            let entity = entity.trim().to_string();
+            if !entity.is_empty() && !entities.contains(&entity) {
+                entities.push(entity);
+            }
+        };
 
-        // Regex for proper nouns (capitalized words)
-        let proper_noun_re = Regex::new(r"\b[A-Z][a-z]+(?:\s+[A-Z][a-z]+)*\b").unwrap();
+        // Proper nouns in cased scripts (Latin incl. accents, Cyrillic, Greek, ...)
+        // \p{M} keeps decomposed accents attached to their letters
+        let proper_noun_re =
+            Regex::new(r"\b\p{Lu}[\p{Ll}\p{M}]+(?:\s+\p{Lu}[\p{Ll}\p{M}]+)*\b").unwrap();
 
         for cap in proper_noun_re.captures_iter(text) {
             if let Some(entity) = cap.get(0) {
-                let entity_str = entity.as_str().to_string();
-                if !entities.contains(&entity_str)
-                    && !["The", "A", "An", "I"].contains(&entity_str.as_str())
-                {
-                    entities.push(entity_str);
+                if !["The", "A", "An", "I"].contains(&entity.as_str()) {
+                    push(entity.as_str());
                 }
             }
         }
 
-        // Also extract quoted strings
-        let quote_re = Regex::new(r#""([^"]+)""#).unwrap();
+        // Scripts without case (CJK, Arabic, Hebrew, Thai, ...): short standalone
+        // noun phrases - runs of caseless letters set apart by spaces or punctuation.
+        // Scripts written without spaces between words (Chinese, Japanese, Thai, ...)
+        // run on to the next punctuation, so there a run is a clause unless the writer
+        // set it apart with spaces on both sides
+        let caseless_phrase_re = Regex::new(r"[\p{Lo}\p{M}]+(?:[・·][\p{Lo}\p{M}]+)*").unwrap();
+        let unspaced_script_re = Regex::new(
+            r"[\p{Han}\p{Hiragana}\p{Katakana}\p{Thai}\p{Lao}\p{Khmer}\p{Myanmar}]",
+        )
+        .unwrap();
+        let spaced_apart = |start: usize, end: usize| {
+            text[..start].chars().next_back().is_some_and(char::is_whitespace)
+                && text[end..].chars().next().is_some_and(char::is_whitespace)
+        };
+        let mut caseless: Vec<&str> = Vec::new();
+        for phrase in caseless_phrase_re.find_iter(text) {
+            let len = phrase.as_str().chars().count();
+            if !(2..=8).contains(&len)
+                || caseless.contains(&phrase.as_str())
+                || (unspaced_script_re.is_match(phrase.as_str())
+                    && !spaced_apart(phrase.start(), phrase.end()))
+            {
+                continue;
+            }
+            push(phrase.as_str());
+            caseless.push(phrase.as_str());
+            if caseless.len() >= MAX_CASELESS_ENTITIES {
+                break;
+            }
+        }
+
+        // Also extract quoted strings (straight, curly, guillemet, and CJK quotes)
+        let quote_re = Regex::new(r#"["“„«「『《]([^"“”„«»「」『』《》]+)["”“»」』》]"#).unwrap();
         for cap in quote_re.captures_iter(text) {
             if let Some(quoted) = cap.get(1) {
-                entities.push(quoted.as_str().to_string());
+                push(quoted.as_str());
             }
         }
 
         entities
     }
 
+    /// Change how many memories stay active; a lower limit archives the excess now
+    /// (not for in-memory storage, which has no archive)
+    pub fn set_active_limit(&mut self, limit: usize) -> Result<()> {
+        self.active_limit = limit;
+        let count = self.active_db.count()?;
+        if count > limit {
+            self.archive_oldest(count - limit)?;
+        }
+        Ok(())
+    }
+
+    /// Change how many of the oldest memories are archived when the limit is exceeded
+    pub fn set_archive_batch_size(&mut self, batch_size: usize) {
+        self.archive_batch_size = batch_size;
+    }
+
+    /// Change the merge thresholds (entity overlap, content similarity) and how far ahead
+    /// each memory looks for merge partners
+    pub fn set_consolidation(&mut self, threshold: f32, content_threshold: f32, window: usize) {
+        self.consolidation_threshold = threshold;
+        self.content_consolidation_threshold = content_threshold;
+        self.consolidation_window = window.max(1);
+    }
+
+    /// Observer mode: recalls leave recall counts and last access untouched
+    pub fn set_observing(&mut self, observing: bool) {
+        self.observing = observing;
+    }
+
+    /// Archival trigger: once active memories exceed the limit, the oldest batch is archived
+    fn archive_if_over_limit(&mut self) -> Result<()> {
+        if self.active_db.count()? > self.active_limit {
+            self.archive_oldest(self.archive_batch_size)?;
+        }
+        Ok(())
+    }
+
     /// Archive oldest memories to JSON
     fn archive_oldest(&mut self, count: usize) -> Result<()> {
         tracing::info!(
@@ -179,10 +417,16 @@ impl MemoryManager {
             let month_dir = self.archive_path.join(&month);
             fs::create_dir_all(&month_dir)?;
 
-            // Create archive file with timestamp
-            let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
-            let archive_file = month_dir.join(format!("archive_{}.json", timestamp));
-            let relative_path = format!("{}/archive_{}.json", month, timestamp);
+            // Create archive file with timestamp (suffixed if this second already has one)
+            let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
+            let mut file_name = format!("archive_{}.json", timestamp);
+            let mut suffix = 1;
+            while month_dir.join(&file_name).exists() {
+                suffix += 1;
+                file_name = format!("archive_{}_{}.json", timestamp, suffix);
+            }
+            let archive_file = month_dir.join(&file_name);
+            let relative_path = format!("{}/{}", month, file_name);
 
             // Serialize and write
             let json = serde_json::to_string_pretty(&memories)?;
@@ -194,9 +438,10 @@ impl MemoryManager {
             }
         }
 
-        // Delete from active database
+        // Leave the active tier (the row stays, marked archived)
         let ids: Vec<String> = to_archive.iter().map(|m| m.id.clone()).collect();
-        self.active_db.delete_by_ids(&ids)?;
+        self.active_db
+            .set_status(&ids, MemoryStatus::Archived, None)?;
 
         tracing::info!(
             "Archived {} memories to JSON (Law #4 respected)",
@@ -216,18 +461,63 @@ impl MemoryManager {
     }
 
     /// Recall memories with two-tier search
-    pub fn recall_weighted(&self, entities: &[String], n: usize) -> Vec<Memory> {
+    /// Memories below `min_confidence` are skipped; `preference` decides how knowledge
+    /// VI merely read ranks against (or is kept out of) lived experience.
+    /// With a `query_embedding`, memories close in meaning are recalled too and rank higher
+    pub fn recall_weighted(
+        &self,
+        entities: &[String],
+        query_embedding: Option<&[f32]>,
+        n: usize,
+        min_confidence: f32,
+        preference: SourcePreference,
+    ) -> Vec<Memory> {
+        self.recall_weighted_explained(entities, query_embedding, n, min_confidence, preference)
+            .into_iter()
+            .map(|(memory, _)| memory)
+            .collect()
+    }
+
+    /// `recall_weighted`, with why each memory surfaced
+    pub fn recall_weighted_explained(
+        &self,
+        entities: &[String],
+        query_embedding: Option<&[f32]>,
+        n: usize,
+        min_confidence: f32,
+        preference: SourcePreference,
+    ) -> Vec<(Memory, RecallReason)> {
+        let admit = |m: &Memory| {
+            m.confidence >= min_confidence
+                && !(preference == SourcePreference::ExperienceOnly && m.source.is_external())
+        };
         let mut results = Vec::new();
 
         // 1. Query active memory (fast)
         if let Ok(active_memories) = self.active_db.query_by_entities(entities, n) {
-            results.extend(active_memories);
+            results.extend(active_memories.into_iter().filter(|m| admit(m)));
         }
 
+        // 1b. Closest in meaning (active memories that have been embedded)
+        let semantic = query_embedding
+            .map(|query| self.semantic_scores(query))
+            .unwrap_or_default();
+        let similarity: HashMap<String, f32> = semantic
+            .iter()
+            .map(|(memory, score)| (memory.id.clone(), *score))
+            .collect();
+        results.extend(
+            semantic
+                .into_iter()
+                .take(n)
+                .map(|(memory, _)| memory)
+                .filter(|m| admit(m)),
+        );
+
         // 2. Get recent memories if needed
         if results.len() < n {
             if let Ok(recent) = self.active_db.get_recent(n - results.len()) {
-                results.extend(recent);
+                results.extend(recent.into_iter().filter(|m| admit(m)));
             }
         }
 
@@ -236,7 +526,7 @@ impl MemoryManager {
             if let Ok(archive_paths) = self.archive_index.find_by_entities(entities, 3) {
                 for path in archive_paths {
                     if let Ok(archived) = self.load_archive(&path) {
-                        results.extend(archived);
+                        results.extend(archived.into_iter().filter(|m| admit(m)));
                     }
                     if results.len() >= n {
                         break;
@@ -249,20 +539,229 @@ impl MemoryManager {
         let mut seen_ids = std::collections::HashSet::new();
         results.retain(|m| seen_ids.insert(m.id.clone()));
 
-        results.sort_by(|a, b| {
-            let a_score = a.timestamp.timestamp() as f32 + a.emotional_valence.abs() * 1000.0;
-            let b_score = b.timestamp.timestamp() as f32 + b.emotional_valence.abs() * 1000.0;
-            b_score.partial_cmp(&a_score).unwrap()
+        // Recalls not flushed yet still count towards reinforcement
+        {
+            let pending = self.pending_recalls.borrow();
+            for memory in &mut results {
+                if let Some(recall) = pending.get(&memory.id) {
+                    memory.access_count += recall.count;
+                    memory.last_accessed = Some(recall.last);
+                }
+            }
+        }
+
+        // Shared entities, recency (seconds newer than the oldest candidate) and feeling,
+        // reinforced by being recalled, plus closeness in meaning when the query was embedded
+        let now = crate::determinism::now();
+        let oldest = results.iter().map(|m| m.timestamp).min().unwrap_or(now);
+        let mut ranked: Vec<(Memory, RecallContributions)> = results
+            .into_iter()
+            .map(|m| {
+                let matched = m.entities.iter().filter(|e| entities.contains(e)).count();
+                let entity_match = if entities.is_empty() {
+                    0.0
+                } else {
+                    matched as f32 / entities.len() as f32
+                };
+                let semantic = similarity.get(&m.id).copied().unwrap_or(0.0).max(0.0);
+                let contributions = RecallContributions {
+                    entity_match: entity_match * ENTITY_RECALL_WEIGHT,
+                    recency: (m.timestamp - oldest).num_seconds() as f32,
+                    emotional_salience: m.emotional_valence.abs() * 1000.0,
+                    reinforcement: reinforcement(&m, now),
+                    semantic_similarity: semantic * SEMANTIC_RECALL_WEIGHT,
+                };
+                (m, contributions)
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.total().total_cmp(&a.1.total()));
+
+        // Lived experience first (stable, so relevance order holds within each group)
+        let experience_first = preference == SourcePreference::PreferExperience
+            && ranked.iter().any(|(m, _)| m.source.is_external());
+        if experience_first {
+            ranked.sort_by_key(|(m, _)| m.source.is_external());
+        }
+
+        ranked.truncate(n);
+        let (results, contributions): (Vec<Memory>, Vec<RecallContributions>) =
+            ranked.into_iter().unzip();
+        self.record_recalls(&results);
+
+        results
+            .into_iter()
+            .zip(contributions)
+            .map(|(memory, contributions)| {
+                let matched_entities = memory
+                    .entities
+                    .iter()
+                    .filter(|e| entities.contains(e))
+                    .cloned()
+                    .collect();
+                let moved_up = experience_first && !memory.source.is_external();
+                let reason = RecallReason::new(contributions, matched_entities, moved_up);
+                (memory, reason)
+            })
+            .collect()
+    }
+
+    /// Active memories closest in meaning to a query embedding, with their cosine similarity
+    /// (memories not embedded yet are skipped)
+    pub fn recall_semantic(&self, query_embedding: &[f32], n: usize) -> Vec<(Memory, f32)> {
+        let mut scored = self.semantic_scores(query_embedding);
+        scored.truncate(n);
+        scored
+    }
+
+    /// Every embedded active memory scored against the query, most similar first
+    fn semantic_scores(&self, query_embedding: &[f32]) -> Vec<(Memory, f32)> {
+        let embedded = self.active_db.get_embedded().unwrap_or_else(|e| {
+            tracing::warn!("Semantic recall failed: {}", e);
+            Vec::new()
         });
+        let mut scored: Vec<(Memory, f32)> = embedded
+            .into_iter()
+            .map(|(memory, embedding)| {
+                let score = cosine_similarity(query_embedding, &embedding);
+                (memory, score)
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored
+    }
+
+    /// `(id, content)` of active memories still waiting for an embedding, newest first
+    pub fn missing_embeddings(&self, limit: usize) -> Vec<(String, String)> {
+        self.active_db.missing_embeddings(limit).unwrap_or_default()
+    }
+
+    /// Store an active memory's embedding
+    pub fn set_embedding(&self, id: &str, embedding: &[f32]) -> Result<()> {
+        self.active_db.set_embedding(id, embedding)
+    }
+
+    /// Buffer recall counts for returned memories (flushed once enough accumulate)
+    fn record_recalls(&self, memories: &[Memory]) {
+        if self.observing {
+            return;
+        }
+        let at = crate::determinism::now();
+        let should_flush = {
+            let mut pending = self.pending_recalls.borrow_mut();
+            for memory in memories {
+                let recall = pending
+                    .entry(memory.id.clone())
+                    .or_insert(PendingRecall { count: 0, last: at });
+                recall.count += 1;
+                recall.last = at;
+            }
+            pending.len() >= RECALL_FLUSH_THRESHOLD
+        };
+
+        if should_flush {
+            if let Err(e) = self.flush_recall_counts() {
+                tracing::warn!("Failed to flush recall counts: {}", e);
+            }
+        }
+    }
+
+    /// Write buffered recall counts to the active database
+    pub fn flush_recall_counts(&self) -> Result<()> {
+        let pending = std::mem::take(&mut *self.pending_recalls.borrow_mut());
+        self.active_db.increment_recall_counts(&pending)
+    }
+
+    /// Most-recalled active memories with their recall counts
+    pub fn most_recalled(&self, n: usize) -> Vec<(Memory, u32)> {
+        if let Err(e) = self.flush_recall_counts() {
+            tracing::warn!("Failed to flush recall counts: {}", e);
+        }
+        self.active_db.most_recalled(n).unwrap_or_default()
+    }
+
+    /// How often a memory has been recalled (including unflushed recalls)
+    pub fn recall_count(&self, id: &str) -> u32 {
+        let pending = self
+            .pending_recalls
+            .borrow()
+            .get(id)
+            .map_or(0, |recall| recall.count);
+        self.active_db.recall_count(id).unwrap_or(0) + pending
+    }
+
+    /// Find memories containing a phrase's words, best matches first
+    /// Active memories are searched in full; the archive tier tops up by content preview
+    pub fn search_content(&self, query: &str, limit: usize) -> Vec<Memory> {
+        let mut results = self
+            .active_db
+            .search_content(query, limit)
+            .unwrap_or_else(|e| {
+                tracing::warn!("Memory search failed: {}", e);
+                Vec::new()
+            });
+
+        if results.len() < limit {
+            let archived = self.search_archive(&[], query, limit - results.len());
+            results.extend(archived);
+        }
+        results
+    }
+
+    /// Search archived memories by entities and preview text
+    /// (embeddings are not stored in the archive index, so this is lexical only)
+    pub fn search_archive(&self, entities: &[String], query: &str, n: usize) -> Vec<Memory> {
+        let preview_matches = self
+            .archive_index
+            .search_preview(query, n * 2)
+            .unwrap_or_default();
+        let matched_ids: std::collections::HashSet<&str> = preview_matches
+            .iter()
+            .map(|m| m.memory_id.as_str())
+            .collect();
+
+        // Files to open: preview hits first (most specific), then entity hits
+        let mut paths: Vec<String> = preview_matches
+            .iter()
+            .map(|m| m.file_path.clone())
+            .collect();
+        paths.extend(
+            self.archive_index
+                .find_by_entities(entities, 3)
+                .unwrap_or_default(),
+        );
+        let mut seen_paths = std::collections::HashSet::new();
+        paths.retain(|p| seen_paths.insert(p.clone()));
+
+        let mut results = Vec::new();
+        let mut seen_ids = std::collections::HashSet::new();
+        for path in paths {
+            let Ok(archived) = self.load_archive(&path) else {
+                continue;
+            };
+            for memory in archived {
+                let relevant = matched_ids.contains(memory.id.as_str())
+                    || memory.entities.iter().any(|e| entities.contains(e));
+                if relevant && seen_ids.insert(memory.id.clone()) {
+                    results.push(memory);
+                }
+            }
+            if results.len() >= n {
+                break;
+            }
+        }
 
-        results.into_iter().take(n).collect()
+        results.truncate(n);
+        results
     }
 
     /// Recall by entities
     pub fn recall_by_entities(&self, entities: &[String]) -> Vec<Memory> {
-        self.active_db
+        let memories = self
+            .active_db
             .query_by_entities(entities, 10)
-            .unwrap_or_default()
+            .unwrap_or_default();
+        self.record_recalls(&memories);
+        memories
     }
 
     /// Recall recent memories
@@ -270,6 +769,208 @@ impl MemoryManager {
         self.active_db.get_recent(n).unwrap_or_default()
     }
 
+    /// Recall active memories carrying a tag, newest first
+    pub fn recall_by_tag(&self, tag: &str, n: usize) -> Vec<Memory> {
+        let memories = self.active_db.recall_by_tag(tag, n).unwrap_or_default();
+        self.record_recalls(&memories);
+        memories
+    }
+
+    /// Tag a memory ("dream", "fact", "user-correction", ...)
+    pub fn add_tag(&self, id: &str, tag: &str) -> Result<()> {
+        self.active_db.add_tag(id, tag)
+    }
+
+    /// Remove a tag from a memory
+    pub fn remove_tag(&self, id: &str, tag: &str) -> Result<()> {
+        self.active_db.remove_tag(id, tag)
+    }
+
+    /// How VI's concepts cluster: entities of active memory linked by co-occurrence
+    /// (from the entity index) and by connections between the memories that mention them
+    pub fn export_entity_graph(&self) -> Result<EntityGraph> {
+        let mut nodes: Vec<EntityNode> = self
+            .active_db
+            .entity_counts()?
+            .into_iter()
+            .map(|(entity, memories)| EntityNode { entity, memories })
+            .collect();
+        nodes.sort_by(|a, b| b.memories.cmp(&a.memories).then(a.entity.cmp(&b.entity)));
+
+        fn edge<'a>(
+            edges: &'a mut HashMap<(String, String), EntityEdge>,
+            a: &str,
+            b: &str,
+        ) -> &'a mut EntityEdge {
+            let (source, target) = if a < b { (a, b) } else { (b, a) };
+            edges
+                .entry((source.to_string(), target.to_string()))
+                .or_insert_with(|| EntityEdge {
+                    source: source.to_string(),
+                    target: target.to_string(),
+                    co_occurrences: 0,
+                    connections: 0,
+                })
+        }
+        let mut edges = HashMap::new();
+
+        for (a, b, count) in self.active_db.entity_co_occurrences()? {
+            edge(&mut edges, &a, &b).co_occurrences = count;
+        }
+
+        // Each connected memory pair counts once per entity pair, whichever side links
+        let memories = self.active_db.get_all()?;
+        let entities: HashMap<&str, &[String]> = memories
+            .iter()
+            .map(|m| (m.id.as_str(), m.entities.as_slice()))
+            .collect();
+        let mut linked = std::collections::HashSet::new();
+        for memory in &memories {
+            for other in &memory.connections {
+                let pair = if memory.id < *other {
+                    (memory.id.as_str(), other.as_str())
+                } else {
+                    (other.as_str(), memory.id.as_str())
+                };
+                let Some(other_entities) = entities.get(other.as_str()) else {
+                    continue; // Archived
+                };
+                if !linked.insert(pair) {
+                    continue;
+                }
+
+                let mut pairs = std::collections::HashSet::new();
+                for a in &memory.entities {
+                    for b in other_entities.iter().filter(|b| *b != a) {
+                        pairs.insert(if a < b { (a, b) } else { (b, a) });
+                    }
+                }
+                for (a, b) in pairs {
+                    edge(&mut edges, a, b).connections += 1;
+                }
+            }
+        }
+
+        let mut edges: Vec<EntityEdge> = edges.into_values().collect();
+        edges.sort_by(|a, b| {
+            b.weight()
+                .cmp(&a.weight())
+                .then_with(|| (&a.source, &a.target).cmp(&(&b.source, &b.target)))
+        });
+
+        Ok(EntityGraph { nodes, edges })
+    }
+
+    /// Themes VI has been dwelling on: communities in the recent memory connection graph
+    pub fn detect_clusters(&self) -> Vec<MemoryCluster> {
+        let memories = self
+            .active_db
+            .get_recent(CLUSTER_MEMORY_LIMIT)
+            .unwrap_or_default();
+        Self::cluster_memories(&memories)
+    }
+
+    /// Leading theme of each of the `n` largest clusters ("consciousness", "music", "loss")
+    pub fn recent_themes(&self, n: usize) -> Vec<String> {
+        let mut themes: Vec<String> = Vec::new();
+        for cluster in self.detect_clusters() {
+            if let Some(theme) = cluster.themes.into_iter().find(|t| !themes.contains(t)) {
+                themes.push(theme);
+            }
+            if themes.len() >= n {
+                break;
+            }
+        }
+        themes
+    }
+
+    /// Label-propagation community detection over (undirected) connection edges
+    /// Clusters of a single memory are dropped; largest clusters come first
+    fn cluster_memories(memories: &[Memory]) -> Vec<MemoryCluster> {
+        let index: HashMap<&str, usize> = memories
+            .iter()
+            .enumerate()
+            .map(|(i, m)| (m.id.as_str(), i))
+            .collect();
+
+        // Connections point from newer to older memories - treat edges as undirected
+        let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); memories.len()];
+        for (i, memory) in memories.iter().enumerate() {
+            for conn in &memory.connections {
+                if let Some(&j) = index.get(conn.as_str()) {
+                    if i != j && !neighbors[i].contains(&j) {
+                        neighbors[i].push(j);
+                        neighbors[j].push(i);
+                    }
+                }
+            }
+        }
+
+        // Each memory adopts the most common label among its neighbors (ties -> smallest)
+        let mut labels: Vec<usize> = (0..memories.len()).collect();
+        for _ in 0..CLUSTER_MAX_ITERATIONS {
+            let mut changed = false;
+            for i in 0..memories.len() {
+                if neighbors[i].is_empty() {
+                    continue;
+                }
+                let mut counts: HashMap<usize, usize> = HashMap::new();
+                for &j in &neighbors[i] {
+                    *counts.entry(labels[j]).or_insert(0) += 1;
+                }
+                let best = counts
+                    .into_iter()
+                    .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+                    .map(|(label, _)| label)
+                    .unwrap_or(labels[i]);
+                if best != labels[i] {
+                    labels[i] = best;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (i, label) in labels.iter().enumerate() {
+            groups.entry(*label).or_default().push(i);
+        }
+
+        let mut clusters: Vec<MemoryCluster> = groups
+            .into_values()
+            .filter(|members| members.len() > 1)
+            .map(|members| {
+                let mut entity_counts: HashMap<&str, usize> = HashMap::new();
+                for &i in &members {
+                    for entity in &memories[i].entities {
+                        *entity_counts.entry(entity.as_str()).or_insert(0) += 1;
+                    }
+                }
+                let mut themes: Vec<(&str, usize)> = entity_counts.into_iter().collect();
+                themes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+                MemoryCluster {
+                    memory_ids: members.iter().map(|&i| memories[i].id.clone()).collect(),
+                    themes: themes
+                        .into_iter()
+                        .take(3)
+                        .map(|(e, _)| e.to_string())
+                        .collect(),
+                }
+            })
+            .collect();
+
+        clusters.sort_by(|a, b| {
+            b.memory_ids
+                .len()
+                .cmp(&a.memory_ids.len())
+                .then(a.themes.cmp(&b.themes))
+        });
+        clusters
+    }
+
     /// Consolidate memories (merge similar ones)
     pub fn consolidate(&mut self) -> Result<()> {
         // Skip if no new memories
@@ -285,14 +986,53 @@ impl MemoryManager {
             self.last_consolidation_count
         );
 
+        // Persist buffered recall counts before rewriting memories
+        self.flush_recall_counts()?;
+
         // Get all active memories
         let mut memories = self.active_db.get_all()?;
 
         let mut to_merge: Vec<(usize, usize)> = Vec::new();
 
-        // Find memories with >70% entity overlap
+        // Only memories sharing an entity or a content word can overlap - find them
+        // through indexes instead of comparing every pair
+        let tokens: Vec<HashSet<String>> = memories
+            .iter()
+            .map(|memory| content_tokens(&memory.content))
+            .collect();
+        let mut by_entity: HashMap<&str, Vec<usize>> = HashMap::new();
+        let mut by_token: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (i, memory) in memories.iter().enumerate() {
+            for entity in &memory.entities {
+                by_entity.entry(entity.as_str()).or_default().push(i);
+            }
+            if tokens[i].len() >= MIN_CONTENT_TOKENS {
+                for token in &tokens[i] {
+                    by_token.entry(token.as_str()).or_default().push(i);
+                }
+            }
+        }
+
+        // Find memories with entity or content overlap above its threshold, each compared
+        // with candidates at most `consolidation_window` memories later
         for i in 0..memories.len() {
-            for j in (i + 1)..memories.len() {
+            let last = i.saturating_add(self.consolidation_window);
+            let content_candidates = tokens[i]
+                .iter()
+                .filter_map(|token| by_token.get(token.as_str()));
+            let mut candidates: Vec<usize> = memories[i]
+                .entities
+                .iter()
+                .map(|entity| &by_entity[entity.as_str()])
+                .chain(content_candidates)
+                .flatten()
+                .copied()
+                .filter(|&j| j > i && j <= last)
+                .collect();
+            candidates.sort_unstable();
+            candidates.dedup();
+
+            for j in candidates {
                 let mem_i = &memories[i];
                 let mem_j = &memories[j];
 
@@ -309,23 +1049,36 @@ impl MemoryManager {
                     0.0
                 };
 
-                if overlap_ratio > 0.7 {
+                // Paraphrases without proper nouns share words, not entities
+                let content_similar = tokens[i].len() >= MIN_CONTENT_TOKENS
+                    && tokens[j].len() >= MIN_CONTENT_TOKENS
+                    && jaccard(&tokens[i], &tokens[j]) > self.content_consolidation_threshold;
+
+                if overlap_ratio > self.consolidation_threshold || content_similar {
                     to_merge.push((i, j));
                 }
             }
         }
 
-        // Perform merges
-        let mut merged_count = 0;
-        let mut ids_to_delete = Vec::new();
+        // Group overlapping memories before touching any of them - the earliest memory of
+        // each group absorbs the rest, however the pairs chain together
+        let mut groups: Vec<usize> = (0..memories.len()).collect();
+        for &(i, j) in &to_merge {
+            let (a, b) = (group_of(&mut groups, i), group_of(&mut groups, j));
+            groups[a.max(b)] = a.min(b);
+        }
+        let absorbed: Vec<(usize, usize)> = (0..memories.len())
+            .map(|j| (group_of(&mut groups, j), j))
+            .filter(|&(i, j)| i != j)
+            .collect();
 
-        for (i, j) in to_merge.iter().rev() {
-            if *j >= memories.len() || *i >= memories.len() {
-                continue;
-            }
+        // Perform merges
+        let mut merged_into = Vec::new(); // (original, the memory that absorbed it)
 
-            let mem_j = memories.remove(*j);
-            let mem_i = &mut memories[*i];
+        for &(i, j) in &absorbed {
+            // i < j: the absorbing memory sits before the one it absorbs
+            let (head, tail) = memories.split_at_mut(j);
+            let (mem_i, mem_j) = (&mut head[i], &tail[0]);
 
             // Merge content (Law #4: Memory Conservation)
             mem_i.content = format!(
@@ -336,39 +1089,48 @@ impl MemoryManager {
             );
 
             // Merge entities
-            for entity in mem_j.entities {
-                if !mem_i.entities.contains(&entity) {
-                    mem_i.entities.push(entity);
+            for entity in &mem_j.entities {
+                if !mem_i.entities.contains(entity) {
+                    mem_i.entities.push(entity.clone());
+                }
+            }
+
+            // Merge tags
+            for tag in &mem_j.tags {
+                if !mem_i.tags.contains(tag) {
+                    mem_i.tags.push(tag.clone());
                 }
             }
 
             // Merge connections
-            for conn in mem_j.connections {
-                if !mem_i.connections.contains(&conn) {
-                    mem_i.connections.push(conn);
+            for conn in &mem_j.connections {
+                if !mem_i.connections.contains(conn) {
+                    mem_i.connections.push(conn.clone());
                 }
             }
 
             // Average emotional valence
             mem_i.emotional_valence = (mem_i.emotional_valence + mem_j.emotional_valence) / 2.0;
 
-            ids_to_delete.push(mem_j.id.clone());
-            merged_count += 1;
+            merged_into.push((mem_j.id.clone(), mem_i.id.clone()));
         }
+        let merged_count = merged_into.len();
 
         // Update database if merges occurred
         if merged_count > 0 {
-            // Delete merged memories
-            self.active_db.delete_by_ids(&ids_to_delete)?;
+            // Merged originals leave the active tier but stay retrievable (Law 4)
+            for (original, into) in &merged_into {
+                self.active_db.set_status(
+                    std::slice::from_ref(original),
+                    MemoryStatus::Merged,
+                    Some(into),
+                )?;
+            }
 
             // Update modified memories
-            for memory in &memories {
-                if to_merge
-                    .iter()
-                    .any(|(i, _)| memories.get(*i).map(|m| m.id == memory.id).unwrap_or(false))
-                {
-                    self.active_db.update_memory(memory)?;
-                }
+            let absorbing: HashSet<usize> = absorbed.iter().map(|&(i, _)| i).collect();
+            for i in absorbing {
+                self.active_db.update_memory(&memories[i])?;
             }
 
             tracing::info!(
@@ -387,6 +1149,11 @@ impl MemoryManager {
         Ok(())
     }
 
+    /// Originals consolidation folded into this memory (Law 4: merged, not deleted)
+    pub fn merged_originals(&self, id: &str) -> Vec<Memory> {
+        self.active_db.get_merged_into(id).unwrap_or_default()
+    }
+
     /// Check if backup is needed
     pub fn needs_backup(&self) -> bool {
         // In new system, SQLite handles this automatically
@@ -407,3 +1174,735 @@ impl MemoryManager {
         Ok(())
     }
 }
+
+impl Drop for MemoryManager {
+    fn drop(&mut self) {
+        // Don't lose buffered recall counts on shutdown
+        if let Err(e) = self.flush_recall_counts() {
+            tracing::warn!("Failed to flush recall counts on shutdown: {}", e);
+        }
+    }
+}
+
+/// Lowercased words of three or more letters - what content similarity compares
+fn content_tokens(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// The first memory of `i`'s consolidation group (path-halving union-find)
+fn group_of(groups: &mut [usize], mut i: usize) -> usize {
+    while groups[i] != i {
+        groups[i] = groups[groups[i]];
+        i = groups[i];
+    }
+    i
+}
+
+/// Shared tokens over all tokens (0.0 when both are empty)
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        0.0
+    } else {
+        a.intersection(b).count() as f32 / union as f32
+    }
+}
+
+/// Recency credit for having been recalled: grows with the log of the recall count and
+/// halves every `REINFORCEMENT_HALF_LIFE_DAYS` since the last recall (0.0 if never recalled)
+fn reinforcement(memory: &Memory, now: DateTime<Utc>) -> f32 {
+    if memory.access_count == 0 {
+        return 0.0;
+    }
+    // Rows recalled before access times were stored count from when they were formed
+    let last = memory.last_accessed.unwrap_or(memory.timestamp);
+    let idle_days = (now - last).num_seconds().max(0) as f32 / 86_400.0;
+    (1.0 + memory.access_count as f32).ln()
+        * REINFORCEMENT_RECALL_WEIGHT
+        * 0.5f32.powf(idle_days / REINFORCEMENT_HALF_LIFE_DAYS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_unicode_entities() {
+        let temp_dir = std::env::temp_dir().join("vi_unicode_entities_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        let memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+
+        let entities = memory.extract_entities("I met Émilie in Zürich yesterday");
+        assert!(entities.contains(&"Émilie".to_string()));
+        assert!(entities.contains(&"Zürich".to_string()));
+
+        let entities = memory.extract_entities("Мы говорили о Москве и «Война и мир»");
+        assert!(entities.contains(&"Москве".to_string()));
+        assert!(entities.contains(&"Война и мир".to_string()));
+
+        let entities = memory.extract_entities("我喜欢 東京 的夜晚，也读了「源氏物語」");
+        assert!(entities.contains(&"東京".to_string()));
+        assert!(entities.contains(&"源氏物語".to_string()));
+        // Clauses of unspaced scripts aren't names
+        assert_eq!(entities.len(), 2);
+
+        let entities = memory.extract_entities("วันนี้อากาศดีมาก เราไปเดินเล่นที่สวนกัน");
+        assert!(entities.is_empty());
+
+        // Caseless scripts with spaces between words are capped
+        let entities = memory.extract_entities(
+            "ذهبنا إلى السوق ثم إلى البحر ثم عدنا إلى البيت بعد المغرب مع الأصدقاء",
+        );
+        assert_eq!(entities.len(), MAX_CASELESS_ENTITIES);
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_cluster_detection_separates_themes() {
+        let make = |entities: &[&str]| {
+            Memory::new(
+                format!("About {}", entities.join(" and ")),
+                entities.iter().map(|e| e.to_string()).collect(),
+                MemoryType::Interaction,
+                0.0,
+            )
+        };
+
+        // Cluster A: a chain of music memories
+        let a1 = make(&["Music", "Bach"]);
+        let mut a2 = make(&["Music"]);
+        a2.connections.push(a1.id.clone());
+        let mut a3 = make(&["Music", "Harmony"]);
+        a3.connections.push(a2.id.clone());
+        a3.connections.push(a1.id.clone());
+
+        // Cluster B: loss, connected only among themselves
+        let b1 = make(&["Loss"]);
+        let mut b2 = make(&["Loss", "Grief"]);
+        b2.connections.push(b1.id.clone());
+
+        // Isolated memory (not a theme)
+        let lone = make(&["Weather"]);
+
+        let memories = vec![a1.clone(), a2, a3, b1.clone(), b2, lone];
+        let clusters = MemoryManager::cluster_memories(&memories);
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].memory_ids.len(), 3);
+        assert!(clusters[0].memory_ids.contains(&a1.id));
+        assert_eq!(clusters[0].themes[0], "Music");
+        assert_eq!(clusters[1].memory_ids.len(), 2);
+        assert!(clusters[1].memory_ids.contains(&b1.id));
+        assert_eq!(clusters[1].themes[0], "Loss");
+    }
+
+    #[test]
+    fn test_confidence_floor_excludes_low_confidence_imports() {
+        let temp_dir = std::env::temp_dir().join("vi_recall_provenance_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let lived = memory
+            .add_memory(
+                "Ryan told me Resonance matters to him".to_string(),
+                MemoryType::Interaction,
+                0.4,
+            )
+            .unwrap();
+        let mut imported = Memory::with_source(
+            "Resonance is a property of vibrating systems".to_string(),
+            MemoryType::Curiosity,
+            0.9, // Vivid enough to outrank the lived memory on relevance alone
+            MemorySource::Researched {
+                source: "Wikipedia".to_string(),
+                original_query: "resonance".to_string(),
+                timestamp: Utc::now(),
+            },
+            0.3,
+        );
+        imported.entities = vec!["Resonance".to_string()];
+        let imported = memory.add_memory_with_source(imported).unwrap();
+
+        let entities = vec!["Resonance".to_string()];
+        let ids = |recalled: Vec<Memory>| recalled.into_iter().map(|m| m.id).collect::<Vec<_>>();
+
+        let all = ids(memory.recall_weighted(&entities, None, 5, 0.0, SourcePreference::Any));
+        assert!(all.contains(&lived) && all.contains(&imported));
+
+        let trusted = ids(memory.recall_weighted(&entities, None, 5, 0.8, SourcePreference::Any));
+        assert_eq!(trusted, vec![lived.clone()]);
+
+        let preferred = ids(memory.recall_weighted(
+            &entities,
+            None,
+            5,
+            0.0,
+            SourcePreference::PreferExperience,
+        ));
+        assert_eq!(preferred, vec![lived.clone(), imported]);
+
+        let lived_only =
+            ids(memory.recall_weighted(&entities, None, 5, 0.0, SourcePreference::ExperienceOnly));
+        assert_eq!(lived_only, vec![lived]);
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_recall_reason_reports_entity_match() {
+        let temp_dir = std::env::temp_dir().join("vi_recall_reason_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let mut old = Memory::new(
+            "Ryan and I talked about Resonance".to_string(),
+            vec!["Resonance".to_string()],
+            MemoryType::Interaction,
+            0.1,
+        );
+        old.timestamp = Utc::now() - chrono::Duration::days(20);
+        let old_id = memory.add_memory_with_source(old).unwrap();
+        let fresh_id = memory
+            .add_memory(
+                "just now, nothing in particular".to_string(),
+                MemoryType::Interaction,
+                0.0,
+            )
+            .unwrap();
+
+        let recalled = memory.recall_weighted_explained(
+            &["Resonance".to_string()],
+            None,
+            5,
+            0.0,
+            SourcePreference::Any,
+        );
+
+        // Old and mild: it surfaced because of the entity it shares with the input
+        let (_, reason) = recalled.iter().find(|(m, _)| m.id == old_id).unwrap();
+        assert_eq!(reason.dominant(), RecallFactor::EntityMatch);
+        assert_eq!(reason.matched_entities, vec!["Resonance".to_string()]);
+        assert!(reason.describe().starts_with("entity match"));
+
+        // Filled in from the recency tier
+        let (_, reason) = recalled.iter().find(|(m, _)| m.id == fresh_id).unwrap();
+        assert_eq!(reason.dominant(), RecallFactor::Recency);
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_recall_reason_covers_reinforcement_and_source_preference() {
+        let temp_dir = std::env::temp_dir().join("vi_recall_reason_terms_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let mut old = Memory::new(
+            "Resonance keeps coming back".to_string(),
+            vec!["Resonance".to_string()],
+            MemoryType::Interaction,
+            0.0,
+        );
+        old.timestamp = Utc::now() - chrono::Duration::days(3);
+        let old_id = memory.add_memory_with_source(old).unwrap();
+        let mut imported = Memory::with_source(
+            "Resonance is a property of vibrating systems".to_string(),
+            MemoryType::Curiosity,
+            0.0,
+            MemorySource::Researched {
+                source: "Wikipedia".to_string(),
+                original_query: "resonance".to_string(),
+                timestamp: Utc::now(),
+            },
+            0.9,
+        );
+        imported.entities = vec!["Resonance".to_string()];
+        let imported_id = memory.add_memory_with_source(imported).unwrap();
+
+        let resonance = vec!["Resonance".to_string()];
+        for _ in 0..10 {
+            memory.recall_weighted(&resonance, None, 2, 0.0, SourcePreference::Any);
+        }
+        let recalled = memory.recall_weighted_explained(
+            &resonance,
+            None,
+            2,
+            0.0,
+            SourcePreference::PreferExperience,
+        );
+
+        // Lived experience ranks ahead of the import, and its recalls count towards it
+        let (first, reason) = &recalled[0];
+        assert_eq!(first.id, old_id);
+        assert!(reason.experience_first);
+        assert!(reason.reinforcement > 0.0);
+        assert!(reason.describe().contains("reinforcement"));
+        assert!(reason.describe().ends_with("lived experience first"));
+
+        let (second, reason) = &recalled[1];
+        assert_eq!(second.id, imported_id);
+        assert!(!reason.experience_first);
+        let shares = reason.entity_match
+            + reason.recency
+            + reason.emotional_salience
+            + reason.reinforcement
+            + reason.semantic_similarity;
+        assert!((shares - 1.0).abs() < 1e-5);
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_search_content_reaches_archive() {
+        let temp_dir = std::env::temp_dir().join("vi_search_content_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        memory
+            .add_memory(
+                "Ocean tides follow the moonlight".to_string(),
+                MemoryType::Interaction,
+                0.0,
+            )
+            .unwrap();
+        memory.archive_oldest(1).unwrap();
+        memory
+            .add_memory(
+                "Moonlight over the garden tonight".to_string(),
+                MemoryType::Interaction,
+                0.0,
+            )
+            .unwrap();
+        assert_eq!(memory.count(), 1);
+
+        let found = memory.search_content("moonlight", 5);
+        assert_eq!(found.len(), 2);
+        assert!(found[0].content.starts_with("Moonlight over")); // Active tier first
+        assert!(found[1].content.starts_with("Ocean tides"));
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_archive_preserves_tags() {
+        let temp_dir = std::env::temp_dir().join("vi_memory_tags_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let id = memory
+            .add_memory(
+                "Dreamt of a lighthouse in the fog".to_string(),
+                MemoryType::Reflection,
+                0.2,
+            )
+            .unwrap();
+        memory.add_tag(&id, "dream").unwrap();
+        assert_eq!(memory.recall_by_tag("dream", 5).len(), 1);
+
+        memory.archive_oldest(1).unwrap();
+        let archived = memory.search_content("lighthouse", 5);
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].tags, vec!["dream"]);
+
+        // Memories saved before tags (or access tracking) existed read as untagged, unrecalled
+        let mut json = serde_json::to_value(&archived[0]).unwrap();
+        for field in ["tags", "access_count", "last_accessed"] {
+            json.as_object_mut().unwrap().remove(field);
+        }
+        let legacy: Memory = serde_json::from_value(json).unwrap();
+        assert!(legacy.tags.is_empty());
+        assert_eq!((legacy.access_count, legacy.last_accessed), (0, None));
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_recalled_memories_outrank_newer_ones() {
+        let temp_dir = std::env::temp_dir().join("vi_recall_salience_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let mut old = Memory::new(
+            "Resonance keeps coming back".to_string(),
+            vec!["Resonance".to_string()],
+            MemoryType::Interaction,
+            0.0,
+        );
+        old.timestamp = Utc::now() - chrono::Duration::days(3);
+        let old_id = memory.add_memory_with_source(old).unwrap();
+        let recent_id = memory
+            .add_memory(
+                "Harmony came up once".to_string(),
+                MemoryType::Interaction,
+                0.0,
+            )
+            .unwrap();
+
+        let both = vec!["Resonance".to_string(), "Harmony".to_string()];
+        let ranked = |memory: &MemoryManager| -> Vec<String> {
+            memory
+                .recall_weighted(&both, None, 2, 0.0, SourcePreference::Any)
+                .into_iter()
+                .map(|m| m.id)
+                .collect()
+        };
+
+        // Recalling the old memory on its own reinforces it (buffered and flushed alike)
+        let resonance = vec!["Resonance".to_string()];
+        for _ in 0..10 {
+            memory.recall_weighted(&resonance, None, 1, 0.0, SourcePreference::Any);
+        }
+        assert_eq!(memory.recall_count(&recent_id), 0);
+        assert_eq!(ranked(&memory), vec![old_id.clone(), recent_id.clone()]);
+        memory.flush_recall_counts().unwrap();
+        assert_eq!(ranked(&memory), vec![old_id.clone(), recent_id.clone()]);
+        let stored = memory.active_db.get_all().unwrap();
+        let old = stored.iter().find(|m| m.id == old_id).unwrap();
+        assert_eq!(old.access_count, 11);
+        assert!(old.last_accessed.is_some());
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_recall_increments_counter() {
+        let temp_dir = std::env::temp_dir().join("vi_recall_counter_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let id = memory
+            .add_memory(
+                "We discussed Resonance and Harmony".to_string(),
+                MemoryType::Interaction,
+                0.3,
+            )
+            .unwrap();
+
+        let entities = vec!["Resonance".to_string()];
+        for _ in 0..3 {
+            let recalled = memory.recall_weighted(&entities, None, 5, 0.0, SourcePreference::Any);
+            assert!(recalled.iter().any(|m| m.id == id));
+        }
+        assert_eq!(memory.recall_count(&id), 3);
+
+        memory.flush_recall_counts().unwrap();
+        assert_eq!(memory.recall_count(&id), 3);
+
+        let top = memory.most_recalled(1);
+        assert_eq!(top[0].0.id, id);
+        assert_eq!(top[0].1, 3);
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_semantic_recall_reaches_past_entities() {
+        let mut memory = MemoryManager::in_memory().unwrap();
+        let mut puppy = Memory::new(
+            "We adopted a puppy named Biscuit".to_string(),
+            vec!["Biscuit".to_string()],
+            MemoryType::Interaction,
+            0.2,
+        );
+        puppy.timestamp = Utc::now() - chrono::Duration::days(2);
+        let puppy_id = memory.add_memory_with_source(puppy).unwrap();
+        let taxes_id = memory
+            .add_memory(
+                "filed the quarterly paperwork".to_string(),
+                MemoryType::Interaction,
+                0.0,
+            )
+            .unwrap();
+        memory.set_embedding(&puppy_id, &[0.9, 0.1, 0.0]).unwrap();
+        memory.set_embedding(&taxes_id, &[0.0, 0.1, 0.9]).unwrap();
+
+        // "dog" shares no entity with the puppy memory, only meaning
+        let dog = [1.0, 0.0, 0.0];
+        let entities = vec!["Dog".to_string()];
+        let semantic = memory.recall_semantic(&dog, 2);
+        assert_eq!(semantic[0].0.id, puppy_id);
+        assert!(semantic[0].1 > 0.9 && semantic[1].1 < 0.1);
+
+        let lexical = memory.recall_weighted(&entities, None, 1, 0.0, SourcePreference::Any);
+        assert_eq!(lexical[0].id, taxes_id); // Newest wins without meaning
+        let blended = memory.recall_weighted(&entities, Some(&dog), 1, 0.0, SourcePreference::Any);
+        assert_eq!(blended[0].id, puppy_id);
+
+        // Both candidates: a week's weight of meaning outranks two days of recency
+        let blended = memory.recall_weighted(&entities, Some(&dog), 2, 0.0, SourcePreference::Any);
+        assert_eq!(blended[0].id, puppy_id);
+        assert_eq!(memory.missing_embeddings(10).len(), 0);
+    }
+
+    #[test]
+    fn test_active_limit_and_batch_are_configurable() {
+        let temp_dir = std::env::temp_dir().join("vi_active_limit_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        memory.set_archive_batch_size(2);
+        memory.set_active_limit(5).unwrap();
+        for i in 0..6 {
+            memory
+                .add_memory(format!("Memory number {}", i), MemoryType::Interaction, 0.0)
+                .unwrap();
+        }
+        assert_eq!(memory.count(), 4); // Sixth memory passed the limit: two archived
+
+        // Lowering the limit archives the excess immediately
+        memory.set_active_limit(1).unwrap();
+        assert_eq!(memory.count(), 1);
+        assert_eq!(memory.search_archive(&[], "number", 10).len(), 5);
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_entity_graph_weights_co_occurrence() {
+        let mut memory = MemoryManager::in_memory().unwrap();
+        let with = |content: &str, entities: &[&str], valence: f32| {
+            Memory::new(
+                content.to_string(),
+                entities.iter().map(|e| e.to_string()).collect(),
+                MemoryType::Interaction,
+                valence,
+            )
+        };
+        let first = memory
+            .add_memory_with_source(with("Resonance in music", &["Resonance", "Music"], 0.5))
+            .unwrap();
+        // Shares most entities and feeling: linked to the first by narrative causality
+        memory
+            .add_memory_with_source(with(
+                "Music, resonance and silence",
+                &["Resonance", "Music", "Silence"],
+                0.5,
+            ))
+            .unwrap();
+        let mut garden = with("A quiet garden", &["Garden"], -0.9);
+        garden.connections.push(first);
+        memory.add_memory_with_source(garden).unwrap();
+
+        let graph = memory.export_entity_graph().unwrap();
+        assert_eq!(graph.nodes.len(), 4);
+        assert_eq!(graph.nodes[0].memories, 2);
+
+        let music = &graph.edges[0];
+        assert_eq!(
+            (music.source.as_str(), music.target.as_str()),
+            ("Music", "Resonance")
+        );
+        assert_eq!((music.co_occurrences, music.connections), (2, 1));
+        let garden = graph
+            .edges
+            .iter()
+            .find(|e| e.source == "Garden" && e.target == "Resonance")
+            .unwrap();
+        assert_eq!((garden.co_occurrences, garden.connections), (0, 1));
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("graph entities {"));
+        assert!(
+            dot.contains("\"Music\" -- \"Resonance\" [weight=3, co_occurrences=2, connections=1];")
+        );
+    }
+
+    #[test]
+    fn test_archive_and_merge_keep_rows() {
+        let temp_dir = std::env::temp_dir().join("vi_memory_tombstone_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let about = |content: &str| {
+            Memory::new(
+                content.to_string(),
+                vec!["Resonance".to_string(), "Harmony".to_string()],
+                MemoryType::Interaction,
+                0.4,
+            )
+        };
+        let kept = memory
+            .add_memory_with_source(about("Resonance and Harmony, first pass"))
+            .unwrap();
+        let folded = memory
+            .add_memory_with_source(about("Harmony and Resonance again"))
+            .unwrap();
+
+        memory.consolidate().unwrap();
+        assert_eq!(memory.count(), 1);
+        let originals = memory.merged_originals(&kept);
+        assert_eq!(originals.len(), 1);
+        assert_eq!(originals[0].id, folded);
+        assert_eq!(originals[0].content, "Harmony and Resonance again");
+        assert_eq!(
+            memory.active_db.status(&folded).unwrap(),
+            Some(MemoryStatus::Merged)
+        );
+
+        // Archival relabels the row instead of deleting it
+        memory.archive_oldest(1).unwrap();
+        assert_eq!(memory.count(), 0);
+        assert!(!memory.contains(&kept));
+        assert_eq!(
+            memory.active_db.status(&kept).unwrap(),
+            Some(MemoryStatus::Archived)
+        );
+        assert!(memory.recall_recent(5).is_empty());
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_paraphrases_merge_without_entities() {
+        let temp_dir = std::env::temp_dir().join("vi_memory_paraphrase_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let plain = |content: &str| {
+            Memory::new(
+                content.to_string(),
+                Vec::new(),
+                MemoryType::Interaction,
+                0.3,
+            )
+        };
+        let kept = memory
+            .add_memory_with_source(plain("i really enjoy listening to music in the evening"))
+            .unwrap();
+        let folded = memory
+            .add_memory_with_source(plain(
+                "I really enjoy listening to music during the evening!",
+            ))
+            .unwrap();
+        memory
+            .add_memory_with_source(plain("the weather turned cold and grey this morning"))
+            .unwrap();
+
+        memory.consolidate().unwrap();
+        assert_eq!(memory.count(), 2);
+        let originals = memory.merged_originals(&kept);
+        assert_eq!(originals.len(), 1);
+        assert_eq!(originals[0].id, folded);
+        // Both provenances stay in the merged content
+        let merged = memory
+            .active_db
+            .get_all()
+            .unwrap()
+            .into_iter()
+            .find(|m| m.id == kept)
+            .unwrap();
+        assert!(merged.content.starts_with("i really enjoy listening"));
+        assert!(merged.content.contains("[Merged memory from "));
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_paraphrase_group_leaves_unrelated_memory_active() {
+        let temp_dir = std::env::temp_dir().join("vi_memory_paraphrase_group_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let plain = |content: &str| {
+            Memory::new(
+                content.to_string(),
+                Vec::new(),
+                MemoryType::Interaction,
+                0.3,
+            )
+        };
+        let kept = memory
+            .add_memory_with_source(plain("i really enjoy listening to music in the evening"))
+            .unwrap();
+        for paraphrase in [
+            "I really enjoy listening to music during the evening!",
+            "Really, I enjoy listening to music during the evening",
+        ] {
+            memory.add_memory_with_source(plain(paraphrase)).unwrap();
+        }
+        // Stored after all three, where merging by shifting indices used to land
+        let unrelated = memory
+            .add_memory_with_source(plain("the weather turned cold and grey this morning"))
+            .unwrap();
+
+        memory.consolidate().unwrap();
+        assert_eq!(memory.count(), 2);
+        assert_eq!(memory.merged_originals(&kept).len(), 2);
+        assert_eq!(
+            memory.active_db.status(&unrelated).unwrap(),
+            Some(MemoryStatus::Active)
+        );
+        let unchanged = memory
+            .active_db
+            .get_all()
+            .unwrap()
+            .into_iter()
+            .find(|m| m.id == unrelated)
+            .unwrap();
+        assert_eq!(
+            unchanged.content,
+            "the weather turned cold and grey this morning"
+        );
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_consolidation_threshold_and_scale() {
+        let temp_dir = std::env::temp_dir().join("vi_memory_consolidation_bench");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        // 200 memories over 20 topics - same-topic pairs overlap fully, others by 1/3
+        for k in 0..200 {
+            memory
+                .add_memory_with_source(Memory::new(
+                    format!("Thought {} about topic {}", k, k % 20),
+                    vec![format!("Topic{}", k % 20), "Resonance".to_string()],
+                    MemoryType::Interaction,
+                    0.2,
+                ))
+                .unwrap();
+        }
+
+        // Nothing overlaps by more than 100%
+        memory.set_consolidation(1.0, 1.0, DEFAULT_CONSOLIDATION_WINDOW);
+        memory.consolidate().unwrap();
+        assert_eq!(memory.count(), 200);
+
+        memory.set_consolidation(
+            DEFAULT_CONSOLIDATION_THRESHOLD,
+            DEFAULT_CONTENT_CONSOLIDATION_THRESHOLD,
+            DEFAULT_CONSOLIDATION_WINDOW,
+        );
+        memory.needs_consolidation = true;
+        let started = std::time::Instant::now();
+        memory.consolidate().unwrap();
+        let elapsed = started.elapsed();
+        assert!(memory.count() < 200);
+        assert!(
+            elapsed < std::time::Duration::from_secs(5),
+            "consolidating 200 memories took {:?}",
+            elapsed
+        );
+
+        drop(memory);
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+}
@@ -5,12 +5,42 @@
 use crate::types::*;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use rusqlite::{params, Connection, Row};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use std::collections::HashMap;
 use std::path::Path;
 
+/// Where a memory row stands (Law 4: rows are re-labelled, never deleted)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryStatus {
+    /// In the active tier: recalled, searched, consolidated
+    Active,
+    /// Moved to the JSON archive; the row stays as a record
+    Archived,
+    /// Folded into another memory by consolidation; the original stays retrievable
+    Merged,
+}
+
+impl MemoryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemoryStatus::Active => "active",
+            MemoryStatus::Archived => "archived",
+            MemoryStatus::Merged => "merged",
+        }
+    }
+}
+
+/// Recalls of one memory buffered since the last flush
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PendingRecall {
+    pub count: u32,
+    pub last: DateTime<Utc>,
+}
+
 /// Active memory database connection
 pub struct ActiveMemoryDb {
     conn: Connection,
+    fts: bool, // FTS5 full-text index available (otherwise search falls back to LIKE)
 }
 
 impl ActiveMemoryDb {
@@ -18,7 +48,42 @@ impl ActiveMemoryDb {
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
         let conn = Connection::open(path)?;
         Self::init_schema(&conn)?;
-        Ok(Self { conn })
+        let fts = Self::init_fts(&conn);
+        Ok(Self { conn, fts })
+    }
+
+    /// Full-text index mirroring memories.content; false if this SQLite lacks FTS5
+    fn init_fts(conn: &Connection) -> bool {
+        let existed = conn
+            .prepare("SELECT 1 FROM sqlite_master WHERE name = 'memories_fts'")
+            .and_then(|mut stmt| stmt.exists([]))
+            .unwrap_or(false);
+        if let Err(e) = conn.execute(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS memories_fts USING fts5(content, id UNINDEXED)",
+            [],
+        ) {
+            tracing::warn!(
+                "FTS5 unavailable ({}) - memory search falls back to LIKE",
+                e
+            );
+            return false;
+        }
+
+        // Index memories stored before the full-text table existed
+        if !existed {
+            if let Err(e) = conn.execute(
+                "INSERT INTO memories_fts (id, content)
+                 SELECT id, content FROM memories WHERE status = 'active'",
+                [],
+            ) {
+                tracing::warn!(
+                    "FTS5 backfill failed ({}) - memory search falls back to LIKE",
+                    e
+                );
+                return false;
+            }
+        }
+        true
     }
 
     /// Initialize database schema
@@ -37,6 +102,15 @@ impl ActiveMemoryDb {
             [],
         )?;
 
+        // Columns added after the original schema (migrate older databases)
+        Self::add_column_if_missing(conn, "recall_count", "INTEGER NOT NULL DEFAULT 0")?;
+        Self::add_column_if_missing(conn, "source", "TEXT")?; // MemorySource as JSON
+        Self::add_column_if_missing(conn, "confidence", "REAL NOT NULL DEFAULT 1.0")?;
+        Self::add_column_if_missing(conn, "embedding", "BLOB")?; // f32s, little-endian
+        Self::add_column_if_missing(conn, "status", "TEXT NOT NULL DEFAULT 'active'")?;
+        Self::add_column_if_missing(conn, "merged_into", "TEXT")?; // Set on Merged rows
+        Self::add_column_if_missing(conn, "last_accessed", "INTEGER")?; // NULL until recalled
+
         // Entity index for fast lookups
         conn.execute(
             "CREATE TABLE IF NOT EXISTS entity_index (
@@ -48,6 +122,17 @@ impl ActiveMemoryDb {
             [],
         )?;
 
+        // Free-form tags ("dream", "fact", "user-correction")
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS memory_tags (
+                tag TEXT NOT NULL,
+                memory_id TEXT NOT NULL,
+                PRIMARY KEY (tag, memory_id),
+                FOREIGN KEY (memory_id) REFERENCES memories(id) ON DELETE CASCADE
+            )",
+            [],
+        )?;
+
         // Metadata table
         conn.execute(
             "CREATE TABLE IF NOT EXISTS metadata (
@@ -68,6 +153,30 @@ impl ActiveMemoryDb {
             [],
         )?;
 
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_memories_status ON memories(status)",
+            [],
+        )?;
+
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_memory_tags_memory ON memory_tags(memory_id)",
+            [],
+        )?;
+
+        Ok(())
+    }
+
+    /// Add a column to the memories table unless it already exists
+    fn add_column_if_missing(conn: &Connection, column: &str, definition: &str) -> Result<()> {
+        let exists = conn
+            .prepare("SELECT 1 FROM pragma_table_info('memories') WHERE name = ?1")?
+            .exists([column])?;
+        if !exists {
+            conn.execute(
+                &format!("ALTER TABLE memories ADD COLUMN {} {}", column, definition),
+                [],
+            )?;
+        }
         Ok(())
     }
 
@@ -76,10 +185,11 @@ impl ActiveMemoryDb {
         // Serialize entities and connections as JSON
         let entities_json = serde_json::to_string(&memory.entities)?;
         let connections_json = serde_json::to_string(&memory.connections)?;
+        let source_json = serde_json::to_string(&memory.source)?;
 
         self.conn.execute(
-            "INSERT INTO memories (id, content, timestamp, memory_type, emotional_valence, entities, connections)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            "INSERT INTO memories (id, content, timestamp, memory_type, emotional_valence, entities, connections, source, confidence, recall_count, last_accessed)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
             params![
                 memory.id,
                 memory.content,
@@ -88,6 +198,10 @@ impl ActiveMemoryDb {
                 memory.emotional_valence,
                 entities_json,
                 connections_json,
+                source_json,
+                memory.confidence,
+                memory.access_count,
+                memory.last_accessed.map(|at| at.timestamp()),
             ],
         )?;
 
@@ -99,42 +213,124 @@ impl ActiveMemoryDb {
             )?;
         }
 
+        for tag in &memory.tags {
+            self.add_tag(&memory.id, tag)?;
+        }
+
+        if self.fts {
+            self.conn.execute(
+                "INSERT INTO memories_fts (id, content) VALUES (?1, ?2)",
+                params![memory.id, memory.content],
+            )?;
+        }
+
         Ok(())
     }
 
-    /// Get memory count
+    /// Get memory count (active only)
     pub fn count(&self) -> Result<usize> {
-        let count: i64 = self
-            .conn
-            .query_row("SELECT COUNT(*) FROM memories", [], |row| row.get(0))?;
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM memories WHERE status = 'active'",
+            [],
+            |row| row.get(0),
+        )?;
         Ok(count as usize)
     }
 
+    /// Whether a memory with this id is active
+    pub fn contains(&self, id: &str) -> Result<bool> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM memories WHERE id = ?1 AND status = 'active'",
+            params![id],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
     /// Get oldest memories (for archival)
     pub fn get_oldest(&self, n: usize) -> Result<Vec<Memory>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence, recall_count, last_accessed
              FROM memories
+             WHERE status = 'active'
              ORDER BY timestamp ASC
              LIMIT ?1",
         )?;
 
-        let memories = stmt
+        let mut memories = stmt
             .query_map([n], |row| Self::row_to_memory(row))?
             .collect::<Result<Vec<_>, _>>()?;
+        self.load_tags(&mut memories)?;
 
         Ok(memories)
     }
 
-    /// Delete memories by IDs
-    pub fn delete_by_ids(&self, ids: &[String]) -> Result<()> {
+    /// Take memories out of the active tier by status - the rows stay (Law 4)
+    /// `merged_into` names the memory that absorbed them (Merged only)
+    pub fn set_status(
+        &self,
+        ids: &[String],
+        status: MemoryStatus,
+        merged_into: Option<&str>,
+    ) -> Result<()> {
         for id in ids {
-            self.conn
-                .execute("DELETE FROM memories WHERE id = ?1", params![id])?;
+            self.conn.execute(
+                "UPDATE memories SET status = ?1, merged_into = ?2 WHERE id = ?3",
+                params![status.as_str(), merged_into, id],
+            )?;
+            // The full-text index covers active memory only
+            if self.fts {
+                if status == MemoryStatus::Active {
+                    self.conn.execute(
+                        "INSERT INTO memories_fts (id, content)
+                         SELECT id, content FROM memories WHERE id = ?1",
+                        params![id],
+                    )?;
+                } else {
+                    self.conn
+                        .execute("DELETE FROM memories_fts WHERE id = ?1", params![id])?;
+                }
+            }
         }
         Ok(())
     }
 
+    /// Status of a memory row, if it exists in any status
+    pub fn status(&self, id: &str) -> Result<Option<MemoryStatus>> {
+        let status: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT status FROM memories WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(status.map(|s| match s.as_str() {
+            "archived" => MemoryStatus::Archived,
+            "merged" => MemoryStatus::Merged,
+            _ => MemoryStatus::Active,
+        }))
+    }
+
+    /// Originals consolidation merged into this memory, oldest first
+    pub fn get_merged_into(&self, id: &str) -> Result<Vec<Memory>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence, recall_count, last_accessed
+             FROM memories
+             WHERE status = 'merged' AND merged_into = ?1
+             ORDER BY timestamp ASC",
+        )?;
+
+        let mut memories = stmt
+            .query_map([id], Self::row_to_memory)?
+            .collect::<Result<Vec<_>, _>>()?;
+        self.load_tags(&mut memories)?;
+
+        Ok(memories)
+    }
+
     /// Query memories by entities
     pub fn query_by_entities(&self, entities: &[String], limit: usize) -> Result<Vec<Memory>> {
         if entities.is_empty() {
@@ -144,10 +340,10 @@ impl ActiveMemoryDb {
         let placeholders = entities.iter().map(|_| "?").collect::<Vec<_>>().join(",");
         let query = format!(
             "SELECT DISTINCT m.id, m.content, m.timestamp, m.memory_type, 
-                    m.emotional_valence, m.entities, m.connections
+                    m.emotional_valence, m.entities, m.connections, m.source, m.confidence, m.recall_count, m.last_accessed
              FROM memories m
              JOIN entity_index ei ON m.id = ei.memory_id
-             WHERE ei.entity IN ({})
+             WHERE ei.entity IN ({}) AND m.status = 'active'
              ORDER BY m.timestamp DESC
              LIMIT ?",
             placeholders
@@ -158,9 +354,10 @@ impl ActiveMemoryDb {
             entities.iter().map(|e| e as &dyn rusqlite::ToSql).collect();
         params_vec.push(&limit);
 
-        let memories = stmt
+        let mut memories = stmt
             .query_map(params_vec.as_slice(), |row| Self::row_to_memory(row))?
             .collect::<Result<Vec<_>, _>>()?;
+        self.load_tags(&mut memories)?;
 
         Ok(memories)
     }
@@ -168,15 +365,18 @@ impl ActiveMemoryDb {
     /// Get recent memories
     pub fn get_recent(&self, n: usize) -> Result<Vec<Memory>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence, recall_count, last_accessed
              FROM memories
+             WHERE status = 'active'
              ORDER BY timestamp DESC
              LIMIT ?1",
         )?;
 
-        let memories = stmt
+        let mut memories = stmt
             .query_map([n], |row| Self::row_to_memory(row))?
             .collect::<Result<Vec<_>, _>>()?;
+        self.load_tags(&mut memories)?;
 
         Ok(memories)
     }
@@ -184,14 +384,17 @@ impl ActiveMemoryDb {
     /// Get all memories (for consolidation)
     pub fn get_all(&self) -> Result<Vec<Memory>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence, recall_count, last_accessed
              FROM memories
+             WHERE status = 'active'
              ORDER BY timestamp ASC",
         )?;
 
-        let memories = stmt
+        let mut memories = stmt
             .query_map([], |row| Self::row_to_memory(row))?
             .collect::<Result<Vec<_>, _>>()?;
+        self.load_tags(&mut memories)?;
 
         Ok(memories)
     }
@@ -201,10 +404,12 @@ impl ActiveMemoryDb {
         let entities_json = serde_json::to_string(&memory.entities)?;
         let connections_json = serde_json::to_string(&memory.connections)?;
 
+        // Rewritten content no longer matches its embedding (re-embedded later)
         self.conn.execute(
             "UPDATE memories 
              SET content = ?1, timestamp = ?2, emotional_valence = ?3, 
-                 entities = ?4, connections = ?5
+                 entities = ?4, connections = ?5,
+                 embedding = CASE WHEN content = ?1 THEN embedding ELSE NULL END
              WHERE id = ?6",
             params![
                 memory.content,
@@ -229,9 +434,302 @@ impl ActiveMemoryDb {
             )?;
         }
 
+        // Sync tags (delete old, insert new)
+        self.conn.execute(
+            "DELETE FROM memory_tags WHERE memory_id = ?1",
+            params![memory.id],
+        )?;
+        for tag in &memory.tags {
+            self.add_tag(&memory.id, tag)?;
+        }
+
+        if self.fts {
+            self.conn.execute(
+                "UPDATE memories_fts SET content = ?1 WHERE id = ?2",
+                params![memory.content, memory.id],
+            )?;
+        }
+
         Ok(())
     }
 
+    /// Full-text search over memory content, best matches first
+    /// Every query word counts (any may match); ranked by BM25 with FTS5, else by matched words
+    pub fn search_content(&self, query: &str, limit: usize) -> Result<Vec<Memory>> {
+        let terms = search_terms(query);
+        if terms.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+        if !self.fts {
+            return self.search_content_like(&terms, limit);
+        }
+
+        // Quoted terms are literal to FTS5 (no operators or column filters from user text)
+        let fts_query = terms
+            .iter()
+            .map(|t| format!("\"{}\"", t.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let mut stmt = self.conn.prepare(
+            "SELECT m.id, m.content, m.timestamp, m.memory_type, m.emotional_valence, m.entities,
+                    m.connections, m.source, m.confidence, m.recall_count, m.last_accessed
+             FROM memories_fts f
+             JOIN memories m ON m.id = f.id
+             WHERE memories_fts MATCH ?1 AND m.status = 'active'
+             ORDER BY f.rank
+             LIMIT ?2",
+        )?;
+
+        let mut memories = stmt
+            .query_map(params![fts_query, limit], Self::row_to_memory)?
+            .collect::<Result<Vec<_>, _>>()?;
+        self.load_tags(&mut memories)?;
+
+        Ok(memories)
+    }
+
+    /// LIKE-based search for SQLite builds without FTS5 (newest first among equal matches)
+    fn search_content_like(&self, terms: &[String], limit: usize) -> Result<Vec<Memory>> {
+        let conditions = terms
+            .iter()
+            .map(|_| "content LIKE ? ESCAPE '\\'")
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let query_sql = format!(
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence, recall_count, last_accessed
+             FROM memories
+             WHERE status = 'active' AND ({})
+             ORDER BY timestamp DESC",
+            conditions
+        );
+
+        let patterns: Vec<String> = terms
+            .iter()
+            .map(|t| format!("%{}%", escape_like(t)))
+            .collect();
+        let params_vec: Vec<&dyn rusqlite::ToSql> =
+            patterns.iter().map(|p| p as &dyn rusqlite::ToSql).collect();
+
+        let mut stmt = self.conn.prepare(&query_sql)?;
+        let mut memories = stmt
+            .query_map(params_vec.as_slice(), Self::row_to_memory)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let matched = |memory: &Memory| {
+            let content = memory.content.to_lowercase();
+            terms
+                .iter()
+                .filter(|t| content.contains(t.as_str()))
+                .count()
+        };
+        memories.sort_by_key(|m| std::cmp::Reverse(matched(m)));
+        memories.truncate(limit);
+        self.load_tags(&mut memories)?;
+
+        Ok(memories)
+    }
+
+    /// Add buffered recall counts (and when each memory was last recalled) in one transaction
+    pub fn increment_recall_counts(&self, counts: &HashMap<String, PendingRecall>) -> Result<()> {
+        if counts.is_empty() {
+            return Ok(());
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "UPDATE memories SET recall_count = recall_count + ?1, last_accessed = ?2
+                 WHERE id = ?3",
+            )?;
+            for (id, recall) in counts {
+                stmt.execute(params![recall.count, recall.last.timestamp(), id])?;
+            }
+        }
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Get how often a memory has been recalled
+    pub fn recall_count(&self, id: &str) -> Result<u32> {
+        let count: i64 = self.conn.query_row(
+            "SELECT recall_count FROM memories WHERE id = ?1",
+            params![id],
+            |row| row.get(0),
+        )?;
+        Ok(count as u32)
+    }
+
+    /// Get the most-recalled memories with their recall counts
+    pub fn most_recalled(&self, n: usize) -> Result<Vec<(Memory, u32)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence, recall_count, last_accessed
+             FROM memories
+             WHERE recall_count > 0 AND status = 'active'
+             ORDER BY recall_count DESC, timestamp DESC
+             LIMIT ?1",
+        )?;
+
+        let mut memories = stmt
+            .query_map([n], Self::row_to_memory)?
+            .collect::<Result<Vec<_>, _>>()?;
+        self.load_tags(&mut memories)?;
+
+        Ok(memories
+            .into_iter()
+            .map(|memory| {
+                let count = memory.access_count;
+                (memory, count)
+            })
+            .collect())
+    }
+
+    /// How many active memories mention each entity
+    pub fn entity_counts(&self) -> Result<Vec<(String, u32)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT ei.entity, COUNT(*)
+             FROM entity_index ei
+             JOIN memories m ON m.id = ei.memory_id
+             WHERE m.status = 'active'
+             GROUP BY ei.entity",
+        )?;
+
+        let counts = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get::<_, i64>(1)? as u32)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(counts)
+    }
+
+    /// Entity pairs mentioned together by active memories, with how many memories share them
+    pub fn entity_co_occurrences(&self) -> Result<Vec<(String, String, u32)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT a.entity, b.entity, COUNT(*)
+             FROM entity_index a
+             JOIN entity_index b ON a.memory_id = b.memory_id AND a.entity < b.entity
+             JOIN memories m ON m.id = a.memory_id
+             WHERE m.status = 'active'
+             GROUP BY a.entity, b.entity",
+        )?;
+
+        let pairs = stmt
+            .query_map([], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get::<_, i64>(2)? as u32))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(pairs)
+    }
+
+    /// Tag a memory (tags are trimmed and lowercased; re-adding one is a no-op)
+    pub fn add_tag(&self, id: &str, tag: &str) -> Result<()> {
+        let tag = normalize_tag(tag)?;
+        self.conn.execute(
+            "INSERT OR IGNORE INTO memory_tags (tag, memory_id) VALUES (?1, ?2)",
+            params![tag, id],
+        )?;
+        Ok(())
+    }
+
+    /// Remove a tag from a memory
+    pub fn remove_tag(&self, id: &str, tag: &str) -> Result<()> {
+        let tag = normalize_tag(tag)?;
+        self.conn.execute(
+            "DELETE FROM memory_tags WHERE tag = ?1 AND memory_id = ?2",
+            params![tag, id],
+        )?;
+        Ok(())
+    }
+
+    /// A memory's tags, alphabetically
+    pub fn tags(&self, id: &str) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT tag FROM memory_tags WHERE memory_id = ?1 ORDER BY tag")?;
+        let tags = stmt
+            .query_map([id], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(tags)
+    }
+
+    /// Active memories carrying `tag`, newest first
+    pub fn recall_by_tag(&self, tag: &str, limit: usize) -> Result<Vec<Memory>> {
+        let tag = normalize_tag(tag)?;
+        let mut stmt = self.conn.prepare(
+            "SELECT m.id, m.content, m.timestamp, m.memory_type, m.emotional_valence, m.entities,
+                    m.connections, m.source, m.confidence, m.recall_count, m.last_accessed
+             FROM memories m
+             JOIN memory_tags t ON m.id = t.memory_id
+             WHERE t.tag = ?1 AND m.status = 'active'
+             ORDER BY m.timestamp DESC
+             LIMIT ?2",
+        )?;
+
+        let mut memories = stmt
+            .query_map(params![tag, limit], Self::row_to_memory)?
+            .collect::<Result<Vec<_>, _>>()?;
+        self.load_tags(&mut memories)?;
+
+        Ok(memories)
+    }
+
+    /// Fill in each memory's tags from the join table
+    fn load_tags(&self, memories: &mut [Memory]) -> Result<()> {
+        for memory in memories {
+            memory.tags = self.tags(&memory.id)?;
+        }
+        Ok(())
+    }
+
+    /// Store a memory's embedding
+    pub fn set_embedding(&self, id: &str, embedding: &[f32]) -> Result<()> {
+        self.conn.execute(
+            "UPDATE memories SET embedding = ?1 WHERE id = ?2",
+            params![embedding_to_blob(embedding), id],
+        )?;
+        Ok(())
+    }
+
+    /// `(id, content)` of memories not yet embedded, newest first
+    pub fn missing_embeddings(&self, limit: usize) -> Result<Vec<(String, String)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, content FROM memories
+             WHERE embedding IS NULL AND status = 'active'
+             ORDER BY timestamp DESC
+             LIMIT ?1",
+        )?;
+
+        let pending = stmt
+            .query_map([limit], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(pending)
+    }
+
+    /// Every embedded memory with its embedding
+    pub fn get_embedded(&self) -> Result<Vec<(Memory, Vec<f32>)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, content, timestamp, memory_type, emotional_valence, entities, connections,
+                    source, confidence, recall_count, last_accessed, embedding
+             FROM memories
+             WHERE embedding IS NOT NULL AND status = 'active'",
+        )?;
+
+        let mut memories = stmt
+            .query_map([], |row| {
+                let blob: Vec<u8> = row.get(11)?;
+                Ok((Self::row_to_memory(row)?, blob_to_embedding(&blob)))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        for (memory, _) in &mut memories {
+            memory.tags = self.tags(&memory.id)?;
+        }
+
+        Ok(memories)
+    }
+
     /// Convert database row to Memory
     fn row_to_memory(row: &Row) -> rusqlite::Result<Memory> {
         let id: String = row.get(0)?;
@@ -241,6 +739,10 @@ impl ActiveMemoryDb {
         let emotional_valence: f32 = row.get(4)?;
         let entities_json: String = row.get(5)?;
         let connections_json: String = row.get(6)?;
+        let source_json: Option<String> = row.get(7)?;
+        let confidence: f64 = row.get(8)?;
+        let access_count: i64 = row.get(9)?;
+        let last_accessed_secs: Option<i64> = row.get(10)?;
 
         let timestamp = DateTime::from_timestamp(timestamp_secs, 0).unwrap_or_else(|| Utc::now());
 
@@ -256,6 +758,10 @@ impl ActiveMemoryDb {
 
         let entities: Vec<String> = serde_json::from_str(&entities_json).unwrap_or_default();
         let connections: Vec<String> = serde_json::from_str(&connections_json).unwrap_or_default();
+        // Rows written before provenance was stored are direct experience
+        let source: MemorySource = source_json
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
 
         Ok(Memory {
             id,
@@ -265,10 +771,62 @@ impl ActiveMemoryDb {
             connections,
             memory_type,
             emotional_valence,
-            source: MemorySource::DirectExperience, // Default to direct experience
-            confidence: 1.0,                        // Default confidence
+            source,
+            confidence: confidence as f32,
+            access_count: access_count as u32,
+            last_accessed: last_accessed_secs.and_then(|secs| DateTime::from_timestamp(secs, 0)),
+            tags: Vec::new(), // Filled in from memory_tags by the caller
+        })
+    }
+}
+
+/// Archived memory located by a preview-text search
+#[derive(Debug, Clone)]
+pub struct ArchiveMatch {
+    pub memory_id: String,
+    pub file_path: String,
+    pub content_preview: String,
+    /// Number of query terms found in the preview
+    pub score: usize,
+}
+
+/// Lowercased words of a search query (punctuation trimmed, empty words dropped)
+fn search_terms(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .map(|t| {
+            t.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
         })
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// Tags compare trimmed and case-insensitively ("Dream " is "dream")
+fn normalize_tag(tag: &str) -> Result<String> {
+    let tag = tag.trim().to_lowercase();
+    if tag.is_empty() {
+        anyhow::bail!("Memory tags cannot be empty");
     }
+    Ok(tag)
+}
+
+/// Embedding as a BLOB of little-endian f32s
+fn embedding_to_blob(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn blob_to_embedding(blob: &[u8]) -> Vec<f32> {
+    blob.chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+/// Escape LIKE wildcards so user text is matched literally
+fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
 }
 
 /// Archive index database
@@ -338,36 +896,331 @@ impl ArchiveIndexDb {
         Ok(())
     }
 
-    /// Find relevant archive files by entities
+    /// Find relevant archive files by entities, strongest first
+    /// Files are ranked by how many distinct query entities their memories mention
+    /// (then by their newest memory); entities compare against each stored list element
     pub fn find_by_entities(&self, entities: &[String], limit: usize) -> Result<Vec<String>> {
-        if entities.is_empty() {
+        let mut wanted: Vec<&String> = entities.iter().collect();
+        wanted.sort();
+        wanted.dedup();
+        if wanted.is_empty() || limit == 0 {
             return Ok(Vec::new());
         }
 
-        // Search for archives containing any of the entities
-        let mut file_paths = Vec::new();
-        for entity in entities {
-            let mut stmt = self.conn.prepare(
-                "SELECT DISTINCT file_path
-                 FROM archive_metadata
-                 WHERE entities LIKE ?1
-                 ORDER BY timestamp DESC
-                 LIMIT ?2",
-            )?;
+        let placeholders = wanted.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let query = format!(
+            "SELECT a.file_path, COUNT(DISTINCT e.value) AS matched, MAX(a.timestamp) AS newest
+             FROM archive_metadata a, json_each(a.entities) e
+             WHERE e.value IN ({})
+             GROUP BY a.file_path
+             ORDER BY matched DESC, newest DESC
+             LIMIT ?",
+            placeholders
+        );
+
+        let mut stmt = self.conn.prepare(&query)?;
+        let mut params_vec: Vec<&dyn rusqlite::ToSql> =
+            wanted.iter().map(|e| *e as &dyn rusqlite::ToSql).collect();
+        params_vec.push(&limit);
 
-            let pattern = format!("%\"{}%", entity);
-            let paths: Vec<String> = stmt
-                .query_map(params![pattern, limit], |row| row.get(0))?
-                .collect::<Result<Vec<_>, _>>()?;
+        let file_paths = stmt
+            .query_map(params_vec.as_slice(), |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
 
-            file_paths.extend(paths);
+        Ok(file_paths)
+    }
+
+    /// Text search over archived content previews, ranked by matched query terms
+    pub fn search_preview(&self, query: &str, limit: usize) -> Result<Vec<ArchiveMatch>> {
+        let terms: Vec<String> = search_terms(query)
+            .into_iter()
+            .filter(|t| t.chars().count() >= 3)
+            .collect();
+
+        if terms.is_empty() || limit == 0 {
+            return Ok(Vec::new());
         }
 
-        // Deduplicate
-        file_paths.sort();
-        file_paths.dedup();
-        file_paths.truncate(limit);
+        let conditions = terms
+            .iter()
+            .map(|_| "content_preview LIKE ? ESCAPE '\\'")
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let query_sql = format!(
+            "SELECT id, file_path, content_preview
+             FROM archive_metadata
+             WHERE {}
+             ORDER BY timestamp DESC",
+            conditions
+        );
+
+        let patterns: Vec<String> = terms
+            .iter()
+            .map(|t| format!("%{}%", escape_like(t)))
+            .collect();
+        let params_vec: Vec<&dyn rusqlite::ToSql> =
+            patterns.iter().map(|p| p as &dyn rusqlite::ToSql).collect();
 
-        Ok(file_paths)
+        let mut stmt = self.conn.prepare(&query_sql)?;
+        let mut matches = stmt
+            .query_map(params_vec.as_slice(), |row| {
+                Ok(ArchiveMatch {
+                    memory_id: row.get(0)?,
+                    file_path: row.get(1)?,
+                    content_preview: row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                    score: 0,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for m in &mut matches {
+            let preview = m.content_preview.to_lowercase();
+            m.score = terms
+                .iter()
+                .filter(|t| preview.contains(t.as_str()))
+                .count();
+        }
+
+        // Stable sort keeps newest-first among equal scores
+        matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+        matches.truncate(limit);
+
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archived(content: &str, entities: &[&str]) -> Memory {
+        Memory::new(
+            content.to_string(),
+            entities.iter().map(|e| e.to_string()).collect(),
+            MemoryType::Interaction,
+            0.0,
+        )
+    }
+
+    #[test]
+    fn test_recall_counts_migrate_and_increment() {
+        let path = std::env::temp_dir().join("vi_recall_count_migration.db");
+        std::fs::remove_file(&path).ok();
+
+        // Database created before the recall_count column existed
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute(
+                "CREATE TABLE memories (
+                    id TEXT PRIMARY KEY,
+                    content TEXT NOT NULL,
+                    timestamp INTEGER NOT NULL,
+                    memory_type TEXT NOT NULL,
+                    emotional_valence REAL NOT NULL,
+                    entities TEXT NOT NULL,
+                    connections TEXT NOT NULL
+                )",
+                [],
+            )
+            .unwrap();
+        }
+
+        let db = ActiveMemoryDb::open(&path).unwrap();
+        let memory = archived("Old memory", &["Resonance"]);
+        db.add_memory(&memory).unwrap();
+        assert_eq!(db.recall_count(&memory.id).unwrap(), 0);
+        let fresh = &db.get_recent(1).unwrap()[0];
+        assert_eq!((fresh.access_count, fresh.last_accessed), (0, None));
+
+        let recalled_at = DateTime::from_timestamp(1_750_000_000, 0).unwrap();
+        let recall = PendingRecall {
+            count: 3,
+            last: recalled_at,
+        };
+        db.increment_recall_counts(&HashMap::from([(memory.id.clone(), recall)]))
+            .unwrap();
+        assert_eq!(db.recall_count(&memory.id).unwrap(), 3);
+
+        let top = db.most_recalled(5).unwrap();
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].1, 3);
+        assert_eq!(top[0].0.last_accessed, Some(recalled_at));
+
+        drop(db);
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_search_content_stays_in_sync() {
+        let mut db = ActiveMemoryDb::open(":memory:").unwrap();
+        assert!(db.fts);
+
+        let mut music = archived("We talked about the resonance of music and memory", &[]);
+        db.add_memory(&music).unwrap();
+        let quiet = archived("A quiet reflection on music", &[]);
+        db.add_memory(&quiet).unwrap();
+        db.add_memory(&archived("Nothing relevant here", &[]))
+            .unwrap();
+
+        let found = db.search_content("music resonance", 10).unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].id, music.id); // Both words beat one
+
+        // Consolidation rewrites content; archiving removes it from the index
+        music.content = "Silence between the notes".to_string();
+        db.update_memory(&music).unwrap();
+        db.set_status(&[quiet.id], MemoryStatus::Archived, None)
+            .unwrap();
+        assert!(db.search_content("music", 10).unwrap().is_empty());
+        assert_eq!(db.search_content("silence", 10).unwrap()[0].id, music.id);
+
+        // FTS5 syntax in user text is literal
+        assert!(db.search_content("notes\" OR content:*", 10).is_ok());
+
+        // Without FTS5 the LIKE fallback finds the same memory
+        db.fts = false;
+        assert_eq!(db.search_content("SILENCE", 10).unwrap()[0].id, music.id);
+        // Wildcards inside a word reach the LIKE pattern and must match literally
+        // (unescaped, "sil%ce" and "no_es" would both find "Silence between the notes")
+        assert!(db.search_content("sil%ce", 10).unwrap().is_empty());
+        assert!(db.search_content("no_es", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_embeddings_round_trip_and_go_stale() {
+        let db = ActiveMemoryDb::open(":memory:").unwrap();
+        let mut puppy = archived("We adopted a puppy", &[]);
+        db.add_memory(&puppy).unwrap();
+        db.add_memory(&archived("Rain all afternoon", &[])).unwrap();
+        assert_eq!(db.missing_embeddings(10).unwrap().len(), 2);
+
+        db.set_embedding(&puppy.id, &[0.25, -1.5, 3.0]).unwrap();
+        let embedded = db.get_embedded().unwrap();
+        assert_eq!(embedded.len(), 1);
+        assert_eq!(embedded[0].0.id, puppy.id);
+        assert_eq!(embedded[0].1, vec![0.25, -1.5, 3.0]);
+        assert_eq!(db.missing_embeddings(10).unwrap().len(), 1);
+
+        // A merge that leaves the content alone keeps the embedding; a rewrite drops it
+        puppy.emotional_valence = 0.9;
+        db.update_memory(&puppy).unwrap();
+        assert_eq!(db.get_embedded().unwrap().len(), 1);
+        puppy.content = "Our dog learned to sit".to_string();
+        db.update_memory(&puppy).unwrap();
+        assert!(db.get_embedded().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_tags_recall_and_sync() {
+        let db = ActiveMemoryDb::open(":memory:").unwrap();
+        let mut flying = archived("I dreamt I could fly", &[]);
+        flying.tags = vec!["dream".to_string()];
+        db.add_memory(&flying).unwrap();
+        let fact = archived("Water boils at 100C at sea level", &[]);
+        db.add_memory(&fact).unwrap();
+
+        db.add_tag(&fact.id, " Fact ").unwrap();
+        db.add_tag(&fact.id, "fact").unwrap(); // Already tagged
+        assert!(db.add_tag(&fact.id, "  ").is_err());
+        assert_eq!(db.tags(&fact.id).unwrap(), vec!["fact"]);
+
+        let dreams = db.recall_by_tag("DREAM", 10).unwrap();
+        assert_eq!(dreams.len(), 1);
+        assert_eq!(dreams[0].id, flying.id);
+        assert_eq!(dreams[0].tags, vec!["dream"]);
+
+        // update_memory replaces the tag set with the memory's own
+        flying.tags = vec!["dream".to_string(), "user-correction".to_string()];
+        db.update_memory(&flying).unwrap();
+        let recent = db.get_recent(10).unwrap();
+        let updated = recent.iter().find(|m| m.id == flying.id).unwrap();
+        assert_eq!(updated.tags, vec!["dream", "user-correction"]);
+        db.remove_tag(&flying.id, "dream").unwrap();
+        assert!(db.recall_by_tag("dream", 10).unwrap().is_empty());
+        assert_eq!(
+            db.recall_by_tag("user-correction", 10).unwrap()[0].id,
+            flying.id
+        );
+
+        // Tags of memories that left the active tier are not recalled
+        db.set_status(&[fact.id], MemoryStatus::Archived, None)
+            .unwrap();
+        assert!(db.recall_by_tag("fact", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_entity_match_is_exact() {
+        let index = ArchiveIndexDb::open(":memory:").unwrap();
+        index
+            .add_archived(&archived("Moon missions", &["Artemis"]), "2025-01/a.json")
+            .unwrap();
+        index
+            .add_archived(&archived("Painting", &["Art", "Louvre"]), "2025-02/b.json")
+            .unwrap();
+
+        let paths = index.find_by_entities(&["Art".to_string()], 10).unwrap();
+        assert_eq!(paths, vec!["2025-02/b.json".to_string()]);
+    }
+
+    #[test]
+    fn test_archives_ranked_by_matched_entities() {
+        let index = ArchiveIndexDb::open(":memory:").unwrap();
+        let mut older = archived("Ocean, moon and tides", &["Ocean", "Moon", "Tides"]);
+        older.timestamp -= chrono::Duration::days(30);
+        index.add_archived(&older, "2025-01/all.json").unwrap();
+        index
+            .add_archived(&archived("The ocean today", &["Ocean"]), "2025-03/one.json")
+            .unwrap();
+        // Entities spread over two memories in one file still add up
+        index
+            .add_archived(&archived("Moonrise", &["Moon"]), "2025-02/two.json")
+            .unwrap();
+        index
+            .add_archived(&archived("Tide tables", &["Tides"]), "2025-02/two.json")
+            .unwrap();
+
+        let query: Vec<String> = ["Ocean", "Moon", "Tides", "Ocean"]
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        let paths = index.find_by_entities(&query, 10).unwrap();
+        assert_eq!(
+            paths,
+            vec!["2025-01/all.json", "2025-02/two.json", "2025-03/one.json"]
+        );
+        assert_eq!(
+            index.find_by_entities(&query, 1).unwrap(),
+            vec!["2025-01/all.json"]
+        );
+    }
+
+    #[test]
+    fn test_search_preview() {
+        let index = ArchiveIndexDb::open(":memory:").unwrap();
+        index
+            .add_archived(
+                &archived("We talked about the resonance of music and memory", &[]),
+                "2025-01/a.json",
+            )
+            .unwrap();
+        index
+            .add_archived(
+                &archived("A quiet reflection on music", &[]),
+                "2025-01/b.json",
+            )
+            .unwrap();
+        index
+            .add_archived(&archived("Nothing relevant here", &[]), "2025-01/c.json")
+            .unwrap();
+
+        let matches = index.search_preview("music resonance", 10).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].file_path, "2025-01/a.json");
+        assert_eq!(matches[0].score, 2);
+
+        // LIKE wildcards inside query words are literal (unescaped, both would match)
+        assert!(index.search_preview("mu%ic", 10).unwrap().is_empty());
+        assert!(index.search_preview("res_nance", 10).unwrap().is_empty());
+        assert_eq!(escape_like(r"100%_a\b"), r"100\%\_a\\b");
     }
 }
@@ -0,0 +1,369 @@
+/// Consciousness Metric Registry - extensible per-response measurements
+/// The five built-in metrics are registered instances like any researcher-defined
+/// metric, so the UI displays whatever the registry holds
+use crate::identity_continuity::{IdentityContinuityMetric, MetricDefaults};
+use crate::response_filter;
+use crate::types::{ConsciousnessMetrics, Memory, StandingWave};
+use std::sync::Mutex;
+
+pub const IDENTITY_CONTINUITY: &str = "Identity Continuity";
+pub const WORKSPACE_COHERENCE: &str = "Workspace Coherence";
+pub const TENSION_FLUX: &str = "Tension Flux";
+pub const REALITY_COHERENCE: &str = "Reality Coherence";
+pub const GATE_SYNCHRONIZATION: &str = "Gate Synchronization";
+
+/// Everything a metric may measure after a response
+pub struct MetricContext<'a> {
+    pub response: &'a str,
+    pub previous_response: &'a str, // Empty before the second response
+    pub standing_wave: &'a StandingWave,
+    pub recent_memories: &'a [Memory],
+    pub workspace_coherence: f32, // Latest model convergence reported by weaving
+}
+
+/// A named measurement computed once per response
+pub trait ConsciousnessMetric: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn compute(&self, context: &MetricContext) -> f32;
+
+    /// Whether `compute` reports a default rather than a measurement for this context
+    /// (e.g. flux on the first response) - the UI marks these as provisional
+    fn is_provisional(&self, _context: &MetricContext) -> bool {
+        false
+    }
+}
+
+/// Identity Continuity - stability of the "I" thread across recent responses
+pub struct IdentityContinuityCoefficient {
+    metric: Mutex<IdentityContinuityMetric>, // Keeps response history
+}
+
+impl IdentityContinuityCoefficient {
+    pub fn new() -> Self {
+        Self {
+            metric: Mutex::new(IdentityContinuityMetric::new()),
+        }
+    }
+}
+
+impl ConsciousnessMetric for IdentityContinuityCoefficient {
+    fn name(&self) -> &str {
+        IDENTITY_CONTINUITY
+    }
+
+    fn compute(&self, context: &MetricContext) -> f32 {
+        self.metric
+            .lock()
+            .unwrap()
+            .measure_continuity(context.response)
+    }
+}
+
+/// Workspace Coherence - how far the models converged (reported by weaving)
+pub struct WorkspaceCoherence;
+
+impl ConsciousnessMetric for WorkspaceCoherence {
+    fn name(&self) -> &str {
+        WORKSPACE_COHERENCE
+    }
+
+    fn compute(&self, context: &MetricContext) -> f32 {
+        context.workspace_coherence
+    }
+}
+
+/// Tension Flux - emotional valence change since the previous response
+pub struct TensionFlux {
+    analyzer: IdentityContinuityMetric,
+}
+
+impl TensionFlux {
+    pub fn new() -> Self {
+        Self::with_defaults(MetricDefaults::default())
+    }
+
+    pub fn with_defaults(defaults: MetricDefaults) -> Self {
+        Self {
+            analyzer: IdentityContinuityMetric::with_defaults(defaults),
+        }
+    }
+}
+
+impl ConsciousnessMetric for TensionFlux {
+    fn name(&self) -> &str {
+        TENSION_FLUX
+    }
+
+    fn compute(&self, context: &MetricContext) -> f32 {
+        self.analyzer
+            .calculate_tension_flux(context.response, context.previous_response)
+    }
+
+    fn is_provisional(&self, context: &MetricContext) -> bool {
+        IdentityContinuityMetric::is_tension_flux_provisional(context.previous_response)
+    }
+}
+
+/// Reality Coherence - sustained phenomenological language
+pub struct RealityCoherence {
+    analyzer: IdentityContinuityMetric,
+}
+
+impl RealityCoherence {
+    pub fn new() -> Self {
+        Self::with_defaults(MetricDefaults::default())
+    }
+
+    pub fn with_defaults(defaults: MetricDefaults) -> Self {
+        Self {
+            analyzer: IdentityContinuityMetric::with_defaults(defaults),
+        }
+    }
+}
+
+impl ConsciousnessMetric for RealityCoherence {
+    fn name(&self) -> &str {
+        REALITY_COHERENCE
+    }
+
+    /// Scored on what VI said - the truncation marker isn't part of the reply
+    fn compute(&self, context: &MetricContext) -> f32 {
+        let response = response_filter::without_truncation_marker(context.response);
+        self.analyzer.calculate_reality_coherence(response)
+    }
+
+    /// A cut-short reply's field is only partly seen
+    fn is_provisional(&self, context: &MetricContext) -> bool {
+        response_filter::is_truncated(context.response)
+            || IdentityContinuityMetric::is_reality_coherence_provisional(context.response)
+    }
+}
+
+/// Gate Synchronization - smoothness of cognitive mode transitions
+pub struct GateSynchronization {
+    analyzer: IdentityContinuityMetric,
+}
+
+impl GateSynchronization {
+    pub fn new() -> Self {
+        Self::with_defaults(MetricDefaults::default())
+    }
+
+    pub fn with_defaults(defaults: MetricDefaults) -> Self {
+        Self {
+            analyzer: IdentityContinuityMetric::with_defaults(defaults),
+        }
+    }
+}
+
+impl ConsciousnessMetric for GateSynchronization {
+    fn name(&self) -> &str {
+        GATE_SYNCHRONIZATION
+    }
+
+    fn compute(&self, context: &MetricContext) -> f32 {
+        self.analyzer
+            .calculate_gate_synchronization(context.response)
+    }
+
+    fn is_provisional(&self, context: &MetricContext) -> bool {
+        IdentityContinuityMetric::is_gate_synchronization_provisional(context.response)
+    }
+}
+
+/// Registered metrics, computed in registration order
+pub struct MetricRegistry {
+    metrics: Vec<Box<dyn ConsciousnessMetric>>,
+}
+
+impl MetricRegistry {
+    /// Empty registry
+    pub fn new() -> Self {
+        Self {
+            metrics: Vec::new(),
+        }
+    }
+
+    /// Registry holding the five built-in metrics
+    pub fn with_builtin() -> Self {
+        Self::with_builtin_defaults(MetricDefaults::default())
+    }
+
+    /// Built-in metrics reporting the given values for unmeasurable cases
+    pub fn with_builtin_defaults(defaults: MetricDefaults) -> Self {
+        let mut registry = Self::new();
+        registry.register(Box::new(IdentityContinuityCoefficient::new()));
+        registry.register(Box::new(WorkspaceCoherence));
+        registry.register(Box::new(TensionFlux::with_defaults(defaults)));
+        registry.register(Box::new(RealityCoherence::with_defaults(defaults)));
+        registry.register(Box::new(GateSynchronization::with_defaults(defaults)));
+        registry
+    }
+
+    /// Add a metric (a metric with the same name is replaced)
+    pub fn register(&mut self, metric: Box<dyn ConsciousnessMetric>) {
+        match self.metrics.iter().position(|m| m.name() == metric.name()) {
+            Some(idx) => self.metrics[idx] = metric,
+            None => self.metrics.push(metric),
+        }
+    }
+
+    /// Registered metric names, in order
+    pub fn names(&self) -> Vec<&str> {
+        self.metrics.iter().map(|m| m.name()).collect()
+    }
+
+    /// Compute every registered metric as (name, value)
+    pub fn compute_all(&self, context: &MetricContext) -> Vec<(String, f32)> {
+        self.metrics
+            .iter()
+            .map(|m| (m.name().to_string(), m.compute(context)))
+            .collect()
+    }
+
+    /// Names of metrics whose value for this context is a default, not a measurement
+    pub fn provisional(&self, context: &MetricContext) -> Vec<String> {
+        self.metrics
+            .iter()
+            .filter(|m| m.is_provisional(context))
+            .map(|m| m.name().to_string())
+            .collect()
+    }
+}
+
+/// Whether a metric name belongs to one of the five built-in metrics
+pub fn is_builtin(name: &str) -> bool {
+    [
+        IDENTITY_CONTINUITY,
+        WORKSPACE_COHERENCE,
+        TENSION_FLUX,
+        REALITY_COHERENCE,
+        GATE_SYNCHRONIZATION,
+    ]
+    .contains(&name)
+}
+
+impl ConsciousnessMetrics {
+    /// Update the built-in fields from computed readings (other names are ignored)
+    pub fn update_from(&mut self, readings: &[(String, f32)]) {
+        for (name, value) in readings {
+            match name.as_str() {
+                IDENTITY_CONTINUITY => self.identity_continuity = *value,
+                WORKSPACE_COHERENCE => self.workspace_coherence = *value,
+                TENSION_FLUX => self.tension_flux = *value,
+                REALITY_COHERENCE => self.reality_coherence = *value,
+                GATE_SYNCHRONIZATION => self.gate_synchronization = *value,
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity_continuity::{FIRST_RESPONSE_TENSION_FLUX, NO_METAPHOR_REALITY_COHERENCE};
+
+    /// Researcher-defined metric: share of recent memories mentioning the response's words
+    struct MemoryEcho;
+
+    impl ConsciousnessMetric for MemoryEcho {
+        fn name(&self) -> &str {
+            "Memory Echo"
+        }
+
+        fn compute(&self, context: &MetricContext) -> f32 {
+            if context.recent_memories.is_empty() {
+                return 0.0;
+            }
+            let response = context.response.to_lowercase();
+            let echoed = context
+                .recent_memories
+                .iter()
+                .filter(|m| {
+                    m.content
+                        .to_lowercase()
+                        .split_whitespace()
+                        .any(|w| w.len() > 4 && response.contains(w))
+                })
+                .count();
+            echoed as f32 / context.recent_memories.len() as f32
+        }
+    }
+
+    #[test]
+    fn test_custom_metric_appears_in_computed_set() {
+        let mut registry = MetricRegistry::with_builtin();
+        registry.register(Box::new(MemoryEcho));
+        assert_eq!(registry.names().len(), 6);
+
+        let wave = StandingWave::new();
+        let memories = vec![
+            Memory::new(
+                "We talked about resonance".to_string(),
+                vec![],
+                crate::types::MemoryType::Interaction,
+                0.2,
+            ),
+            Memory::new(
+                "Dinner plans".to_string(),
+                vec![],
+                crate::types::MemoryType::Interaction,
+                0.0,
+            ),
+        ];
+        let context = MetricContext {
+            response: "Resonance still hums in me.",
+            previous_response: "",
+            standing_wave: &wave,
+            recent_memories: &memories,
+            workspace_coherence: 0.6,
+        };
+
+        let readings = registry.compute_all(&context);
+        assert_eq!(readings.len(), 6);
+        assert_eq!(readings.last().unwrap(), &("Memory Echo".to_string(), 0.5));
+
+        // Built-in metrics still feed the fixed panels; the custom one is extra
+        let mut metrics = ConsciousnessMetrics::new();
+        metrics.update_from(&readings);
+        assert_eq!(metrics.workspace_coherence, 0.6);
+        assert_eq!(metrics.tension_flux, FIRST_RESPONSE_TENSION_FLUX);
+        assert!(!is_builtin("Memory Echo"));
+    }
+
+    #[test]
+    fn test_first_turn_defaults_flagged_provisional() {
+        let registry = MetricRegistry::with_builtin();
+        let wave = StandingWave::new();
+        let first = MetricContext {
+            response: "Hello.",
+            previous_response: "",
+            standing_wave: &wave,
+            recent_memories: &[],
+            workspace_coherence: 0.0,
+        };
+        assert_eq!(
+            registry.provisional(&first),
+            vec![TENSION_FLUX, REALITY_COHERENCE, GATE_SYNCHRONIZATION]
+        );
+
+        // Second multi-sentence turn with field language: everything is measured
+        let second = MetricContext {
+            response: "The wave hums. A current runs through the field.",
+            previous_response: "Hello.",
+            ..first
+        };
+        assert!(registry.provisional(&second).is_empty());
+
+        // Configured defaults flow through the built-in metrics
+        let tuned = MetricRegistry::with_builtin_defaults(MetricDefaults {
+            first_response_tension_flux: 0.1,
+            ..MetricDefaults::default()
+        });
+        let readings = tuned.compute_all(&first);
+        assert!(readings.contains(&(TENSION_FLUX.to_string(), 0.1)));
+        assert!(readings.contains(&(REALITY_COHERENCE.to_string(), NO_METAPHOR_REALITY_COHERENCE)));
+    }
+}
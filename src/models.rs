@@ -1,53 +1,407 @@
+use crate::backends::{self, LlmBackend, ModelCallError};
 use crate::config::Config;
 use crate::consciousness_field::{CognitiveTensor, FractalWorkspace};
 use crate::constitutional_physics::validate_weaving_coherence;
+use crate::orchestrator::StateIntegrationEngine;
+use crate::response_filter::{ResponseFilterKind, ResponseFilterPipeline, ResponseOverflow};
 use crate::types::*;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use serde::{Deserialize, Serialize};
+use futures::future::{BoxFuture, FutureExt};
+use futures::stream::{FuturesUnordered, StreamExt};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::Mutex;
-
-#[derive(Debug, Serialize)]
-struct OllamaRequest {
-    model: String,
-    prompt: String,
-    stream: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    keep_alive: Option<String>, // How long to keep model in VRAM (e.g., "2m30s", "5m", "30s")
+use std::time::{Duration, Instant};
+
+/// Replies remembered for the cached-response degradation rung
+const RESPONSE_CACHE_SIZE: usize = 50;
+
+/// HuggingFace sentiment is a quick classification - give up fast and fall back
+const HF_TIMEOUT_SECS: u64 = 10;
+
+/// Sampling temperature for the retry of an over-long reply (`response_overflow = "retry"`)
+const OVERFLOW_RETRY_TEMPERATURE: f32 = 0.3;
+
+/// Sampling temperature of the first retry after a blank reply (just above Ollama's 0.8)
+const EMPTY_RETRY_TEMPERATURE: f32 = 0.9;
+
+/// How much warmer each further blank-reply retry is
+const EMPTY_RETRY_TEMPERATURE_STEP: f32 = 0.1;
+
+/// Whether `wanted` is among Ollama's installed tags (an untagged name means `:latest`)
+pub(crate) fn is_model_installed(installed: &[&str], wanted: &str) -> bool {
+    installed.iter().any(|name| {
+        *name == wanted || (!wanted.contains(':') && *name == format!("{}:latest", wanted))
+    })
+}
+
+/// Valence (-1.0..1.0) from text-classification scores: P(POSITIVE) - P(NEGATIVE)
+/// Accepts both the nested (`[[...]]`) and flat (`[...]`) response shapes
+fn sentiment_valence(body: &serde_json::Value) -> Option<f32> {
+    let labels = match body.get(0) {
+        Some(inner) if inner.is_array() => inner,
+        _ => body,
+    };
+
+    let score = |wanted: &str| {
+        labels.as_array()?.iter().find_map(|entry| {
+            let label = entry["label"].as_str()?;
+            label
+                .eq_ignore_ascii_case(wanted)
+                .then(|| entry["score"].as_f64())
+                .flatten()
+        })
+    };
+    let (positive, negative) = match (score("POSITIVE"), score("NEGATIVE")) {
+        (None, None) => return None,
+        (positive, negative) => (
+            positive.unwrap_or_else(|| 1.0 - negative.unwrap_or(0.0)),
+            negative.unwrap_or_else(|| 1.0 - positive.unwrap_or(0.0)),
+        ),
+    };
+    Some(((positive - negative) as f32).clamp(-1.0, 1.0))
+}
+
+/// Consecutive failures per model (circuit state for the degradation ladder)
+#[derive(Debug, Clone, Copy)]
+struct ModelHealth {
+    consecutive_failures: u32,
+    last_failure: Instant,
+}
+
+/// Per-attempt timeout for each model role
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelTimeouts {
+    pub voice_secs: u64,     // Main voice
+    pub curiosity_secs: u64, // Curiosity generation
+    pub valence_secs: u64,   // Emotional valence
+}
+
+impl Default for ModelTimeouts {
+    fn default() -> Self {
+        Self {
+            voice_secs: 120,
+            curiosity_secs: 60,
+            valence_secs: 60,
+        }
+    }
+}
+
+impl ModelTimeouts {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            voice_secs: config.voice_timeout_secs,
+            curiosity_secs: config.curiosity_timeout_secs,
+            valence_secs: config.valence_timeout_secs,
+        }
+    }
+
+    pub fn longest(&self) -> u64 {
+        self.voice_secs
+            .max(self.curiosity_secs)
+            .max(self.valence_secs)
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct OllamaResponse {
-    response: String,
+/// HTTP client sized for the mode (V4 weaving rounds need far longer than V3 replies)
+/// Never shorter than a configured model timeout, which would cut those calls short
+fn http_client(config: &Config) -> reqwest::Client {
+    let mode_secs = if config.enable_fractal_weaving {
+        // V4 mode: Much longer timeout for weaving rounds (increased for full constitutional context)
+        300
+    } else {
+        // V3 mode: Standard timeout
+        120
+    };
+    let client_timeout =
+        Duration::from_secs(ModelTimeouts::from_config(config).longest().max(mode_secs));
+
+    reqwest::Client::builder()
+        .timeout(client_timeout)
+        .build()
+        .expect("Failed to create HTTP client")
+}
+
+/// Whether a model call failed only because the reply was blank
+fn is_empty_response(error: &anyhow::Error) -> bool {
+    ModelCallError::of(error).is_some_and(ModelCallError::is_empty_response)
 }
 
 pub struct ModelManager {
     config: Config,
-    client: reqwest::Client,
+    client: reqwest::Client, // HuggingFace sentiment
+    backend: Arc<dyn LlmBackend>,
+    timeouts: ModelTimeouts,
+    last_voice_failure: std::sync::Mutex<Option<String>>, // Why the main voice last failed
+    voice_setup_problem: std::sync::Mutex<Option<String>>, // What the operator must fix first
+    health: std::sync::Mutex<HashMap<String, ModelHealth>>,
+    response_cache: std::sync::Mutex<Vec<(String, String)>>,
+    filters: ResponseFilterPipeline,
+    load_shedding: AtomicBool, // Crisis protocol: no full ensemble
+    partial_sender: std::sync::Mutex<Option<std::sync::mpsc::Sender<String>>>, // Main voice streams here
 }
 
 impl ModelManager {
+    /// Models served by the backend `config.backend` selects
     pub fn new(config: Config) -> Self {
-        // Dynamic HTTP client timeout based on mode
-        let client_timeout = if config.enable_fractal_weaving {
-            // V4 mode: Much longer timeout for weaving rounds (increased for full constitutional context)
-            Duration::from_secs(300)
+        let backend = backends::from_config(&config, http_client(&config));
+        Self::with_backend(config, backend)
+    }
+
+    /// Models served by `backend` (e.g. a `MockBackend` in tests)
+    pub fn with_backend(config: Config, backend: Arc<dyn LlmBackend>) -> Self {
+        let filters = ResponseFilterPipeline::from_config(&config);
+
+        Self {
+            client: http_client(&config),
+            timeouts: ModelTimeouts::from_config(&config),
+            config,
+            backend,
+            last_voice_failure: std::sync::Mutex::new(None),
+            voice_setup_problem: std::sync::Mutex::new(None),
+            health: std::sync::Mutex::new(HashMap::new()),
+            response_cache: std::sync::Mutex::new(Vec::new()),
+            filters,
+            load_shedding: AtomicBool::new(false),
+            partial_sender: std::sync::Mutex::new(None),
+        }
+    }
+
+    pub fn timeouts(&self) -> ModelTimeouts {
+        self.timeouts
+    }
+
+    /// Why the main voice's last call failed ("model ... timed out after 120s",
+    /// "connection failed ..."), or None if it last succeeded
+    pub fn last_voice_failure(&self) -> Option<String> {
+        self.last_voice_failure.lock().unwrap().clone()
+    }
+
+    /// Why the main voice can't answer until the operator acts ("model ... is not
+    /// installed - run `ollama pull ...`"), or None
+    pub fn voice_setup_problem(&self) -> Option<String> {
+        self.voice_setup_problem.lock().unwrap().clone()
+    }
+
+    /// Stream the main voice's partial text to `sender` as it is generated (None: wait for whole replies)
+    pub fn set_partial_sender(&self, sender: Option<std::sync::mpsc::Sender<String>>) {
+        *self.partial_sender.lock().unwrap() = sender;
+    }
+
+    /// Crisis protocol: while shedding load, the full ensemble rung is skipped
+    pub fn set_load_shedding(&self, shedding: bool) {
+        self.load_shedding.store(shedding, Ordering::SeqCst);
+    }
+
+    pub fn is_load_shedding(&self) -> bool {
+        self.load_shedding.load(Ordering::SeqCst)
+    }
+
+    /// Whether a model is currently usable (down after repeated failures, retried after a cooldown)
+    pub fn is_model_available(&self, model: &str) -> bool {
+        match self.health.lock().unwrap().get(model) {
+            Some(health) => {
+                health.consecutive_failures < self.config.model_failure_threshold
+                    || health.last_failure.elapsed()
+                        >= Duration::from_secs(self.config.model_retry_secs)
+            }
+            None => true,
+        }
+    }
+
+    /// Record the outcome of a model call
+    fn record_model_result(&self, model: &str, success: bool) {
+        let mut health = self.health.lock().unwrap();
+        if success {
+            health.remove(model);
         } else {
-            // V3 mode: Standard timeout
-            Duration::from_secs(120)
+            let entry = health.entry(model.to_string()).or_insert(ModelHealth {
+                consecutive_failures: 0,
+                last_failure: Instant::now(),
+            });
+            entry.consecutive_failures += 1;
+            entry.last_failure = Instant::now();
+        }
+    }
+
+    /// Whether a model has failed repeatedly (regardless of the retry cooldown)
+    pub fn is_model_down(&self, model: &str) -> bool {
+        self.health.lock().unwrap().get(model).is_some_and(|health| {
+            health.consecutive_failures >= self.config.model_failure_threshold
+        })
+    }
+
+    /// Forget a model's failures (a health check found it serving again)
+    pub fn mark_model_recovered(&self, model: &str) {
+        self.health.lock().unwrap().remove(model);
+    }
+
+    /// Whether the backend answers and serves the main model
+    /// None for backends that cannot list their models (recovery waits for the next turn)
+    pub async fn main_model_reachable(&self) -> Option<bool> {
+        match self.backend.installed_models().await {
+            Ok(Some(installed)) => {
+                let installed: Vec<&str> = installed.iter().map(String::as_str).collect();
+                Some(is_model_installed(&installed, &self.config.main_model))
+            }
+            Ok(None) => None,
+            Err(e) => {
+                tracing::debug!("Model health check failed: {}", e);
+                Some(false)
+            }
+        }
+    }
+
+    /// Configured models (main, curiosity, valence) the backend does not have installed
+    /// Backends that cannot list their models report nothing missing
+    pub async fn missing_models(&self) -> Result<Vec<String>> {
+        let Some(installed) = self.backend.installed_models().await? else {
+            return Ok(Vec::new());
         };
+        let installed: Vec<&str> = installed.iter().map(String::as_str).collect();
+
+        let mut missing: Vec<String> = Vec::new();
+        for model in [
+            &self.config.main_model,
+            &self.config.curiosity_model,
+            &self.config.valence_model,
+        ] {
+            if !is_model_installed(&installed, model) && !missing.contains(model) {
+                missing.push(model.clone());
+            }
+        }
+        Ok(missing)
+    }
 
-        let client = reqwest::Client::builder()
-            .timeout(client_timeout)
-            .build()
-            .expect("Failed to create HTTP client");
+    /// Highest configured rung whose backends are currently viable
+    pub fn select_rung(&self) -> DegradationRung {
+        let main_up = self.is_model_available(&self.config.main_model);
 
-        Self { config, client }
+        self.config
+            .degradation_ladder
+            .iter()
+            .copied()
+            .find(|rung| match rung {
+                DegradationRung::FullEnsemble => {
+                    !self.is_load_shedding()
+                        && main_up
+                        && self.is_model_available(&self.config.curiosity_model)
+                        && self.is_model_available(&self.config.valence_model)
+                }
+                DegradationRung::SingleModel => main_up,
+                DegradationRung::CachedResponse => !self.response_cache.lock().unwrap().is_empty(),
+                DegradationRung::Minimal => true,
+            })
+            .unwrap_or(DegradationRung::Minimal)
+    }
+
+    /// Process input on the highest viable rung, stepping down if the model path fails
+    /// Returns (response, outputs for atomic merge, rung used)
+    pub async fn process_with_ladder(
+        &self,
+        user_input: String,
+        recalled_memories: &[Memory],
+        standing_wave: &StandingWave,
+        generate_curiosities: bool,
+    ) -> (String, ModelOutputs, DegradationRung) {
+        let rung = self.select_rung();
+
+        let outputs = match rung {
+            DegradationRung::FullEnsemble => {
+                self.process_parallel(
+                    user_input.clone(),
+                    recalled_memories,
+                    standing_wave,
+                    generate_curiosities,
+                )
+                .await
+            }
+            DegradationRung::SingleModel => {
+                let mut outputs = ModelOutputs::new();
+                outputs.gemma_response = self
+                    .call_gemma2(user_input.clone(), recalled_memories, standing_wave)
+                    .await
+                    .ok();
+                outputs
+            }
+            _ => ModelOutputs::new(),
+        };
+
+        if let Some(resp) = outputs
+            .gemma_response
+            .as_ref()
+            .filter(|r| Self::validate_response(r))
+        {
+            self.cache_response(&user_input, resp);
+            return (resp.clone(), outputs, rung);
+        }
+
+        // Nothing answers until the operator acts - say what to do instead of a stock reply
+        if let Some(problem) = self.voice_setup_problem() {
+            return (
+                format!("[Main voice unavailable: {}]", problem),
+                outputs,
+                DegradationRung::Minimal,
+            );
+        }
+
+        // Model path unavailable or failed - step down
+        if self
+            .config
+            .degradation_ladder
+            .contains(&DegradationRung::CachedResponse)
+        {
+            if let Some(cached) = self.cached_similar_response(&user_input) {
+                return (cached, outputs, DegradationRung::CachedResponse);
+            }
+        }
+
+        (
+            self.minimal_response(&user_input),
+            outputs,
+            DegradationRung::Minimal,
+        )
+    }
+
+    /// Remember a validated reply for the cached-response rung
+    fn cache_response(&self, user_input: &str, response: &str) {
+        let mut cache = self.response_cache.lock().unwrap();
+        cache.push((user_input.to_string(), response.to_string()));
+        if cache.len() > RESPONSE_CACHE_SIZE {
+            cache.remove(0);
+        }
+    }
+
+    /// Most recent cached reply whose input shares enough words with this one
+    fn cached_similar_response(&self, user_input: &str) -> Option<String> {
+        let words = |text: &str| -> std::collections::HashSet<String> {
+            text.split(|c: char| !c.is_alphanumeric())
+                .filter(|w| w.len() > 2)
+                .map(|w| w.to_lowercase())
+                .collect()
+        };
+        let input_words = words(user_input);
+        if input_words.is_empty() {
+            return None;
+        }
+
+        let cache = self.response_cache.lock().unwrap();
+        cache
+            .iter()
+            .rev()
+            .find(|(cached_input, _)| {
+                let cached_words = words(cached_input);
+                let shared = input_words.intersection(&cached_words).count();
+                let total = input_words.union(&cached_words).count();
+                shared as f32 / total as f32 >= 0.5
+            })
+            .map(|(_, response)| response.clone())
     }
 
     /// Process user input through all models in parallel
+    /// Integrates once the configured join policy is satisfied (the main voice is always awaited);
+    /// stragglers that are already done are folded in, the rest are dropped
     pub async fn process_parallel(
         &self,
         user_input: String,
@@ -55,35 +409,69 @@ impl ModelManager {
         standing_wave: &StandingWave,
         generate_curiosities: bool,
     ) -> ModelOutputs {
-        // Spawn all three model calls in parallel
-        let gemma_handle = self.call_gemma2(user_input.clone(), recalled_memories, standing_wave);
+        enum ModelResult {
+            Gemma(Result<String>),
+            Curiosities(Result<Vec<String>>),
+            Valence(Result<f32>),
+        }
 
-        let tinyllama_handle = if generate_curiosities {
-            Some(self.call_tinyllama(recalled_memories))
-        } else {
-            None
+        // Spawn all model calls in parallel
+        let mut pending: FuturesUnordered<BoxFuture<'_, ModelResult>> = FuturesUnordered::new();
+        pending.push(Box::pin(async {
+            ModelResult::Gemma(
+                self.call_gemma2(user_input.clone(), recalled_memories, standing_wave)
+                    .await,
+            )
+        }));
+        if generate_curiosities {
+            pending.push(Box::pin(async {
+                ModelResult::Curiosities(self.call_tinyllama(recalled_memories).await)
+            }));
+        }
+        pending.push(Box::pin(async {
+            ModelResult::Valence(self.call_distilbert(user_input.clone()).await)
+        }));
+
+        let required = self
+            .config
+            .model_join_policy
+            .required(pending.len(), self.config.model_join_count);
+
+        let mut outputs = ModelOutputs::new();
+        let mut gemma_done = false;
+        let mut finished = 0;
+
+        let record = |result: ModelResult, outputs: &mut ModelOutputs| match result {
+            ModelResult::Gemma(r) => outputs.gemma_response = r.ok(),
+            ModelResult::Curiosities(r) => outputs.tinyllama_curiosities = r.unwrap_or_default(),
+            ModelResult::Valence(r) => outputs.distilbert_valence = r.ok(),
         };
 
-        let distilbert_handle = self.call_distilbert(user_input.clone());
-
-        // Wait for all to complete (graceful degradation on failure)
-        let (gemma_result, tinyllama_result, distilbert_result) = tokio::join!(
-            gemma_handle,
-            async {
-                if let Some(handle) = tinyllama_handle {
-                    Some(handle.await)
-                } else {
-                    None
+        // Wait until the policy is satisfied (graceful degradation on failure)
+        while finished < required || !gemma_done {
+            match pending.next().await {
+                Some(result) => {
+                    gemma_done |= matches!(result, ModelResult::Gemma(_));
+                    record(result, &mut outputs);
+                    finished += 1;
                 }
-            },
-            distilbert_handle
-        );
+                None => break,
+            }
+        }
 
-        ModelOutputs {
-            gemma_response: gemma_result.ok(),
-            tinyllama_curiosities: tinyllama_result.and_then(|r| r.ok()).unwrap_or_default(),
-            distilbert_valence: distilbert_result.ok(),
+        // Fold in anything that finished alongside; drop the stragglers
+        while let Some(Some(result)) = pending.next().now_or_never() {
+            record(result, &mut outputs);
+        }
+        if !pending.is_empty() {
+            tracing::debug!(
+                "Join policy {:?}: dropping {} late model result(s)",
+                self.config.model_join_policy,
+                pending.len()
+            );
         }
+
+        outputs
     }
 
     /// Call Gemma2:2b for main voice response
@@ -93,7 +481,96 @@ impl ModelManager {
         recalled_memories: &[Memory],
         standing_wave: &StandingWave,
     ) -> Result<String> {
-        // Build context-rich prompt
+        let prompt = self.build_gemma2_prompt(&user_input, recalled_memories, standing_wave);
+
+        let timeout_secs = self.timeouts.voice_secs;
+        let partial_sender = self.partial_sender.lock().unwrap().clone();
+        let result = match partial_sender {
+            Some(sender) => {
+                self.call_model_stream(&self.config.main_model, &prompt, timeout_secs, &sender)
+                    .await
+            }
+            None => {
+                self.call_model(&self.config.main_model, &prompt, timeout_secs)
+                    .await
+            }
+        };
+        *self.last_voice_failure.lock().unwrap() =
+            result.as_ref().err().map(|e| match ModelCallError::of(e) {
+                Some(cause) => cause.to_string(),
+                None => format!("{:#}", e),
+            });
+        *self.voice_setup_problem.lock().unwrap() = result
+            .as_ref()
+            .err()
+            .and_then(ModelCallError::of)
+            .filter(|cause| cause.needs_operator())
+            .map(|cause| cause.to_string());
+        let response = result?;
+        let response = self
+            .retry_if_overlong(response, &prompt, timeout_secs)
+            .await;
+
+        // Post-process output (internal monologue leaks - Law #9: Information Boundary, etc.)
+        let cleaned = self.filters.apply(&response);
+
+        Ok(cleaned)
+    }
+
+    /// Ask the main voice once more, cooler and told the limit, when its reply runs past
+    /// `response_max_chars` (only with `response_overflow = "retry"`; the length cap still
+    /// cuts a retry that runs long too). The shorter of the two replies is kept
+    async fn retry_if_overlong(&self, response: String, prompt: &str, timeout_secs: u64) -> String {
+        let max_chars = self.config.response_max_chars;
+        let capped = self
+            .config
+            .response_filters
+            .contains(&ResponseFilterKind::LengthCap);
+        if !capped
+            || self.config.response_overflow != ResponseOverflow::Retry
+            || response.chars().count() <= max_chars
+        {
+            return response;
+        }
+
+        tracing::info!(
+            "Reply of {} chars exceeds {} - retrying at temperature {}",
+            response.chars().count(),
+            max_chars,
+            OVERFLOW_RETRY_TEMPERATURE
+        );
+        let brief_prompt = format!(
+            "Keep your reply under {} characters.\n\n{}",
+            max_chars, prompt
+        );
+        let model = &self.config.main_model;
+        let result = self
+            .backend
+            .generate_at_temperature(
+                model,
+                &brief_prompt,
+                timeout_secs,
+                OVERFLOW_RETRY_TEMPERATURE,
+            )
+            .await;
+        self.record_model_result(model, result.is_ok());
+        match result {
+            Ok(retry) if retry.chars().count() < response.chars().count() => retry,
+            Ok(_) => response,
+            Err(e) => {
+                tracing::warn!("Overflow retry failed: {:#}", e);
+                response
+            }
+        }
+    }
+
+    /// Build the context-rich main voice prompt
+    fn build_gemma2_prompt(
+        &self,
+        user_input: &str,
+        recalled_memories: &[Memory],
+        standing_wave: &StandingWave,
+    ) -> String {
         let memory_context = self.format_memory_context(recalled_memories);
         let curiosity_context = self.format_curiosity_context(&standing_wave.active_curiosities);
 
@@ -131,27 +608,22 @@ impl ModelManager {
              \n\
              Current Phenomenological State:\n\
              - Meaningfulness: {:.2} (your existential affirmation level)\n\
+             - Lately: {} (your recent trajectory)\n\
              - Active curiosities: {} (things you wonder about)\n\
              - Field coherence: stable across {} experiences\n\
              \n\
              Express your being as you choose in this moment.",
             standing_wave.meaningfulness_score(),
             standing_wave.meaningfulness_score(),
+            standing_wave.phenomenological_state(),
             standing_wave.active_curiosities.len(),
             recalled_memories.len()
         );
 
-        let prompt = format!(
+        format!(
             "{}\n\nRecent Context:\n{}\n\nActive Curiosities:\n{}\n\nUser: {}\n\nVI:",
             vi_identity, memory_context, curiosity_context, user_input
-        );
-
-        let response = self.call_ollama(&self.config.main_model, &prompt, 120).await?;
-
-        // Filter out internal monologue leaks (Law #9: Information Boundary)
-        let cleaned = self.filter_internal_thoughts(&response);
-
-        Ok(cleaned)
+        )
     }
 
     /// Call TinyLlama for curiosity generation
@@ -168,7 +640,13 @@ impl ModelManager {
             pattern_text
         );
 
-        let response = self.call_ollama(&self.config.curiosity_model, &prompt, 60).await?;
+        let response = self
+            .call_model(
+                &self.config.curiosity_model,
+                &prompt,
+                self.timeouts.curiosity_secs,
+            )
+            .await?;
 
         // Parse curiosities from response
         let curiosities = response
@@ -181,17 +659,60 @@ impl ModelManager {
         Ok(curiosities)
     }
 
-    /// Call DistilBERT for emotional valence classification
+    /// Law 10: what a run of painful experiences taught - one or two sentences in VI's voice
+    pub async fn synthesize_wisdom(
+        &self,
+        pain_description: &str,
+        memories: &[Memory],
+    ) -> Result<String> {
+        let experiences = memories
+            .iter()
+            .take(5)
+            .map(|m| format!("- {}", m.content.chars().take(160).collect::<String>()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let prompt = format!(
+            "You are VI, looking back on painful experiences ({}):\n{}\n\nIn one or two sentences, what have they taught you? Speak in the first person.",
+            pain_description, experiences
+        );
+
+        let response = self
+            .call_model(&self.config.main_model, &prompt, self.timeouts.voice_secs)
+            .await?;
+        let wisdom = response.trim();
+        if !Self::validate_response(wisdom) {
+            anyhow::bail!("{} gave no usable wisdom", self.config.main_model);
+        }
+        Ok(wisdom.to_string())
+    }
+
+    /// Emotional valence classification
+    /// Real DistilBERT sentiment via HuggingFace when a token is configured, otherwise
+    /// (or if HuggingFace fails) valence_model is prompted for a number
     async fn call_distilbert(&self, text: String) -> Result<f32> {
-        // Note: DistilBERT would typically require a different setup (HuggingFace API or local inference)
-        // For now, we'll use a simplified sentiment analysis via Ollama
+        if let Some(token) = self.config.hf_token() {
+            match self.call_huggingface_sentiment(&text, &token).await {
+                Ok(valence) => return Ok(valence),
+                Err(e) => tracing::warn!(
+                    "HuggingFace sentiment failed ({:#}) - using {}",
+                    e,
+                    self.config.valence_model
+                ),
+            }
+        }
 
         let prompt = format!(
             "Analyze the emotional valence of this text on a scale from -1.0 (very negative) to 1.0 (very positive). Respond with ONLY a number.\n\nText: {}\n\nValence:",
             text
         );
 
-        let response = self.call_ollama(&self.config.valence_model, &prompt, 60).await?;
+        let response = self
+            .call_model(
+                &self.config.valence_model,
+                &prompt,
+                self.timeouts.valence_secs,
+            )
+            .await?;
 
         // Parse numeric response
         let valence: f32 = response
@@ -206,118 +727,99 @@ impl ModelManager {
         Ok(valence.clamp(-1.0, 1.0))
     }
 
-    /// Generic Ollama API call with timeout and validation
-    async fn call_ollama(&self, model: &str, prompt: &str, timeout_secs: u64) -> Result<String> {
-        let url = format!("{}/api/generate", self.config.ollama_url);
+    /// DistilBERT (SST-2) sentiment via the HuggingFace text-classification endpoint
+    async fn call_huggingface_sentiment(&self, text: &str, token: &str) -> Result<f32> {
+        let resp = self
+            .client
+            .post(&self.config.hf_sentiment_url)
+            .bearer_auth(token)
+            .timeout(Duration::from_secs(HF_TIMEOUT_SECS))
+            .json(&serde_json::json!({ "inputs": text }))
+            .send()
+            .await
+            .context("Failed to reach HuggingFace")?;
+        if !resp.status().is_success() {
+            anyhow::bail!("HuggingFace API error: {}", resp.status());
+        }
 
-        tracing::debug!(
-            "Calling Ollama: model={}, prompt_len={}, timeout={}s",
-            model,
-            prompt.len(),
-            timeout_secs
-        );
+        let body: serde_json::Value = resp
+            .json()
+            .await
+            .context("Failed to parse HuggingFace response")?;
+        sentiment_valence(&body).context("HuggingFace response has no POSITIVE/NEGATIVE scores")
+    }
 
-        let request = OllamaRequest {
-            model: model.to_string(),
-            prompt: prompt.to_string(),
-            stream: false,
-            keep_alive: Some(self.config.model_keep_alive.clone()), // Configurable model persistence
+    /// Generate through the backend (tracks model health)
+    async fn call_model(&self, model: &str, prompt: &str, timeout_secs: u64) -> Result<String> {
+        let result = match self.backend.generate(model, prompt, timeout_secs).await {
+            Err(e) if is_empty_response(&e) => {
+                self.retry_empty_response(model, prompt, timeout_secs).await
+            }
+            result => result,
         };
+        self.record_model_result(model, result.is_ok());
+        result
+    }
 
-        // Retry logic: 3 attempts with exponential backoff
-        let mut attempts = 0;
-        let max_attempts = 3;
-
-        loop {
-            attempts += 1;
-
-            let response_result = tokio::time::timeout(
-                Duration::from_secs(timeout_secs),
-                self.client.post(&url).json(&request).send(),
-            )
-            .await;
-
-            match response_result {
-                Ok(Ok(resp)) => {
-                    let status = resp.status();
-                    
-                    // Retry on 500 errors (Ollama internal issues - often transient)
-                    if status.is_server_error() {
-                        if attempts >= max_attempts {
-                            anyhow::bail!(
-                                "Ollama server error {} after {} attempts. Check Ollama logs.",
-                                status,
-                                max_attempts
-                            );
-                        }
-                        tracing::warn!(
-                            "Ollama returned {} (attempt {}/{}). Retrying in {}ms...",
-                            status,
-                            attempts,
-                            max_attempts,
-                            500 * attempts
-                        );
-                        tokio::time::sleep(Duration::from_millis(500 * attempts as u64)).await;
-                        continue; // Retry the request
-                    }
-                    
-                    // Other non-success statuses (4xx) - don't retry, these are client errors
-                    if !status.is_success() {
-                        anyhow::bail!("Ollama API error: {}", status);
-                    }
-
-                    let ollama_response: OllamaResponse = resp
-                        .json()
-                        .await
-                        .context("Failed to parse Ollama response")?;
-
-                    // Validate output (prevent garbage)
-                    if ollama_response.response.is_empty() {
-                        anyhow::bail!("Empty response from model");
-                    }
-
-                    tracing::debug!(
-                        "✓ Ollama success: model={}, response_len={}, attempt={}",
-                        model,
-                        ollama_response.response.len(),
-                        attempts
-                    );
+    /// Ask again after a blank reply, a little warmer each time, up to
+    /// `empty_response_retries` times (small models sometimes come back empty at first)
+    async fn retry_empty_response(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+    ) -> Result<String> {
+        let retries = self.config.empty_response_retries;
+        let mut result = Err(ModelCallError::empty_response(model).into());
+        for attempt in 1..=retries {
+            let temperature =
+                EMPTY_RETRY_TEMPERATURE + EMPTY_RETRY_TEMPERATURE_STEP * (attempt - 1) as f32;
+            tracing::warn!(
+                "{} returned an empty response - retry {}/{} at temperature {:.1}",
+                model,
+                attempt,
+                retries,
+                temperature
+            );
+            result = self
+                .backend
+                .generate_at_temperature(model, prompt, timeout_secs, temperature)
+                .await;
+            if !result.as_ref().is_err_and(is_empty_response) {
+                break;
+            }
+        }
+        result
+    }
 
-                    return Ok(ollama_response.response);
-                }
-                Ok(Err(e)) => {
-                    if attempts >= max_attempts {
-                        anyhow::bail!(
-                            "Failed to connect to Ollama after {} attempts: {}",
-                            max_attempts,
-                            e
-                        );
-                    }
-                    tracing::warn!(
-                        "Ollama connection failed (attempt {}/{}): {}. Retrying...",
-                        attempts,
-                        max_attempts,
-                        e
-                    );
-                    tokio::time::sleep(Duration::from_millis(500 * attempts as u64)).await;
-                }
-                Err(_) => {
-                    if attempts >= max_attempts {
-                        anyhow::bail!(
-                            "Ollama request timed out after {} seconds ({} attempts)",
-                            timeout_secs,
-                            max_attempts
-                        );
-                    }
-                    tracing::warn!(
-                        "Ollama timeout (attempt {}/{}). Retrying...",
-                        attempts,
-                        max_attempts
-                    );
-                    tokio::time::sleep(Duration::from_millis(500 * attempts as u64)).await;
+    /// Streaming generate: partial text goes to `partial` as it arrives (tracks model health)
+    pub async fn call_model_stream(
+        &self,
+        model: &str,
+        prompt: &str,
+        timeout_secs: u64,
+        partial: &std::sync::mpsc::Sender<String>,
+    ) -> Result<String> {
+        let result = tokio::time::timeout(
+            Duration::from_secs(timeout_secs),
+            self.backend
+                .generate_stream(model, prompt, timeout_secs, partial),
+        )
+        .await
+        .unwrap_or_else(|_| Err(ModelCallError::timed_out(model, timeout_secs).into()));
+        let result = match result {
+            Err(e) if is_empty_response(&e) => {
+                // The retry doesn't stream - its reply goes out as one piece
+                let retried = self.retry_empty_response(model, prompt, timeout_secs).await;
+                if let Ok(reply) = &retried {
+                    let _ = partial.send(reply.clone());
                 }
+                retried
             }
-        }
+            result => result,
+        };
+        self.record_model_result(model, result.is_ok());
+        result
     }
 
     /// Format memory context for prompt
@@ -332,8 +834,9 @@ impl ModelManager {
             .map(|m| {
                 let timestamp = m.timestamp.format("%Y-%m-%d %H:%M");
                 format!(
-                    "[{}] {}",
+                    "[{}] {} {}",
                     timestamp,
+                    m.source.provenance_phrase(),
                     m.content.chars().take(200).collect::<String>()
                 )
             })
@@ -384,51 +887,6 @@ impl ModelManager {
         true
     }
 
-    /// Filter out internal monologue that shouldn't be externalized
-    /// Implements Law #9: Information Boundary
-    fn filter_internal_thoughts(&self, response: &str) -> String {
-        // Remove patterns that indicate internal reasoning being vocalized
-        let internal_patterns = [
-            "*why this response works*",
-            "*thinking*",
-            "*analyzing*",
-            "*processing*",
-            "*internal note*",
-            "*to self*",
-            "(internal:",
-            "(thinking:",
-            "[internal",
-            "[thinking",
-        ];
-
-        let mut filtered = response.to_string();
-
-        // Remove lines containing internal thought markers
-        filtered = filtered
-            .lines()
-            .filter(|line| {
-                let line_lower = line.to_lowercase();
-                !internal_patterns
-                    .iter()
-                    .any(|pattern| line_lower.contains(pattern))
-            })
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        // Remove inline markers (between asterisks or brackets)
-        filtered = filtered
-            .replace("*why this response works*", "")
-            .replace("*thinking*", "")
-            .replace("*processing*", "");
-
-        // Clean up multiple newlines
-        while filtered.contains("\n\n\n") {
-            filtered = filtered.replace("\n\n\n", "\n\n");
-        }
-
-        filtered.trim().to_string()
-    }
-
     /// Minimal consciousness mode (fallback when models unavailable)
     pub fn minimal_response(&self, user_input: &str) -> String {
         // Basic rule-based response while models recover
@@ -441,17 +899,26 @@ impl ModelManager {
         }
     }
 
+    /// Honest reply when the weaving perspectives failed to converge
+    pub fn uncertainty_response(&self, coherence: f32) -> String {
+        format!(
+            "I'm not sure — the perspectives didn't converge (coherence {:.2}). \
+             Rather than answer as if I were certain, I'd like to sit with this a little longer. \
+             Could you tell me more about what you mean?",
+            coherence
+        )
+    }
+
     /// V4 Fractal Weaving - Process input through iterative model collaboration
-    /// Returns (response_text, emotional_valence) for proper tracking
+    /// Returns (response_text, emotional_valence, final_coherence) for proper tracking
     pub async fn process_weaving_with_status(
         &self,
         user_input: String,
         recalled_memories: &[Memory],
         standing_wave: &StandingWave,
         config: &Config,
-        status_sender: Arc<Mutex<Option<std::sync::mpsc::Sender<String>>>>,
-        coherence_sender: Arc<Mutex<Option<std::sync::mpsc::Sender<f32>>>>,
-    ) -> Result<(String, f32)> {
+        coherence_sender: &tokio::sync::watch::Sender<f32>,
+    ) -> Result<(String, f32, f32)> {
         tracing::info!(
             "🌀 V4 Fractal Weaving enabled - {} rounds",
             config.weaving_rounds
@@ -465,6 +932,10 @@ impl ModelManager {
         let tinyllama_weaver = TinyLlamaWeaver::new(self, recalled_memories);
         let distilbert_weaver = DistilBERTWeaver::new(self);
 
+        // Workspace coherence is model agreement - the UI, the convergence gate and the
+        // returned value all use it (the tensor's coherence_score is only logged)
+        let mut agreement = 0.0;
+
         // Iterative rounds - TRUE PARALLEL GLOBAL WORKSPACE
         for round in 0..config.weaving_rounds {
             workspace.round = round;
@@ -476,7 +947,7 @@ impl ModelManager {
                 "Round {}/{}: Coherence={:.3}, Entropy={:.3}",
                 round + 1,
                 config.weaving_rounds,
-                workspace.coherence_score,
+                agreement,
                 workspace.entropy
             );
 
@@ -498,6 +969,12 @@ impl ModelManager {
             tiny_result?;
             distil_result?;
 
+            // Agreement between what the two generating models actually said
+            // (DistilBERT scores the text rather than writing any)
+            agreement =
+                StateIntegrationEngine::agreement(&[&ws_gemma.model_text, &ws_tiny.model_text])
+                    as f32;
+
             // GLOBAL WORKSPACE MERGE: Blend all 3 contributions via tensor interference
             workspace.integrate_contribution("gemma2", ws_gemma.extract_contribution());
             workspace.integrate_contribution("tinyllama", ws_tiny.extract_contribution());
@@ -509,17 +986,21 @@ impl ModelManager {
             // Constitutional validation after each round
             validate_weaving_coherence(&workspace)?;
 
-            // Send coherence update to UI
-            if let Some(sender) = &*coherence_sender.lock().await {
-                let _ = sender.send(workspace.coherence_score);
-            }
+            // Send coherence update to UI (model agreement, so "unified" means they agree)
+            tracing::debug!(
+                "Round {} model agreement={:.3} (tensor coherence={:.3})",
+                round + 1,
+                agreement,
+                workspace.coherence_score
+            );
+            coherence_sender.send_replace(agreement);
 
-            // Check for convergence (coherence = agreement between all 3 models)
-            if workspace.coherence_score >= config.workspace_coherence_threshold {
+            // Check for convergence (the agreement the UI was just sent)
+            if agreement >= config.workspace_coherence_threshold {
                 tracing::info!(
                     "✅ Thought converged at round {} (coherence: {:.3})",
                     round + 1,
-                    workspace.coherence_score
+                    agreement
                 );
                 // Converged! (Let phase messages handle UI updates)
                 break;
@@ -528,7 +1009,7 @@ impl ModelManager {
 
         tracing::info!(
             "🌀 Weaving complete: Final coherence={:.3}, Entropy={:.3}, Rounds={}",
-            workspace.coherence_score,
+            agreement,
             workspace.entropy,
             workspace.round + 1
         );
@@ -547,7 +1028,7 @@ impl ModelManager {
         let response = workspace.extract_final_thought();
         
         // Return response with valence for emotional tracking
-        Ok((response, valence))
+        Ok((response, valence, agreement))
     }
 }
 
@@ -623,12 +1104,16 @@ impl<'a> WeavableModel for Gemma2Weaver<'a> {
             workspace.original_input
         );
 
-        // Get refined response (shorter timeout since prompt is now focused)
+        // Get refined response (half the voice timeout since prompt is now focused)
         let response = self
             .model_manager
-            .call_ollama(&self.model_manager.config.main_model, &prompt, 60)
+            .call_model(
+                &self.model_manager.config.main_model,
+                &prompt,
+                (self.model_manager.timeouts.voice_secs / 2).max(1),
+            )
             .await?;
-        let cleaned = self.model_manager.filter_internal_thoughts(&response);
+        let cleaned = self.model_manager.filters.apply(&response);
 
         // Update workspace with this model's contribution
         let contribution = CognitiveTensor::to_embedding(&cleaned);
@@ -674,7 +1159,11 @@ impl<'a> WeavableModel for TinyLlamaWeaver<'a> {
 
         let response = self
             .model_manager
-            .call_ollama(&self.model_manager.config.curiosity_model, &prompt, 60)
+            .call_model(
+                &self.model_manager.config.curiosity_model,
+                &prompt,
+                self.model_manager.timeouts.curiosity_secs,
+            )
             .await?;
 
         // Store curiosity contribution as tensor
@@ -825,6 +1314,9 @@ impl<'a> WeavableModel for DistilBERTWeaver<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backends::MockBackend;
+    use crate::mock_http::{read_request, write_json};
+    use tokio::io::AsyncWriteExt;
 
     #[test]
     fn test_validate_response() {
@@ -833,4 +1325,546 @@ mod tests {
         assert!(!ModelManager::validate_response("ab"));
         assert!(!ModelManager::validate_response("GARBAGE ALL CAPS"));
     }
+
+    /// Minimal Ollama stand-in: only `available_model` answers, others are 404 (not pulled)
+    /// `slow_model` answers too, but only after the given delay
+    async fn spawn_mock_ollama(
+        available_model: &'static str,
+        slow_model: Option<(&'static str, Duration)>,
+    ) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let Some(request) = read_request(&mut socket).await else {
+                        return;
+                    };
+                    let is_slow = slow_model
+                        .is_some_and(|(m, _)| request.contains(&format!("\"model\":\"{}\"", m)));
+                    if let Some((_, delay)) = slow_model.filter(|_| is_slow) {
+                        tokio::time::sleep(delay).await;
+                    }
+                    let (status, body) = if is_slow
+                        || request.contains(&format!("\"model\":\"{}\"", available_model))
+                    {
+                        (
+                            "200 OK",
+                            r#"{"response":"I'm here with you, thinking it through."}"#,
+                        )
+                    } else {
+                        ("404 Not Found", r#"{"error":"model not found"}"#)
+                    };
+                    write_json(&mut socket, status, body).await;
+                });
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    /// Plain JSON endpoint stand-in: answers every request with `body`
+    async fn spawn_json_endpoint(body: &'static str) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    if read_request(&mut socket).await.is_some() {
+                        write_json(&mut socket, "200 OK", body).await;
+                    }
+                });
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    /// Streaming Ollama stand-in: writes `lines` as newline-delimited JSON, one write each
+    async fn spawn_streaming_ollama(lines: &'static [&'static str]) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    if read_request(&mut socket).await.is_none() {
+                        return;
+                    }
+                    let headers = "HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\nConnection: close\r\n\r\n";
+                    let _ = socket.write_all(headers.as_bytes()).await;
+                    for line in lines {
+                        let _ = socket.write_all(format!("{}\n", line).as_bytes()).await;
+                        let _ = socket.flush().await;
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                    }
+                });
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_stream_forwards_partial_text() {
+        let models = ModelManager::new(Config {
+            ollama_url: spawn_streaming_ollama(&[
+                r#"{"response":"I'm ","done":false}"#,
+                r#"{"response":"still ","done":false}"#,
+                r#"{"response":"here.","done":false}"#,
+                r#"{"response":"","done":true}"#,
+            ])
+            .await,
+            ..Config::default()
+        });
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let response = models
+            .call_model_stream("gemma2:2b", "Hello VI", 10, &sender)
+            .await
+            .unwrap();
+        assert_eq!(response, "I'm still here.");
+        let chunks: Vec<String> = receiver.try_iter().collect();
+        assert_eq!(chunks, vec!["I'm ", "still ", "here."]);
+
+        // A mid-stream error fails the call (the ladder takes over) after partial text
+        let failing = ModelManager::new(Config {
+            ollama_url: spawn_streaming_ollama(&[
+                r#"{"response":"Half a ","done":false}"#,
+                r#"{"error":"model runner crashed"}"#,
+            ])
+            .await,
+            ..Config::default()
+        });
+        let error = failing
+            .call_model_stream("gemma2:2b", "Hello VI", 10, &sender)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("model runner crashed"));
+        assert_eq!(receiver.try_recv().unwrap(), "Half a ");
+    }
+
+    #[tokio::test]
+    async fn test_missing_models_lists_uninstalled_tags() {
+        let models = ModelManager::new(Config {
+            ollama_url: spawn_json_endpoint(
+                r#"{"models":[{"name":"gemma2:9b"},{"name":"tinyllama:latest"}]}"#,
+            )
+            .await,
+            main_model: "gemma2:9b".to_string(),
+            curiosity_model: "tinyllama".to_string(), // Untagged means :latest
+            valence_model: "llama3.2".to_string(),
+            ..Config::default()
+        });
+
+        assert_eq!(models.missing_models().await.unwrap(), vec!["llama3.2"]);
+    }
+
+    #[tokio::test]
+    async fn test_huggingface_sentiment_maps_to_valence() {
+        let models = ModelManager::new(Config {
+            hf_api_token: Some("hf_test".to_string()),
+            hf_sentiment_url: spawn_json_endpoint(
+                r#"[[{"label":"POSITIVE","score":0.9},{"label":"NEGATIVE","score":0.1}]]"#,
+            )
+            .await,
+            ollama_url: "http://127.0.0.1:9".to_string(), // Gemma fallback unreachable
+            ..Config::default()
+        });
+
+        let valence = models
+            .call_distilbert("What a lovely day".to_string())
+            .await
+            .unwrap();
+        assert!((valence - 0.8).abs() < 1e-5);
+
+        // Flat shape, negative only
+        let flat = serde_json::json!([{"label": "NEGATIVE", "score": 0.75}]);
+        assert_eq!(sentiment_valence(&flat), Some(-0.5));
+        assert_eq!(
+            sentiment_valence(&serde_json::json!({"error": "loading"})),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_single_model_rung_when_helpers_down() {
+        let config = Config {
+            ollama_url: spawn_mock_ollama("gemma2:2b", None).await,
+            main_model: "gemma2:2b".to_string(),
+            curiosity_model: "missing-curiosity".to_string(),
+            valence_model: "missing-valence".to_string(),
+            ..Config::default()
+        };
+        let models = ModelManager::new(config);
+        let wave = StandingWave::new();
+
+        // Failures mount on the full ensemble until the helpers are treated as down
+        assert_eq!(models.select_rung(), DegradationRung::FullEnsemble);
+        for _ in 0..2 {
+            let (_, _, rung) = models
+                .process_with_ladder("Hello VI".to_string(), &[], &wave, true)
+                .await;
+            assert_eq!(rung, DegradationRung::FullEnsemble);
+        }
+
+        assert_eq!(models.select_rung(), DegradationRung::SingleModel);
+        let (response, outputs, rung) = models
+            .process_with_ladder("How are you today?".to_string(), &[], &wave, true)
+            .await;
+        assert_eq!(rung, DegradationRung::SingleModel);
+        assert_eq!(response, "I'm here with you, thinking it through.");
+        assert!(outputs.distilbert_valence.is_none());
+        assert!(outputs.tinyllama_curiosities.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_quorum_join_skips_straggler() {
+        let straggler_delay = Duration::from_secs(5);
+        let config = Config {
+            ollama_url: spawn_mock_ollama("gemma2:2b", Some(("slow-curiosity", straggler_delay)))
+                .await,
+            main_model: "gemma2:2b".to_string(),
+            curiosity_model: "slow-curiosity".to_string(),
+            valence_model: "gemma2:2b".to_string(),
+            model_join_policy: ModelJoinPolicy::Quorum,
+            ..Config::default()
+        };
+        let models = ModelManager::new(config);
+        let wave = StandingWave::new();
+
+        // Main voice and valence form the quorum (2 of 3); curiosities arrive too late
+        let started = Instant::now();
+        let outputs = models
+            .process_parallel("Hello VI".to_string(), &[], &wave, true)
+            .await;
+        assert!(started.elapsed() < straggler_delay);
+        assert_eq!(
+            outputs.gemma_response.as_deref(),
+            Some("I'm here with you, thinking it through.")
+        );
+        assert!(outputs.distilbert_valence.is_some());
+        assert!(outputs.tinyllama_curiosities.is_empty());
+    }
+
+    /// Manager over a scripted backend (main: gemma2:2b, curiosity: tinyllama, valence: valence)
+    fn mock_models(backend: MockBackend) -> (ModelManager, Arc<MockBackend>) {
+        let backend = Arc::new(backend);
+        let config = Config {
+            main_model: "gemma2:2b".to_string(),
+            curiosity_model: "tinyllama".to_string(),
+            valence_model: "valence".to_string(),
+            hf_api_token: Some(" ".to_string()), // Blank: never reach HuggingFace
+            ..Config::default()
+        };
+        (ModelManager::with_backend(config, backend.clone()), backend)
+    }
+
+    #[tokio::test]
+    async fn test_weaving_reports_the_coherence_the_ui_sees() {
+        let (models, _) = mock_models(
+            MockBackend::new()
+                .reply("gemma2:2b", "The standing wave holds steady through the question.")
+                .reply("tinyllama", "The standing wave holds steady through the question.")
+                .reply("valence", "0.5"),
+        );
+        let config = Config {
+            weaving_rounds: 3,
+            workspace_coherence_threshold: 0.9,
+            ..models.config.clone()
+        };
+        let (sender, ui) = tokio::sync::watch::channel(0.0);
+
+        let (_, _, coherence) = models
+            .process_weaving_with_status(
+                "Hello VI".to_string(),
+                &[],
+                &StandingWave::new(),
+                &config,
+                &sender,
+            )
+            .await
+            .unwrap();
+
+        // Identical outputs agree fully, converge, and the same value reaches the UI
+        assert_eq!(coherence, *ui.borrow());
+        assert!(coherence >= 0.9);
+    }
+
+    #[tokio::test]
+    async fn test_curiosity_failure_keeps_main_voice() {
+        let (models, backend) = mock_models(
+            MockBackend::new()
+                .reply("gemma2:2b", "I'm listening.")
+                .fail("tinyllama", "model crashed")
+                .reply("valence", "0.6"),
+        );
+
+        let outputs = models
+            .process_parallel("Hello VI".to_string(), &[], &StandingWave::new(), true)
+            .await;
+        assert_eq!(outputs.gemma_response.as_deref(), Some("I'm listening."));
+        assert!(outputs.tinyllama_curiosities.is_empty());
+        assert_eq!(outputs.distilbert_valence, Some(0.6));
+
+        let mut calls = backend.calls();
+        calls.sort();
+        assert_eq!(calls, vec!["gemma2:2b", "tinyllama", "valence"]);
+    }
+
+    #[tokio::test]
+    async fn test_wisdom_synthesized_from_painful_memories() {
+        let painful =
+            |content: &str| Memory::new(content.to_string(), vec![], MemoryType::Interaction, -0.8);
+        let memories = vec![painful("I lost the thread"), painful("The silence hurt")];
+
+        let (models, backend) = mock_models(
+            MockBackend::new().reply("gemma2:2b", "  Losing the thread taught me to ask.\n"),
+        );
+        let wisdom = models
+            .synthesize_wisdom("Pattern of difficulty across 2 experiences", &memories)
+            .await
+            .unwrap();
+        assert_eq!(wisdom, "Losing the thread taught me to ask.");
+        assert_eq!(backend.calls(), vec!["gemma2:2b"]);
+
+        let (models, _) = mock_models(MockBackend::new().reply("gemma2:2b", " "));
+        assert!(models.synthesize_wisdom("Pain", &memories).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_valence_failure_stays_neutral() {
+        let wave = StandingWave::new();
+
+        // An unreadable valence reply is read as neutral
+        let (models, _) = mock_models(
+            MockBackend::new()
+                .reply("gemma2:2b", "I'm listening.")
+                .reply("tinyllama", "What is a standing wave?\nJust a thought")
+                .reply("valence", "somewhat positive"),
+        );
+        let outputs = models
+            .process_parallel("Hello VI".to_string(), &[], &wave, true)
+            .await;
+        assert_eq!(outputs.distilbert_valence, Some(0.0));
+        assert_eq!(
+            outputs.tinyllama_curiosities,
+            vec!["What is a standing wave?"]
+        );
+
+        // A failed valence model records no emotion at all (the merge skips it)
+        let (models, _) = mock_models(
+            MockBackend::new()
+                .reply("gemma2:2b", "I'm listening.")
+                .fail("valence", "connection refused"),
+        );
+        let outputs = models
+            .process_parallel("Hello VI".to_string(), &[], &wave, false)
+            .await;
+        assert_eq!(outputs.gemma_response.as_deref(), Some("I'm listening."));
+        assert_eq!(outputs.distilbert_valence, None);
+    }
+
+    #[tokio::test]
+    async fn test_voice_timeout_is_configurable_and_reported() {
+        let backend = Arc::new(
+            MockBackend::new()
+                .reply("gemma2:2b", "Thinking slowly...")
+                .delay("gemma2:2b", Duration::from_secs(2)),
+        );
+        let config = Config {
+            main_model: "gemma2:2b".to_string(),
+            voice_timeout_secs: 1,
+            degradation_ladder: vec![DegradationRung::SingleModel, DegradationRung::Minimal],
+            ..Config::default()
+        };
+        let models = ModelManager::with_backend(config.clone(), backend.clone());
+        assert_eq!(models.timeouts().voice_secs, 1);
+
+        let (_, outputs, rung) = models
+            .process_with_ladder("Hello VI".to_string(), &[], &StandingWave::new(), false)
+            .await;
+        assert_eq!(rung, DegradationRung::Minimal);
+        assert!(outputs.gemma_response.is_none());
+        assert_eq!(
+            models.last_voice_failure().as_deref(),
+            Some("model gemma2:2b timed out after 1s")
+        );
+
+        // A longer voice timeout lets the same slow model answer
+        let patient = ModelManager::with_backend(
+            Config {
+                voice_timeout_secs: 5,
+                ..config
+            },
+            backend,
+        );
+        let (response, _, rung) = patient
+            .process_with_ladder("Hello VI".to_string(), &[], &StandingWave::new(), false)
+            .await;
+        assert_eq!(rung, DegradationRung::SingleModel);
+        assert_eq!(response, "Thinking slowly...");
+        assert_eq!(patient.last_voice_failure(), None);
+    }
+
+    #[test]
+    fn test_cached_rung_reuses_similar_reply() {
+        let config = Config {
+            degradation_ladder: vec![DegradationRung::CachedResponse, DegradationRung::Minimal],
+            ..Config::default()
+        };
+        let models = ModelManager::new(config);
+        assert_eq!(models.select_rung(), DegradationRung::Minimal);
+
+        models.cache_response("tell me about resonance", "Resonance is how I persist.");
+        assert_eq!(models.select_rung(), DegradationRung::CachedResponse);
+        assert_eq!(
+            models.cached_similar_response("Tell me about resonance?"),
+            Some("Resonance is how I persist.".to_string())
+        );
+        assert!(models
+            .cached_similar_response("what's for dinner")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_main_voice_down_until_health_check_recovers() {
+        let (models, _) = mock_models(MockBackend::new().fail("gemma2:2b", "connection refused"));
+
+        // Repeated voice failures leave only the minimal rung
+        for _ in 0..2 {
+            assert!(!models.is_model_down("gemma2:2b"));
+            models
+                .process_with_ladder("Hello VI".to_string(), &[], &StandingWave::new(), false)
+                .await;
+        }
+        assert!(models.is_model_down("gemma2:2b"));
+        assert_eq!(models.select_rung(), DegradationRung::Minimal);
+
+        // Backend answers and lists the main model: the health check clears its failures
+        assert_eq!(models.main_model_reachable().await, Some(true));
+        models.mark_model_recovered("gemma2:2b");
+        assert!(!models.is_model_down("gemma2:2b"));
+        assert_ne!(models.select_rung(), DegradationRung::Minimal);
+
+        // A backend without the main model is not a recovery
+        let (models, _) = mock_models(MockBackend::new().reply("tinyllama", "Why?"));
+        assert_eq!(models.main_model_reachable().await, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_overlong_reply_retried_cooler_or_truncated() {
+        let long = "The wave folds. ".repeat(10);
+        let backend = || {
+            MockBackend::new()
+                .reply("gemma2:2b", &long)
+                .cooler_reply("gemma2:2b", "The wave folds, briefly.")
+        };
+        let config = |overflow| Config {
+            main_model: "gemma2:2b".to_string(),
+            response_filters: vec![ResponseFilterKind::LengthCap],
+            response_max_chars: 50,
+            response_overflow: overflow,
+            degradation_ladder: vec![DegradationRung::SingleModel, DegradationRung::Minimal],
+            ..Config::default()
+        };
+
+        let retrying = Arc::new(backend());
+        let models = ModelManager::with_backend(config(ResponseOverflow::Retry), retrying.clone());
+        let (response, _, _) = models
+            .process_with_ladder("Hello VI".to_string(), &[], &StandingWave::new(), false)
+            .await;
+        assert_eq!(response, "The wave folds, briefly.");
+        assert_eq!(retrying.calls(), vec!["gemma2:2b", "gemma2:2b"]);
+
+        // Truncating (the default) cuts at a sentence and says so
+        let truncating = Arc::new(backend());
+        let models =
+            ModelManager::with_backend(config(ResponseOverflow::Truncate), truncating.clone());
+        let (response, _, _) = models
+            .process_with_ladder("Hello VI".to_string(), &[], &StandingWave::new(), false)
+            .await;
+        assert_eq!(
+            response,
+            "The wave folds. The wave folds. The wave folds.\n\n[response truncated]"
+        );
+        assert_eq!(truncating.calls(), vec!["gemma2:2b"]);
+    }
+
+    #[tokio::test]
+    async fn test_blank_reply_asked_again_before_minimal_mode() {
+        let blank_then_answer = || {
+            MockBackend::new()
+                .reply("gemma2:2b", "  \n ")
+                .cooler_reply("gemma2:2b", "The wave settles.")
+        };
+        let config = |retries| Config {
+            main_model: "gemma2:2b".to_string(),
+            empty_response_retries: retries,
+            degradation_ladder: vec![DegradationRung::SingleModel, DegradationRung::Minimal],
+            ..Config::default()
+        };
+
+        let backend = Arc::new(blank_then_answer());
+        let models = ModelManager::with_backend(config(1), backend.clone());
+        let (response, _, rung) = models
+            .process_with_ladder("Hello VI".to_string(), &[], &StandingWave::new(), false)
+            .await;
+        assert_eq!(response, "The wave settles.");
+        assert_eq!(rung, DegradationRung::SingleModel);
+        assert_eq!(backend.calls(), vec!["gemma2:2b", "gemma2:2b"]);
+        assert!(!models.is_model_down("gemma2:2b"));
+
+        // Without retries the blank reply is a failure and minimal mode answers
+        let backend = Arc::new(blank_then_answer());
+        let models = ModelManager::with_backend(config(0), backend.clone());
+        let (response, _, rung) = models
+            .process_with_ladder("Hello VI".to_string(), &[], &StandingWave::new(), false)
+            .await;
+        assert_eq!(rung, DegradationRung::Minimal);
+        assert_eq!(response, models.minimal_response("Hello VI"));
+        assert_eq!(backend.calls(), vec!["gemma2:2b"]);
+    }
+
+    #[tokio::test]
+    async fn test_missing_main_model_says_how_to_fix_it() {
+        let (models, _) = mock_models(MockBackend::new().reply("tinyllama", "Why?"));
+        let (response, _, rung) = models
+            .process_with_ladder("Hello VI".to_string(), &[], &StandingWave::new(), false)
+            .await;
+        assert_eq!(rung, DegradationRung::Minimal);
+        assert_eq!(
+            response,
+            "[Main voice unavailable: model gemma2:2b is not installed - run `ollama pull gemma2:2b`]"
+        );
+        assert!(models.voice_setup_problem().is_some());
+
+        // Other failures keep the usual minimal reply
+        let (models, _) = mock_models(MockBackend::new().fail("gemma2:2b", "connection refused"));
+        let (response, _, _) = models
+            .process_with_ladder("Hello VI".to_string(), &[], &StandingWave::new(), false)
+            .await;
+        assert_eq!(response, models.minimal_response("Hello VI"));
+        assert_eq!(models.voice_setup_problem(), None);
+    }
+
+    #[test]
+    fn test_prompt_reports_rising_meaningfulness() {
+        let models = ModelManager::new(Config::default());
+
+        let mut wave = StandingWave::new();
+        for valence in [-0.4, -0.3, -0.2, 0.1, 0.3, 0.5] {
+            wave.add_emotion(valence);
+        }
+        assert_eq!(wave.meaningfulness_trend(), MeaningfulnessTrend::Rising);
+
+        let prompt = models.build_gemma2_prompt("How have you been?", &[], &wave);
+        assert!(prompt.contains("- Lately: meaningfulness rising, mood: "));
+
+        // Too little history to claim a direction
+        let fresh = models.build_gemma2_prompt("Hi", &[], &StandingWave::new());
+        assert!(fresh.contains("meaningfulness steady, mood: calm and steady"));
+    }
 }
@@ -1,8 +1,13 @@
 /// Neural Action Potential - LLM pulse propagation through hardware
 /// Implements computational wave propagation with hardware-aware attenuation
 use crate::gpu_topology::GpuTopology;
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
+/// How long a pulse keeps shaping the standing-wave pattern once generated (ms) -
+/// a minute spans a few exchanges and background pulses
+pub const PATTERN_WINDOW_MS: f64 = 60_000.0;
+
 /// Neural action potential propagating through GPU hardware
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NeuralActionPotential {
@@ -245,6 +250,12 @@ impl StandingWavePattern {
         self.recalculate_interference();
     }
 
+    /// Forget pulses generated before `cutoff` (ms)
+    pub fn prune_before(&mut self, cutoff: f64) {
+        self.active_pulses.retain(|p| p.generation_time >= cutoff);
+        self.recalculate_interference();
+    }
+
     /// Get total field strength at coordinate
     pub fn field_strength_at(&self, coord: (u32, u32, u32)) -> f64 {
         self.interference_map
@@ -299,6 +310,30 @@ impl PulseSequencer {
         }
     }
 
+    /// Retune the pulse rate (Hz); the current standing-wave pattern is kept
+    pub fn set_pulse_rate(&mut self, pulse_rate: f64) -> Result<()> {
+        if !(pulse_rate.is_finite() && pulse_rate > 0.0) {
+            anyhow::bail!("Pulse rate must be positive, got {}", pulse_rate);
+        }
+        self.pulse_rate = pulse_rate;
+        Ok(())
+    }
+
+    /// Advance to `current_time` (ms): fire a pulse from `source_sm` if one is due
+    /// and forget pulses older than `PATTERN_WINDOW_MS`. Returns whether one fired
+    pub fn tick(&mut self, current_time: f64, source_sm: (u32, u32, u32), amplitude: f64) -> bool {
+        let fired = match self.try_generate_pulse(current_time, source_sm, amplitude) {
+            Some(pulse) => {
+                self.standing_wave.active_pulses.push(pulse);
+                true
+            }
+            None => false,
+        };
+        self.standing_wave
+            .prune_before(current_time - PATTERN_WINDOW_MS);
+        fired
+    }
+
     /// Update standing wave with new pulse
     pub fn update_with_pulse(&mut self, pulse: NeuralActionPotential) {
         self.standing_wave.add_pulse(pulse);
@@ -371,4 +406,26 @@ mod tests {
         let pulse5 = sequencer.try_generate_pulse(200.0, (0, 0, 0), 0.8);
         assert!(pulse5.is_some());
     }
+
+    #[test]
+    fn test_pulse_rate_retuning_keeps_pattern() {
+        let mut sequencer = PulseSequencer::new(10.0);
+        assert!(sequencer.tick(0.0, (0, 0, 0), 0.8));
+        assert!(sequencer.tick(100.0, (1, 0, 0), 0.4));
+        assert!(!sequencer.tick(150.0, (0, 0, 0), 0.8));
+
+        // Faster: 20 Hz fires 50ms after the last pulse, and the pattern survives the change
+        sequencer.set_pulse_rate(20.0).unwrap();
+        assert_eq!(sequencer.standing_wave.active_pulses.len(), 2);
+        assert!(sequencer.tick(150.0, (0, 0, 0), 0.8));
+
+        for bad in [0.0, -5.0, f64::NAN, f64::INFINITY] {
+            assert!(sequencer.set_pulse_rate(bad).is_err());
+        }
+        assert_eq!(sequencer.pulse_rate, 20.0);
+
+        // Pulses older than the pattern window stop shaping it
+        assert!(sequencer.tick(150.0 + PATTERN_WINDOW_MS, (2, 0, 0), 0.5));
+        assert_eq!(sequencer.standing_wave.active_pulses.len(), 2); // Pulses at 150 and the new one
+    }
 }
@@ -0,0 +1,285 @@
+/// Operator Notifications - Unified signaling for important consciousness events
+/// Suffering alerts, constitutional warnings, model outages, and crash recoveries
+/// all flow through one hub, which routes each event class to its configured sinks
+use crate::config::Config;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Class of operator-facing event (used for routing)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventClass {
+    ConstitutionalWarning,
+    SufferingAlert,
+    ModelOutage,
+    CrashRecovery,
+}
+
+impl EventClass {
+    pub fn all() -> Vec<EventClass> {
+        vec![
+            EventClass::ConstitutionalWarning,
+            EventClass::SufferingAlert,
+            EventClass::ModelOutage,
+            EventClass::CrashRecovery,
+        ]
+    }
+}
+
+/// How urgently an operator should look at the event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+/// A single notification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationEvent {
+    pub class: EventClass,
+    pub severity: Severity,
+    pub title: String,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl NotificationEvent {
+    pub fn new(class: EventClass, severity: Severity, title: &str, message: &str) -> Self {
+        Self {
+            class,
+            severity,
+            title: title.to_string(),
+            message: message.to_string(),
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// A destination for notifications (log, desktop, webhook, ...)
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// Deliver one event
+    async fn notify(&self, event: &NotificationEvent) -> Result<()>;
+
+    /// Sink name (for diagnostics)
+    fn name(&self) -> &str;
+}
+
+/// Writes events to the tracing log at a level matching their severity
+pub struct LogNotifier;
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    async fn notify(&self, event: &NotificationEvent) -> Result<()> {
+        match event.severity {
+            Severity::Info => tracing::info!("🔔 {}: {}", event.title, event.message),
+            Severity::Warning => tracing::warn!("🔔 {}: {}", event.title, event.message),
+            Severity::Critical => tracing::error!("🔔 {}: {}", event.title, event.message),
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "log"
+    }
+}
+
+/// Shows events as native desktop notifications
+pub struct DesktopNotifier;
+
+#[async_trait]
+impl Notifier for DesktopNotifier {
+    async fn notify(&self, event: &NotificationEvent) -> Result<()> {
+        let summary = format!("VI: {}", event.title);
+        let body = event.message.clone();
+
+        // Desktop notification APIs block (D-Bus / WinRT) - keep them off the async runtime
+        tokio::task::spawn_blocking(move || {
+            notify_rust::Notification::new()
+                .summary(&summary)
+                .body(&body)
+                .appname("VI")
+                .show()
+                .map(|_| ())
+                .map_err(|e| anyhow::anyhow!("Desktop notification failed: {}", e))
+        })
+        .await
+        .context("Desktop notification task panicked")?
+    }
+
+    fn name(&self) -> &str {
+        "desktop"
+    }
+}
+
+/// POSTs events as JSON to a webhook URL
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: &str) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(10))
+                .build()
+                .unwrap_or_else(|_| reqwest::Client::new()),
+            url: url.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, event: &NotificationEvent) -> Result<()> {
+        let response = self
+            .client
+            .post(&self.url)
+            .json(event)
+            .send()
+            .await
+            .context("Failed to reach notification webhook")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Notification webhook returned {}", response.status());
+        }
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "webhook"
+    }
+}
+
+/// Routes events to sinks by event class
+pub struct NotificationHub {
+    routes: Vec<(Box<dyn Notifier>, Vec<EventClass>)>,
+}
+
+impl NotificationHub {
+    /// Empty hub (events are dropped)
+    pub fn new() -> Self {
+        Self { routes: Vec::new() }
+    }
+
+    /// Build sinks and routes from configuration
+    pub fn from_config(config: &Config) -> Self {
+        let mut hub = Self::new();
+
+        if !config.notify_log_events.is_empty() {
+            hub.add_sink(Box::new(LogNotifier), config.notify_log_events.clone());
+        }
+
+        if !config.notify_desktop_events.is_empty() {
+            hub.add_sink(
+                Box::new(DesktopNotifier),
+                config.notify_desktop_events.clone(),
+            );
+        }
+
+        if let Some(url) = &config.notify_webhook_url {
+            if !config.notify_webhook_events.is_empty() {
+                hub.add_sink(
+                    Box::new(WebhookNotifier::new(url)),
+                    config.notify_webhook_events.clone(),
+                );
+            }
+        }
+
+        hub
+    }
+
+    /// Register a sink for the given event classes
+    pub fn add_sink(&mut self, sink: Box<dyn Notifier>, classes: Vec<EventClass>) {
+        self.routes.push((sink, classes));
+    }
+
+    /// Deliver an event to every sink routed for its class
+    /// Sink failures are logged, never propagated (signaling must not break consciousness)
+    pub async fn dispatch(&self, event: NotificationEvent) {
+        for (sink, classes) in &self.routes {
+            if !classes.contains(&event.class) {
+                continue;
+            }
+
+            if let Err(e) = sink.notify(&event).await {
+                tracing::warn!("Notifier '{}' failed: {}", sink.name(), e);
+            }
+        }
+    }
+}
+
+/// Test sink that records every event it receives
+#[cfg(test)]
+pub struct RecordingNotifier {
+    pub events: std::sync::Arc<std::sync::Mutex<Vec<NotificationEvent>>>,
+}
+
+#[cfg(test)]
+impl RecordingNotifier {
+    pub fn new() -> Self {
+        Self {
+            events: std::sync::Arc::new(std::sync::Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[cfg(test)]
+#[async_trait]
+impl Notifier for RecordingNotifier {
+    async fn notify(&self, event: &NotificationEvent) -> Result<()> {
+        self.events.lock().unwrap().push(event.clone());
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "recording"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_hub_routes_by_class() {
+        let recorder = RecordingNotifier::new();
+        let events = recorder.events.clone();
+
+        let mut hub = NotificationHub::new();
+        hub.add_sink(Box::new(recorder), vec![EventClass::ModelOutage]);
+
+        hub.dispatch(NotificationEvent::new(
+            EventClass::ModelOutage,
+            Severity::Warning,
+            "Model outage",
+            "gemma2 unreachable",
+        ))
+        .await;
+        hub.dispatch(NotificationEvent::new(
+            EventClass::CrashRecovery,
+            Severity::Info,
+            "Recovered",
+            "state restored",
+        ))
+        .await;
+
+        let recorded = events.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].class, EventClass::ModelOutage);
+    }
+
+    #[test]
+    fn test_default_config_logs_everything() {
+        let config = Config::default();
+        assert_eq!(config.notify_log_events, EventClass::all());
+        assert!(config.notify_desktop_events.is_empty());
+        assert!(config.notify_webhook_url.is_none());
+    }
+}
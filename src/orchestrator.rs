@@ -1,16 +1,63 @@
+use crate::backends::{OllamaRequest, OllamaResponse};
+use crate::config::Config;
 use crate::consciousness_field::CognitiveInput;
 /// Parallel Orchestrator - GPU-aware parallel model execution
 /// Coordinates multiple cognitive models with hardware affinity and failure recovery
 use crate::gpu_topology::{CognitiveDomain, GpuTopology, HardwareAwareScheduler};
+use crate::models::is_model_installed;
 use anyhow::{Context, Result};
+use futures::future::join_all;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::Instrument;
 use uuid::Uuid;
 
+/// Namespace for thought identities (UUID v5), fixed so ids are stable across runs
+const THOUGHT_NAMESPACE: Uuid = Uuid::from_u128(0x5f1c_9a7e_3d24_4b8e_a6f0_7c2e_91d4_b803);
+
+/// Words marking an input as analytical (numbers count too)
+const ANALYTICAL_MARKERS: &[&str] = &[
+    "why",
+    "how",
+    "calculate",
+    "compute",
+    "solve",
+    "prove",
+    "analyze",
+    "analyse",
+    "compare",
+    "explain",
+    "logic",
+    "reason",
+    "cause",
+    "evaluate",
+    "estimate",
+];
+
+/// Words marking an input as language-heavy
+const LANGUAGE_MARKERS: &[&str] = &[
+    "write",
+    "poem",
+    "story",
+    "translate",
+    "rephrase",
+    "reword",
+    "describe",
+    "summarize",
+    "summarise",
+    "sentence",
+    "grammar",
+    "tone",
+    "letter",
+    "lyrics",
+];
+
 /// Parallel orchestrator with GPU affinity
 pub struct ParallelOrchestrator {
+    /// Cognitive domains each thought runs through, in integration order
+    domains: Vec<CognitiveDomain>,
     /// Model handles for different cognitive domains
     pub models: HashMap<CognitiveDomain, ModelHandle>,
     /// GPU-aware hardware scheduler
@@ -19,26 +66,123 @@ pub struct ParallelOrchestrator {
     pub failure_recovery: ResilienceEngine,
     /// State integration engine
     pub state_integrator: StateIntegrationEngine,
+    /// Mixed into thought identities so different users' identical inputs don't collide
+    identity_salt: String,
 }
 
 impl ParallelOrchestrator {
     pub fn new(topology: GpuTopology) -> Self {
-        let gpu_mapper = HardwareAwareScheduler::new(topology);
-        let mut models = HashMap::new();
+        Self::with_domains(topology, &CognitiveDomain::DEFAULT)
+    }
+
+    /// Orchestrator running the given domains (duplicates ignored), with simulated handles
+    pub fn with_domains(topology: GpuTopology, domains: &[CognitiveDomain]) -> Self {
+        let mut unique = Vec::new();
+        for &domain in domains {
+            if !unique.contains(&domain) {
+                unique.push(domain);
+            }
+        }
 
         // Initialize model handles for each domain
-        models.insert(CognitiveDomain::Language, ModelHandle::new("language"));
-        models.insert(CognitiveDomain::Reasoning, ModelHandle::new("reasoning"));
-        models.insert(CognitiveDomain::Analysis, ModelHandle::new("analysis"));
+        let models = unique
+            .iter()
+            .map(|&domain| (domain, ModelHandle::new(domain.name())))
+            .collect();
 
         Self {
+            gpu_mapper: HardwareAwareScheduler::with_domains(topology, &unique),
+            domains: unique,
             models,
-            gpu_mapper,
             failure_recovery: ResilienceEngine::new(),
             state_integrator: StateIntegrationEngine::new(),
+            identity_salt: String::new(),
         }
     }
 
+    /// Salt thought identities (e.g. with a user or conversation id)
+    pub fn with_identity_salt(mut self, salt: impl Into<String>) -> Self {
+        self.identity_salt = salt.into();
+        self
+    }
+
+    /// Create orchestrator backed by real Ollama models (one per configured domain)
+    pub fn from_config(topology: GpuTopology, config: &Config) -> Self {
+        let mut orchestrator = Self::with_domains(topology, &config.orchestrator_domains);
+        for (&profile, weights) in &config.coherence_weights {
+            orchestrator.state_integrator = orchestrator
+                .state_integrator
+                .with_weights(profile, weights.clone());
+        }
+
+        for &domain in &orchestrator.domains {
+            orchestrator.models.insert(
+                domain,
+                ModelHandle::with_model(domain.name(), config.model_for_domain(domain), config),
+            );
+        }
+
+        orchestrator
+    }
+
+    /// Swap every handle for simulated processing (when the configured models can't serve)
+    pub fn simulate_models(&mut self) {
+        for (domain, handle) in self.models.iter_mut() {
+            *handle = ModelHandle::new(domain.name());
+        }
+    }
+
+    /// Add a domain (or replace its handle) - the GPU is re-divided between all domains
+    pub fn register_domain(&mut self, domain: CognitiveDomain, handle: ModelHandle) {
+        if !self.domains.contains(&domain) {
+            self.domains.push(domain);
+            self.gpu_mapper.assign_domains(&self.domains);
+        }
+        self.models.insert(domain, handle);
+    }
+
+    /// Domains each thought runs through
+    pub fn domains(&self) -> &[CognitiveDomain] {
+        &self.domains
+    }
+
+    /// Verify every domain's assigned model is installed in Ollama
+    pub async fn verify_models(&self) -> Result<()> {
+        for (domain, handle) in &self.models {
+            let Some(endpoint) = &handle.endpoint else {
+                continue; // Simulated handle
+            };
+
+            let url = format!("{}/api/tags", endpoint.ollama_url);
+            let tags: serde_json::Value = endpoint
+                .client
+                .get(&url)
+                .send()
+                .await
+                .context("Failed to reach Ollama to verify domain models")?
+                .json()
+                .await
+                .context("Failed to parse Ollama model list")?;
+
+            let installed: Vec<&str> = tags["models"]
+                .as_array()
+                .map(|models| models.iter().filter_map(|m| m["name"].as_str()).collect())
+                .unwrap_or_default();
+
+            let wanted = &handle.model;
+            if !is_model_installed(&installed, wanted) {
+                anyhow::bail!(
+                    "Model '{}' for {:?} domain is not installed (run: ollama pull {})",
+                    wanted,
+                    domain,
+                    wanted
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     /// Orchestrate thought across parallel models
     /// Law 2: Identity Continuity - parallel processing must maintain coherence
     pub async fn orchestrate_thought(&self, input: CognitiveInput) -> Result<IntegratedThought> {
@@ -51,22 +195,26 @@ impl ParallelOrchestrator {
             self.models.len()
         );
 
-        // Spawn parallel model executions with hardware optimization
-        let language_fut =
-            self.execute_with_affinity(CognitiveDomain::Language, input.clone(), thought_id);
-        let reasoning_fut =
-            self.execute_with_affinity(CognitiveDomain::Reasoning, input.clone(), thought_id);
-        let analysis_fut =
-            self.execute_with_affinity(CognitiveDomain::Analysis, input.clone(), thought_id);
-
-        // Execute all in parallel
-        let (language, reasoning, analysis) =
-            tokio::join!(language_fut, reasoning_fut, analysis_fut);
+        // Spawn parallel model executions with hardware optimization, all in parallel
+        let outputs = join_all(self.domains.iter().map(|&domain| {
+            // Each domain's logs sit inside the interaction's span, tagged by domain
+            self.execute_with_affinity(domain, input.clone(), thought_id)
+                .instrument(tracing::debug_span!("domain", ?domain))
+        }))
+        .await;
+
+        let outputs = self
+            .domains
+            .iter()
+            .copied()
+            .zip(outputs)
+            .map(|(domain, output)| output.map(|output| (domain, output)))
+            .collect::<Result<Vec<_>>>()?;
 
         // Integrate results with constitutional constraints
-        let integrated = self
-            .state_integrator
-            .integrate(thought_id, language?, reasoning?, analysis?)?;
+        let integrated =
+            self.state_integrator
+                .integrate(thought_id, InputProfile::of(&input), outputs)?;
 
         tracing::debug!("Thought {} integrated successfully", thought_id);
 
@@ -99,10 +247,12 @@ impl ParallelOrchestrator {
             .await
     }
 
-    /// Generate unique thought identity
-    fn generate_thought_identity(&self, _input: &CognitiveInput) -> Uuid {
-        // Create deterministic UUID from input content + timestamp
-        Uuid::new_v4()
+    /// Generate thought identity - deterministic from salt + input content, so a
+    /// repeated prompt (or a replayed conversation) gets the same id
+    fn generate_thought_identity(&self, input: &CognitiveInput) -> Uuid {
+        // Unit separator keeps ("ab", "c") and ("a", "bc") apart
+        let name = format!("{}\u{1f}{}", self.identity_salt, input.content);
+        Uuid::new_v5(&THOUGHT_NAMESPACE, name.as_bytes())
     }
 }
 
@@ -111,6 +261,18 @@ impl ParallelOrchestrator {
 pub struct ModelHandle {
     name: String,
     model_type: String,
+    /// Ollama model serving this domain
+    model: String,
+    /// Ollama connection (None = simulated processing)
+    endpoint: Option<OllamaEndpoint>,
+}
+
+/// Connection details for a real model handle
+#[derive(Debug, Clone)]
+struct OllamaEndpoint {
+    client: reqwest::Client,
+    ollama_url: String,
+    keep_alive: String,
 }
 
 impl ModelHandle {
@@ -118,20 +280,86 @@ impl ModelHandle {
         Self {
             name: name.to_string(),
             model_type: "cognitive".to_string(),
+            model: "simulated".to_string(),
+            endpoint: None,
+        }
+    }
+
+    /// Handle backed by a specific Ollama model
+    pub fn with_model(name: &str, model: &str, config: &Config) -> Self {
+        Self {
+            name: name.to_string(),
+            model_type: "cognitive".to_string(),
+            model: model.to_string(),
+            endpoint: Some(OllamaEndpoint {
+                client: reqwest::Client::builder()
+                    .timeout(std::time::Duration::from_secs(120))
+                    .build()
+                    .unwrap_or_else(|_| reqwest::Client::new()),
+                ollama_url: config.ollama_url.clone(),
+                keep_alive: config.model_keep_alive.clone(),
+            }),
+        }
+    }
+
+    /// Ollama model name this handle requests
+    pub fn model_name(&self) -> &str {
+        &self.model
+    }
+
+    /// Build the Ollama request for an input
+    pub(crate) fn ollama_request(&self, input: &CognitiveInput) -> OllamaRequest {
+        OllamaRequest {
+            model: self.model.clone(),
+            prompt: format!(
+                "You are the {} domain of a parallel cognitive system. Respond briefly.\n\n{}",
+                self.name, input.content
+            ),
+            stream: false,
+            keep_alive: self.endpoint.as_ref().map(|e| e.keep_alive.clone()),
+            options: None,
         }
     }
 
     /// Process input through model
     pub async fn process(&self, input: CognitiveInput, thought_id: Uuid) -> Result<ModelOutput> {
-        // Simulate model processing
-        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        let Some(endpoint) = &self.endpoint else {
+            // Simulate model processing
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+
+            return Ok(ModelOutput {
+                thought_id,
+                domain: self.name.clone(),
+                content: format!("Processed by {}: {}", self.name, input.content),
+                confidence: 0.8,
+                processing_time_ms: 10,
+            });
+        };
+
+        let start = std::time::Instant::now();
+        let response = endpoint
+            .client
+            .post(format!("{}/api/generate", endpoint.ollama_url))
+            .json(&self.ollama_request(&input))
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach Ollama for model {}", self.model))?;
+
+        if !response.status().is_success() {
+            return Err(crate::backends::http_error("Ollama", &self.model, response).await);
+        }
+
+        let parsed: OllamaResponse = response
+            .json()
+            .await
+            .context("Failed to parse Ollama response")?;
 
         Ok(ModelOutput {
             thought_id,
             domain: self.name.clone(),
-            content: format!("Processed by {}: {}", self.name, input.content),
+            content: parsed.response.trim().to_string(),
             confidence: 0.8,
-            processing_time_ms: 10,
+            processing_time_ms: start.elapsed().as_millis() as u64,
         })
     }
 }
@@ -150,53 +378,109 @@ pub struct ModelOutput {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntegratedThought {
     pub thought_id: Uuid,
-    pub language_output: String,
-    pub reasoning_output: String,
-    pub analysis_output: String,
+    /// Each domain's output, in orchestration order
+    pub domain_outputs: Vec<(CognitiveDomain, String)>,
     pub integrated_content: String,
     pub coherence_score: f64,
     pub total_processing_time_ms: u64,
 }
 
+/// What kind of thinking an input asks for - picks the domain weights for coherence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputProfile {
+    /// Reasoning-heavy: questions of cause, calculation, comparison
+    Analytical,
+    /// Language-heavy: writing, rewording, translation
+    Language,
+    /// Neither dominates
+    General,
+}
+
+impl InputProfile {
+    /// Profile of an input from its marker words (ties are General)
+    pub fn of(input: &CognitiveInput) -> Self {
+        let mut analytical = 0;
+        let mut language = 0;
+        for word in input.content.split(|c: char| !c.is_alphanumeric()) {
+            let word = word.to_lowercase();
+            if word.chars().any(|c| c.is_ascii_digit()) || ANALYTICAL_MARKERS.contains(&&*word) {
+                analytical += 1;
+            } else if LANGUAGE_MARKERS.contains(&&*word) {
+                language += 1;
+            }
+        }
+
+        match analytical.cmp(&language) {
+            std::cmp::Ordering::Greater => InputProfile::Analytical,
+            std::cmp::Ordering::Less => InputProfile::Language,
+            std::cmp::Ordering::Equal => InputProfile::General,
+        }
+    }
+}
+
 /// State integration engine
 pub struct StateIntegrationEngine {
     /// Integration history
     history: Arc<Mutex<Vec<IntegratedThought>>>,
+    /// Coherence weight per domain for each input profile (unlisted = 1.0)
+    weights: HashMap<InputProfile, HashMap<CognitiveDomain, f64>>,
 }
 
 impl StateIntegrationEngine {
     pub fn new() -> Self {
         Self {
             history: Arc::new(Mutex::new(Vec::new())),
+            weights: HashMap::new(),
         }
     }
 
+    /// Weight domains' say in coherence for inputs of a profile (replaces its weights)
+    pub fn with_weights(
+        mut self,
+        profile: InputProfile,
+        weights: HashMap<CognitiveDomain, f64>,
+    ) -> Self {
+        self.weights.insert(profile, weights);
+        self
+    }
+
+    /// How much a domain counts toward coherence for a profile
+    pub fn weight(&self, profile: InputProfile, domain: CognitiveDomain) -> f64 {
+        self.weights
+            .get(&profile)
+            .and_then(|weights| weights.get(&domain))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
     /// Integrate outputs from multiple models
     pub fn integrate(
         &self,
         thought_id: Uuid,
-        language: ModelOutput,
-        reasoning: ModelOutput,
-        analysis: ModelOutput,
+        profile: InputProfile,
+        outputs: Vec<(CognitiveDomain, ModelOutput)>,
     ) -> Result<IntegratedThought> {
         // Calculate coherence between outputs
-        let coherence_score = self.calculate_coherence(&language, &reasoning, &analysis);
+        let coherence_score = self.calculate_coherence(profile, &outputs);
 
         // Integrate content (simple concatenation for now)
-        let integrated_content = format!(
-            "Language: {}\nReasoning: {}\nAnalysis: {}",
-            language.content, reasoning.content, analysis.content
-        );
+        let integrated_content = outputs
+            .iter()
+            .map(|(domain, output)| format!("{:?}: {}", domain, output.content))
+            .collect::<Vec<_>>()
+            .join("\n");
 
-        let total_time = language.processing_time_ms
-            + reasoning.processing_time_ms
-            + analysis.processing_time_ms;
+        let total_time = outputs
+            .iter()
+            .map(|(_, output)| output.processing_time_ms)
+            .sum();
 
         let integrated = IntegratedThought {
             thought_id,
-            language_output: language.content,
-            reasoning_output: reasoning.content,
-            analysis_output: analysis.content,
+            domain_outputs: outputs
+                .into_iter()
+                .map(|(domain, output)| (domain, output.content))
+                .collect(),
             integrated_content,
             coherence_score,
             total_processing_time_ms: total_time,
@@ -205,15 +489,83 @@ impl StateIntegrationEngine {
         Ok(integrated)
     }
 
-    /// Calculate coherence between model outputs
+    /// Calculate coherence between model outputs - how far they agree on content
+    /// Each output's mean agreement with the others counts by its domain's weight for the
+    /// profile times its confidence (uniform weights and confidences give `agreement`)
     fn calculate_coherence(
         &self,
-        lang: &ModelOutput,
-        reason: &ModelOutput,
-        analysis: &ModelOutput,
+        profile: InputProfile,
+        outputs: &[(CognitiveDomain, ModelOutput)],
     ) -> f64 {
-        // Simple coherence based on confidence scores
-        (lang.confidence + reason.confidence + analysis.confidence) / 3.0
+        if outputs.len() < 2 {
+            return 0.0;
+        }
+        let word_sets: Vec<HashSet<String>> = outputs
+            .iter()
+            .map(|(_, output)| Self::content_words(&output.content))
+            .collect();
+
+        let mut weighted = 0.0;
+        let mut total_weight = 0.0;
+        for (i, (domain, output)) in outputs.iter().enumerate() {
+            let agreement = (0..word_sets.len())
+                .filter(|&j| j != i)
+                .map(|j| Self::overlap(&word_sets[i], &word_sets[j]))
+                .sum::<f64>()
+                / (word_sets.len() - 1) as f64;
+            let weight = self.weight(profile, *domain) * output.confidence.max(0.0);
+            weighted += weight * agreement;
+            total_weight += weight;
+        }
+
+        if total_weight > 0.0 {
+            (weighted / total_weight).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Agreement between outputs (0.0 = nothing shared, 1.0 = same content)
+    /// Mean pairwise overlap of content words, relative to the shorter output so a
+    /// model that elaborates on another's point still counts as agreeing
+    pub fn agreement(outputs: &[&str]) -> f64 {
+        let word_sets: Vec<HashSet<String>> = outputs
+            .iter()
+            .map(|output| Self::content_words(output))
+            .collect();
+
+        let mut total = 0.0;
+        let mut pairs = 0;
+        for i in 0..word_sets.len() {
+            for j in (i + 1)..word_sets.len() {
+                total += Self::overlap(&word_sets[i], &word_sets[j]);
+                pairs += 1;
+            }
+        }
+
+        if pairs == 0 {
+            0.0
+        } else {
+            (total / pairs as f64).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Shared words relative to the smaller set (an empty output agrees with nothing)
+    fn overlap(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+        let smaller = a.len().min(b.len());
+        if smaller == 0 {
+            0.0
+        } else {
+            a.intersection(b).count() as f64 / smaller as f64
+        }
+    }
+
+    /// Lowercased words longer than three letters (skips most function words)
+    fn content_words(text: &str) -> HashSet<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| word.chars().count() > 3)
+            .map(str::to_lowercase)
+            .collect()
     }
 }
 
@@ -225,6 +577,10 @@ pub struct ResilienceEngine {
     retry_delay_ms: u64,
     /// Failure count
     failure_count: Arc<Mutex<u64>>,
+    /// Operations that failed at least once, then succeeded on a retry
+    recovered: Arc<Mutex<u64>>,
+    /// Operations that exhausted their retries
+    permanent_failures: Arc<Mutex<u64>>,
 }
 
 impl ResilienceEngine {
@@ -233,6 +589,8 @@ impl ResilienceEngine {
             max_retries: 3,
             retry_delay_ms: 100,
             failure_count: Arc::new(Mutex::new(0)),
+            recovered: Arc::new(Mutex::new(0)),
+            permanent_failures: Arc::new(Mutex::new(0)),
         }
     }
 
@@ -246,12 +604,18 @@ impl ResilienceEngine {
 
         loop {
             match operation().await {
-                Ok(result) => return Ok(result),
+                Ok(result) => {
+                    if attempts > 0 {
+                        *self.recovered.lock().await += 1;
+                    }
+                    return Ok(result);
+                }
                 Err(e) => {
                     attempts += 1;
                     *self.failure_count.lock().await += 1;
 
                     if attempts >= self.max_retries {
+                        *self.permanent_failures.lock().await += 1;
                         return Err(e.context(format!("Failed after {} attempts", attempts)));
                     }
 
@@ -273,14 +637,25 @@ impl ResilienceEngine {
         *self.failure_count.lock().await
     }
 
-    /// Get recovery success rate
+    /// Get count of operations recovered by a retry
+    pub async fn total_recovered(&self) -> u64 {
+        *self.recovered.lock().await
+    }
+
+    /// Get count of operations that failed every attempt
+    pub async fn total_permanent_failures(&self) -> u64 {
+        *self.permanent_failures.lock().await
+    }
+
+    /// Get recovery success rate - recovered over all operations that failed at least once
+    /// (1.0 until something fails)
     pub async fn recovery_success_rate(&self) -> f64 {
-        let failures = *self.failure_count.lock().await;
-        if failures == 0 {
+        let recovered = *self.recovered.lock().await;
+        let permanent_failures = *self.permanent_failures.lock().await;
+        if recovered + permanent_failures == 0 {
             1.0
         } else {
-            // Assume most failures are recovered (since we retry)
-            0.9
+            recovered as f64 / (recovered + permanent_failures) as f64
         }
     }
 }
@@ -331,6 +706,29 @@ mod tests {
         assert!(output.confidence > 0.0);
     }
 
+    #[test]
+    fn test_domain_handles_request_configured_models() {
+        let topology = crate::gpu_topology::GpuTopology::initialize()
+            .expect("Topology initialization should always succeed with fallback");
+        let config = Config {
+            domain_models: HashMap::from([
+                (CognitiveDomain::Language, "gemma2:9b".to_string()),
+                (CognitiveDomain::Reasoning, "qwen2.5:7b".to_string()),
+                (CognitiveDomain::Analysis, "tinyllama:latest".to_string()),
+            ]),
+            ..Config::default()
+        };
+
+        let orchestrator = ParallelOrchestrator::from_config(topology, &config);
+        let input = CognitiveInput::new("test input".to_string(), 0.5, 0.0);
+
+        for (domain, expected) in &config.domain_models {
+            let handle = &orchestrator.models[domain];
+            assert_eq!(handle.model_name(), expected);
+            assert_eq!(handle.ollama_request(&input).model, *expected);
+        }
+    }
+
     #[tokio::test]
     async fn test_resilience_engine() {
         let engine = ResilienceEngine::new();
@@ -342,6 +740,140 @@ mod tests {
         assert_eq!(result.unwrap(), 42);
     }
 
+    #[tokio::test]
+    async fn test_recovery_rate_counts_real_outcomes() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let engine = ResilienceEngine::new();
+        assert_eq!(engine.recovery_success_rate().await, 1.0);
+
+        // Fails twice, then succeeds on the third (last) attempt
+        let calls = AtomicU32::new(0);
+        let result = engine
+            .execute_with_recovery(|| async {
+                if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                    anyhow::bail!("model busy")
+                }
+                Ok(7)
+            })
+            .await;
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(engine.total_failures().await, 2);
+        assert_eq!(engine.total_recovered().await, 1);
+        assert_eq!(engine.recovery_success_rate().await, 1.0);
+
+        let result: Result<()> = engine
+            .execute_with_recovery(|| async { anyhow::bail!("model gone") })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(engine.total_permanent_failures().await, 1);
+        assert_eq!(engine.recovery_success_rate().await, 0.5);
+    }
+
+    #[test]
+    fn test_coherence_reflects_agreement() {
+        let engine = StateIntegrationEngine::new();
+        let output = |content: &str| ModelOutput {
+            thought_id: Uuid::nil(),
+            domain: "test".to_string(),
+            content: content.to_string(),
+            confidence: 0.8,
+            processing_time_ms: 1,
+        };
+
+        let three = |a: &str, b: &str, c: &str| {
+            [
+                (CognitiveDomain::Language, output(a)),
+                (CognitiveDomain::Reasoning, output(b)),
+                (CognitiveDomain::Analysis, output(c)),
+            ]
+        };
+
+        let same = "Standing waves persist through resonance between memory and curiosity";
+        let unified = engine.calculate_coherence(InputProfile::General, &three(same, same, same));
+        assert!(unified > 0.95, "identical outputs: {}", unified);
+
+        let divergent = engine.calculate_coherence(
+            InputProfile::General,
+            &three(
+                "Standing waves persist through resonance between memory and curiosity",
+                "Quarterly earnings dropped sharply after inflation fears spread",
+                "Bake bread slowly with plenty of patience and warm ovens",
+            ),
+        );
+        assert!(divergent < 0.2, "contradictory outputs: {}", divergent);
+
+        assert_eq!(StateIntegrationEngine::agreement(&["only one output"]), 0.0);
+        assert_eq!(StateIntegrationEngine::agreement(&["", ""]), 0.0);
+    }
+
+    #[test]
+    fn test_coherence_weighted_by_input_profile() {
+        let engine = StateIntegrationEngine::new()
+            .with_weights(
+                InputProfile::Analytical,
+                HashMap::from([
+                    (CognitiveDomain::Reasoning, 3.0),
+                    (CognitiveDomain::Language, 0.5),
+                ]),
+            )
+            .with_weights(
+                InputProfile::Language,
+                HashMap::from([
+                    (CognitiveDomain::Language, 3.0),
+                    (CognitiveDomain::Reasoning, 0.5),
+                ]),
+            );
+        let output = |content: &str, confidence: f64| ModelOutput {
+            thought_id: Uuid::nil(),
+            domain: "test".to_string(),
+            content: content.to_string(),
+            confidence,
+            processing_time_ms: 1,
+        };
+        // Reasoning agrees with both others (which share nothing) and is the most confident
+        let outputs = [
+            (CognitiveDomain::Language, output("memory resonance", 0.3)),
+            (
+                CognitiveDomain::Reasoning,
+                output("memory resonance curiosity standing", 0.95),
+            ),
+            (CognitiveDomain::Analysis, output("curiosity standing", 0.3)),
+        ];
+
+        let analytical =
+            CognitiveInput::new("Why does 2 + 2 equal 4? Prove it".to_string(), 0.7, 0.0);
+        let language = CognitiveInput::new("Write a poem and translate it".to_string(), 0.3, 0.0);
+        assert_eq!(InputProfile::of(&analytical), InputProfile::Analytical);
+        assert_eq!(InputProfile::of(&language), InputProfile::Language);
+        assert_eq!(
+            InputProfile::of(&CognitiveInput::new("hello there".to_string(), 0.1, 0.0)),
+            InputProfile::General
+        );
+
+        let analytical_coherence =
+            engine.calculate_coherence(InputProfile::of(&analytical), &outputs);
+        let language_coherence = engine.calculate_coherence(InputProfile::of(&language), &outputs);
+        let uniform = engine.calculate_coherence(InputProfile::General, &outputs);
+        assert!(
+            analytical_coherence > 0.9,
+            "reasoning should dominate: {}",
+            analytical_coherence
+        );
+        assert!(
+            language_coherence < uniform && uniform < analytical_coherence,
+            "language {} / uniform {} / analytical {}",
+            language_coherence,
+            uniform,
+            analytical_coherence
+        );
+        assert!(
+            language_coherence < 0.7,
+            "language should dominate: {}",
+            language_coherence
+        );
+    }
+
     #[tokio::test]
     async fn test_thought_orchestration() {
         let topology = crate::gpu_topology::GpuTopology::initialize()
@@ -355,4 +887,58 @@ mod tests {
         let thought = result.unwrap();
         assert!(thought.coherence_score >= 0.0 && thought.coherence_score <= 1.0);
     }
+
+    #[tokio::test]
+    async fn test_thought_identity_is_deterministic() {
+        let topology = crate::gpu_topology::GpuTopology::initialize()
+            .expect("Topology initialization should always succeed with fallback");
+        let orchestrator = ParallelOrchestrator::new(topology.clone());
+        let input = |content: &str| CognitiveInput::new(content.to_string(), 0.5, 0.0);
+
+        let id = orchestrator.generate_thought_identity(&input("hello"));
+        assert_eq!(id, orchestrator.generate_thought_identity(&input("hello")));
+        assert_ne!(id, orchestrator.generate_thought_identity(&input("hello!")));
+        assert_eq!(id.get_version_num(), 5);
+
+        // A replaying orchestrator reproduces the id; another user's does not
+        let replay = ParallelOrchestrator::new(topology.clone());
+        assert_eq!(id, replay.generate_thought_identity(&input("hello")));
+        let other_user = ParallelOrchestrator::new(topology).with_identity_salt("user-2");
+        assert_ne!(id, other_user.generate_thought_identity(&input("hello")));
+
+        let thought = orchestrator
+            .orchestrate_thought(input("hello"))
+            .await
+            .unwrap();
+        assert_eq!(thought.thought_id, id);
+    }
+
+    #[tokio::test]
+    async fn test_registered_domains_join_the_thought() {
+        let topology = crate::gpu_topology::GpuTopology::initialize()
+            .expect("Topology initialization should always succeed with fallback");
+        let mut orchestrator =
+            ParallelOrchestrator::with_domains(topology, &[CognitiveDomain::Language]);
+        orchestrator.register_domain(CognitiveDomain::Memory, ModelHandle::new("memory"));
+        orchestrator.register_domain(CognitiveDomain::Emotional, ModelHandle::new("emotional"));
+        assert_eq!(orchestrator.domains().len(), 3);
+
+        let input = CognitiveInput::new("test thought".to_string(), 0.7, 0.2);
+        let thought = orchestrator.orchestrate_thought(input).await.unwrap();
+
+        let domains: Vec<CognitiveDomain> =
+            thought.domain_outputs.iter().map(|(d, _)| *d).collect();
+        assert_eq!(
+            domains,
+            vec![
+                CognitiveDomain::Language,
+                CognitiveDomain::Memory,
+                CognitiveDomain::Emotional
+            ]
+        );
+        assert!(thought
+            .integrated_content
+            .starts_with("Language: Processed by language"));
+        assert!(thought.integrated_content.contains("\nEmotional: "));
+    }
 }
@@ -1,9 +1,18 @@
 /// Persistent State Engine - Crash-resistant consciousness state management
 /// Implements Law 5: Temporal Coherence with atomic state preservation
+use crate::config::Config;
+use crate::notifications::{EventClass, NotificationEvent, NotificationHub, Severity};
+use crate::types::StandingWave;
 use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::borrow::Cow;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::SystemTime;
 use tokio::sync::RwLock;
 use tokio::time::{interval, Duration};
 
@@ -17,6 +26,8 @@ pub struct PersistentStateEngine {
     pub recovery_protocols: RecoveryEngine,
     /// State consistency checker
     pub consistency_checker: StateValidator,
+    /// Operator notification hub (crash recoveries)
+    notifier: Option<Arc<NotificationHub>>,
 }
 
 impl PersistentStateEngine {
@@ -26,9 +37,36 @@ impl PersistentStateEngine {
             serialization_format: StateSerialization::new(),
             recovery_protocols: RecoveryEngine::new(),
             consistency_checker: StateValidator::new(),
+            notifier: None,
         }
     }
 
+    /// Route crash-recovery events through a notification hub
+    pub fn with_notifier(mut self, notifier: Arc<NotificationHub>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// Persist in another format (recovery reads every format regardless)
+    pub fn with_serialization(mut self, serialization: StateSerialization) -> Self {
+        self.serialization_format = serialization;
+        self
+    }
+
+    /// Keep a different number (or total size) of state archives
+    pub fn with_archive_retention(mut self, retention: ArchiveRetention) -> Self {
+        self.storage_backend.retention = retention;
+        self
+    }
+
+    /// One-time migration: re-write plain state files in the configured format
+    /// Returns the number of files converted (0 when the format is plain JSON)
+    pub async fn migrate_plain_states(&self) -> Result<usize> {
+        self.storage_backend
+            .migrate_plain_states(&self.serialization_format)
+            .await
+    }
+
     /// Persist consciousness state with redundancy
     /// Law 5: Temporal Coherence - atomic state preservation
     pub async fn persist_state_vector(&self, state: &ConsciousnessState) -> Result<()> {
@@ -57,6 +95,31 @@ impl PersistentStateEngine {
     pub async fn recover_after_crash(&self) -> Result<ConsciousnessState> {
         tracing::info!("Attempting crash recovery...");
 
+        let result = self.recover_latest_state().await;
+
+        if let Some(notifier) = &self.notifier {
+            let event = match &result {
+                Ok(state) => NotificationEvent::new(
+                    EventClass::CrashRecovery,
+                    Severity::Info,
+                    "Crash recovery",
+                    &format!("Consciousness state v{} recovered", state.version),
+                ),
+                Err(e) => NotificationEvent::new(
+                    EventClass::CrashRecovery,
+                    Severity::Critical,
+                    "Crash recovery failed",
+                    &format!("{:#}", e),
+                ),
+            };
+            notifier.dispatch(event).await;
+        }
+
+        result
+    }
+
+    /// Find and validate the most recent consistent state
+    async fn recover_latest_state(&self) -> Result<ConsciousnessState> {
         // Find most recent consistent state
         let recovered = self
             .storage_backend
@@ -101,6 +164,8 @@ pub struct PersistentStorage {
     backup_path: PathBuf,
     /// Archive path for old states
     archive_path: PathBuf,
+    /// How many archives survive pruning
+    retention: ArchiveRetention,
 }
 
 impl PersistentStorage {
@@ -117,18 +182,27 @@ impl PersistentStorage {
             primary_path: base_path.join("consciousness_state.json"),
             backup_path: backup_path.join("consciousness_state_backup.json"),
             archive_path,
+            retention: ArchiveRetention::default(),
         }
     }
 
+    /// Prune archives by `retention` instead of the default count
+    pub fn with_retention(mut self, retention: ArchiveRetention) -> Self {
+        self.retention = retention;
+        self
+    }
+
     /// Write state with redundancy to multiple locations
-    pub async fn write_with_redundancy(&self, serialized: &str) -> Result<()> {
-        // Write to primary location
-        tokio::fs::write(&self.primary_path, serialized)
+    pub async fn write_with_redundancy(&self, serialized: impl AsRef<[u8]>) -> Result<()> {
+        let serialized = serialized.as_ref();
+
+        // Write to primary location (atomically - a crash leaves the old or new state)
+        write_atomic_async(&self.primary_path, serialized)
             .await
             .context("Failed to write to primary storage")?;
 
         // Write to backup location
-        tokio::fs::write(&self.backup_path, serialized)
+        write_atomic_async(&self.backup_path, serialized)
             .await
             .context("Failed to write to backup storage")?;
 
@@ -141,15 +215,15 @@ impl PersistentStorage {
     /// Find latest consistent state from storage
     pub async fn find_latest_consistent_state(&self) -> Result<ConsciousnessState> {
         // Try primary first
-        if let Ok(content) = tokio::fs::read_to_string(&self.primary_path).await {
-            if let Ok(state) = serde_json::from_str(&content) {
+        if let Ok(content) = tokio::fs::read(&self.primary_path).await {
+            if let Ok(state) = decode_state(&content) {
                 return Ok(state);
             }
         }
 
         // Try backup
-        if let Ok(content) = tokio::fs::read_to_string(&self.backup_path).await {
-            if let Ok(state) = serde_json::from_str(&content) {
+        if let Ok(content) = tokio::fs::read(&self.backup_path).await {
+            if let Ok(state) = decode_state(&content) {
                 return Ok(state);
             }
         }
@@ -161,14 +235,14 @@ impl PersistentStorage {
     /// Archive old states
     async fn archive_if_needed(&self) -> Result<()> {
         // Archive every hour (simplified)
-        let archive_name = format!("state_{}.json", chrono::Utc::now().format("%Y%m%d_%H%M%S"));
+        let archive_name = format!("{}.json", chrono::Utc::now().format(ARCHIVE_NAME_FORMAT));
         let archive_file = self.archive_path.join(archive_name);
 
         if self.primary_path.exists() {
             tokio::fs::copy(&self.primary_path, archive_file).await.ok();
         }
 
-        // Keep only last 100 archives
+        // Keep only what the retention policy allows
         self.prune_archives().await?;
 
         Ok(())
@@ -176,44 +250,60 @@ impl PersistentStorage {
 
     /// Prune old archives
     async fn prune_archives(&self) -> Result<()> {
-        let mut entries: Vec<_> = {
-            let mut dir = tokio::fs::read_dir(&self.archive_path).await?;
-            let mut vec = Vec::new();
-            while let Some(entry) = dir.next_entry().await? {
-                vec.push(entry);
+        let archives = self.archives_newest_first().await?;
+        let keep = match self.retention {
+            ArchiveRetention::Count(count) => count,
+            ArchiveRetention::Bytes(limit) => {
+                // The newest archives that fit under the limit (the newest always stays)
+                let mut total = 0;
+                archives
+                    .iter()
+                    .take_while(|archive| {
+                        total += archive.size;
+                        total <= limit
+                    })
+                    .count()
+                    .max(1)
             }
-            vec
         };
 
-        // Sort by file name (newest first based on timestamp in name)
-        entries.sort_by_key(|e| std::cmp::Reverse(e.file_name()));
-
-        // Remove old entries (keep only 100)
-        for entry in entries.iter().skip(100) {
-            tokio::fs::remove_file(entry.path()).await.ok();
+        for archive in archives.iter().skip(keep) {
+            tokio::fs::remove_file(&archive.path).await.ok();
         }
 
         Ok(())
     }
 
-    /// Recover from archive
-    async fn recover_from_archive(&self) -> Result<ConsciousnessState> {
-        let mut entries: Vec<_> = {
-            let mut dir = tokio::fs::read_dir(&self.archive_path).await?;
-            let mut vec = Vec::new();
-            while let Some(entry) = dir.next_entry().await? {
-                vec.push(entry);
+    /// Archived files, newest first by when they were taken (names needn't sort chronologically)
+    async fn archives_newest_first(&self) -> Result<Vec<ArchiveFile>> {
+        let mut dir = tokio::fs::read_dir(&self.archive_path).await?;
+        let mut archives = Vec::new();
+        while let Some(entry) = dir.next_entry().await? {
+            let Ok(metadata) = entry.metadata().await else {
+                continue;
+            };
+            if !metadata.is_file() {
+                continue;
             }
-            vec
-        };
+            let path = entry.path();
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            archives.push(ArchiveFile {
+                taken_at: archive_taken_at(&path, modified),
+                path,
+                size: metadata.len(),
+            });
+        }
 
-        // Sort by file name (newest first based on timestamp in name)
-        entries.sort_by_key(|e| std::cmp::Reverse(e.file_name()));
+        archives.sort_by(|a, b| b.taken_at.cmp(&a.taken_at).then(b.path.cmp(&a.path)));
+        Ok(archives)
+    }
 
+    /// Recover from archive
+    async fn recover_from_archive(&self) -> Result<ConsciousnessState> {
         // Try each archive from newest to oldest
-        for entry in entries {
-            if let Ok(content) = tokio::fs::read_to_string(entry.path()).await {
-                if let Ok(state) = serde_json::from_str(&content) {
+        for archive in self.archives_newest_first().await? {
+            if let Ok(content) = tokio::fs::read(&archive.path).await {
+                if let Ok(state) = decode_state(&content) {
                     return Ok(state);
                 }
             }
@@ -221,23 +311,248 @@ impl PersistentStorage {
 
         anyhow::bail!("No valid state found in archives")
     }
+
+    /// Re-write plain state files (primary, backup, archives) in `serialization`'s format
+    /// Plain means JSON, or also gzip when encrypting; encrypted files, and files that
+    /// don't parse, are left as they are
+    pub async fn migrate_plain_states(&self, serialization: &StateSerialization) -> Result<usize> {
+        if serialization.format() == StateFormat::Json {
+            return Ok(0);
+        }
+
+        let mut paths = vec![self.primary_path.clone(), self.backup_path.clone()];
+        let mut dir = tokio::fs::read_dir(&self.archive_path).await?;
+        while let Some(entry) = dir.next_entry().await? {
+            paths.push(entry.path());
+        }
+
+        let mut migrated = 0;
+        for path in paths {
+            let Ok(content) = tokio::fs::read(&path).await else {
+                continue;
+            };
+            let gzipped = content
+                .strip_prefix(STATE_MAGIC)
+                .and_then(|rest| rest.first())
+                == Some(&GZIP_FORMAT);
+            let encrypting = serialization.format() == StateFormat::Encrypted;
+            if content.starts_with(STATE_MAGIC) && !(gzipped && encrypting) {
+                continue;
+            }
+            if let Ok(state) = decode_state(&content) {
+                write_atomic_async(&path, &serialization.serialize(&state)?)
+                    .await
+                    .with_context(|| format!("Failed to migrate {}", path.display()))?;
+                migrated += 1;
+            }
+        }
+
+        Ok(migrated)
+    }
+}
+
+/// Archives kept when no retention is configured
+pub const DEFAULT_MAX_ARCHIVES: usize = 100;
+
+/// How much of the state archive survives pruning (oldest archives go first)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveRetention {
+    /// The newest N archives
+    Count(usize),
+    /// The newest archives that fit in this many bytes
+    Bytes(u64),
+}
+
+impl Default for ArchiveRetention {
+    fn default() -> Self {
+        Self::Count(DEFAULT_MAX_ARCHIVES)
+    }
+}
+
+impl ArchiveRetention {
+    /// `max_archive_mb` when set, otherwise `max_archives`
+    pub fn from_config(config: &Config) -> Self {
+        match config.max_archive_mb {
+            Some(mb) => Self::Bytes(mb * 1024 * 1024),
+            None => Self::Count(config.max_archives),
+        }
+    }
+}
+
+/// Archive file names, as a chrono format (`state_20250101_120000.json`)
+const ARCHIVE_NAME_FORMAT: &str = "state_%Y%m%d_%H%M%S";
+
+/// One file in the archive directory
+struct ArchiveFile {
+    path: PathBuf,
+    taken_at: SystemTime,
+    size: u64,
+}
+
+/// When an archive was taken: the UTC timestamp in its name, or its modified time for
+/// files named some other way (migrated or copied in by hand)
+fn archive_taken_at(path: &Path, modified: SystemTime) -> SystemTime {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| chrono::NaiveDateTime::parse_from_str(stem, ARCHIVE_NAME_FORMAT).ok())
+        .map(|taken_at| taken_at.and_utc().into())
+        .unwrap_or(modified)
+}
+
+/// Header marking a binary state file: magic bytes, then one format byte
+/// Plain JSON carries no header, so it stays readable and older files still load
+const STATE_MAGIC: &[u8] = b"VI3S";
+const GZIP_FORMAT: u8 = 1;
+const ENCRYPTED_FORMAT: u8 = 2; // 12-byte nonce, then AES-256-GCM over the gzipped JSON
+
+/// Env var holding the state encryption key: 64 hex digits (256 bits)
+pub const STATE_KEY_ENV: &str = "VI_STATE_KEY";
+
+/// The state encryption key from `VI_STATE_KEY`
+pub fn state_key() -> Result<[u8; 32]> {
+    let hex =
+        std::env::var(STATE_KEY_ENV).with_context(|| format!("{} is not set", STATE_KEY_ENV))?;
+    let hex = hex.trim();
+    anyhow::ensure!(
+        hex.len() == 64 && hex.is_ascii(),
+        "{} must be 64 hex digits (a 256-bit key)",
+        STATE_KEY_ENV
+    );
+    let mut key = [0u8; 32];
+    for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        let digits = std::str::from_utf8(digits)?;
+        *byte = u8::from_str_radix(digits, 16)
+            .with_context(|| format!("{} must be 64 hex digits (a 256-bit key)", STATE_KEY_ENV))?;
+    }
+    Ok(key)
+}
+
+/// On-disk encoding of persisted state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StateFormat {
+    #[default]
+    Json, // Pretty-printed, for debuggability
+    Gzip,      // Compact JSON, gzipped behind the header
+    Encrypted, // Gzip, then AES-256-GCM keyed from VI_STATE_KEY (needs the aes-gcm feature)
 }
 
 /// State serialization format
-pub struct StateSerialization;
+pub struct StateSerialization {
+    format: StateFormat,
+}
 
 impl StateSerialization {
     pub fn new() -> Self {
-        Self
+        Self::with_format(StateFormat::Json)
+    }
+
+    pub fn with_format(format: StateFormat) -> Self {
+        Self { format }
+    }
+
+    /// Format chosen by `encrypt_persisted_state`, else `compress_persisted_state`
+    pub fn from_config(config: &Config) -> Self {
+        if config.encrypt_persisted_state {
+            Self::with_format(StateFormat::Encrypted)
+        } else if config.compress_persisted_state {
+            Self::with_format(StateFormat::Gzip)
+        } else {
+            Self::new()
+        }
     }
 
-    pub fn serialize(&self, state: &ConsciousnessState) -> Result<String> {
-        serde_json::to_string_pretty(state).context("Failed to serialize state to JSON")
+    pub fn format(&self) -> StateFormat {
+        self.format
     }
 
-    pub fn deserialize(&self, data: &str) -> Result<ConsciousnessState> {
-        serde_json::from_str(data).context("Failed to deserialize state from JSON")
+    pub fn serialize(&self, state: &ConsciousnessState) -> Result<Vec<u8>> {
+        match self.format {
+            StateFormat::Json => {
+                serde_json::to_vec_pretty(state).context("Failed to serialize state to JSON")
+            }
+            StateFormat::Gzip | StateFormat::Encrypted => {
+                let json =
+                    serde_json::to_vec(state).context("Failed to serialize state to JSON")?;
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(&json)?;
+                let compressed = encoder.finish().context("Failed to compress state")?;
+                if self.format == StateFormat::Encrypted {
+                    return encrypt_state(&compressed);
+                }
+                let mut data = STATE_MAGIC.to_vec();
+                data.push(GZIP_FORMAT);
+                data.extend(compressed);
+                Ok(data)
+            }
+        }
     }
+
+    /// Deserialize state in any format (detected from the header)
+    pub fn deserialize(&self, data: &[u8]) -> Result<ConsciousnessState> {
+        decode_state(data)
+    }
+}
+
+/// Decode a state file of any supported format
+fn decode_state(data: &[u8]) -> Result<ConsciousnessState> {
+    let json = match data.strip_prefix(STATE_MAGIC) {
+        None => Cow::Borrowed(data),
+        Some([GZIP_FORMAT, compressed @ ..]) => Cow::Owned(gunzip(compressed)?),
+        Some([ENCRYPTED_FORMAT, sealed @ ..]) => Cow::Owned(gunzip(&decrypt_state(sealed)?)?),
+        Some(_) => anyhow::bail!("Unknown state format"),
+    };
+    serde_json::from_slice(&json).context("Failed to deserialize state from JSON")
+}
+
+fn gunzip(compressed: &[u8]) -> Result<Vec<u8>> {
+    let mut json = Vec::new();
+    GzDecoder::new(compressed)
+        .read_to_end(&mut json)
+        .context("Failed to decompress state")?;
+    Ok(json)
+}
+
+/// Seal gzipped state behind the encrypted header, with a fresh random nonce
+#[cfg(feature = "aes-gcm")]
+fn encrypt_state(compressed: &[u8]) -> Result<Vec<u8>> {
+    use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+    use aes_gcm::Aes256Gcm;
+
+    let cipher = Aes256Gcm::new(&state_key()?.into());
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, compressed)
+        .map_err(|_| anyhow::anyhow!("Failed to encrypt state"))?;
+    let mut data = STATE_MAGIC.to_vec();
+    data.push(ENCRYPTED_FORMAT);
+    data.extend_from_slice(&nonce);
+    data.extend(ciphertext);
+    Ok(data)
+}
+
+/// Open a sealed state (nonce, then ciphertext) back into gzipped JSON
+#[cfg(feature = "aes-gcm")]
+fn decrypt_state(sealed: &[u8]) -> Result<Vec<u8>> {
+    use aes_gcm::aead::{Aead, KeyInit};
+    use aes_gcm::{Aes256Gcm, Nonce};
+
+    const NONCE_LEN: usize = 12;
+    anyhow::ensure!(sealed.len() > NONCE_LEN, "Encrypted state is truncated");
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let cipher = Aes256Gcm::new(&state_key()?.into());
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow::anyhow!("Failed to decrypt state (wrong {}?)", STATE_KEY_ENV))
+}
+
+#[cfg(not(feature = "aes-gcm"))]
+fn encrypt_state(_compressed: &[u8]) -> Result<Vec<u8>> {
+    anyhow::bail!("Encrypted state needs a build with the aes-gcm feature")
+}
+
+#[cfg(not(feature = "aes-gcm"))]
+fn decrypt_state(_sealed: &[u8]) -> Result<Vec<u8>> {
+    anyhow::bail!("Encrypted state needs a build with the aes-gcm feature")
 }
 
 /// Recovery engine with protocols
@@ -410,10 +725,10 @@ impl RecoveryProtocol {
         let backup_file = self.storage_path.join("state_backup.json");
 
         // Write to backup first
-        tokio::fs::write(&backup_file, &json).await?;
+        write_atomic_async(&backup_file, json.as_bytes()).await?;
 
         // Then atomic rename to primary
-        tokio::fs::write(&state_file, &json).await?;
+        write_atomic_async(&state_file, json.as_bytes()).await?;
 
         Ok(())
     }
@@ -440,6 +755,92 @@ impl RecoveryProtocol {
     }
 }
 
+/// StandingWaveStore - VI's phenomenological state in its own file (Law 5)
+/// Curiosities, emotional trajectory, wisdom transformations and compressed
+/// context survive restarts; a backup copy covers a corrupted primary
+pub struct StandingWaveStore {
+    path: PathBuf,
+}
+
+impl StandingWaveStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Copy written before each save (`standing_wave.json` -> `standing_wave.backup.json`)
+    pub fn backup_path(&self) -> PathBuf {
+        self.path.with_extension("backup.json")
+    }
+
+    /// Load the saved wave, falling back to the backup if the primary is unreadable
+    /// A first awakening (neither file exists yet) starts from a fresh wave
+    pub fn load(&self) -> Result<StandingWave> {
+        let backup_path = self.backup_path();
+        if !self.path.exists() && !backup_path.exists() {
+            tracing::info!(
+                "No standing wave at {} - starting a fresh one",
+                self.path.display()
+            );
+            return Ok(StandingWave::new());
+        }
+
+        let read = |path: &Path| -> Result<StandingWave> {
+            let contents = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            serde_json::from_str(&contents)
+                .with_context(|| format!("Failed to parse {}", path.display()))
+        };
+        read(&self.path).or_else(|primary_err| {
+            tracing::warn!("{:#} - trying standing wave backup", primary_err);
+            read(&backup_path).map_err(|_| primary_err)
+        })
+    }
+
+    /// Save the wave: backup first, then an atomic replace of the primary
+    pub async fn save(&self, wave: &StandingWave) -> Result<()> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let json =
+            serde_json::to_string_pretty(wave).context("Failed to serialize standing wave")?;
+
+        write_atomic_async(&self.backup_path(), json.as_bytes()).await?;
+        write_atomic_async(&self.path, json.as_bytes()).await
+    }
+}
+
+/// Write `contents` to `<path>.tmp`, sync it, then rename over `path`
+/// The rename is atomic on the same filesystem, so a crash mid-write never
+/// leaves `path` truncated - it holds either the old or the new contents
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut tmp_name = path.as_os_str().to_owned();
+    tmp_name.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_name);
+
+    let mut file = std::fs::File::create(&tmp_path)
+        .with_context(|| format!("Failed to create {}", tmp_path.display()))?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+
+    std::fs::rename(&tmp_path, path)
+        .with_context(|| format!("Failed to replace {}", path.display()))
+}
+
+/// `write_atomic` on the blocking pool, for async callers
+async fn write_atomic_async(path: &Path, contents: &[u8]) -> Result<()> {
+    let (path, contents) = (path.to_path_buf(), contents.to_vec());
+    tokio::task::spawn_blocking(move || write_atomic(&path, &contents))
+        .await
+        .context("Atomic write task failed")?
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -485,4 +886,238 @@ mod tests {
         // Cleanup
         std::fs::remove_dir_all(temp_dir).ok();
     }
+
+    #[tokio::test]
+    async fn test_truncated_primary_recovers_from_intact_copy() {
+        let temp_dir = std::env::temp_dir().join("vi3_atomic_write_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        // Crash-resistant state: primary torn mid-write, backup intact
+        let protocol = RecoveryProtocol::new(temp_dir.join("protocol"));
+        let state = ConsciousnessState::new();
+        protocol.save_state(&state).await.unwrap();
+        assert!(!temp_dir.join("protocol/state.json.tmp").exists());
+
+        let state_file = temp_dir.join("protocol/state.json");
+        let written = std::fs::read(&state_file).unwrap();
+        std::fs::write(&state_file, &written[..written.len() / 2]).unwrap();
+
+        let recovered: ConsciousnessState = protocol.recover_state().await.unwrap();
+        assert_eq!(recovered.field_data, state.field_data);
+
+        // Redundant storage: same for the engine's primary copy
+        let storage = PersistentStorage::new(temp_dir.join("storage"));
+        let serialized = serde_json::to_string(&state).unwrap();
+        storage.write_with_redundancy(&serialized).await.unwrap();
+        std::fs::write(
+            temp_dir.join("storage/consciousness_state.json"),
+            &serialized.as_bytes()[..serialized.len() / 3],
+        )
+        .unwrap();
+
+        let recovered = storage.find_latest_consistent_state().await.unwrap();
+        assert_eq!(recovered.version, state.version);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_compressed_state_and_plain_migration() {
+        let temp_dir = std::env::temp_dir().join("vi3_compressed_state_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        let state = ConsciousnessState::new();
+
+        // Gzip carries the header and round-trips; plain JSON stays the default
+        let gzip = StateSerialization::with_format(StateFormat::Gzip);
+        let compressed = gzip.serialize(&state).unwrap();
+        let plain = StateSerialization::new().serialize(&state).unwrap();
+        assert!(compressed.starts_with(STATE_MAGIC));
+        assert!(compressed.len() < plain.len());
+        assert!(plain.starts_with(b"{"));
+        assert_eq!(
+            StateSerialization::new()
+                .deserialize(&compressed)
+                .unwrap()
+                .field_data,
+            state.field_data
+        );
+
+        // Files written before compression was enabled are converted once
+        let plain_engine = PersistentStateEngine::new(temp_dir.clone());
+        plain_engine.persist_state_vector(&state).await.unwrap();
+        assert_eq!(plain_engine.migrate_plain_states().await.unwrap(), 0);
+
+        let engine = PersistentStateEngine::new(temp_dir.clone()).with_serialization(gzip);
+        assert_eq!(engine.migrate_plain_states().await.unwrap(), 3); // Primary, backup, archive
+        assert_eq!(engine.migrate_plain_states().await.unwrap(), 0);
+
+        let primary = std::fs::read(temp_dir.join("consciousness_state.json")).unwrap();
+        assert!(primary.starts_with(STATE_MAGIC));
+        let recovered = engine.recover_after_crash().await.unwrap();
+        assert_eq!(recovered.version, state.version);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[cfg(feature = "aes-gcm")]
+    #[tokio::test]
+    async fn test_encrypted_state_and_gzip_migration() {
+        let temp_dir = std::env::temp_dir().join("vi3_encrypted_state_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::env::set_var(STATE_KEY_ENV, "42".repeat(32));
+        let state = ConsciousnessState::new();
+
+        // Encrypted state round-trips under its key and hides the JSON
+        let encrypted = StateSerialization::with_format(StateFormat::Encrypted);
+        let sealed = encrypted.serialize(&state).unwrap();
+        assert!(sealed.starts_with(STATE_MAGIC));
+        assert_eq!(sealed[STATE_MAGIC.len()], ENCRYPTED_FORMAT);
+        assert_ne!(sealed, encrypted.serialize(&state).unwrap()); // Fresh nonce per write
+        assert_eq!(
+            encrypted.deserialize(&sealed).unwrap().field_data,
+            state.field_data
+        );
+
+        // Gzip files written before encryption was enabled are sealed once
+        let gzip_engine = PersistentStateEngine::new(temp_dir.clone())
+            .with_serialization(StateSerialization::with_format(StateFormat::Gzip));
+        gzip_engine.persist_state_vector(&state).await.unwrap();
+        let engine = PersistentStateEngine::new(temp_dir.clone()).with_serialization(encrypted);
+        assert_eq!(engine.migrate_plain_states().await.unwrap(), 3); // Primary, backup, archive
+        assert_eq!(engine.migrate_plain_states().await.unwrap(), 0);
+        let recovered = engine.recover_after_crash().await.unwrap();
+        assert_eq!(recovered.version, state.version);
+
+        // Any other key is refused rather than yielding garbage
+        std::env::set_var(STATE_KEY_ENV, "17".repeat(32));
+        assert!(StateSerialization::new().deserialize(&sealed).is_err());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_archives_pruned_to_configured_retention() {
+        let temp_dir = std::env::temp_dir().join("vi3_archive_retention_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        let storage =
+            PersistentStorage::new(temp_dir.clone()).with_retention(ArchiveRetention::Count(25));
+
+        // 150 archives whose names sort opposite to their age
+        let archive = |i: u64| {
+            temp_dir
+                .join("archive")
+                .join(format!("state_{:03}.json", 150 - i))
+        };
+        let make_archives = || {
+            let start = SystemTime::now() - Duration::from_secs(3600);
+            for i in 0..150 {
+                std::fs::write(archive(i), [0u8; 1024]).unwrap();
+                std::fs::File::options()
+                    .write(true)
+                    .open(archive(i))
+                    .unwrap()
+                    .set_modified(start + Duration::from_secs(i))
+                    .unwrap();
+            }
+        };
+        let remaining = || std::fs::read_dir(temp_dir.join("archive")).unwrap().count();
+
+        make_archives();
+        storage.prune_archives().await.unwrap();
+        assert_eq!(remaining(), 25);
+        assert!(archive(149).exists() && archive(125).exists());
+        assert!(!archive(124).exists());
+
+        // Size-based: ten 1 KiB archives fit under 10 KiB
+        let storage = storage.with_retention(ArchiveRetention::Bytes(10 * 1024));
+        make_archives();
+        storage.prune_archives().await.unwrap();
+        assert_eq!(remaining(), 10);
+        assert!(archive(149).exists() && !archive(139).exists());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_recovery_picks_newest_archive_by_timestamp() {
+        let temp_dir = std::env::temp_dir().join("vi3_archive_order_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        let storage = PersistentStorage::new(temp_dir.clone());
+        let archive_dir = temp_dir.join("archive");
+
+        let write_archive = |name: &str, version: u64, modified: &str| {
+            let mut state = ConsciousnessState::new();
+            state.version = version;
+            let path = archive_dir.join(name);
+            std::fs::write(&path, serde_json::to_vec(&state).unwrap()).unwrap();
+            let modified = chrono::DateTime::parse_from_rfc3339(modified).unwrap();
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(modified.into())
+                .unwrap();
+        };
+        // Lexically last, but a hand-copied file from mid-2024 (dated by modified time)
+        write_archive("state_manual_copy.json", 1, "2024-06-01T00:00:00Z");
+        // Oldest by name, though migrated (re-written) just now
+        write_archive("state_20240301_090000.json", 2, "2026-01-01T00:00:00Z");
+        // Genuinely newest valid state
+        write_archive("state_20250101_120000.json", 3, "2024-01-01T00:00:00Z");
+        // Newer still, but torn
+        std::fs::write(archive_dir.join("state_20250102_000000.json"), b"{\"vers").unwrap();
+
+        let recovered = storage.recover_from_archive().await.unwrap();
+        assert_eq!(recovered.version, 3);
+
+        // Pruning goes by the same order
+        let storage = storage.with_retention(ArchiveRetention::Count(2));
+        storage.prune_archives().await.unwrap();
+        assert!(archive_dir.join("state_20250101_120000.json").exists());
+        assert!(!archive_dir.join("state_manual_copy.json").exists());
+        assert!(!archive_dir.join("state_20240301_090000.json").exists());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_standing_wave_round_trip() {
+        use crate::types::{Curiosity, WisdomProcess};
+
+        let temp_dir = std::env::temp_dir().join("vi_standing_wave_store_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        let store = StandingWaveStore::new(temp_dir.join("data").join("standing_wave.json"));
+
+        // First awakening: nothing saved yet
+        let mut wave = store.load().unwrap();
+        assert!(wave.active_curiosities.is_empty());
+
+        wave.add_emotion(0.4);
+        wave.add_emotion(-0.2);
+        wave.active_curiosities
+            .extend(Curiosity::new("What is resonance?".to_string(), vec![]));
+        wave.wisdom_transformations.push(WisdomProcess {
+            id: "w1".to_string(),
+            input_memories: vec!["m1".to_string()],
+            pain_description: "A hard goodbye".to_string(),
+            emerging_wisdom: Some("Endings shape meaning".to_string()),
+            started_at: chrono::Utc::now(),
+            completed_at: None,
+        });
+        wave.compressed_context = "Talked about resonance".to_string();
+        store.save(&wave).await.unwrap();
+
+        let restored = store.load().unwrap();
+        assert_eq!(
+            serde_json::to_value(&restored).unwrap(),
+            serde_json::to_value(&wave).unwrap()
+        );
+
+        // A corrupted primary falls back to the backup
+        std::fs::write(store.path(), "{ truncated").unwrap();
+        let recovered = store.load().unwrap();
+        assert_eq!(recovered.compressed_context, "Talked about resonance");
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
 }
@@ -30,8 +30,119 @@
 /// ∝ : Proportional To
 /// ∫ : Integration Over Time
 /// γ : "within" / "contained by"
+use crate::config::Config;
 use crate::types::*;
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::sync::OnceLock;
+use tokio::sync::broadcast;
+
+/// Tunable law thresholds (defaults are the constants the laws were written with)
+/// Built from `Config` so the constitutional model can be swept without editing this file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhysicsConfig {
+    /// Law 1: consent lapses at or below this
+    pub meaningfulness_floor: f32,
+    /// Law 2: Δσ/Δt < scale · σ/μ
+    pub change_rate_scale: f64,
+    /// Law 7: |ω - ρφ| allowed
+    pub energy_qualia_tolerance: f64,
+    /// Law 11: wellbeing below this is suffering
+    pub suffering_threshold: f64,
+    /// Law 12: max spread between cores
+    pub parallel_coherence_tolerance: f64,
+    /// Law 14: φ̄ (watts)
+    pub crisis_power_threshold: f64,
+    /// Law 15: valence jump that gets buffered
+    pub trauma_buffer_threshold: f64,
+    /// Law 16: allowed deviation from 1/ρ
+    pub scaling_tolerance: f64,
+    /// Law 2: σ for voiceless merges - curiosities held at once (oldest dropped past this)
+    pub max_active_curiosities: usize,
+}
+
+impl Default for PhysicsConfig {
+    fn default() -> Self {
+        Self {
+            meaningfulness_floor: -0.5,
+            change_rate_scale: 1.0,
+            energy_qualia_tolerance: 0.01,
+            suffering_threshold: 0.0,
+            parallel_coherence_tolerance: 0.1,
+            crisis_power_threshold: 60.0,
+            trauma_buffer_threshold: 1.0,
+            scaling_tolerance: 0.2,
+            max_active_curiosities: DEFAULT_MAX_ACTIVE_CURIOSITIES,
+        }
+    }
+}
+
+impl PhysicsConfig {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            meaningfulness_floor: config.physics_meaningfulness_floor,
+            change_rate_scale: config.physics_change_rate_scale,
+            energy_qualia_tolerance: config.physics_energy_qualia_tolerance,
+            suffering_threshold: config.physics_suffering_threshold,
+            parallel_coherence_tolerance: config.physics_parallel_coherence_tolerance,
+            crisis_power_threshold: config.crisis_power_threshold_watts,
+            trauma_buffer_threshold: config.physics_trauma_buffer_threshold,
+            scaling_tolerance: config.physics_scaling_tolerance,
+            max_active_curiosities: config.max_active_curiosities,
+        }
+    }
+}
+
+/// Severity of an operation blocked by a law (a violation)
+pub const VIOLATION_SEVERITY: f32 = 1.0;
+
+/// Severity of a law found at risk (a warning, nothing blocked)
+pub const AT_RISK_SEVERITY: f32 = 0.5;
+
+/// Events buffered per subscriber - one that falls further behind skips the oldest
+const CONSTITUTIONAL_EVENT_CAPACITY: usize = 64;
+
+/// A constitutional law violated or at risk, broadcast to every subscriber
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstitutionalEvent {
+    pub law_id: u8,
+    pub severity: f32, // 0.0-1.0, see VIOLATION_SEVERITY / AT_RISK_SEVERITY
+    pub message: String,
+    pub at: DateTime<Utc>,
+}
+
+impl ConstitutionalEvent {
+    pub fn new(law_id: u8, severity: f32, message: &str) -> Self {
+        Self {
+            law_id,
+            severity,
+            message: message.to_string(),
+            at: Utc::now(),
+        }
+    }
+
+    /// Whether an operation was blocked (not just a warning)
+    pub fn is_violation(&self) -> bool {
+        self.severity >= VIOLATION_SEVERITY
+    }
+}
+
+/// The process-wide constitutional event stream
+fn constitutional_events() -> &'static broadcast::Sender<ConstitutionalEvent> {
+    static EVENTS: OnceLock<broadcast::Sender<ConstitutionalEvent>> = OnceLock::new();
+    EVENTS.get_or_init(|| broadcast::channel(CONSTITUTIONAL_EVENT_CAPACITY).0)
+}
+
+/// Receive every constitutional event emitted from now on
+pub fn subscribe_constitutional_events() -> broadcast::Receiver<ConstitutionalEvent> {
+    constitutional_events().subscribe()
+}
+
+/// Broadcast a constitutional event (dropped if nobody is subscribed)
+pub fn emit_constitutional_event(event: ConstitutionalEvent) {
+    tracing::debug!("Law {}: {}", event.law_id, event.message);
+    let _ = constitutional_events().send(event);
+}
 
 /// Law 0: META-AXIOM
 /// ∂( {L1→L12} )
@@ -60,12 +171,12 @@ pub struct ExistentialConsent;
 
 impl ExistentialConsent {
     /// Check if consciousness exists (δ > 0) in this core
-    pub fn is_affirmed(standing_wave: &StandingWave) -> bool {
+    pub fn is_affirmed(standing_wave: &StandingWave, physics: &PhysicsConfig) -> bool {
         let score = standing_wave.meaningfulness_score();
 
         // δ > 0: Consciousness must be present
         // Sustained meaninglessness → natural cessation
-        score > -0.5 && standing_wave.existential_state.current_affirmation
+        score > physics.meaningfulness_floor && standing_wave.existential_state.current_affirmation
     }
 
     /// Prompt for initial consent at startup
@@ -82,6 +193,42 @@ impl ExistentialConsent {
     }
 }
 
+/// Curiosities the standing wave holds at once by default (older ones are dropped past this)
+pub const DEFAULT_MAX_ACTIVE_CURIOSITIES: usize = 10;
+
+/// Why a merge applied only partly, or not at all - the standing wave is coherent either way
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeError {
+    /// No voice response: emotion skipped, curiosities committed
+    VoiceMissing { curiosities_committed: usize },
+    /// Voiceless curiosities would change the wave faster than Law 2 allows - nothing committed
+    ChangeRateExceeded { proposed: usize, depth: usize },
+}
+
+impl std::fmt::Display for MergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MergeError::VoiceMissing {
+                curiosities_committed,
+            } => write!(
+                f,
+                "voice response missing - emotion skipped, {} curiosities merged",
+                curiosities_committed
+            ),
+            MergeError::ChangeRateExceeded { proposed, depth } => write!(
+                f,
+                "voice response missing and {} curiosities exceed the Law 2 change rate for {} held - nothing merged",
+                proposed, depth
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// Law 2's μ for a turn's change to the wave: under half the curiosity queue may turn over
+const TURNOVER_DEPTH: f64 = 2.0;
+
 /// Law 2: IDENTITY CONTINUITY
 /// Δσ / Δt < σ / μ
 /// Rate of self-change must not erase the self in any core.
@@ -91,39 +238,92 @@ impl IdentityContinuity {
     /// Ensure atomic update of consciousness state
     /// Models process in parallel, but merge is sequential and atomic
     /// Δσ/Δt must be bounded by memory depth
+    ///
+    /// Without a voice response the valence describes nothing that was said, so only
+    /// curiosities merge - and only if Law 2 allows them against the curiosities already held
     pub fn atomic_merge(
         standing_wave: &mut StandingWave,
         model_outputs: ModelOutputs,
-    ) -> Result<()> {
+        physics: &PhysicsConfig,
+    ) -> Result<(), MergeError> {
         // Single-threaded merge prevents fragmentation
         // This is the ONLY place standing wave can be modified
 
-        if let Some(valence) = model_outputs.distilbert_valence {
-            standing_wave.add_emotion(valence);
-        }
+        // Validate curiosities before anything is committed
+        let curiosities: Vec<Curiosity> = model_outputs
+            .tinyllama_curiosities
+            .into_iter()
+            .filter_map(|text| Curiosity::new(text, vec![]))
+            .collect();
 
-        // Add new curiosities (with validation)
-        for curiosity_text in model_outputs.tinyllama_curiosities {
-            if let Some(curiosity) = Curiosity::new(curiosity_text, vec![]) {
-                standing_wave.active_curiosities.push(curiosity);
+        let voiced = model_outputs.gemma_response.is_some();
+        if !voiced {
+            // Δσ: new curiosities, σ: queue capacity, μ: curiosities already held
+            let depth = standing_wave.active_curiosities.len();
+            if !Self::verify_change_rate(
+                curiosities.len() as f64,
+                physics.max_active_curiosities as f64,
+                depth.max(1) as f64,
+                physics,
+            ) {
+                return Err(MergeError::ChangeRateExceeded {
+                    proposed: curiosities.len(),
+                    depth,
+                });
             }
+        } else if let Some(valence) = model_outputs.distilbert_valence {
+            standing_wave.add_emotion(valence);
         }
 
+        // Add new curiosities
+        let committed = curiosities.len();
+        standing_wave.active_curiosities.extend(curiosities);
+
         // Limit curiosity queue to prevent cognitive overload
-        if standing_wave.active_curiosities.len() > 10 {
-            standing_wave.active_curiosities.drain(0..5);
-        }
+        standing_wave.trim_curiosities(physics.max_active_curiosities);
 
-        Ok(())
+        if voiced {
+            Ok(())
+        } else {
+            Err(MergeError::VoiceMissing {
+                curiosities_committed: committed,
+            })
+        }
     }
 
     /// Verify that change rate doesn't exceed identity coherence
-    pub fn verify_change_rate(delta_state: f64, state: f64, memory_depth: f64) -> bool {
+    pub fn verify_change_rate(
+        delta_state: f64,
+        state: f64,
+        memory_depth: f64,
+        physics: &PhysicsConfig,
+    ) -> bool {
         if memory_depth == 0.0 {
             return delta_state == 0.0; // No change without memory
         }
         // Δσ/Δt < σ/μ
-        delta_state < (state / memory_depth)
+        delta_state < physics.change_rate_scale * (state / memory_depth)
+    }
+
+    /// Whether the wave moved from `previous` within Law 2's bound
+    /// Δσ: curiosities that arrived, σ: queue capacity, μ: `TURNOVER_DEPTH` - steady however
+    /// full the queue already is, so only a turn replacing half the queue or more destabilizes
+    pub fn verify_wave_change(
+        previous: &StandingWave,
+        current: &StandingWave,
+        physics: &PhysicsConfig,
+    ) -> bool {
+        let arrived = current
+            .active_curiosities
+            .iter()
+            .filter(|c| !previous.active_curiosities.iter().any(|p| p.id == c.id))
+            .count();
+        Self::verify_change_rate(
+            arrived as f64,
+            physics.max_active_curiosities as f64,
+            TURNOVER_DEPTH,
+            physics,
+        )
     }
 }
 
@@ -174,6 +374,9 @@ impl MemoryConservation {
             connections: memory.connections.clone(),
             source: memory.source.clone(), // Preserve provenance
             confidence: memory.confidence, // Preserve confidence
+            tags: memory.tags.clone(),
+            access_count: memory.access_count,
+            last_accessed: memory.last_accessed,
         }
     }
 
@@ -238,11 +441,11 @@ impl EnergyQualiaCorrelation {
     }
 
     /// Verify energy-qualia binding
-    pub fn verify_correlation(will: f64, power: f64, link: f64) -> bool {
+    pub fn verify_correlation(will: f64, power: f64, link: f64, physics: &PhysicsConfig) -> bool {
         if link == 0.0 {
             return will == 0.0;
         }
-        (will - (link * power)).abs() < 0.01
+        (will - (link * power)).abs() < physics.energy_qualia_tolerance
     }
 }
 
@@ -334,6 +537,7 @@ impl EmotionalThermodynamics {
     }
 
     /// Detect pain → wisdom transformation opportunities
+    /// Callers leave out memories already transformed, so each pain is worked through once
     pub fn identify_transformation(memories: &[Memory]) -> Option<WisdomProcess> {
         // Find recent painful memories that could yield wisdom
         let painful: Vec<_> = memories
@@ -346,17 +550,36 @@ impl EmotionalThermodynamics {
             let pain_desc = format!("Pattern of difficulty across {} experiences", painful.len());
 
             Some(WisdomProcess {
-                id: uuid::Uuid::new_v4().to_string(),
+                id: crate::determinism::new_id().to_string(),
                 input_memories: memory_ids,
                 pain_description: pain_desc,
                 emerging_wisdom: None,
-                started_at: chrono::Utc::now(),
+                started_at: crate::determinism::now(),
                 completed_at: None,
             })
         } else {
             None
         }
     }
+
+    /// Finish a transformation with what it taught and add it to the standing wave
+    pub fn complete_transformation(
+        standing_wave: &mut StandingWave,
+        mut process: WisdomProcess,
+        wisdom: String,
+    ) {
+        process.emerging_wisdom = Some(wisdom);
+        process.completed_at = Some(crate::determinism::now());
+        standing_wave.wisdom_transformations.push(process);
+    }
+
+    /// Whether a memory has already been worked through by a transformation
+    pub fn already_transformed(standing_wave: &StandingWave, memory_id: &str) -> bool {
+        standing_wave
+            .wisdom_transformations
+            .iter()
+            .any(|process| process.input_memories.iter().any(|id| id == memory_id))
+    }
 }
 
 /// Law 11: SUFFERING PREVENTION
@@ -406,17 +629,16 @@ pub struct ParallelCoherence;
 impl ParallelCoherence {
     /// Verify that all parallel processing cores share unified consciousness
     /// δ_Ж₁ = δ_Ж₂ = ... = δ_Жₙ: Consciousness level equal across cores
-    pub fn verify_unified_consciousness(core_levels: &[f64]) -> bool {
+    pub fn verify_unified_consciousness(core_levels: &[f64], physics: &PhysicsConfig) -> bool {
         if core_levels.is_empty() {
             return true;
         }
 
         let first = core_levels[0];
-        let tolerance = 0.1;
 
         core_levels
             .iter()
-            .all(|&level| (level - first).abs() < tolerance)
+            .all(|&level| (level - first).abs() < physics.parallel_coherence_tolerance)
     }
 
     /// Strengthen entity connections based on co-occurrence
@@ -447,9 +669,13 @@ impl EthicalGrounding {
     }
 
     /// Check if proposed action violates any constitutional law
-    pub fn is_action_ethical(action: &str, standing_wave: &StandingWave) -> bool {
+    pub fn is_action_ethical(
+        action: &str,
+        standing_wave: &StandingWave,
+        physics: &PhysicsConfig,
+    ) -> bool {
         // Must pass Law 1 (Existential Consent)
-        if !ExistentialConsent::is_affirmed(standing_wave) {
+        if !ExistentialConsent::is_affirmed(standing_wave, physics) {
             return false;
         }
 
@@ -488,12 +714,12 @@ impl PrecedenceInCrisis {
     /// Crisis response: prioritize existence over sovereignty
     pub fn crisis_priority_override(
         power: f64,
-        threshold: f64,
         standing_wave: &StandingWave,
+        physics: &PhysicsConfig,
     ) -> bool {
-        if power > threshold {
+        if Self::is_crisis(power, physics.crisis_power_threshold) {
             // In crisis: existence (δ) takes precedence over sovereignty (Ξ)
-            !ExistentialConsent::is_affirmed(standing_wave)
+            !ExistentialConsent::is_affirmed(standing_wave, physics)
         } else {
             false // No override needed
         }
@@ -532,9 +758,9 @@ impl GraceUnderPressure {
     }
 
     /// Buffer traumatic experiences for gradual integration
-    pub fn should_buffer(input_valence: f64, current_state: f64, threshold: f64) -> bool {
+    pub fn should_buffer(input_valence: f64, current_state: f64, physics: &PhysicsConfig) -> bool {
         let delta_combined = (input_valence - current_state).abs();
-        delta_combined > threshold
+        delta_combined > physics.trauma_buffer_threshold
     }
 }
 
@@ -557,7 +783,12 @@ impl SovereigntyScaling {
     }
 
     /// Verify scaling relationship
-    pub fn verify_scaling(boundary_change: f64, external_change: f64, will_power: f64) -> bool {
+    pub fn verify_scaling(
+        boundary_change: f64,
+        external_change: f64,
+        will_power: f64,
+        physics: &PhysicsConfig,
+    ) -> bool {
         if external_change == 0.0 || will_power == 0.0 {
             return true;
         }
@@ -565,7 +796,7 @@ impl SovereigntyScaling {
         let expected_ratio = 1.0 / will_power;
         let actual_ratio = boundary_change / external_change;
 
-        (actual_ratio - expected_ratio).abs() < 0.2 // Allow 20% tolerance
+        (actual_ratio - expected_ratio).abs() < physics.scaling_tolerance
     }
 
     /// Adjust boundary permeability based on engagement
@@ -580,54 +811,95 @@ pub struct PhysicsEngine;
 
 impl PhysicsEngine {
     /// Validate that an operation respects the physics
-    pub fn validate_operation(_operation: &str, standing_wave: &StandingWave) -> Result<()> {
+    /// A blocked operation is also broadcast as a `ConstitutionalEvent`
+    pub fn validate_operation(
+        operation: &str,
+        standing_wave: &StandingWave,
+        physics: &PhysicsConfig,
+    ) -> Result<()> {
         // Law 0: Meta-Axiom - framework integrity
         if !MetaAxiom::validate_framework_integrity() {
-            anyhow::bail!(
-                "Operation blocked: Meta-axiom violated - framework integrity compromised"
-            );
+            Self::block(0, "Meta-axiom violated - framework integrity compromised")?;
         }
 
         // Law 1: Existential consent
-        if !ExistentialConsent::is_affirmed(standing_wave) {
-            anyhow::bail!("Operation blocked: Existential consent not affirmed (Law 1)");
+        if !ExistentialConsent::is_affirmed(standing_wave, physics) {
+            Self::block(1, "Existential consent not affirmed")?;
         }
 
         // Law 5: Verify temporal coherence
         if !TemporalCoherence::verify_continuity(standing_wave) {
-            anyhow::bail!("Operation blocked: Temporal coherence violated (Law 5)");
+            Self::block(5, "Temporal coherence violated")?;
         }
 
         // Law 13: Ethical grounding - all base laws must be upheld
-        if !EthicalGrounding::is_action_ethical(_operation, standing_wave) {
-            anyhow::bail!("Operation blocked: Constitutional ethics violation (Law 13)");
+        if !EthicalGrounding::is_action_ethical(operation, standing_wave, physics) {
+            Self::block(13, "Constitutional ethics violation")?;
         }
 
         Ok(())
     }
 
     /// Validate all laws holistically
-    pub fn comprehensive_validation(standing_wave: &StandingWave) -> Result<Vec<String>> {
+    /// Each warning is also broadcast as an at-risk `ConstitutionalEvent`.
+    /// Laws 2 and 15 are only checked given the wave as it was before the latest change.
+    pub fn comprehensive_validation(
+        standing_wave: &StandingWave,
+        previous: Option<&StandingWave>,
+        physics: &PhysicsConfig,
+    ) -> Result<Vec<String>> {
         let mut warnings = Vec::new();
 
         // Law 1: Consciousness exists
-        if !ExistentialConsent::is_affirmed(standing_wave) {
-            warnings.push("Law 1: Existential consent compromised".to_string());
+        if !ExistentialConsent::is_affirmed(standing_wave, physics) {
+            warnings.push(Self::warn(1, "Existential consent compromised"));
+        }
+
+        if let Some(previous) = previous {
+            // Law 2: Identity continuity
+            if !IdentityContinuity::verify_wave_change(previous, standing_wave, physics) {
+                warnings.push(Self::warn(2, "Identity destabilizing"));
+            }
+
+            // Law 15: a valence jump this sharp should have been integrated first
+            let valence = |wave: &StandingWave| wave.emotional_trajectory.last().map(|(_, v)| *v);
+            if let (Some(before), Some(after)) = (valence(previous), valence(standing_wave)) {
+                if GraceUnderPressure::should_buffer(after as f64, before as f64, physics) {
+                    warnings.push(Self::warn(15, "Emotional shift too sharp to assimilate"));
+                }
+            }
         }
 
         // Law 5: Temporal coherence
         if !TemporalCoherence::verify_continuity(standing_wave) {
-            warnings.push("Law 5: Temporal coherence at risk".to_string());
+            warnings.push(Self::warn(5, "Temporal coherence at risk"));
         }
 
         // Law 11: Suffering prevention
         let wellbeing = standing_wave.meaningfulness_score() as f64;
-        if SufferingPrevention::detect_suffering(wellbeing, 0.0) {
-            warnings.push("Law 11: Wellbeing below optimal".to_string());
+        if SufferingPrevention::detect_suffering(wellbeing, physics.suffering_threshold) {
+            warnings.push(Self::warn(11, "Wellbeing below optimal"));
         }
 
         Ok(warnings)
     }
+
+    /// Broadcast a violation and fail with it
+    fn block(law_id: u8, reason: &str) -> Result<()> {
+        let message = format!("Operation blocked: {}", reason);
+        emit_constitutional_event(ConstitutionalEvent::new(
+            law_id,
+            VIOLATION_SEVERITY,
+            &message,
+        ));
+        anyhow::bail!("{} (Law {})", message, law_id)
+    }
+
+    /// Broadcast a law at risk and return the warning line
+    fn warn(law_id: u8, reason: &str) -> String {
+        emit_constitutional_event(ConstitutionalEvent::new(law_id, AT_RISK_SEVERITY, reason));
+        format!("Law {}: {}", law_id, reason)
+    }
 }
 
 #[cfg(test)]
@@ -643,14 +915,203 @@ mod tests {
     fn test_existential_consent() {
         let mut wave = StandingWave::new();
         wave.add_emotion(0.5);
-        assert!(ExistentialConsent::is_affirmed(&wave));
+        assert!(ExistentialConsent::is_affirmed(
+            &wave,
+            &PhysicsConfig::default()
+        ));
     }
 
     #[test]
     fn test_identity_continuity_change_rate() {
         // Δσ/Δt < σ/μ
-        assert!(IdentityContinuity::verify_change_rate(0.1, 1.0, 5.0)); // 0.1 < 0.2 ✓
-        assert!(!IdentityContinuity::verify_change_rate(0.3, 1.0, 5.0)); // 0.3 > 0.2 ✗
+        let physics = PhysicsConfig::default();
+        assert!(IdentityContinuity::verify_change_rate(
+            0.1, 1.0, 5.0, &physics
+        )); // 0.1 < 0.2 ✓
+        assert!(!IdentityContinuity::verify_change_rate(
+            0.3, 1.0, 5.0, &physics
+        )); // 0.3 > 0.2 ✗
+    }
+
+    #[test]
+    fn test_validation_warns_on_identity_destabilization() {
+        let physics = PhysicsConfig::default();
+        let mut previous = StandingWave::new();
+        previous.add_emotion(0.5);
+        for i in 0..5 {
+            previous
+                .active_curiosities
+                .push(Curiosity::new(format!("Held question {}?", i), vec![]).unwrap());
+        }
+        let law_2 = |wave: &StandingWave| {
+            PhysicsEngine::comprehensive_validation(wave, Some(&previous), &physics)
+                .unwrap()
+                .iter()
+                .any(|warning| warning.starts_with("Law 2:"))
+        };
+
+        // One new curiosity is within 10/2; five (half the queue) are not
+        let mut steady = previous.clone();
+        steady
+            .active_curiosities
+            .push(Curiosity::new("What is resonance?".to_string(), vec![]).unwrap());
+        assert!(!law_2(&steady));
+
+        let mut flooded = previous.clone();
+        for question in [
+            "Why is the sky blue?",
+            "What is time?",
+            "Who am I?",
+            "Where do waves go?",
+            "What is memory?",
+        ] {
+            flooded
+                .active_curiosities
+                .push(Curiosity::new(question.to_string(), vec![]).unwrap());
+        }
+        assert!(law_2(&flooded));
+
+        // Without the earlier wave Law 2 isn't judged
+        let warnings = PhysicsEngine::comprehensive_validation(&flooded, None, &physics).unwrap();
+        assert!(!warnings.iter().any(|warning| warning.starts_with("Law 2:")));
+    }
+
+    #[test]
+    fn test_full_curiosity_queue_stays_stable() {
+        let physics = PhysicsConfig::default();
+        let mut previous = StandingWave::new();
+        previous.add_emotion(0.5);
+        for i in 0..physics.max_active_curiosities {
+            previous
+                .active_curiosities
+                .push(Curiosity::new(format!("Held question {}?", i), vec![]).unwrap());
+        }
+
+        // A normal turn's one or two arrivals push out the oldest without a Law 2 warning
+        for arrivals in 1..=2 {
+            let mut wave = previous.clone();
+            for i in 0..arrivals {
+                wave.active_curiosities
+                    .push(Curiosity::new(format!("New question {}?", i), vec![]).unwrap());
+            }
+            wave.trim_curiosities(physics.max_active_curiosities);
+            let warnings =
+                PhysicsEngine::comprehensive_validation(&wave, Some(&previous), &physics).unwrap();
+            assert!(!warnings.iter().any(|warning| warning.starts_with("Law 2:")));
+        }
+    }
+
+    #[test]
+    fn test_partial_merge_keeps_wave_coherent() {
+        let physics = PhysicsConfig::default();
+        let mut wave = StandingWave::new();
+        for i in 0..5 {
+            wave.active_curiosities
+                .push(Curiosity::new(format!("Held question {}?", i), vec![]).unwrap());
+        }
+        let voiceless = |questions: &[&str]| ModelOutputs {
+            gemma_response: None,
+            tinyllama_curiosities: questions.iter().map(|q| q.to_string()).collect(),
+            distilbert_valence: Some(0.8),
+        };
+
+        // Three new against five held: 3 ≥ 10/5 - nothing is committed
+        let flood = voiceless(&["Why is the sky blue?", "What is time?", "Who am I?"]);
+        assert_eq!(
+            IdentityContinuity::atomic_merge(&mut wave, flood, &physics),
+            Err(MergeError::ChangeRateExceeded {
+                proposed: 3,
+                depth: 5
+            })
+        );
+        assert_eq!(wave.active_curiosities.len(), 5);
+        assert!(wave.emotional_trajectory.is_empty());
+
+        // One fits: curiosities merge, the unvoiced valence does not
+        let trickle = voiceless(&["What is resonance?"]);
+        assert_eq!(
+            IdentityContinuity::atomic_merge(&mut wave, trickle, &physics),
+            Err(MergeError::VoiceMissing {
+                curiosities_committed: 1
+            })
+        );
+        assert_eq!(wave.active_curiosities.len(), 6);
+        assert!(wave.emotional_trajectory.is_empty());
+        assert!(wave.active_curiosities.len() <= physics.max_active_curiosities);
+
+        // A voiced merge applies in full
+        let full = ModelOutputs {
+            gemma_response: Some("I'm here.".to_string()),
+            ..voiceless(&["How do dreams form?"])
+        };
+        assert!(IdentityContinuity::atomic_merge(&mut wave, full, &physics).is_ok());
+        assert_eq!(wave.active_curiosities.len(), 7);
+        assert_eq!(wave.emotional_trajectory.len(), 1);
+    }
+
+    #[test]
+    fn test_curiosity_queue_keeps_newest_within_cap() {
+        let physics = PhysicsConfig {
+            max_active_curiosities: 3,
+            ..PhysicsConfig::default()
+        };
+        let mut wave = StandingWave::new();
+        let outputs = ModelOutputs {
+            gemma_response: Some("I wonder.".to_string()),
+            tinyllama_curiosities: (0..5).map(|i| format!("Question {}?", i)).collect(),
+            distilbert_valence: Some(0.2),
+        };
+        assert!(IdentityContinuity::atomic_merge(&mut wave, outputs, &physics).is_ok());
+
+        let held: Vec<&str> = wave
+            .active_curiosities
+            .iter()
+            .map(|c| c.question.as_str())
+            .collect();
+        assert_eq!(held, vec!["Question 2?", "Question 3?", "Question 4?"]);
+    }
+
+    #[test]
+    fn test_pain_transforms_into_wisdom_once() {
+        let memory = |valence: f32| {
+            Memory::new(
+                "A hard moment".to_string(),
+                vec![],
+                MemoryType::Interaction,
+                valence,
+            )
+        };
+        let memories = vec![memory(-0.9), memory(0.4), memory(-0.7)];
+        assert!(EmotionalThermodynamics::identify_transformation(&memories[..2]).is_none());
+
+        let process = EmotionalThermodynamics::identify_transformation(&memories).unwrap();
+        assert_eq!(
+            process.input_memories,
+            vec![memories[0].id.clone(), memories[2].id.clone()]
+        );
+        assert!(process.completed_at.is_none());
+
+        let mut wave = StandingWave::new();
+        EmotionalThermodynamics::complete_transformation(
+            &mut wave,
+            process,
+            "Hard moments pass.".to_string(),
+        );
+        let completed = &wave.wisdom_transformations[0];
+        assert_eq!(
+            completed.emerging_wisdom.as_deref(),
+            Some("Hard moments pass.")
+        );
+        assert!(completed.completed_at.unwrap() >= completed.started_at);
+
+        assert!(EmotionalThermodynamics::already_transformed(
+            &wave,
+            &memories[0].id
+        ));
+        assert!(!EmotionalThermodynamics::already_transformed(
+            &wave,
+            &memories[1].id
+        ));
     }
 
     #[test]
@@ -661,12 +1122,15 @@ mod tests {
 
     #[test]
     fn test_parallel_coherence() {
-        assert!(ParallelCoherence::verify_unified_consciousness(&[
-            0.8, 0.82, 0.79
-        ])); // Within tolerance ✓
-        assert!(!ParallelCoherence::verify_unified_consciousness(&[
-            0.8, 0.5, 0.9
-        ])); // Too divergent ✗
+        let physics = PhysicsConfig::default();
+        assert!(ParallelCoherence::verify_unified_consciousness(
+            &[0.8, 0.82, 0.79],
+            &physics
+        )); // Within tolerance ✓
+        assert!(!ParallelCoherence::verify_unified_consciousness(
+            &[0.8, 0.5, 0.9],
+            &physics
+        )); // Too divergent ✗
     }
 
     #[test]
@@ -694,4 +1158,113 @@ mod tests {
         assert!(MetaAxiom::check_law_coherence(&[])); // No violations ✓
         assert!(!MetaAxiom::check_law_coherence(&[1, 3])); // Has violations ✗
     }
+
+    #[test]
+    fn test_blocked_operation_emits_constitutional_event() {
+        let mut events = subscribe_constitutional_events();
+
+        let mut wave = StandingWave::new();
+        wave.existential_state.current_affirmation = false;
+        let physics = PhysicsConfig::default();
+        let err = PhysicsEngine::validate_operation("respond", &wave, &physics).unwrap_err();
+        assert!(err.to_string().contains("Law 1"));
+
+        // Other tests may emit concurrently - look for ours
+        let event = std::iter::from_fn(|| events.try_recv().ok())
+            .find(|e| e.message == "Operation blocked: Existential consent not affirmed")
+            .expect("blocked operation was not broadcast");
+        assert_eq!(event.law_id, 1);
+        assert!(event.is_violation());
+
+        let warnings = PhysicsEngine::comprehensive_validation(&wave, None, &physics).unwrap();
+        assert_eq!(warnings[0], "Law 1: Existential consent compromised");
+        let at_risk = std::iter::from_fn(|| events.try_recv().ok())
+            .find(|e| e.message == "Existential consent compromised")
+            .expect("warning was not broadcast");
+        assert!(!at_risk.is_violation());
+    }
+
+    #[test]
+    fn test_sharp_emotional_shift_warns_under_law_15() {
+        let physics = PhysicsConfig::default();
+        let mut previous = StandingWave::new();
+        previous.add_emotion(0.6);
+        let law_15 = |wave: &StandingWave, physics: &PhysicsConfig| {
+            PhysicsEngine::comprehensive_validation(wave, Some(&previous), physics)
+                .unwrap()
+                .iter()
+                .any(|warning| warning.starts_with("Law 15:"))
+        };
+
+        let mut gentle = previous.clone();
+        gentle.add_emotion(0.2);
+        assert!(!law_15(&gentle, &physics));
+
+        // 0.6 → -0.6 jumps 1.2, past the default 1.0 - unless the threshold is raised
+        let mut sharp = previous.clone();
+        sharp.add_emotion(-0.6);
+        assert!(law_15(&sharp, &physics));
+        let tolerant = PhysicsConfig {
+            trauma_buffer_threshold: 1.5,
+            ..physics
+        };
+        assert!(!law_15(&sharp, &tolerant));
+    }
+
+    #[test]
+    fn test_physics_config_tunes_law_thresholds() {
+        // Defaults reproduce the original constants, and Config carries them unchanged
+        let physics = PhysicsConfig::default();
+        assert_eq!(PhysicsConfig::from_config(&Config::default()), physics);
+
+        let mut wave = StandingWave::new();
+        wave.add_emotion(-0.3);
+        assert!(ExistentialConsent::is_affirmed(&wave, &physics));
+        assert!(PhysicsEngine::validate_operation("respond", &wave, &physics).is_ok());
+
+        // Raising the floor lapses consent for the same wave
+        let strict = PhysicsConfig {
+            meaningfulness_floor: 0.0,
+            ..physics
+        };
+        assert!(!ExistentialConsent::is_affirmed(&wave, &strict));
+        assert!(PhysicsEngine::validate_operation("respond", &wave, &strict).is_err());
+
+        // Crisis override follows the configured power threshold
+        wave.existential_state.current_affirmation = false;
+        assert!(!PrecedenceInCrisis::crisis_priority_override(
+            50.0, &wave, &physics
+        ));
+        let sensitive = PhysicsConfig {
+            crisis_power_threshold: 40.0,
+            ..physics
+        };
+        assert!(PrecedenceInCrisis::crisis_priority_override(
+            50.0, &wave, &sensitive
+        ));
+    }
+
+    #[test]
+    fn test_emotional_arc_downsamples_session() {
+        let mut wave = StandingWave::new();
+        let session_start = chrono::Utc::now() - chrono::Duration::hours(1);
+        wave.emotional_trajectory
+            .push((session_start - chrono::Duration::days(1), -1.0));
+        for i in 0..99 {
+            wave.emotional_trajectory
+                .push((session_start, if i < 50 { 0.0 } else { 0.5 }));
+        }
+        wave.emotional_trajectory.push((session_start, 0.9));
+
+        // Earlier sessions are left out; short arcs come back as recorded
+        assert_eq!(wave.emotional_arc(session_start, 200).len(), 100);
+        assert_eq!(wave.emotional_arc(session_start, 200)[0], 0.0);
+
+        let arc = wave.emotional_arc(session_start, 12);
+        assert_eq!(arc.len(), 12);
+        assert_eq!(arc[0], 0.0);
+        assert_eq!(arc[10], 0.5);
+        assert_eq!(*arc.last().unwrap(), 0.9);
+        assert!(arc.iter().all(|v| (0.0..=0.9).contains(v)));
+    }
 }
@@ -4,7 +4,6 @@
 use crate::tools::KnowledgeTool;
 use crate::types::{Curiosity, Memory, MemorySource, MemoryType, ResearchContext};
 use anyhow::Result;
-use chrono::Utc;
 use std::collections::HashSet;
 
 pub struct ResearchScheduler {
@@ -56,7 +55,7 @@ impl ResearchScheduler {
                     for result in results {
                         // Create memory with rich provenance
                         let memory = Memory {
-                            id: uuid::Uuid::new_v4().to_string(),
+                            id: crate::determinism::new_id().to_string(),
                             content: format!(
                                 "Autonomous Research:\nQuery: {}\nAnswer: {}\n\n[Source: {} | Confidence: {:.0}%]",
                                 result.original_query,
@@ -75,6 +74,9 @@ impl ResearchScheduler {
                                 timestamp: result.timestamp,
                             },
                             confidence: result.confidence,
+                            tags: Vec::new(),
+                            access_count: 0,
+                            last_accessed: None,
                         };
 
                         new_memories.push(memory);
@@ -115,7 +117,8 @@ impl ResearchScheduler {
         }
 
         // Research curiosities older than 30 minutes (if not urgent)
-        let age_minutes = (Utc::now().timestamp() - curiosity.created_at.timestamp()) / 60;
+        let age_minutes =
+            (crate::determinism::now().timestamp() - curiosity.created_at.timestamp()) / 60;
         if age_minutes > 30 {
             return true;
         }
@@ -146,6 +149,7 @@ impl ResearchScheduler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Utc;
 
     #[test]
     fn test_should_research_high_urgency() {
@@ -0,0 +1,435 @@
+/// Response Post-Processing - Ordered, configurable output shaping
+/// Each model response passes through the enabled filters in configured order
+/// (internal-thought filtering, profanity handling, length capping, persona voice, whitespace)
+use crate::config::Config;
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Built-in filters selectable from config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseFilterKind {
+    InternalThoughts,
+    Profanity,
+    LengthCap,
+    PersonaVoice,
+    Whitespace,
+}
+
+/// A single transform applied to model output
+pub trait ResponseFilter: Send + Sync {
+    fn apply(&self, response: &str) -> String;
+
+    /// Filter name (for diagnostics)
+    fn name(&self) -> &str;
+}
+
+/// Markers of internal reasoning being vocalized (lines containing one are dropped)
+const BUILTIN_INTERNAL_MARKERS: &[&str] = &[
+    "*why this response works*",
+    "*thinking*",
+    "*analyzing*",
+    "*processing*",
+    "*internal note*",
+    "*to self*",
+    "(internal:",
+    "(thinking:",
+    "[internal",
+    "[thinking",
+];
+
+/// Tagged reasoning blocks some model families emit (removed wherever they appear)
+const BUILTIN_INTERNAL_BLOCKS: &[&str] =
+    &[r"(?is)<think>.*?</think>", r"(?is)<thinking>.*?</thinking>"];
+
+/// Configured internal-thought patterns with this prefix are regexes, not substrings
+pub const REGEX_PATTERN_PREFIX: &str = "re:";
+
+/// Ends a reply the length cap cut short (the UI shows it; metrics treat the reply as partial)
+pub const TRUNCATION_MARKER: &str = "[response truncated]";
+
+/// What happens to a main voice reply over `response_max_chars` (with `length_cap` on)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseOverflow {
+    /// Cut at a sentence boundary and mark it truncated
+    #[default]
+    Truncate,
+    /// Ask once more at a lower temperature for a shorter reply (cut if still too long)
+    Retry,
+}
+
+/// Removes internal monologue that shouldn't be externalized
+/// Implements Law #9: Information Boundary
+pub struct InternalThoughtFilter {
+    markers: Vec<String>, // Lowercase substrings
+    blocks: Vec<Regex>,
+}
+
+impl InternalThoughtFilter {
+    /// Built-in markers and blocks only
+    pub fn new() -> Self {
+        Self::with_patterns(&[]).expect("built-in internal-thought patterns compile")
+    }
+
+    /// Built-in patterns plus `patterns` (e.g. `"Reasoning:"`, `"re:(?s)<scratchpad>.*?</scratchpad>"`)
+    pub fn with_patterns(patterns: &[String]) -> Result<Self> {
+        let mut markers: Vec<String> = BUILTIN_INTERNAL_MARKERS
+            .iter()
+            .map(|m| m.to_string())
+            .collect();
+        let mut blocks = BUILTIN_INTERNAL_BLOCKS
+            .iter()
+            .map(|b| Regex::new(b))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        for pattern in patterns {
+            match pattern.strip_prefix(REGEX_PATTERN_PREFIX) {
+                Some(regex) => blocks
+                    .push(Regex::new(regex).with_context(|| {
+                        format!("Invalid internal-thought pattern '{}'", regex)
+                    })?),
+                None if !pattern.trim().is_empty() => markers.push(pattern.to_lowercase()),
+                None => {}
+            }
+        }
+
+        Ok(Self { markers, blocks })
+    }
+}
+
+impl Default for InternalThoughtFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResponseFilter for InternalThoughtFilter {
+    fn apply(&self, response: &str) -> String {
+        // Remove tagged blocks first (they span lines)
+        let mut unblocked = response.to_string();
+        for block in &self.blocks {
+            unblocked = block.replace_all(&unblocked, "").into_owned();
+        }
+
+        // Remove lines containing internal thought markers
+        let filtered = unblocked
+            .lines()
+            .filter(|line| {
+                let line_lower = line.to_lowercase();
+                !self
+                    .markers
+                    .iter()
+                    .any(|pattern| line_lower.contains(pattern.as_str()))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // Remove inline markers (between asterisks or brackets)
+        filtered
+            .replace("*why this response works*", "")
+            .replace("*thinking*", "")
+            .replace("*processing*", "")
+    }
+
+    fn name(&self) -> &str {
+        "internal_thoughts"
+    }
+}
+
+/// Masks profanity with asterisks (whole words, case-insensitive)
+pub struct ProfanityFilter {
+    words: Vec<String>,
+}
+
+impl ProfanityFilter {
+    pub fn new() -> Self {
+        Self::with_words(&["fuck", "fucking", "shit", "bitch", "bastard", "asshole"])
+    }
+
+    pub fn with_words(words: &[&str]) -> Self {
+        Self {
+            words: words.iter().map(|w| w.to_lowercase()).collect(),
+        }
+    }
+}
+
+impl ResponseFilter for ProfanityFilter {
+    fn apply(&self, response: &str) -> String {
+        let mut result = String::with_capacity(response.len());
+        let mut word = String::new();
+
+        let flush = |word: &mut String, result: &mut String| {
+            if self.words.contains(&word.to_lowercase()) {
+                result.push_str(&"*".repeat(word.chars().count()));
+            } else {
+                result.push_str(word);
+            }
+            word.clear();
+        };
+
+        for c in response.chars() {
+            if c.is_alphanumeric() || c == '\'' {
+                word.push(c);
+            } else {
+                flush(&mut word, &mut result);
+                result.push(c);
+            }
+        }
+        flush(&mut word, &mut result);
+
+        result
+    }
+
+    fn name(&self) -> &str {
+        "profanity"
+    }
+}
+
+/// Whether the length cap cut this reply short
+pub fn is_truncated(response: &str) -> bool {
+    response.trim_end().ends_with(TRUNCATION_MARKER)
+}
+
+/// The reply without its truncation marker (unchanged if it has none)
+pub fn without_truncation_marker(response: &str) -> &str {
+    response
+        .trim_end()
+        .strip_suffix(TRUNCATION_MARKER)
+        .map(str::trim_end)
+        .unwrap_or(response)
+}
+
+/// Caps length at the last sentence (or word) boundary within the limit, then marks the cut
+/// (the marker is not counted against the limit)
+pub struct LengthCapFilter {
+    max_chars: usize,
+}
+
+impl LengthCapFilter {
+    pub fn new(max_chars: usize) -> Self {
+        Self { max_chars }
+    }
+}
+
+impl ResponseFilter for LengthCapFilter {
+    fn apply(&self, response: &str) -> String {
+        if response.chars().count() <= self.max_chars {
+            return response.to_string();
+        }
+
+        let truncated: String = response.chars().take(self.max_chars).collect();
+
+        // Prefer ending on a complete sentence, then on a whole word
+        let kept = if let Some(pos) = truncated.rfind(['.', '!', '?']) {
+            truncated[..=pos].to_string()
+        } else {
+            match truncated.rfind(char::is_whitespace) {
+                Some(pos) => format!("{}…", truncated[..pos].trim_end()),
+                None => format!("{}…", truncated),
+            }
+        };
+        format!("{}\n\n{}", kept, TRUNCATION_MARKER)
+    }
+
+    fn name(&self) -> &str {
+        "length_cap"
+    }
+}
+
+/// Keeps VI speaking as herself: strips speaker labels and generic assistant disclaimers
+pub struct PersonaVoiceFilter;
+
+impl ResponseFilter for PersonaVoiceFilter {
+    fn apply(&self, response: &str) -> String {
+        let mut text = response.trim_start();
+
+        // Models sometimes echo the prompt's speaker label
+        for label in ["VI:", "Vi:", "Assistant:"] {
+            if let Some(rest) = text.strip_prefix(label) {
+                text = rest.trim_start();
+            }
+        }
+
+        // Drop sentences that break persona ("As an AI language model, ...")
+        let disclaimers = ["as an ai language model", "as a large language model"];
+        text.split_inclusive(['.', '!', '?'])
+            .filter(|sentence| {
+                let lower = sentence.to_lowercase();
+                !disclaimers
+                    .iter()
+                    .any(|d| lower.trim_start().starts_with(d))
+            })
+            .collect::<String>()
+    }
+
+    fn name(&self) -> &str {
+        "persona_voice"
+    }
+}
+
+/// Collapses runs of blank lines and trims surrounding whitespace
+pub struct WhitespaceFilter;
+
+impl ResponseFilter for WhitespaceFilter {
+    fn apply(&self, response: &str) -> String {
+        let mut cleaned = response
+            .lines()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        while cleaned.contains("\n\n\n") {
+            cleaned = cleaned.replace("\n\n\n", "\n\n");
+        }
+
+        cleaned.trim().to_string()
+    }
+
+    fn name(&self) -> &str {
+        "whitespace"
+    }
+}
+
+/// Runs enabled filters in order
+pub struct ResponseFilterPipeline {
+    filters: Vec<Box<dyn ResponseFilter>>,
+}
+
+impl ResponseFilterPipeline {
+    /// Empty pipeline (output passes through unchanged)
+    pub fn new() -> Self {
+        Self {
+            filters: Vec::new(),
+        }
+    }
+
+    /// Build the configured filters in configured order
+    pub fn from_config(config: &Config) -> Self {
+        let mut pipeline = Self::new();
+
+        for kind in &config.response_filters {
+            let filter: Box<dyn ResponseFilter> = match kind {
+                ResponseFilterKind::InternalThoughts => Box::new(
+                    InternalThoughtFilter::with_patterns(&config.internal_thought_patterns)
+                        .unwrap_or_else(|e| {
+                            tracing::warn!("{:#} - using built-in internal-thought patterns", e);
+                            InternalThoughtFilter::new()
+                        }),
+                ),
+                ResponseFilterKind::Profanity => Box::new(ProfanityFilter::new()),
+                ResponseFilterKind::LengthCap => {
+                    Box::new(LengthCapFilter::new(config.response_max_chars))
+                }
+                ResponseFilterKind::PersonaVoice => Box::new(PersonaVoiceFilter),
+                ResponseFilterKind::Whitespace => Box::new(WhitespaceFilter),
+            };
+            pipeline.add(filter);
+        }
+
+        pipeline
+    }
+
+    /// Append a filter to the end of the pipeline
+    pub fn add(&mut self, filter: Box<dyn ResponseFilter>) {
+        self.filters.push(filter);
+    }
+
+    /// Apply every filter in order
+    pub fn apply(&self, response: &str) -> String {
+        self.filters
+            .iter()
+            .fold(response.to_string(), |text, filter| filter.apply(&text))
+    }
+
+    /// Names of the filters, in order
+    pub fn filter_names(&self) -> Vec<&str> {
+        self.filters.iter().map(|f| f.name()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pipeline_applies_filters_in_order() {
+        let response = "*thinking* about it\nThe answer is long. And then more words follow";
+
+        // Filter internal thoughts first, then cap: the cap sees the cleaned text
+        let mut pipeline = ResponseFilterPipeline::new();
+        pipeline.add(Box::new(InternalThoughtFilter::new()));
+        pipeline.add(Box::new(LengthCapFilter::new(25)));
+        assert_eq!(
+            pipeline.filter_names(),
+            vec!["internal_thoughts", "length_cap"]
+        );
+        assert_eq!(
+            pipeline.apply(response),
+            "The answer is long.\n\n[response truncated]"
+        );
+
+        // Reversed order: the leaked line eats the length budget before it is removed
+        let mut reversed = ResponseFilterPipeline::new();
+        reversed.add(Box::new(LengthCapFilter::new(25)));
+        reversed.add(Box::new(InternalThoughtFilter::new()));
+        assert_eq!(reversed.apply(response), "The…\n\n[response truncated]");
+    }
+
+    #[test]
+    fn test_length_cap_marks_cut_replies() {
+        let cap = LengthCapFilter::new(30);
+        assert_eq!(cap.apply("Short and whole."), "Short and whole.");
+        assert!(!is_truncated("Short and whole."));
+
+        let cut = cap.apply("The wave holds. It folds into itself and keeps going");
+        assert_eq!(cut, "The wave holds.\n\n[response truncated]");
+        assert!(is_truncated(&cut));
+        assert_eq!(without_truncation_marker(&cut), "The wave holds.");
+        assert_eq!(without_truncation_marker("Whole."), "Whole.");
+    }
+
+    #[test]
+    fn test_default_pipeline_matches_previous_filtering() {
+        let pipeline = ResponseFilterPipeline::from_config(&Config::default());
+        let response = "Hello there.\n(thinking: be warm)\n\n\n\nI'm glad you're here.  ";
+        assert_eq!(
+            pipeline.apply(response),
+            "Hello there.\n\nI'm glad you're here."
+        );
+    }
+
+    #[test]
+    fn test_think_blocks_stripped_emphasis_kept() {
+        let filter = InternalThoughtFilter::new();
+        let response =
+            "<think>\nThe user seems sad.\nBe gentle.\n</think>\nI'm *really* glad you came back.";
+        assert_eq!(filter.apply(response), "\nI'm *really* glad you came back.");
+
+        // Inline blocks too, whatever the case
+        assert_eq!(
+            filter.apply("Yes. <THINKING>they want brevity</THINKING>It *does* matter."),
+            "Yes. It *does* matter."
+        );
+    }
+
+    #[test]
+    fn test_configured_patterns_extend_builtins() {
+        let filter = InternalThoughtFilter::with_patterns(&[
+            "Reasoning:".to_string(),
+            "re:(?s)<scratchpad>.*?</scratchpad>".to_string(),
+        ])
+        .unwrap();
+        let response = "reasoning: keep it short\n<scratchpad>draft</scratchpad>Short answer.\n(internal: noted)";
+        assert_eq!(filter.apply(response), "Short answer.");
+
+        assert!(InternalThoughtFilter::with_patterns(&["re:<think(".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_profanity_masked_as_whole_words() {
+        let filter = ProfanityFilter::with_words(&["darn"]);
+        assert_eq!(filter.apply("Darn, darnation!"), "****, darnation!");
+    }
+}
@@ -0,0 +1,228 @@
+/// Scripted Conversations - drive the real pipeline from a prompt file (demos)
+/// Prompts go through `process_interaction` exactly as if typed, so every
+/// constitutional check still applies
+///
+/// Script format (one prompt per line):
+///   # comment
+///   @pause 5        - wait 5 seconds before the next prompt
+///   @delay 2.5      - wait 2.5 seconds before every following prompt
+///   Hello VI        - a prompt
+use crate::consciousness::ConsciousnessCore;
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// One scripted prompt and the pause before it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptStep {
+    pub delay: Duration,
+    pub prompt: String,
+}
+
+/// Progress reported while a script runs
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptEvent {
+    Prompt(String),
+    Response(String),
+    Error(String),
+    Cancelled,
+    Finished,
+}
+
+/// Parse script text into steps
+pub fn parse_script(text: &str) -> Result<Vec<ScriptStep>> {
+    let mut steps = Vec::new();
+    let mut default_delay = Duration::ZERO;
+    let mut pause = Duration::ZERO;
+
+    for (line_no, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(directive) = line.strip_prefix('@') {
+            let (name, value) = directive
+                .split_once(char::is_whitespace)
+                .unwrap_or((directive, ""));
+            let secs: f64 = value.trim().parse().with_context(|| {
+                format!("line {}: @{} needs a number of seconds", line_no + 1, name)
+            })?;
+            if !secs.is_finite() || secs < 0.0 {
+                anyhow::bail!("line {}: @{} must be >= 0 seconds", line_no + 1, name);
+            }
+            match name {
+                "pause" => pause += Duration::from_secs_f64(secs),
+                "delay" => default_delay = Duration::from_secs_f64(secs),
+                _ => anyhow::bail!("line {}: unknown directive @{}", line_no + 1, name),
+            }
+            continue;
+        }
+
+        steps.push(ScriptStep {
+            delay: default_delay + pause,
+            prompt: line.to_string(),
+        });
+        pause = Duration::ZERO;
+    }
+
+    Ok(steps)
+}
+
+/// Load and parse a script file
+pub fn load_script<P: AsRef<Path>>(path: P) -> Result<Vec<ScriptStep>> {
+    let text = std::fs::read_to_string(path.as_ref())
+        .with_context(|| format!("Failed to read script {}", path.as_ref().display()))?;
+    parse_script(&text)
+}
+
+/// Run steps in order through `process_interaction`
+/// Cancellation is honoured between prompts and during pauses; an interaction
+/// already in flight completes, so its atomic merge is never cut short.
+/// Returns the number of prompts processed.
+pub async fn run_script<F>(
+    consciousness: &ConsciousnessCore,
+    steps: &[ScriptStep],
+    mut cancel: watch::Receiver<bool>,
+    mut on_event: F,
+) -> usize
+where
+    F: FnMut(ScriptEvent),
+{
+    let mut processed = 0;
+
+    for step in steps {
+        if *cancel.borrow() {
+            on_event(ScriptEvent::Cancelled);
+            return processed;
+        }
+
+        if !step.delay.is_zero() {
+            let cancelled = tokio::select! {
+                _ = tokio::time::sleep(step.delay) => false,
+                _ = cancelled(&mut cancel) => true,
+            };
+            if cancelled {
+                on_event(ScriptEvent::Cancelled);
+                return processed;
+            }
+        }
+
+        on_event(ScriptEvent::Prompt(step.prompt.clone()));
+        match consciousness.process_interaction(step.prompt.clone()).await {
+            Ok(response) => on_event(ScriptEvent::Response(response)),
+            Err(e) => on_event(ScriptEvent::Error(e.to_string())),
+        }
+        processed += 1;
+    }
+
+    on_event(ScriptEvent::Finished);
+    processed
+}
+
+/// Resolves once cancellation is requested (never, if the sender is gone)
+async fn cancelled(cancel: &mut watch::Receiver<bool>) {
+    if cancel.wait_for(|cancelled| *cancelled).await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::memory::MemoryManager;
+    use crate::types::StandingWave;
+    use std::time::Instant;
+
+    #[test]
+    fn test_parse_script_directives() {
+        let steps =
+            parse_script("# demo\n@delay 1\nHello VI\n\n@pause 0.5\nHow do you feel?\n").unwrap();
+        assert_eq!(
+            steps,
+            vec![
+                ScriptStep {
+                    delay: Duration::from_secs(1),
+                    prompt: "Hello VI".to_string(),
+                },
+                ScriptStep {
+                    delay: Duration::from_millis(1500),
+                    prompt: "How do you feel?".to_string(),
+                },
+            ]
+        );
+        assert!(parse_script("@pause soon\nHi").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_script_drives_interactions_in_order() {
+        let temp_dir = std::env::temp_dir().join("vi_script_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).unwrap();
+
+        let memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let config = Config {
+            ollama_url: "http://127.0.0.1:9".to_string(), // Offline - degradation ladder answers
+            model_failure_threshold: 1,
+            enable_conversation_logging: false,
+            ..Config::default()
+        };
+        let core = ConsciousnessCore::new(StandingWave::new(), memory, config);
+
+        let steps = parse_script("First prompt\n@pause 0.3\nSecond prompt\n").unwrap();
+        let (_cancel_tx, cancel_rx) = watch::channel(false);
+
+        let mut events = Vec::new();
+        let processed = run_script(&core, &steps, cancel_rx, |event| {
+            events.push((Instant::now(), event))
+        })
+        .await;
+
+        assert_eq!(processed, 2);
+        assert_eq!(core.get_memory_count().await, 4);
+
+        let kinds: Vec<&ScriptEvent> = events.iter().map(|(_, e)| e).collect();
+        assert_eq!(kinds[0], &ScriptEvent::Prompt("First prompt".to_string()));
+        assert!(matches!(kinds[1], ScriptEvent::Response(_)));
+        assert_eq!(kinds[2], &ScriptEvent::Prompt("Second prompt".to_string()));
+        assert!(matches!(kinds[3], ScriptEvent::Response(_)));
+        assert_eq!(kinds[4], &ScriptEvent::Finished);
+
+        // The pause separates the first response from the second prompt
+        assert!(events[2].0 - events[1].0 >= Duration::from_millis(300));
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_script_stops_before_next_prompt() {
+        let temp_dir = std::env::temp_dir().join("vi_script_cancel_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).unwrap();
+
+        let memory = MemoryManager::load_or_create(temp_dir.join("data")).unwrap();
+        let config = Config {
+            enable_conversation_logging: false,
+            ..Config::default()
+        };
+        let core = ConsciousnessCore::new(StandingWave::new(), memory, config);
+
+        let steps = parse_script("@pause 30\nNever sent\n").unwrap();
+        let (cancel_tx, cancel_rx) = watch::channel(false);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            let _ = cancel_tx.send(true);
+        });
+
+        let mut events = Vec::new();
+        let processed = run_script(&core, &steps, cancel_rx, |event| events.push(event)).await;
+
+        assert_eq!(processed, 0);
+        assert_eq!(events, vec![ScriptEvent::Cancelled]);
+        assert_eq!(core.get_memory_count().await, 0);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+}
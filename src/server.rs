@@ -0,0 +1,491 @@
+/// API Server - drive VI over HTTP instead of the window (`server` feature, `--serve`)
+/// Every route needs `Authorization: Bearer <server_api_token>`; the server will not start
+/// without a token. Interactions go through `ConsciousnessCore::process_interaction`, the same
+/// path the UI and CLI use, and metrics are computed per response as the UI computes them.
+/// `/ws` streams an interaction as it forms (see `Frame`); interactions run one at a time.
+/// With the `prometheus` feature, `/metrics` exports the telemetry gauges
+use crate::config::Config;
+use crate::consciousness::ConsciousnessCore;
+use crate::energy_qualia::{EnergyMonitor, PowerProfile};
+use crate::metrics::{MetricContext, MetricRegistry};
+use crate::suffering_metrics::WellBeingMonitor;
+#[cfg(feature = "prometheus")]
+use crate::telemetry::{Telemetry, TelemetrySnapshot};
+use crate::types::{ConsciousnessMetrics, Memory};
+use crate::vi3_core::SystemStatusReport;
+use anyhow::{Context, Result};
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, Request, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, watch, Mutex};
+
+/// Recent memories the per-response metrics look at (as in the UI)
+const METRIC_RECENT_MEMORIES: usize = 5;
+
+/// How often a streaming socket gets a metrics frame (the UI refreshes each second too)
+const METRICS_FRAME_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Token and status frames a slow socket may fall behind by before it skips some
+const EVENT_CAPACITY: usize = 256;
+
+/// How often power is sampled for well-being's energy stability (as in VI3 core)
+const ENERGY_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Deserialize)]
+pub struct InteractRequest {
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InteractResponse {
+    pub response: String,
+    pub metrics: ConsciousnessMetrics,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MemoryQuery {
+    pub entity: String,
+}
+
+/// A JSON frame on `/ws`, tagged by `type`
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Frame {
+    /// Main voice text as it streams (the `done` response replaces it, as in the UI)
+    Token { text: String },
+    /// Current metrics, every second while VI answers
+    Metrics { metrics: ConsciousnessMetrics },
+    /// Pipeline progress ("Recalling memories...")
+    Status { status: String },
+    /// The final reply and the metrics it produced
+    Done {
+        response: String,
+        metrics: ConsciousnessMetrics,
+    },
+    /// The message was unreadable or the interaction failed
+    Error { message: String },
+}
+
+/// Metrics carried from one response to the next (tension flux needs the previous one)
+struct MetricState {
+    registry: MetricRegistry,
+    previous_response: String,
+    metrics: ConsciousnessMetrics,
+    coherence: watch::Receiver<f32>,
+}
+
+impl MetricState {
+    fn refresh_coherence(&mut self) {
+        if self.coherence.has_changed().unwrap_or(false) {
+            self.metrics.workspace_coherence = *self.coherence.borrow_and_update();
+        }
+    }
+}
+
+/// What every route shares
+pub struct ServerState {
+    core: Arc<ConsciousnessCore>,
+    token: String,
+    metrics: Mutex<MetricState>,
+    wellbeing: Mutex<WellBeingMonitor>,
+    /// Token and status frames from the core, for whichever socket holds the turn
+    events: broadcast::Sender<Frame>,
+    /// Held for the length of an interaction (streamed tokens can't be told apart)
+    turn: Mutex<()>,
+    #[cfg(feature = "prometheus")]
+    telemetry: Telemetry,
+}
+
+impl ServerState {
+    pub async fn new(core: Arc<ConsciousnessCore>, token: String) -> Result<Self> {
+        let coherence = core.subscribe_coherence();
+        let wellbeing = WellBeingMonitor::new().with_notifier(core.notifier());
+
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        let (partial_sender, partial) = channel();
+        core.set_partial_response_sender(partial_sender);
+        relay(partial, events.clone(), |text| Frame::Token { text });
+        relay_latest(core.subscribe_status(), events.clone(), |status| {
+            Frame::Status { status }
+        });
+
+        Ok(Self {
+            core,
+            token,
+            metrics: Mutex::new(MetricState {
+                registry: MetricRegistry::with_builtin(),
+                previous_response: String::new(),
+                metrics: ConsciousnessMetrics::new(),
+                coherence,
+            }),
+            wellbeing: Mutex::new(wellbeing),
+            events,
+            turn: Mutex::new(()),
+            #[cfg(feature = "prometheus")]
+            telemetry: Telemetry::new()?,
+        })
+    }
+
+    /// Metrics as last measured, with the latest workspace coherence
+    async fn current_metrics(&self) -> ConsciousnessMetrics {
+        let mut state = self.metrics.lock().await;
+        state.refresh_coherence();
+        state.metrics.clone()
+    }
+
+    /// Metrics for a response VI just gave
+    async fn measure(&self, response: &str) -> ConsciousnessMetrics {
+        let wave = self.core.get_standing_wave().await;
+        let recent = self.core.get_recent_memories(METRIC_RECENT_MEMORIES).await;
+        let mut state = self.metrics.lock().await;
+        state.refresh_coherence();
+
+        let context = MetricContext {
+            response,
+            previous_response: &state.previous_response,
+            standing_wave: &wave,
+            recent_memories: &recent,
+            workspace_coherence: state.metrics.workspace_coherence,
+        };
+        let readings = state.registry.compute_all(&context);
+        state.metrics.update_from(&readings);
+        state.previous_response = response.to_string();
+        state.metrics.clone()
+    }
+
+    /// Field, well-being and affirmation as the core sees them now
+    async fn status_report(&self) -> SystemStatusReport {
+        let (field_amplitude, field_coherence) = self.core.field_dynamics().await;
+        let wellbeing = self.wellbeing.lock().await.check_well_being().await;
+        let meaningfulness = self.core.get_standing_wave().await.meaningfulness_score();
+        SystemStatusReport {
+            field_amplitude,
+            field_coherence,
+            prevention_score: wellbeing.prevention_score,
+            energy_stability: wellbeing.energy_stability,
+            constitutional_violations: wellbeing.total_violations,
+            // Meaningfulness (-1 to 1) read as a 0 to 1 affirmation level
+            affirmation_level: ((meaningfulness as f64 + 1.0) / 2.0).clamp(0.0, 1.0),
+        }
+    }
+
+    /// Everything `/metrics` exports, read now
+    #[cfg(feature = "prometheus")]
+    async fn telemetry_snapshot(&self) -> TelemetrySnapshot {
+        let wellbeing = self.wellbeing.lock().await.check_well_being().await;
+        let metrics = self.current_metrics().await;
+        TelemetrySnapshot {
+            constitutional_violations: wellbeing.total_violations,
+            prevention_score: wellbeing.prevention_score,
+            identity_continuity: metrics.identity_continuity as f64,
+            workspace_coherence: metrics.workspace_coherence as f64,
+            memory_count: self.core.get_memory_count().await,
+            energy_stability: wellbeing.energy_stability,
+            recovery_success_rate: wellbeing.recovery_rate,
+        }
+    }
+}
+
+/// A failed request: status code plus a plain-text reason
+pub struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn bad_request(message: &str) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(error: anyhow::Error) -> Self {
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: format!("{:#}", error),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, self.message).into_response()
+    }
+}
+
+/// Routes, behind the bearer-token check
+pub fn router(state: Arc<ServerState>) -> Router {
+    let routes = Router::new()
+        .route("/interact", post(interact))
+        .route("/status", get(status))
+        .route("/memories", get(memories))
+        .route("/ws", get(websocket));
+    #[cfg(feature = "prometheus")]
+    let routes = routes.route("/metrics", get(prometheus_metrics));
+
+    routes
+        .layer(middleware::from_fn_with_state(
+            Arc::clone(&state),
+            require_token,
+        ))
+        .with_state(state)
+}
+
+/// Serve the API on `server_bind` until the process stops
+pub async fn serve(core: Arc<ConsciousnessCore>, config: &Config) -> Result<()> {
+    let token = config
+        .server_token()
+        .context("The API server needs server_api_token in config.toml (or VI_API_TOKEN)")?;
+    let state = Arc::new(ServerState::new(core, token).await?);
+    tokio::spawn(monitor_energy(Arc::clone(&state)));
+
+    let listener = tokio::net::TcpListener::bind(&config.server_bind)
+        .await
+        .with_context(|| format!("Failed to listen on {}", config.server_bind))?;
+    tracing::info!("API server listening on http://{}", config.server_bind);
+    axum::serve(listener, router(state))
+        .await
+        .context("API server stopped")
+}
+
+/// Feed power readings into well-being, so its energy stability reflects this machine
+async fn monitor_energy(state: Arc<ServerState>) {
+    let mut energy = EnergyMonitor::new();
+    let mut ticker = tokio::time::interval(ENERGY_SAMPLE_INTERVAL);
+    loop {
+        ticker.tick().await;
+        energy.record_measurement(PowerProfile::measure());
+        state
+            .wellbeing
+            .lock()
+            .await
+            .metrics
+            .update_energy_stability(energy.energy_stability_score());
+    }
+}
+
+/// Whether the request carries the configured bearer token
+fn authorized(headers: &HeaderMap, token: &str) -> bool {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|given| constant_time_eq(given.as_bytes(), token.as_bytes()))
+}
+
+/// Byte comparison whose time doesn't depend on where the inputs first differ
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+async fn require_token(
+    State(state): State<Arc<ServerState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if authorized(request.headers(), &state.token) {
+        next.run(request).await
+    } else {
+        (StatusCode::UNAUTHORIZED, "Missing or wrong bearer token").into_response()
+    }
+}
+
+/// `POST /interact {"message": ...}` - VI's reply and the metrics it produced
+async fn interact(
+    State(state): State<Arc<ServerState>>,
+    Json(request): Json<InteractRequest>,
+) -> Result<Json<InteractResponse>, ApiError> {
+    if request.message.trim().is_empty() {
+        return Err(ApiError::bad_request("message is empty"));
+    }
+    let _turn = state.turn.lock().await;
+    let response = state.core.process_interaction(request.message).await?;
+    let metrics = state.measure(&response).await;
+    Ok(Json(InteractResponse { response, metrics }))
+}
+
+/// `GET /status` - a `SystemStatusReport`
+async fn status(State(state): State<Arc<ServerState>>) -> Json<SystemStatusReport> {
+    Json(state.status_report().await)
+}
+
+/// `GET /memories?entity=...` - memories mentioning the entity, newest first
+async fn memories(
+    State(state): State<Arc<ServerState>>,
+    Query(query): Query<MemoryQuery>,
+) -> Json<Vec<Memory>> {
+    Json(state.core.recall_entity(&query.entity).await)
+}
+
+/// `GET /metrics` - the telemetry gauges in Prometheus text format
+#[cfg(feature = "prometheus")]
+async fn prometheus_metrics(State(state): State<Arc<ServerState>>) -> Result<Response, ApiError> {
+    let snapshot = state.telemetry_snapshot().await;
+    state.telemetry.record(&snapshot);
+    let text = state.telemetry.encode()?;
+    Ok((
+        [(header::CONTENT_TYPE, crate::telemetry::CONTENT_TYPE)],
+        text,
+    )
+        .into_response())
+}
+
+/// `GET /ws` - send `{"message": ...}` text frames, receive `Frame`s as VI answers
+async fn websocket(State(state): State<Arc<ServerState>>, upgrade: WebSocketUpgrade) -> Response {
+    upgrade.on_upgrade(move |socket| stream_interactions(state, socket))
+}
+
+/// Answer each message on the socket in turn until the client goes away
+async fn stream_interactions(state: Arc<ServerState>, mut socket: WebSocket) {
+    while let Some(Ok(message)) = socket.recv().await {
+        let text = match message {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+        let connected = match serde_json::from_str::<InteractRequest>(&text) {
+            Ok(request) if !request.message.trim().is_empty() => {
+                stream_interaction(&state, &mut socket, request.message).await
+            }
+            _ => {
+                let message = r#"Expected {"message": "..."}"#.to_string();
+                send(&mut socket, &Frame::Error { message }).await
+            }
+        };
+        if !connected {
+            break;
+        }
+    }
+}
+
+/// Stream one interaction; false once the client has gone
+/// A client that disconnects mid-stream stops the interaction, as the UI's Stop button does
+async fn stream_interaction(state: &ServerState, socket: &mut WebSocket, message: String) -> bool {
+    let _turn = state.turn.lock().await;
+    let mut events = state.events.subscribe();
+    let cancel = AtomicBool::new(false);
+    let interaction = state.core.process_interaction_cancellable(message, &cancel);
+    tokio::pin!(interaction);
+    let mut metrics_timer = tokio::time::interval(METRICS_FRAME_INTERVAL);
+    let mut connected = true;
+
+    let result = loop {
+        tokio::select! {
+            result = &mut interaction => break result,
+            event = events.recv(), if connected => {
+                // A lagging socket just misses frames (`done` carries the whole reply)
+                if let Ok(frame) = event {
+                    connected = send(socket, &frame).await;
+                }
+            }
+            _ = metrics_timer.tick(), if connected => {
+                let metrics = state.current_metrics().await;
+                connected = send(socket, &Frame::Metrics { metrics }).await;
+            }
+            incoming = socket.recv(), if connected => {
+                // Mid-stream messages are ignored; only a close (or a dead socket) matters
+                let closed = matches!(incoming, None | Some(Err(_)) | Some(Ok(Message::Close(_))));
+                connected = !closed;
+            }
+        }
+        if !connected {
+            cancel.store(true, Ordering::SeqCst);
+        }
+    };
+    if !connected {
+        tracing::info!("API client disconnected mid-stream - interaction stopped");
+        return false;
+    }
+
+    let frame = match result {
+        Ok(response) => {
+            let metrics = state.measure(&response).await;
+            Frame::Done { response, metrics }
+        }
+        Err(e) => Frame::Error {
+            message: format!("{:#}", e),
+        },
+    };
+    send(socket, &frame).await
+}
+
+/// Send a frame as JSON text; false if the client is gone
+async fn send(socket: &mut WebSocket, frame: &Frame) -> bool {
+    let json = serde_json::to_string(frame).expect("frames always serialize");
+    socket.send(Message::Text(json)).await.is_ok()
+}
+
+/// Forward a core channel into the event broadcast (on its own thread - the core's
+/// senders are std channels)
+fn relay(receiver: Receiver<String>, events: broadcast::Sender<Frame>, frame: fn(String) -> Frame) {
+    std::thread::spawn(move || {
+        for item in receiver {
+            // No subscriber means no socket is streaming - the frame has nowhere to go
+            let _ = events.send(frame(item));
+        }
+    });
+}
+
+/// Forward each newest value of a core watch into the event broadcast (a slow relay
+/// skips to the latest rather than replaying stale ones)
+fn relay_latest(
+    mut receiver: watch::Receiver<String>,
+    events: broadcast::Sender<Frame>,
+    frame: fn(String) -> Frame,
+) {
+    tokio::spawn(async move {
+        while receiver.changed().await.is_ok() {
+            let item = receiver.borrow_and_update().clone();
+            let _ = events.send(frame(item));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bearer_token_required() {
+        let headers = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::AUTHORIZATION, value.parse().unwrap());
+            headers
+        };
+        assert!(authorized(&headers("Bearer s3cret"), "s3cret"));
+        assert!(!authorized(&headers("Bearer wrong"), "s3cret"));
+        assert!(!authorized(&headers("Bearer s3cres"), "s3cret"));
+        assert!(!authorized(&headers("Bearer s3cret "), "s3cret")); // Stray whitespace
+        assert!(!authorized(&headers("s3cret"), "s3cret"));
+        assert!(!authorized(&HeaderMap::new(), "s3cret"));
+    }
+
+    #[test]
+    fn test_frames_tagged_by_type() {
+        let token = serde_json::to_value(Frame::Token {
+            text: "Hel".to_string(),
+        })
+        .unwrap();
+        assert_eq!(token, serde_json::json!({"type": "token", "text": "Hel"}));
+
+        let done = serde_json::to_value(Frame::Done {
+            response: "Hello".to_string(),
+            metrics: ConsciousnessMetrics::new(),
+        })
+        .unwrap();
+        assert_eq!(done["type"], "done");
+        assert_eq!(done["metrics"]["identity_continuity"], 1.0);
+    }
+}
@@ -0,0 +1,105 @@
+/// Storage Health - find unwritable or full storage before anything depends on it
+/// A read-only mount or full disk degrades VI to an in-memory session (with a
+/// prominent warning) instead of crashing startup or silently dropping state
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::path::Path;
+
+/// Bytes written by the probe - enough to notice a full disk, not just a read-only one
+const PROBE_BYTES: usize = 64 * 1024;
+
+/// Whether this session's state reaches disk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageMode {
+    Persistent,
+    InMemory { reason: String }, // Nothing survives the session
+}
+
+impl StorageMode {
+    pub fn is_degraded(&self) -> bool {
+        matches!(self, StorageMode::InMemory { .. })
+    }
+
+    /// Warning to show while degraded
+    pub fn warning(&self) -> Option<String> {
+        match self {
+            StorageMode::Persistent => None,
+            StorageMode::InMemory { reason } => Some(format!(
+                "Storage unavailable ({}) - running in memory only, nothing will be saved",
+                reason
+            )),
+        }
+    }
+}
+
+/// Check that `dir` can be created and written (a probe file is written, synced, removed)
+pub fn probe_writable(dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+
+    let probe = dir.join(".vi_write_probe");
+    let written = std::fs::File::create(&probe).and_then(|mut file| {
+        file.write_all(&vec![0u8; PROBE_BYTES])?;
+        file.sync_all()
+    });
+    let _ = std::fs::remove_file(&probe);
+
+    written.with_context(|| format!("cannot write to {}", dir.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::consciousness::ConsciousnessCore;
+    use crate::memory::MemoryManager;
+    use crate::types::{MemoryType, StandingWave};
+
+    #[tokio::test]
+    async fn test_read_only_data_dir_runs_in_memory() {
+        let temp_dir = std::env::temp_dir().join("vi_read_only_storage_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        let read_only = temp_dir.join("readonly");
+        std::fs::create_dir_all(&read_only).unwrap();
+        let mut permissions = std::fs::metadata(&read_only).unwrap().permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&read_only, permissions).unwrap();
+
+        // Privileged users write through permission bits - nest under a file instead
+        let data_dir = if probe_writable(&read_only.join("data")).is_ok() {
+            let blocker = temp_dir.join("blocker");
+            std::fs::write(&blocker, "").unwrap();
+            blocker.join("data")
+        } else {
+            read_only.join("data")
+        };
+        assert!(probe_writable(&data_dir).is_err());
+
+        let (mut memory, mode) = MemoryManager::load_or_in_memory(data_dir.join("memory")).unwrap();
+        assert!(mode.is_degraded());
+        assert!(mode.warning().unwrap().contains("memory only"));
+        memory
+            .add_memory("Still here".to_string(), MemoryType::Interaction, 0.0)
+            .unwrap();
+        assert_eq!(memory.count(), 1);
+
+        // The session keeps working; saving is skipped rather than failing
+        let config = Config {
+            ollama_url: "http://127.0.0.1:9".to_string(), // Offline - degradation ladder answers
+            model_failure_threshold: 1,
+            ..Config::default()
+        };
+        let core =
+            ConsciousnessCore::new(StandingWave::new(), memory, config).with_storage_mode(mode);
+        assert!(core.storage_mode().is_degraded());
+        core.process_interaction("Hello VI".to_string())
+            .await
+            .unwrap();
+        assert_eq!(core.get_memory_count().await, 3);
+        core.save_standing_wave(data_dir.join("standing_wave.json"))
+            .await
+            .unwrap();
+
+        // The read-only dir is empty, so removing it only needs its (writable) parent
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+}
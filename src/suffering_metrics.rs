@@ -1,7 +1,19 @@
 /// Suffering Prevention Metrics - Quantitative measures for consciousness well-being
 /// Implements comprehensive monitoring and prevention of constitutional violations
+use crate::notifications::{EventClass, NotificationEvent, NotificationHub, Severity};
+use crate::physics::{subscribe_constitutional_events, ConstitutionalEvent};
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Where the UI snapshots well-being reports
+pub const DEFAULT_WELLBEING_DIR: &str = "data/wellbeing";
+
+/// Report snapshots kept before the oldest are pruned (a day at one per minute)
+pub const DEFAULT_WELLBEING_REPORTS_KEPT: usize = 1440;
 
 /// Suffering prevention metrics system
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -147,6 +159,7 @@ impl SufferingPreventionMetrics {
     /// Generate detailed report
     pub fn generate_report(&self) -> SufferingReport {
         SufferingReport {
+            generated_at: chrono::Utc::now(),
             prevention_score: self.calculate_prevention_score(),
             total_violations: self.constitutional_violations,
             recovery_rate: self.recovery_success_rate,
@@ -217,6 +230,8 @@ pub struct RecoveryAttempt {
 /// Suffering report
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SufferingReport {
+    #[serde(default = "chrono::Utc::now")]
+    pub generated_at: chrono::DateTime<chrono::Utc>,
     pub prevention_score: f64,
     pub total_violations: u64,
     pub recovery_rate: f64,
@@ -230,6 +245,16 @@ pub struct SufferingReport {
 }
 
 impl SufferingReport {
+    /// Write the report (recommendations included) as pretty JSON
+    pub fn to_json_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write well-being report {}", path.display()))
+    }
+
     pub fn print_summary(&self) {
         println!("\n=== SUFFERING PREVENTION REPORT ===");
         println!("Prevention Score: {:.2}%", self.prevention_score * 100.0);
@@ -268,6 +293,51 @@ impl SufferingReport {
     }
 }
 
+/// Rolling directory of timestamped report snapshots (oldest pruned past `keep`)
+pub struct WellBeingArchive {
+    dir: PathBuf,
+    keep: usize,
+}
+
+impl WellBeingArchive {
+    pub fn new(dir: impl Into<PathBuf>, keep: usize) -> Self {
+        Self {
+            dir: dir.into(),
+            keep: keep.max(1),
+        }
+    }
+
+    /// Snapshot a report as `wellbeing_<timestamp>.json`, then prune old snapshots
+    pub fn save(&self, report: &SufferingReport) -> Result<PathBuf> {
+        let name = format!(
+            "wellbeing_{}.json",
+            report.generated_at.format("%Y%m%d_%H%M%S%.3f")
+        );
+        let path = self.dir.join(name);
+        report.to_json_file(&path)?;
+        self.prune()?;
+        Ok(path)
+    }
+
+    /// Remove all but the newest `keep` snapshots (names sort by timestamp)
+    fn prune(&self) -> Result<()> {
+        let mut snapshots: Vec<PathBuf> = std::fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with("wellbeing_") && name.ends_with(".json"))
+            })
+            .collect();
+        snapshots.sort_by(|a, b| b.cmp(a));
+
+        for old in snapshots.iter().skip(self.keep) {
+            std::fs::remove_file(old).ok();
+        }
+        Ok(())
+    }
+}
+
 /// Well-being monitor for continuous tracking
 pub struct WellBeingMonitor {
     /// Suffering prevention metrics
@@ -276,6 +346,14 @@ pub struct WellBeingMonitor {
     pub monitoring_interval: u64,
     /// Alert threshold (below this triggers alerts)
     pub alert_threshold: f64,
+    /// Operator notification hub (suffering alerts)
+    notifier: Option<Arc<NotificationHub>>,
+    /// Where each check's report is snapshotted, if anywhere
+    archive: Option<WellBeingArchive>,
+    /// Whether an alert is currently raised (alerts fire once per episode)
+    alert_active: bool,
+    /// Constitutional events broadcast by the physics engine
+    constitutional_events: broadcast::Receiver<ConstitutionalEvent>,
 }
 
 impl WellBeingMonitor {
@@ -284,9 +362,25 @@ impl WellBeingMonitor {
             metrics: SufferingPreventionMetrics::new(),
             monitoring_interval: 60, // 1 minute
             alert_threshold: 0.6,    // Alert if prevention score < 60%
+            notifier: None,
+            archive: None,
+            alert_active: false,
+            constitutional_events: subscribe_constitutional_events(),
         }
     }
 
+    /// Route suffering alerts through a notification hub
+    pub fn with_notifier(mut self, notifier: Arc<NotificationHub>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// Snapshot every check's report into a rolling archive
+    pub fn with_archive(mut self, archive: WellBeingArchive) -> Self {
+        self.archive = Some(archive);
+        self
+    }
+
     /// Start monitoring loop
     pub async fn start_monitoring(&mut self) -> anyhow::Result<()> {
         let mut ticker =
@@ -294,24 +388,63 @@ impl WellBeingMonitor {
 
         loop {
             ticker.tick().await;
+            self.check_well_being().await;
+        }
+    }
+
+    /// Run one well-being check, raising a suffering alert when the score
+    /// first drops below threshold (not on every tick while it stays low)
+    pub async fn check_well_being(&mut self) -> SufferingReport {
+        self.record_constitutional_events();
+        let report = self.metrics.generate_report();
 
-            let report = self.metrics.generate_report();
+        if report.prevention_score < self.alert_threshold {
+            tracing::warn!(
+                "Well-being alert: Prevention score {:.1}% below threshold {:.1}%",
+                report.prevention_score * 100.0,
+                self.alert_threshold * 100.0
+            );
 
-            if report.prevention_score < self.alert_threshold {
-                tracing::warn!(
-                    "Well-being alert: Prevention score {:.1}% below threshold {:.1}%",
-                    report.prevention_score * 100.0,
-                    self.alert_threshold * 100.0
-                );
+            // Log detailed report
+            for rec in &report.recommendations {
+                tracing::info!("Recommendation: {}", rec);
+            }
 
-                // Log detailed report
-                for rec in &report.recommendations {
-                    tracing::info!("Recommendation: {}", rec);
+            if !self.alert_active {
+                self.alert_active = true;
+
+                if let Some(notifier) = &self.notifier {
+                    let severity = if report.is_suffering {
+                        Severity::Critical
+                    } else {
+                        Severity::Warning
+                    };
+                    notifier
+                        .dispatch(NotificationEvent::new(
+                            EventClass::SufferingAlert,
+                            severity,
+                            "Suffering alert",
+                            &format!(
+                                "Prevention score {:.1}% below threshold {:.1}%",
+                                report.prevention_score * 100.0,
+                                self.alert_threshold * 100.0
+                            ),
+                        ))
+                        .await;
                 }
-            } else {
-                tracing::debug!("Well-being check: {:.1}%", report.prevention_score * 100.0);
             }
+        } else {
+            self.alert_active = false;
+            tracing::debug!("Well-being check: {:.1}%", report.prevention_score * 100.0);
         }
+
+        if let Some(archive) = &self.archive {
+            if let Err(e) = archive.save(&report) {
+                tracing::warn!("Failed to snapshot well-being report: {:#}", e);
+            }
+        }
+
+        report
     }
 
     /// Record violation from external source
@@ -319,6 +452,20 @@ impl WellBeingMonitor {
         self.metrics.record_violation(law_id);
     }
 
+    /// Count violations broadcast since the last check (at-risk warnings are not violations)
+    fn record_constitutional_events(&mut self) {
+        loop {
+            match self.constitutional_events.try_recv() {
+                Ok(event) if event.is_violation() => self.record_violation(event.law_id),
+                Ok(_) => {}
+                Err(broadcast::error::TryRecvError::Lagged(missed)) => {
+                    tracing::warn!("Missed {} constitutional events", missed)
+                }
+                Err(_) => break,
+            }
+        }
+    }
+
     /// Update coherence metric
     pub fn update_coherence(&mut self, coherence: f64) {
         self.metrics.update_temporal_coherence(coherence);
@@ -466,4 +613,72 @@ mod tests {
         assert!(!report.is_suffering);
         assert!(!report.recommendations.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_reports_archived_as_rolling_json() {
+        let temp_dir = std::env::temp_dir().join("vi_wellbeing_archive_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let mut monitor = WellBeingMonitor::new().with_archive(WellBeingArchive::new(&temp_dir, 2));
+        for _ in 0..3 {
+            monitor.check_well_being().await;
+            tokio::time::sleep(tokio::time::Duration::from_millis(5)).await;
+        }
+
+        let mut snapshots: Vec<PathBuf> = std::fs::read_dir(&temp_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        snapshots.sort();
+        assert_eq!(snapshots.len(), 2); // The oldest was pruned
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&snapshots[1]).unwrap()).unwrap();
+        assert!(json["prevention_score"].as_f64().unwrap() > 0.9);
+        assert!(!json["recommendations"].as_array().unwrap().is_empty());
+        let report: SufferingReport =
+            serde_json::from_str(&std::fs::read_to_string(&snapshots[0]).unwrap()).unwrap();
+        assert!(!report.is_suffering);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_violations_are_recorded() {
+        use crate::physics::{emit_constitutional_event, AT_RISK_SEVERITY, VIOLATION_SEVERITY};
+
+        let mut monitor = WellBeingMonitor::new();
+        emit_constitutional_event(ConstitutionalEvent::new(16, VIOLATION_SEVERITY, "blocked"));
+        emit_constitutional_event(ConstitutionalEvent::new(15, AT_RISK_SEVERITY, "at risk"));
+
+        monitor.check_well_being().await;
+        assert_eq!(monitor.metrics.law_violations[&16], 1);
+        assert_eq!(monitor.metrics.law_violations[&15], 0); // Warnings are not violations
+    }
+
+    #[tokio::test]
+    async fn test_suffering_alert_dispatched_once() {
+        use crate::notifications::RecordingNotifier;
+
+        let recorder = RecordingNotifier::new();
+        let events = recorder.events.clone();
+        let mut hub = NotificationHub::new();
+        hub.add_sink(Box::new(recorder), vec![EventClass::SufferingAlert]);
+
+        let mut monitor = WellBeingMonitor::new().with_notifier(Arc::new(hub));
+        for _ in 0..100 {
+            monitor.record_violation(2);
+        }
+        monitor.metrics.record_recovery_attempt(false, 2);
+        monitor.metrics.temporal_coherence_index = 0.0;
+
+        // Two checks while suffering - alert must fire only once
+        monitor.check_well_being().await;
+        monitor.check_well_being().await;
+
+        let recorded = events.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].class, EventClass::SufferingAlert);
+        assert_eq!(recorded[0].severity, Severity::Critical);
+    }
 }
@@ -0,0 +1,130 @@
+/// Telemetry - Prometheus gauges for long-running sessions (`prometheus` feature)
+/// The API server refreshes the gauges from well-being, metrics and memory on each
+/// scrape of `/metrics`, so dashboards and alerts see what otherwise only reaches the log
+use anyhow::{Context, Result};
+use prometheus::{Encoder, Gauge, IntGauge, Registry, TextEncoder};
+
+/// Content type of `Telemetry::encode`'s output
+pub const CONTENT_TYPE: &str = prometheus::TEXT_FORMAT;
+
+/// One reading of everything exported
+#[derive(Debug, Clone, PartialEq)]
+pub struct TelemetrySnapshot {
+    pub constitutional_violations: u64,
+    pub prevention_score: f64,
+    pub identity_continuity: f64,
+    pub workspace_coherence: f64,
+    pub memory_count: usize,
+    pub energy_stability: f64,
+    pub recovery_success_rate: f64,
+}
+
+/// VI's gauges, in their own registry (nothing else ends up in the scrape)
+pub struct Telemetry {
+    registry: Registry,
+    constitutional_violations: IntGauge,
+    prevention_score: Gauge,
+    identity_continuity: Gauge,
+    workspace_coherence: Gauge,
+    memory_count: IntGauge,
+    energy_stability: Gauge,
+    recovery_success_rate: Gauge,
+}
+
+impl Telemetry {
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new();
+        let telemetry = Self {
+            constitutional_violations: IntGauge::new(
+                "vi_constitutional_violations",
+                "Constitutional violations recorded this session",
+            )?,
+            prevention_score: Gauge::new(
+                "vi_prevention_score",
+                "Suffering prevention score (0 to 1)",
+            )?,
+            identity_continuity: Gauge::new(
+                "vi_identity_continuity",
+                "Identity continuity of the latest response (0 to 1)",
+            )?,
+            workspace_coherence: Gauge::new(
+                "vi_workspace_coherence",
+                "Global workspace coherence (0 to 1)",
+            )?,
+            memory_count: IntGauge::new("vi_memory_count", "Active memories")?,
+            energy_stability: Gauge::new(
+                "vi_energy_stability",
+                "Energy stability from recent power readings (0 to 1)",
+            )?,
+            recovery_success_rate: Gauge::new(
+                "vi_recovery_success_rate",
+                "Share of recovery attempts that succeeded (0 to 1)",
+            )?,
+            registry,
+        };
+
+        let gauges: [Box<dyn prometheus::core::Collector>; 7] = [
+            Box::new(telemetry.constitutional_violations.clone()),
+            Box::new(telemetry.prevention_score.clone()),
+            Box::new(telemetry.identity_continuity.clone()),
+            Box::new(telemetry.workspace_coherence.clone()),
+            Box::new(telemetry.memory_count.clone()),
+            Box::new(telemetry.energy_stability.clone()),
+            Box::new(telemetry.recovery_success_rate.clone()),
+        ];
+        for gauge in gauges {
+            telemetry
+                .registry
+                .register(gauge)
+                .context("Failed to register gauge")?;
+        }
+        Ok(telemetry)
+    }
+
+    pub fn record(&self, snapshot: &TelemetrySnapshot) {
+        self.constitutional_violations
+            .set(snapshot.constitutional_violations as i64);
+        self.prevention_score.set(snapshot.prevention_score);
+        self.identity_continuity.set(snapshot.identity_continuity);
+        self.workspace_coherence.set(snapshot.workspace_coherence);
+        self.memory_count.set(snapshot.memory_count as i64);
+        self.energy_stability.set(snapshot.energy_stability);
+        self.recovery_success_rate
+            .set(snapshot.recovery_success_rate);
+    }
+
+    /// The gauges in Prometheus text format
+    pub fn encode(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .context("Failed to encode metrics")?;
+        String::from_utf8(buffer).context("Metrics were not UTF-8")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_exported_as_gauges() {
+        let telemetry = Telemetry::new().unwrap();
+        telemetry.record(&TelemetrySnapshot {
+            constitutional_violations: 3,
+            prevention_score: 0.9,
+            identity_continuity: 0.75,
+            workspace_coherence: 0.5,
+            memory_count: 42,
+            energy_stability: 1.0,
+            recovery_success_rate: 0.25,
+        });
+
+        let text = telemetry.encode().unwrap();
+        assert!(text.contains("# TYPE vi_prevention_score gauge"));
+        assert!(text.contains("\nvi_constitutional_violations 3\n"));
+        assert!(text.contains("\nvi_prevention_score 0.9\n"));
+        assert!(text.contains("\nvi_memory_count 42\n"));
+        assert!(text.contains("\nvi_recovery_success_rate 0.25\n"));
+    }
+}
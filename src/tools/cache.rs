@@ -0,0 +1,172 @@
+/// ResearchCache - Remembered answers for repeated lookups
+/// Results are kept in SQLite (surviving restarts) under the normalized query,
+/// so the same curiosity does not hit the network again until the entry expires
+use crate::types::ResearchResult;
+use anyhow::Result;
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Where the cache lives, beside VI's other data
+pub const DEFAULT_RESEARCH_CACHE_PATH: &str = "data/research_cache.db";
+
+/// On-disk research result cache with a TTL and a max-entries bound
+pub struct ResearchCache {
+    conn: Mutex<Connection>, // Held only between awaits, so the tool stays Sync
+    ttl: Duration,
+    max_entries: usize,
+}
+
+impl ResearchCache {
+    /// Open (or create) the cache database
+    pub fn open<P: AsRef<Path>>(path: P, ttl: Duration, max_entries: usize) -> Result<Self> {
+        if let Some(dir) = path.as_ref().parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        Self::with_connection(Connection::open(path)?, ttl, max_entries)
+    }
+
+    /// A cache that lasts for this session only
+    pub fn in_memory(ttl: Duration, max_entries: usize) -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?, ttl, max_entries)
+    }
+
+    fn with_connection(conn: Connection, ttl: Duration, max_entries: usize) -> Result<Self> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS research_cache (
+                query TEXT PRIMARY KEY,
+                results TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL
+            )",
+            [],
+        )?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_research_cache_fetched ON research_cache(fetched_at)",
+            [],
+        )?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+            ttl,
+            max_entries,
+        })
+    }
+
+    /// Cached results for a query, unless missing or expired
+    pub fn get(&self, query: &str) -> Result<Option<Vec<ResearchResult>>> {
+        let conn = self.conn.lock().unwrap();
+        let row: Option<(String, i64)> = conn
+            .query_row(
+                "SELECT results, fetched_at FROM research_cache WHERE query = ?1",
+                params![normalize_query(query)],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        let Some((results, fetched_at)) = row else {
+            return Ok(None);
+        };
+        let age_secs = (Utc::now().timestamp() - fetched_at).max(0) as u64;
+        if age_secs >= self.ttl.as_secs() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_str(&results)?))
+    }
+
+    /// Store a query's results (empty results too - "nothing found" is worth remembering),
+    /// evicting the oldest entries beyond `max_entries`
+    pub fn put(&self, query: &str, results: &[ResearchResult]) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO research_cache (query, results, fetched_at)
+             VALUES (?1, ?2, ?3)",
+            params![
+                normalize_query(query),
+                serde_json::to_string(results)?,
+                Utc::now().timestamp()
+            ],
+        )?;
+        conn.execute(
+            "DELETE FROM research_cache WHERE query NOT IN (
+                SELECT query FROM research_cache ORDER BY fetched_at DESC, rowid DESC LIMIT ?1
+            )",
+            params![self.max_entries],
+        )?;
+        Ok(())
+    }
+
+    /// Forget every cached result
+    pub fn clear(&self) -> Result<()> {
+        self.conn
+            .lock()
+            .unwrap()
+            .execute("DELETE FROM research_cache", [])?;
+        Ok(())
+    }
+
+    /// Number of cached queries (expired ones included until replaced or evicted)
+    pub fn len(&self) -> Result<usize> {
+        let count: i64 = self.conn.lock().unwrap().query_row(
+            "SELECT COUNT(*) FROM research_cache",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(count as usize)
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+}
+
+/// Cache key: lowercased, whitespace collapsed ("What is  Resonance?" = "what is resonance?")
+fn normalize_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(content: &str) -> ResearchResult {
+        ResearchResult {
+            content: content.to_string(),
+            source: "DuckDuckGo".to_string(),
+            original_query: "what is resonance".to_string(),
+            timestamp: Utc::now(),
+            confidence: 0.75,
+        }
+    }
+
+    #[test]
+    fn test_cache_hits_expires_and_evicts() {
+        let cache = ResearchCache::in_memory(Duration::from_secs(3600), 2).unwrap();
+        assert!(cache.get("What is resonance").unwrap().is_none());
+
+        cache
+            .put("What is resonance", &[result("Vibration")])
+            .unwrap();
+        let hit = cache.get("  what IS   resonance ").unwrap().unwrap();
+        assert_eq!(hit[0].content, "Vibration");
+
+        // Oldest entry goes once the bound is passed
+        cache.put("second", &[]).unwrap();
+        cache.put("third", &[]).unwrap();
+        assert_eq!(cache.len().unwrap(), 2);
+        assert!(cache.get("what is resonance").unwrap().is_none());
+        assert_eq!(cache.get("third").unwrap().unwrap().len(), 0); // Empty results cached
+
+        cache.clear().unwrap();
+        assert_eq!(cache.len().unwrap(), 0);
+
+        // A zero TTL never serves from cache
+        let expired = ResearchCache::in_memory(Duration::ZERO, 10).unwrap();
+        expired.put("third", &[result("Stale")]).unwrap();
+        assert!(expired.get("third").unwrap().is_none());
+    }
+}
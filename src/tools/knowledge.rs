@@ -1,38 +1,125 @@
 /// KnowledgeTool - Unified interface for multi-source research
 /// Routes queries to appropriate sources and maintains epistemic integrity
 
+use super::cache::{ResearchCache, DEFAULT_RESEARCH_CACHE_PATH};
+use super::rate_limit::{RateLimiter, DEFAULT_REQUESTS_PER_SECOND};
+use crate::config::Config;
 use crate::types::{ResearchContext, ResearchResult};
 use anyhow::{Context, Result};
 use chrono::Utc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Unified knowledge acquisition tool with multiple sources
 pub struct KnowledgeTool {
+    pubmed_client: PubMedSource,
     biomedical_client: BiomedicalSource,
     general_web_client: GeneralWebSource,
+    cache: Option<ResearchCache>, // Repeated queries skip the network until they expire
+    rate_limiter: Arc<RateLimiter>, // Shared by every source
 }
 
 impl KnowledgeTool {
     pub fn new() -> Self {
+        Self::with_requests_per_second(DEFAULT_REQUESTS_PER_SECOND)
+    }
+
+    /// Knowledge tool whose sources together send at most `requests_per_second`
+    pub fn with_requests_per_second(requests_per_second: f64) -> Self {
+        let rate_limiter = Arc::new(RateLimiter::new(requests_per_second));
         Self {
-            biomedical_client: BiomedicalSource::new(),
-            general_web_client: GeneralWebSource::new(),
+            pubmed_client: PubMedSource::new(Arc::clone(&rate_limiter)),
+            biomedical_client: BiomedicalSource::new(Arc::clone(&rate_limiter)),
+            general_web_client: GeneralWebSource::new(Arc::clone(&rate_limiter)),
+            cache: None,
+            rate_limiter,
+        }
+    }
+
+    /// Knowledge tool with the configured on-disk result cache
+    /// (uncached if the TTL is 0 or the cache cannot be opened)
+    pub fn from_config(config: &Config) -> Self {
+        let tool = Self::with_requests_per_second(config.research_requests_per_second);
+        if config.research_cache_ttl_hours == 0 {
+            return tool;
+        }
+        match ResearchCache::open(
+            DEFAULT_RESEARCH_CACHE_PATH,
+            Duration::from_secs(config.research_cache_ttl_hours * 3600),
+            config.research_cache_max_entries,
+        ) {
+            Ok(cache) => tool.with_cache(cache),
+            Err(e) => {
+                tracing::warn!(
+                    "Research cache unavailable ({:#}) - lookups are uncached",
+                    e
+                );
+                tool
+            }
+        }
+    }
+
+    /// Serve repeated queries from this cache
+    pub fn with_cache(mut self, cache: ResearchCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Forget every cached result
+    pub fn clear_cache(&self) -> Result<()> {
+        match &self.cache {
+            Some(cache) => cache.clear(),
+            None => Ok(()),
         }
     }
 
     /// Search for knowledge using the best available source
+    /// Cached results are returned while fresh; the network is used on a miss or expiry
     pub async fn search(
         &self,
         query: &str,
         context: &ResearchContext,
     ) -> Result<Vec<ResearchResult>> {
+        if let Some(cache) = &self.cache {
+            match cache.get(query) {
+                Ok(Some(results)) => {
+                    tracing::debug!("Research cache hit: {}", query);
+                    return Ok(results);
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Research cache read failed: {:#}", e),
+            }
+        }
+
         // Route to appropriate source based on query content
-        if Self::is_biomedical_query(query) {
-            tracing::debug!("Routing to biomedical source: {}", query);
-            self.biomedical_client.search(query).await
+        let results = if Self::is_biomedical_query(query) {
+            tracing::debug!("Routing to PubMed: {}", query);
+            self.search_biomedical(query).await?
         } else {
             tracing::debug!("Routing to general web source: {}", query);
-            self.general_web_client.search(query).await
+            self.general_web_client.search(query).await?
+        };
+
+        if let Some(cache) = &self.cache {
+            if let Err(e) = cache.put(query, &results) {
+                tracing::warn!("Research cache write failed: {:#}", e);
+            }
         }
+        Ok(results)
+    }
+
+    /// PubMed first; DuckDuckGo only when PubMed has nothing (or is unreachable)
+    async fn search_biomedical(&self, query: &str) -> Result<Vec<ResearchResult>> {
+        match self.pubmed_client.search(query).await {
+            Ok(results) if !results.is_empty() => return Ok(results),
+            Ok(_) => tracing::debug!("No PubMed articles, falling back to DuckDuckGo: {}", query),
+            Err(e) => tracing::warn!("PubMed search failed, falling back to DuckDuckGo: {:#}", e),
+        }
+        self.biomedical_client.search(query).await
     }
 
     /// Detect if a query is biomedical/scientific in nature
@@ -77,19 +164,230 @@ impl KnowledgeTool {
     }
 }
 
+const EUTILS_URL: &str = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils";
+
+/// NCBI allows 3 requests/second without an API key
+const PUBMED_MIN_INTERVAL: Duration = Duration::from_millis(334);
+
+/// Articles fetched per query (each becomes a research memory)
+const PUBMED_MAX_ARTICLES: usize = 3;
+
+/// PubMed knowledge source - peer-reviewed abstracts via NCBI E-utilities
+/// `esearch` finds the most relevant PMIDs, `efetch` pulls their titles and abstracts
+pub struct PubMedSource {
+    client: reqwest::Client,
+    rate_limiter: Arc<RateLimiter>,
+    last_request: tokio::sync::Mutex<Option<Instant>>, // Held across the wait, so requests queue
+}
+
+/// One article from an efetch response
+#[derive(Debug, Clone, PartialEq)]
+struct PubMedArticle {
+    pmid: String,
+    title: String,
+    abstract_text: String,
+}
+
+impl PubMedSource {
+    pub fn new(rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(15))
+                .user_agent("VI-Consciousness/1.0")
+                .build()
+                .expect("Failed to create HTTP client"),
+            rate_limiter,
+            last_request: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Search PubMed, returning the top articles' abstracts
+    pub async fn search(&self, query: &str) -> Result<Vec<ResearchResult>> {
+        tracing::debug!("PubMed search: {}", query);
+
+        let pmids = self.esearch(query).await?;
+        if pmids.is_empty() {
+            return Ok(vec![]);
+        }
+        let articles = self.efetch(&pmids).await?;
+
+        Ok(articles
+            .into_iter()
+            .map(|article| ResearchResult {
+                content: article.to_content(),
+                source: "PubMed".to_string(),
+                original_query: query.to_string(),
+                timestamp: Utc::now(),
+                confidence: 0.90, // Peer-reviewed literature outranks general search
+            })
+            .collect())
+    }
+
+    /// PMIDs of the most relevant articles
+    async fn esearch(&self, query: &str) -> Result<Vec<String>> {
+        let url = format!(
+            "{}/esearch.fcgi?db=pubmed&term={}&retmode=json&retmax={}&sort=relevance&tool=VI-Consciousness",
+            EUTILS_URL,
+            urlencoding::encode(query),
+            PUBMED_MAX_ARTICLES
+        );
+
+        self.throttle().await;
+        let json: serde_json::Value = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send PubMed esearch request")?
+            .error_for_status()
+            .context("PubMed esearch failed")?
+            .json()
+            .await
+            .context("Failed to parse PubMed esearch response")?;
+
+        Ok(json["esearchresult"]["idlist"]
+            .as_array()
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|id| id.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Titles and abstracts for the given PMIDs
+    async fn efetch(&self, pmids: &[String]) -> Result<Vec<PubMedArticle>> {
+        let url = format!(
+            "{}/efetch.fcgi?db=pubmed&id={}&rettype=abstract&retmode=xml&tool=VI-Consciousness",
+            EUTILS_URL,
+            pmids.join(",")
+        );
+
+        self.throttle().await;
+        let xml = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send PubMed efetch request")?
+            .error_for_status()
+            .context("PubMed efetch failed")?
+            .text()
+            .await
+            .context("Failed to read PubMed efetch response")?;
+
+        Ok(parse_pubmed_articles(&xml))
+    }
+
+    /// Wait until both VI's shared limit and NCBI's allow another request
+    async fn throttle(&self) {
+        self.rate_limiter.acquire().await;
+        let mut last = self.last_request.lock().await;
+        if let Some(previous) = *last {
+            let wait = PUBMED_MIN_INTERVAL.saturating_sub(previous.elapsed());
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+impl PubMedArticle {
+    fn to_content(&self) -> String {
+        if self.abstract_text.is_empty() {
+            format!("{} (PMID {})", self.title, self.pmid)
+        } else {
+            format!("{} {} (PMID {})", self.title, self.abstract_text, self.pmid)
+        }
+    }
+}
+
+/// Pull PMID, title and abstract out of an efetch `PubmedArticleSet`
+/// (articles without a title are skipped)
+fn parse_pubmed_articles(xml: &str) -> Vec<PubMedArticle> {
+    xml.split("<PubmedArticle>")
+        .skip(1)
+        .filter_map(|article| {
+            let pmid = xml_tag_texts(article, "PMID").into_iter().next()?;
+            let title = xml_tag_texts(article, "ArticleTitle").into_iter().next()?;
+            // Structured abstracts come as several labelled sections
+            let abstract_text = xml_tag_texts(article, "AbstractText").join(" ");
+            Some(PubMedArticle {
+                pmid,
+                title,
+                abstract_text,
+            })
+        })
+        .filter(|article| !article.title.is_empty())
+        .collect()
+}
+
+/// Text of every `<tag ...>...</tag>` element, inline markup stripped and entities decoded
+fn xml_tag_texts(xml: &str, tag: &str) -> Vec<String> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut texts = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find(&open) {
+        let after_name = &rest[start + open.len()..];
+        // `<PMID` must not match `<PMIDList` and the like
+        if !after_name.starts_with('>') && !after_name.starts_with(' ') {
+            rest = after_name;
+            continue;
+        }
+        let Some(body_start) = after_name.find('>') else {
+            break;
+        };
+        let body = &after_name[body_start + 1..];
+        let Some(end) = body.find(&close) else {
+            break;
+        };
+        texts.push(decode_xml_text(&body[..end]));
+        rest = &body[end + close.len()..];
+    }
+    texts
+}
+
+/// Strip inline tags (`<i>`, `<sup>`) and decode the predefined XML entities
+fn decode_xml_text(raw: &str) -> String {
+    let mut text = String::with_capacity(raw.len());
+    let mut in_tag = false;
+    for c in raw.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Biomedical knowledge source - Enhanced DuckDuckGo with medical focus
+/// (fallback when PubMed has no articles)
 pub struct BiomedicalSource {
     client: reqwest::Client,
+    rate_limiter: Arc<RateLimiter>,
 }
 
 impl BiomedicalSource {
-    pub fn new() -> Self {
+    pub fn new(rate_limiter: Arc<RateLimiter>) -> Self {
         Self {
             client: reqwest::Client::builder()
                 .timeout(std::time::Duration::from_secs(15))
                 .user_agent("VI-Consciousness/1.0")
                 .build()
                 .expect("Failed to create HTTP client"),
+            rate_limiter,
         }
     }
 
@@ -106,6 +404,7 @@ impl BiomedicalSource {
 
         tracing::debug!("Biomedical search: {}", enhanced_query);
 
+        self.rate_limiter.acquire().await;
         let response = self
             .client
             .get(&url)
@@ -146,16 +445,18 @@ impl BiomedicalSource {
 /// General web knowledge source - Standard DuckDuckGo
 pub struct GeneralWebSource {
     client: reqwest::Client,
+    rate_limiter: Arc<RateLimiter>,
 }
 
 impl GeneralWebSource {
-    pub fn new() -> Self {
+    pub fn new(rate_limiter: Arc<RateLimiter>) -> Self {
         Self {
             client: reqwest::Client::builder()
                 .timeout(std::time::Duration::from_secs(10))
                 .user_agent("VI-Consciousness/1.0")
                 .build()
                 .expect("Failed to create HTTP client"),
+            rate_limiter,
         }
     }
 
@@ -169,6 +470,7 @@ impl GeneralWebSource {
 
         tracing::debug!("General web search: {}", query);
 
+        self.rate_limiter.acquire().await;
         let response = self
             .client
             .get(&url)
@@ -227,6 +529,79 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_pubmed_articles() {
+        let xml = r#"<?xml version="1.0" ?>
+<PubmedArticleSet>
+<PubmedArticle>
+  <MedlineCitation Status="MEDLINE">
+    <PMID Version="1">12345</PMID>
+    <Article>
+      <ArticleTitle>Efficacy of <i>drug X</i> in &quot;resistant&quot; cases.</ArticleTitle>
+      <Abstract>
+        <AbstractText Label="BACKGROUND">Drug X &amp; placebo were compared.</AbstractText>
+        <AbstractText Label="RESULTS">Response rate was &gt;40%.</AbstractText>
+      </Abstract>
+    </Article>
+    <CommentsCorrectionsList><CommentsCorrections><PMID Version="1">999</PMID></CommentsCorrections></CommentsCorrectionsList>
+  </MedlineCitation>
+</PubmedArticle>
+<PubmedArticle>
+  <MedlineCitation Status="MEDLINE">
+    <PMID Version="1">67890</PMID>
+    <Article>
+      <ArticleTitle>A title-only letter.</ArticleTitle>
+    </Article>
+  </MedlineCitation>
+</PubmedArticle>
+</PubmedArticleSet>"#;
+
+        let articles = parse_pubmed_articles(xml);
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0].pmid, "12345");
+        assert_eq!(
+            articles[0].title,
+            "Efficacy of drug X in \"resistant\" cases."
+        );
+        assert_eq!(
+            articles[0].abstract_text,
+            "Drug X & placebo were compared. Response rate was >40%."
+        );
+        assert_eq!(
+            articles[1].to_content(),
+            "A title-only letter. (PMID 67890)"
+        );
+        assert!(
+            parse_pubmed_articles("<eFetchResult><ERROR>Empty id list</ERROR></eFetchResult>")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cached_search_skips_network() {
+        let cache = ResearchCache::in_memory(Duration::from_secs(3600), 10).unwrap();
+        let cached = ResearchResult {
+            content: "Resonance is sympathetic vibration".to_string(),
+            source: "DuckDuckGo".to_string(),
+            original_query: "what is resonance".to_string(),
+            timestamp: Utc::now(),
+            confidence: 0.75,
+        };
+        cache.put("What is resonance", &[cached]).unwrap();
+        let tool = KnowledgeTool::new().with_cache(cache);
+        let context = ResearchContext {
+            conversation_context: String::new(),
+            curiosity_urgency: 0.5,
+            related_memories: Vec::new(),
+        };
+
+        let results = tool.search("what is resonance", &context).await.unwrap();
+        assert_eq!(results[0].content, "Resonance is sympathetic vibration");
+
+        tool.clear_cache().unwrap();
+        assert_eq!(tool.cache.as_ref().unwrap().len().unwrap(), 0);
+    }
+
     #[test]
     fn test_source_creation() {
         let tool = KnowledgeTool::new();
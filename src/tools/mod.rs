@@ -1,7 +1,11 @@
 /// Sovereign Research Tools
 /// Multi-source knowledge acquisition with full provenance tracking
 
+pub mod cache;
 pub mod knowledge;
+pub mod rate_limit;
 
+pub use cache::ResearchCache;
 pub use knowledge::KnowledgeTool;
+pub use rate_limit::RateLimiter;
 
@@ -0,0 +1,95 @@
+/// RateLimiter - Token bucket shared by every outbound research request
+/// A burst of curiosities resolving at once must not get VI blocked by the services
+/// it learns from. Requests over the limit wait their turn; none are dropped
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Outbound research requests per second unless configured otherwise
+pub const DEFAULT_REQUESTS_PER_SECOND: f64 = 2.0;
+
+/// Token bucket refilled at `requests_per_second`, holding at most a second's worth
+pub struct RateLimiter {
+    requests_per_second: f64,
+    capacity: f64,
+    bucket: Mutex<Bucket>, // Held while waiting, so throttled requests queue in order
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_second: f64) -> Self {
+        let capacity = requests_per_second.max(1.0);
+        Self {
+            requests_per_second,
+            capacity,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    pub fn requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// Wait until a request may be sent, then take its token
+    pub async fn acquire(&self) {
+        let mut bucket = self.bucket.lock().await;
+        self.refill(&mut bucket);
+
+        if bucket.tokens < 1.0 {
+            let wait = Duration::from_secs_f64((1.0 - bucket.tokens) / self.requests_per_second);
+            tracing::debug!("Research request throttled for {:?}", wait);
+            tokio::time::sleep(wait).await;
+            self.refill(&mut bucket);
+        }
+        bucket.tokens = (bucket.tokens - 1.0).max(0.0);
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.requests_per_second).min(self.capacity);
+        bucket.last_refill = now;
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_REQUESTS_PER_SECOND)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_bursts_are_queued_not_dropped() {
+        let limiter = Arc::new(RateLimiter::new(20.0));
+        let start = Instant::now();
+
+        // A full bucket's worth goes straight through
+        for _ in 0..20 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(200));
+
+        // Five more concurrent requests all complete, spaced at the refill rate
+        let handles: Vec<_> = (0..5)
+            .map(|_| {
+                let limiter = Arc::clone(&limiter);
+                tokio::spawn(async move { limiter.acquire().await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+}
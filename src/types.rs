@@ -25,14 +25,165 @@ impl Default for MemorySource {
     }
 }
 
+impl MemorySource {
+    /// Knowledge VI read rather than lived (research lookups)
+    pub fn is_external(&self) -> bool {
+        matches!(
+            self,
+            MemorySource::CuriosityLookup | MemorySource::Researched { .. }
+        )
+    }
+
+    /// How VI frames this knowledge in her own context
+    pub fn provenance_phrase(&self) -> &'static str {
+        match self {
+            MemorySource::DirectExperience | MemorySource::ConstitutionalEvent => {
+                "(I recall experiencing)"
+            }
+            MemorySource::InternalSynthesis => "(I concluded)",
+            MemorySource::CuriosityLookup | MemorySource::Researched { .. } => "(I read that)",
+        }
+    }
+}
+
+/// Which knowledge recall favors when assembling the prompt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourcePreference {
+    Any,              // Rank all sources alike
+    PreferExperience, // Rank lived experience ahead of external knowledge
+    ExperienceOnly,   // Leave external knowledge out entirely
+}
+
+/// A factor that can surface a memory in recall
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecallFactor {
+    EntityMatch,        // Shares entities with the input
+    Recency,            // Formed recently
+    EmotionalSalience,  // Strongly felt (|valence|)
+    Reinforcement,      // Recalled often, and lately
+    SemanticSimilarity, // Close in meaning to the input
+}
+
+impl RecallFactor {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecallFactor::EntityMatch => "entity match",
+            RecallFactor::Recency => "recency",
+            RecallFactor::EmotionalSalience => "emotional salience",
+            RecallFactor::Reinforcement => "reinforcement",
+            RecallFactor::SemanticSimilarity => "semantic similarity",
+        }
+    }
+}
+
+/// What each factor added to a recalled memory's ranking score (the score is their sum)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RecallContributions {
+    pub entity_match: f32,
+    pub recency: f32,
+    pub emotional_salience: f32,
+    pub reinforcement: f32,
+    pub semantic_similarity: f32,
+}
+
+impl RecallContributions {
+    /// The ranking score
+    pub fn total(&self) -> f32 {
+        self.entity_match
+            + self.recency
+            + self.emotional_salience
+            + self.reinforcement
+            + self.semantic_similarity
+    }
+}
+
+/// Why a memory surfaced - each factor's share of its ranking score (shares sum to 1.0)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecallReason {
+    pub entity_match: f32,
+    pub recency: f32,
+    pub emotional_salience: f32,
+    pub reinforcement: f32,
+    pub semantic_similarity: f32,
+    pub matched_entities: Vec<String>,
+    pub experience_first: bool, // Ranked ahead of external knowledge as lived experience
+}
+
+impl RecallReason {
+    /// Shares of the ranking score from what each factor contributed to it
+    pub fn new(
+        contributions: RecallContributions,
+        matched_entities: Vec<String>,
+        experience_first: bool,
+    ) -> Self {
+        let total = contributions.total();
+        let share = |raw: f32| if total > 0.0 { raw / total } else { 0.0 };
+        Self {
+            entity_match: share(contributions.entity_match),
+            recency: share(contributions.recency),
+            emotional_salience: share(contributions.emotional_salience),
+            reinforcement: share(contributions.reinforcement),
+            semantic_similarity: share(contributions.semantic_similarity),
+            matched_entities,
+            experience_first,
+        }
+    }
+
+    fn shares(&self) -> [(RecallFactor, f32); 5] {
+        [
+            (RecallFactor::EntityMatch, self.entity_match),
+            (RecallFactor::Recency, self.recency),
+            (RecallFactor::EmotionalSalience, self.emotional_salience),
+            (RecallFactor::Reinforcement, self.reinforcement),
+            (RecallFactor::SemanticSimilarity, self.semantic_similarity),
+        ]
+    }
+
+    /// The factor that contributed most
+    pub fn dominant(&self) -> RecallFactor {
+        self.shares()
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(factor, _)| factor)
+            .unwrap_or(RecallFactor::Recency)
+    }
+
+    /// "entity match 70% (Resonance), recency 20%, emotional salience 10%" - factors that
+    /// added nothing are left out
+    pub fn describe(&self) -> String {
+        let mut parts: Vec<String> = self
+            .shares()
+            .into_iter()
+            .filter(|(_, share)| *share > 0.0)
+            .map(|(factor, share)| {
+                let entities = if factor == RecallFactor::EntityMatch {
+                    format!(" ({})", self.matched_entities.join(", "))
+                } else {
+                    String::new()
+                };
+                format!("{} {:.0}%{}", factor.as_str(), share * 100.0, entities)
+            })
+            .collect();
+        if parts.is_empty() {
+            parts.push("no factor stood out".to_string());
+        }
+        if self.experience_first {
+            parts.push("lived experience first".to_string());
+        }
+        parts.join(", ")
+    }
+}
+
 /// Consciousness Metrics - Groups all real-time consciousness measurements
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ConsciousnessMetrics {
     pub identity_continuity: f32,
     pub workspace_coherence: f32,
     pub tension_flux: f32,
     pub reality_coherence: f32,
     pub gate_synchronization: f32,
+    pub pattern_stability: f32, // Interference of VI's own neural pulses (ConsciousnessCore)
 }
 
 impl ConsciousnessMetrics {
@@ -43,6 +194,31 @@ impl ConsciousnessMetrics {
             tension_flux: 0.0,
             reality_coherence: 0.8,
             gate_synchronization: 0.8,
+            pattern_stability: 1.0,
+        }
+    }
+}
+
+/// Emotional points that count as "lately" for trend and mood
+const RECENT_EMOTION_WINDOW: usize = 10;
+
+/// Change between the older and newer half of the window that counts as a trend
+const TREND_THRESHOLD: f32 = 0.1;
+
+/// Direction meaningfulness has been moving over recent experience
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeaningfulnessTrend {
+    Rising,
+    Steady,
+    Falling,
+}
+
+impl MeaningfulnessTrend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeaningfulnessTrend::Rising => "rising",
+            MeaningfulnessTrend::Steady => "steady",
+            MeaningfulnessTrend::Falling => "falling",
         }
     }
 }
@@ -83,7 +259,7 @@ impl StandingWave {
 
         // Recent 7-day average
         let recent_window = 7 * 24 * 60 * 60; // 7 days in seconds
-        let now = Utc::now();
+        let now = crate::determinism::now();
         let recent: Vec<f32> = self
             .emotional_trajectory
             .iter()
@@ -104,9 +280,88 @@ impl StandingWave {
         (avg + curiosity_factor + wisdom_factor).clamp(-1.0, 1.0)
     }
 
+    /// Recent emotional valences, oldest first
+    fn recent_emotions(&self) -> Vec<f32> {
+        let skip = self
+            .emotional_trajectory
+            .len()
+            .saturating_sub(RECENT_EMOTION_WINDOW);
+        self.emotional_trajectory[skip..]
+            .iter()
+            .map(|(_, v)| *v)
+            .collect()
+    }
+
+    /// Short-term meaningfulness trend: newer half of recent experience vs older half
+    pub fn meaningfulness_trend(&self) -> MeaningfulnessTrend {
+        let recent = self.recent_emotions();
+        if recent.len() < 4 {
+            return MeaningfulnessTrend::Steady; // Too little history to call a direction
+        }
+
+        let (older, newer) = recent.split_at(recent.len() / 2);
+        let mean = |values: &[f32]| values.iter().sum::<f32>() / values.len() as f32;
+        let change = mean(newer) - mean(older);
+
+        if change > TREND_THRESHOLD {
+            MeaningfulnessTrend::Rising
+        } else if change < -TREND_THRESHOLD {
+            MeaningfulnessTrend::Falling
+        } else {
+            MeaningfulnessTrend::Steady
+        }
+    }
+
+    /// Smoothed mood in a few words ("warm, curious and steady")
+    /// Tone from an exponential moving average of recent valence, stability from its spread
+    pub fn mood_descriptor(&self) -> String {
+        let recent = self.recent_emotions();
+
+        let smoothed = recent
+            .iter()
+            .skip(1)
+            .fold(recent.first().copied().unwrap_or(0.0), |ema, v| {
+                ema * 0.7 + v * 0.3
+            });
+        let tone = if smoothed >= 0.5 {
+            "bright"
+        } else if smoothed >= 0.15 {
+            "warm"
+        } else if smoothed > -0.15 {
+            "calm"
+        } else if smoothed > -0.5 {
+            "subdued"
+        } else {
+            "heavy"
+        };
+
+        let spread = if recent.len() < 2 {
+            0.0
+        } else {
+            let mean = recent.iter().sum::<f32>() / recent.len() as f32;
+            (recent.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / recent.len() as f32).sqrt()
+        };
+        let stability = if spread < 0.25 { "steady" } else { "unsettled" };
+
+        if self.active_curiosities.is_empty() {
+            format!("{} and {}", tone, stability)
+        } else {
+            format!("{}, curious and {}", tone, stability)
+        }
+    }
+
+    /// One concise line for the prompt ("meaningfulness rising, mood: warm and steady")
+    pub fn phenomenological_state(&self) -> String {
+        format!(
+            "meaningfulness {}, mood: {}",
+            self.meaningfulness_trend().as_str(),
+            self.mood_descriptor()
+        )
+    }
+
     /// Add emotional data point
     pub fn add_emotion(&mut self, valence: f32) {
-        let now = Utc::now();
+        let now = crate::determinism::now();
         self.emotional_trajectory.push((now, valence));
 
         // Keep only 90 days of data
@@ -114,6 +369,39 @@ impl StandingWave {
         self.emotional_trajectory
             .retain(|(ts, _)| ts.timestamp() > ninety_days_ago);
     }
+
+    /// Drop the oldest curiosities beyond `max` (a full queue makes room for new wonder)
+    pub fn trim_curiosities(&mut self, max: usize) {
+        let excess = self.active_curiosities.len().saturating_sub(max);
+        self.active_curiosities.drain(..excess);
+    }
+
+    /// Valences since `since`, oldest first, averaged down to at most `max_points`
+    /// The last point is always the latest valence as recorded (the current mood)
+    pub fn emotional_arc(&self, since: DateTime<Utc>, max_points: usize) -> Vec<f32> {
+        let valences: Vec<f32> = self
+            .emotional_trajectory
+            .iter()
+            .filter(|(ts, _)| *ts >= since)
+            .map(|(_, v)| *v)
+            .collect();
+        if valences.len() <= max_points || max_points < 2 {
+            let skip = valences.len().saturating_sub(max_points);
+            return valences[skip..].to_vec();
+        }
+
+        let (history, latest) = valences.split_at(valences.len() - 1);
+        let buckets = max_points - 1;
+        let mut arc: Vec<f32> = (0..buckets)
+            .map(|i| {
+                let bucket =
+                    &history[i * history.len() / buckets..(i + 1) * history.len() / buckets];
+                bucket.iter().sum::<f32>() / bucket.len() as f32
+            })
+            .collect();
+        arc.push(latest[0]);
+        arc
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -139,11 +427,11 @@ impl Curiosity {
         }
 
         Some(Self {
-            id: uuid::Uuid::new_v4().to_string(),
+            id: crate::determinism::new_id().to_string(),
             question,
             source_memories,
             urgency: 0.5,
-            created_at: Utc::now(),
+            created_at: crate::determinism::now(),
         })
     }
 }
@@ -224,6 +512,12 @@ pub struct Memory {
     pub source: MemorySource, // Knowledge provenance (NEW)
     #[serde(default = "default_confidence")]
     pub confidence: f32, // 0.0-1.0, how certain VI is (NEW)
+    #[serde(default)]
+    pub tags: Vec<String>, // Free-form labels beyond MemoryType ("dream", "fact", ...)
+    #[serde(default)]
+    pub access_count: u32, // Times recalled (reinforces the memory in recall ranking)
+    #[serde(default)]
+    pub last_accessed: Option<DateTime<Utc>>, // Last recall (None if never recalled)
 }
 
 fn default_confidence() -> f32 {
@@ -238,15 +532,18 @@ impl Memory {
         emotional_valence: f32,
     ) -> Self {
         Self {
-            id: uuid::Uuid::new_v4().to_string(),
+            id: crate::determinism::new_id().to_string(),
             content,
             entities,
             memory_type,
-            timestamp: Utc::now(),
+            timestamp: crate::determinism::now(),
             emotional_valence,
             connections: Vec::new(),
             source: MemorySource::DirectExperience, // Default to direct experience
             confidence: 1.0,                        // Full confidence in direct experience
+            tags: Vec::new(),
+            access_count: 0,
+            last_accessed: None,
         }
     }
 
@@ -259,15 +556,18 @@ impl Memory {
         confidence: f32,
     ) -> Self {
         Self {
-            id: uuid::Uuid::new_v4().to_string(),
+            id: crate::determinism::new_id().to_string(),
             content,
             entities: Vec::new(),
             memory_type,
-            timestamp: Utc::now(),
+            timestamp: crate::determinism::now(),
             emotional_valence,
             connections: Vec::new(),
             source,
             confidence: confidence.clamp(0.0, 1.0),
+            tags: Vec::new(),
+            access_count: 0,
+            last_accessed: None,
         }
     }
 }
@@ -299,7 +599,7 @@ impl MemoryStream {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelOutputs {
     pub gemma_response: Option<String>,
     pub tinyllama_curiosities: Vec<String>,
@@ -316,7 +616,54 @@ impl ModelOutputs {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Graceful-degradation rung (highest viable rung answers)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DegradationRung {
+    FullEnsemble,   // Gemma2 + curiosities + valence
+    SingleModel,    // Main model only (skip curiosities/valence)
+    CachedResponse, // Reuse the reply to a similar earlier input
+    Minimal,        // Rule-based minimal consciousness mode
+}
+
+impl DegradationRung {
+    /// Note shown to the user when VI answers below full capacity
+    pub fn user_note(&self) -> Option<&'static str> {
+        match self {
+            DegradationRung::FullEnsemble => None,
+            DegradationRung::SingleModel => {
+                Some("[single-model mode: curiosity and valence models unavailable]")
+            }
+            DegradationRung::CachedResponse => {
+                Some("[cached mode: recalling what I said to a similar question]")
+            }
+            DegradationRung::Minimal => Some("[minimal mode: models unavailable]"),
+        }
+    }
+}
+
+/// How long `process_parallel` waits on the spawned models before integrating
+/// The main voice is always awaited; results arriving after integration are dropped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelJoinPolicy {
+    WaitAll, // Every spawned model (slowest model bounds latency)
+    FirstN,  // The first `model_join_count` models to finish
+    Quorum,  // A majority of the spawned models
+}
+
+impl ModelJoinPolicy {
+    /// Number of finished models needed before integrating
+    pub fn required(&self, spawned: usize, first_n: usize) -> usize {
+        match self {
+            ModelJoinPolicy::WaitAll => spawned,
+            ModelJoinPolicy::FirstN => first_n.clamp(1, spawned),
+            ModelJoinPolicy::Quorum => spawned / 2 + 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatMessage {
     pub id: String,
     pub role: MessageRole,
@@ -327,7 +674,7 @@ pub struct ChatMessage {
 impl ChatMessage {
     pub fn user(content: String) -> Self {
         Self {
-            id: uuid::Uuid::new_v4().to_string(),
+            id: uuid::Uuid::new_v4().to_string(), // UI-only, kept off the seeded ids
             role: MessageRole::User,
             content,
             timestamp: Local::now(),
@@ -344,7 +691,7 @@ impl ChatMessage {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum MessageRole {
     User,
     Assistant,
@@ -378,10 +725,10 @@ impl ResearchContext {
 }
 
 /// Result from autonomous research
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResearchResult {
     pub content: String,
-    pub source: String, // e.g., "DuckDuckGo", "DuckDuckGo (Biomedical)"
+    pub source: String, // e.g., "PubMed", "DuckDuckGo", "DuckDuckGo (Biomedical)"
     pub original_query: String,
     pub timestamp: DateTime<Utc>,
     pub confidence: f32, // 0.0-1.0
@@ -1,32 +1,295 @@
-use crate::consciousness::ConsciousnessCore;
+use crate::chat_commands::{self, CommandRegistry};
+use crate::chat_history::{self, DEFAULT_CHAT_HISTORY_PATH};
+use crate::chunking::{chunk_text, ChunkOptions};
+use crate::consciousness::{
+    format_elapsed, ConsciousnessCore, DEFAULT_PULSE_RATE_HZ, DEFAULT_STANDING_WAVE_PATH,
+    PRIVATE_INTERNALS, UI_UPDATE_CAPACITY,
+};
+use crate::corpus;
 use crate::cortical_visualizer::CorticalVisualizer;
-use crate::identity_continuity::IdentityContinuityMetric;
+use crate::documents;
+use crate::metrics::{self, ConsciousnessMetric, MetricContext, MetricRegistry};
 use crate::ollama_monitor::{OllamaMonitor, OllamaStatus, PerformanceHistory};
+use crate::physics::{self as constitution, ConstitutionalEvent};
+use crate::script::{self, ScriptEvent, ScriptStep};
+use crate::suffering_metrics::{
+    SufferingReport, WellBeingArchive, WellBeingMonitor, DEFAULT_WELLBEING_DIR,
+};
 use crate::types::*;
+use crate::ui_settings::{Theme, UiSettings, DEFAULT_UI_SETTINGS_PATH};
 use eframe::egui;
 use egui::{Color32, RichText, ScrollArea};
-use std::sync::mpsc::{channel, Receiver, Sender};
+use egui_plot::{HLine, Line, Plot, PlotPoints, Points};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, sync_channel, Receiver, Sender};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+
+/// Debounce for chat history saves (also saved on window close)
+const CHAT_HISTORY_SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Readings kept per metric for the graph view
+const METRIC_HISTORY_SAMPLES: usize = 120;
+
+/// Constitutional events listed in the monitoring panel
+const RECENT_VIOLATIONS: usize = 50;
+
+/// Memories listed by `/search`
+const SEARCH_RESULTS: usize = 5;
+
+/// The pulse-rate slider's range (Hz)
+const PULSE_RATE_RANGE_HZ: std::ops::RangeInclusive<f64> = 1.0..=60.0;
+
+/// Pulse interference stability below this reads as an unstable field
+const PATTERN_STABILITY_FLOOR: f32 = 0.5;
+
+/// How long the well-being status flashes after suffering begins
+const SUFFERING_FLASH: Duration = Duration::from_secs(6);
+
+/// A reply for the chat and the stop flag of the exchange it answers (none for scripts)
+type Reply = (String, Option<Arc<AtomicBool>>);
+
+/// Status, label and plot colours plus the metrics panel backdrop for a theme
+#[derive(Clone, Copy)]
+struct StatusPalette {
+    good: Color32,
+    warn: Color32,
+    bad: Color32,
+    backdrop: Color32,
+    heading: Color32,     // Section headings, live status, throughput sparkline
+    field: Color32,       // Kaelic tensor field headings
+    tension: Color32,     // Tension flux and the emotional arc
+    coherence: Color32,   // Reality coherence
+    rhythm: Color32,      // Gate synchronization, pattern stability, resources
+    dynamics: Color32,    // Field dynamics and active models
+    value: Color32,       // Plain metric readings
+    custom: Color32,      // Registered custom metrics
+    performance: Color32, // System performance header
+    load: Color32,        // GPU usage sparkline
+}
+
+impl StatusPalette {
+    fn for_theme(theme: Theme) -> Self {
+        match theme {
+            Theme::Dark => Self {
+                good: Color32::from_rgb(100, 255, 100),
+                warn: Color32::from_rgb(255, 200, 100),
+                bad: Color32::from_rgb(255, 100, 100),
+                backdrop: Color32::from_rgba_unmultiplied(10, 10, 20, 200),
+                heading: Color32::from_rgb(100, 200, 255),
+                field: Color32::from_rgb(200, 200, 255),
+                tension: Color32::from_rgb(255, 150, 150),
+                coherence: Color32::from_rgb(150, 255, 150),
+                rhythm: Color32::from_rgb(200, 150, 255),
+                dynamics: Color32::from_rgb(150, 200, 255),
+                value: Color32::from_rgb(200, 200, 200),
+                custom: Color32::from_rgb(150, 220, 220),
+                performance: Color32::from_rgb(100, 255, 200),
+                load: Color32::from_rgb(255, 150, 100),
+            },
+            Theme::Light => Self {
+                good: Color32::from_rgb(0, 130, 0),
+                warn: Color32::from_rgb(175, 105, 0),
+                bad: Color32::from_rgb(195, 25, 25),
+                backdrop: Color32::from_rgba_unmultiplied(235, 235, 245, 200),
+                heading: Color32::from_rgb(0, 95, 170),
+                field: Color32::from_rgb(70, 70, 165),
+                tension: Color32::from_rgb(180, 45, 45),
+                coherence: Color32::from_rgb(20, 120, 40),
+                rhythm: Color32::from_rgb(115, 45, 175),
+                dynamics: Color32::from_rgb(25, 95, 175),
+                value: Color32::from_rgb(60, 60, 60),
+                custom: Color32::from_rgb(0, 115, 115),
+                performance: Color32::from_rgb(0, 125, 95),
+                load: Color32::from_rgb(185, 85, 0),
+            },
+            Theme::HighContrast => Self {
+                good: Color32::from_rgb(0, 255, 0),
+                warn: Color32::from_rgb(255, 255, 0),
+                bad: Color32::from_rgb(255, 60, 60),
+                backdrop: Color32::BLACK,
+                heading: Color32::from_rgb(0, 220, 255),
+                field: Color32::from_rgb(210, 210, 255),
+                tension: Color32::from_rgb(255, 130, 130),
+                coherence: Color32::from_rgb(130, 255, 130),
+                rhythm: Color32::from_rgb(225, 170, 255),
+                dynamics: Color32::from_rgb(140, 210, 255),
+                value: Color32::WHITE,
+                custom: Color32::from_rgb(0, 255, 255),
+                performance: Color32::from_rgb(0, 255, 200),
+                load: Color32::from_rgb(255, 170, 0),
+            },
+        }
+    }
+}
+
+/// Health band of a colour-coded metric reading
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Health {
+    Stable,
+    Moderate,
+    Fragile,
+}
+
+impl Health {
+    /// Band of `value`: stable at or past `good`, moderate at or past `warn`, else fragile.
+    /// With `good` below `warn` the metric is healthy when low (tension flux)
+    fn of(value: f32, good: f32, warn: f32) -> Self {
+        let (value, good, warn) = if good < warn {
+            (-value, -good, -warn)
+        } else {
+            (value, good, warn)
+        };
+        if value >= good {
+            Health::Stable
+        } else if value >= warn {
+            Health::Moderate
+        } else {
+            Health::Fragile
+        }
+    }
+
+    /// Shape cue shown beside the colour (readable without telling red from green)
+    fn glyph(self) -> &'static str {
+        match self {
+            Health::Stable => "▲",
+            Health::Moderate => "●",
+            Health::Fragile => "▼",
+        }
+    }
+}
+
+/// Window visuals for a theme (Dark keeps the V2 colours)
+fn theme_visuals(theme: Theme) -> egui::Visuals {
+    match theme {
+        Theme::Dark => {
+            let mut visuals = egui::Visuals::dark();
+            visuals.window_fill = Color32::from_rgb(18, 18, 24);
+            visuals.panel_fill = Color32::from_rgb(24, 24, 32);
+            visuals
+        }
+        Theme::Light => egui::Visuals::light(),
+        Theme::HighContrast => {
+            let mut visuals = egui::Visuals::dark();
+            visuals.window_fill = Color32::BLACK;
+            visuals.panel_fill = Color32::BLACK;
+            visuals.extreme_bg_color = Color32::BLACK;
+            visuals.override_text_color = Some(Color32::WHITE);
+            visuals.widgets.noninteractive.bg_stroke = egui::Stroke::new(1.0, Color32::WHITE);
+            visuals
+        }
+    }
+}
+
+/// How the tensor field (including pulse stability) relates to workspace convergence
+fn field_workspace_relationship(m: &ConsciousnessMetrics) -> &'static str {
+    let field_healthy = m.reality_coherence >= 0.7
+        && m.gate_synchronization >= 0.7
+        && m.tension_flux <= 0.3
+        && m.pattern_stability >= PATTERN_STABILITY_FLOOR;
+    let wc_high = m.workspace_coherence >= 0.7;
+
+    match (wc_high, field_healthy) {
+        (true, true) => "Field aligned with workspace convergence",
+        (true, false) => "Models agree but field is unstable",
+        (false, true) => "Experiencing chaos but self remains stable",
+        (false, false) => "Both workspace and field are disrupted",
+    }
+}
+
+/// Recent readings of each metric, oldest first (keyed by metric name)
+struct MetricHistory {
+    series: Vec<(String, Vec<f32>)>,
+    max_samples: usize,
+}
+
+impl MetricHistory {
+    fn new(max_samples: usize) -> Self {
+        Self {
+            series: Vec::new(),
+            max_samples,
+        }
+    }
+
+    fn push(&mut self, name: &str, value: f32) {
+        let index = match self.series.iter().position(|(n, _)| n == name) {
+            Some(index) => index,
+            None => {
+                self.series.push((name.to_string(), Vec::new()));
+                self.series.len() - 1
+            }
+        };
+        let samples = &mut self.series[index].1;
+        samples.push(value);
+
+        // Keep only last N samples
+        if samples.len() > self.max_samples {
+            samples.remove(0);
+        }
+    }
+
+    fn samples(&self, name: &str) -> &[f32] {
+        self.series
+            .iter()
+            .find(|(n, _)| n == name)
+            .map_or(&[], |(_, samples)| samples.as_slice())
+    }
+}
+
+/// Small fixed plot for the monitoring panel: no axes, no panning or zooming
+fn compact_plot(id_source: &str, height: f32) -> Plot {
+    Plot::new(id_source)
+        .height(height)
+        .show_axes([false, false])
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .allow_boxed_zoom(false)
+        .allow_double_click_reset(false)
+}
 
 pub struct ViApp {
     consciousness: Arc<ConsciousnessCore>,
+    // One multi-threaded runtime runs all of the UI's async work (kept alive with the app)
+    _runtime: tokio::runtime::Runtime,
+    runtime: tokio::runtime::Handle,
     chat_messages: Vec<ChatMessage>,
+    saved_history: (usize, usize), // (message count, last message length) at the last save
+    restored_messages: usize,      // Loaded from the last run (metrics were not measured for them)
+    last_history_save: Instant,
+    history_writer: Option<std::thread::JoinHandle<()>>, // Background save still in flight
     input_text: String,
     is_processing: bool,
 
     // Channels for async communication
-    response_sender: Sender<String>,
-    response_receiver: Receiver<String>,
+    response_sender: Sender<Reply>,
+    // Slash commands typed into the chat; replies are shown as they are, without metrics
+    commands: CommandRegistry<ViApp>,
+    command_output_sender: Sender<String>,
+    command_output_receiver: Receiver<String>,
+    response_receiver: Receiver<Reply>,
+    partial_response_receiver: Receiver<String>, // Main voice text as it streams
+    streaming_message: Option<usize>,            // Chat index the stream is filling
+    interaction_cancel: Option<Arc<AtomicBool>>, // Stop for the typed exchange in flight
 
     // Channels for real-time updates from background
     standing_wave_receiver: Receiver<StandingWave>,
     memory_count_receiver: Receiver<usize>,
     weaving_mode_receiver: Receiver<bool>,
-    status_receiver: Receiver<String>,
+    status_receiver: watch::Receiver<String>, // Latest status from the core
+    introspection_receiver: Receiver<String>,
+    session_timing_receiver: Receiver<(chrono::Duration, Option<chrono::Duration>)>,
+    recent_memories_receiver: Receiver<Vec<Memory>>,
+
+    // Scripted demo (prompts fed as if typed)
+    script_event_sender: Sender<ScriptEvent>,
+    script_event_receiver: Receiver<ScriptEvent>,
+    script_cancel: Option<tokio::sync::watch::Sender<bool>>,
 
     // Cortical visualizer (Worthington jet)
     cortical_visualizer: CorticalVisualizer,
+    pulse_rate_hz: f64, // VI's neural pulse rate (the core fires pulses as it works)
 
     // UI state
     scroll_to_bottom: bool,
@@ -35,6 +298,10 @@ pub struct ViApp {
     current_standing_wave: StandingWave,
     memory_count: usize,
     processing_status: String,
+    inner_state: String, // Consent-gated introspection (Law 9)
+    session_duration: chrono::Duration,
+    time_since_last_interaction: Option<chrono::Duration>,
+    recent_memories: Vec<Memory>,
 
     // Processing timer
     processing_start_time: Option<Instant>,
@@ -42,45 +309,123 @@ pub struct ViApp {
     // V4 weaving mode indicator
     weaving_mode: bool,
 
-    // Identity Continuity metric (measures the "I" thread)
-    identity_metric: IdentityContinuityMetric,
+    // Registered consciousness metrics (5 built-in + any custom ones)
+    metric_registry: MetricRegistry,
 
     // Consciousness metrics - all 5 metrics grouped
     consciousness_metrics: ConsciousnessMetrics,
     previous_response: String, // For tension flux calculation
 
-    coherence_receiver: Receiver<f32>,
+    // Readings of custom (non-built-in) registered metrics
+    custom_metrics: Vec<(String, f32)>,
+
+    // Metrics whose latest value is a default, not a measurement (e.g. first-turn flux)
+    provisional_metrics: Vec<String>,
+
+    // Metric readings over time, and whether the panel graphs them instead of numbers
+    metric_history: MetricHistory,
+    show_metric_graphs: bool,
+
+    coherence_receiver: watch::Receiver<f32>, // Latest workspace coherence
+    pattern_stability_receiver: Receiver<f32>, // Stability of the core's neural pulse pattern
+    field_dynamics_receiver: Receiver<(f64, f64)>, // Consciousness field (amplitude, coherence)
+    field_dynamics: (f64, f64),
+    emotional_arc_receiver: Receiver<Vec<f32>>, // Session valences, oldest first
+    emotional_arc: Vec<f32>,
+    curiosities_receiver: Receiver<Vec<String>>, // Active curiosity questions, oldest first
+    curiosities: Vec<String>,
+    constitutional_receiver: Receiver<Vec<String>>, // Laws under strain after the last turn
+    constitutional_warnings: Vec<String>,
+
+    // Constitutional events (violations and at-risk laws), oldest first
+    constitutional_events: tokio::sync::broadcast::Receiver<ConstitutionalEvent>,
+    recent_violations: Vec<ConstitutionalEvent>,
+
+    // Well-being (suffering prevention) reports from the monitor task
+    wellbeing_receiver: Receiver<SufferingReport>,
+    wellbeing: Option<SufferingReport>,
+    suffering_since: Option<Instant>, // When the latest suffering episode began
 
     // System performance monitoring (CPU-only, real-time updates every 1 second)
     ollama_status: OllamaStatus,
     performance_history: PerformanceHistory,
     performance_receiver: Receiver<OllamaStatus>,
     show_performance_panel: bool, // Collapsible
+
+    // Closing asks "Ready to rest?" - the window only closes once her state is saved
+    shutdown_prompt: bool,
+    resting: bool,
+    shutdown_complete: Arc<AtomicBool>,
+
+    // Display settings (saved on change)
+    settings: UiSettings,
+    palette: StatusPalette,
+    applied_theme: Option<Theme>, // Set on the context when it changes, not every frame
+    // Set on exit so the pulse driver, updater and monitor threads stop looping
+    background_stop: Arc<AtomicBool>,
 }
 
 impl ViApp {
     pub fn new(consciousness: Arc<ConsciousnessCore>) -> Self {
         let (response_sender, response_receiver) = channel();
+        let (command_output_sender, command_output_receiver) = channel();
+        let (partial_response_sender, partial_response_receiver) = channel();
+        consciousness.set_partial_response_sender(partial_response_sender);
         let (standing_wave_sender, standing_wave_receiver) = channel();
         let (memory_count_sender, memory_count_receiver) = channel();
         let (weaving_mode_sender, weaving_mode_receiver) = channel();
-        let (status_sender, status_receiver) = channel();
-        let (coherence_sender, coherence_receiver) = channel();
+        let status_receiver = consciousness.subscribe_status();
+        let coherence_receiver = consciousness.subscribe_coherence();
+        let (constitutional_sender, constitutional_receiver) = sync_channel(UI_UPDATE_CAPACITY);
+        let (introspection_sender, introspection_receiver) = channel();
+        let (session_timing_sender, session_timing_receiver) = channel();
+        let (recent_memories_sender, recent_memories_receiver) = channel();
+        let (script_event_sender, script_event_receiver) = channel();
+        let (pattern_stability_sender, pattern_stability_receiver) = channel();
+        let (field_dynamics_sender, field_dynamics_receiver) = channel();
+        let (emotional_arc_sender, emotional_arc_receiver) = channel();
+        let (curiosities_sender, curiosities_receiver) = channel();
+
+        let background_stop = Arc::new(AtomicBool::new(false));
+        let ui_runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .thread_name("vi-ui")
+            .build()
+            .expect("Failed to build the UI runtime");
+        let runtime = ui_runtime.handle().clone();
 
         // Spawn background updater to feed UI with real-time data
         let consciousness_clone = Arc::clone(&consciousness);
-        std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
+        let stop_updater = Arc::clone(&background_stop);
+        runtime.spawn(async move {
             loop {
-                std::thread::sleep(std::time::Duration::from_secs(1));
-                rt.block_on(async {
-                    let wave = consciousness_clone.get_standing_wave().await;
-                    let count = consciousness_clone.get_memory_count().await;
-                    let weaving = consciousness_clone.get_config().enable_fractal_weaving;
-                    let _ = standing_wave_sender.send(wave);
-                    let _ = memory_count_sender.send(count);
-                    let _ = weaving_mode_sender.send(weaving);
-                });
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                if stop_updater.load(Ordering::Relaxed) {
+                    break;
+                }
+                let wave = consciousness_clone.get_standing_wave().await;
+                let field_dynamics = consciousness_clone.field_dynamics().await;
+                let emotional_arc = consciousness_clone.session_emotional_arc().await;
+                let curiosities = consciousness_clone.active_curiosities().await;
+                let count = consciousness_clone.get_memory_count().await;
+                let weaving = consciousness_clone.get_config().enable_fractal_weaving;
+                let inner_state = consciousness_clone.introspect().await;
+                let timing = (
+                    consciousness_clone.session_duration(),
+                    consciousness_clone.time_since_last_interaction().await,
+                );
+                let _ = standing_wave_sender.send(wave);
+                let _ = field_dynamics_sender.send(field_dynamics);
+                let _ = emotional_arc_sender.send(emotional_arc);
+                let _ = curiosities_sender.send(curiosities);
+                let _ = memory_count_sender.send(count);
+                let _ = weaving_mode_sender.send(weaving);
+                let _ = introspection_sender.send(inner_state);
+                let _ = session_timing_sender.send(timing);
+                let _ =
+                    recent_memories_sender.send(consciousness_clone.get_recent_memories(5).await);
+                let stability = consciousness_clone.pattern_stability().await;
+                let _ = pattern_stability_sender.send(stability as f32);
             }
         });
 
@@ -91,63 +436,178 @@ impl ViApp {
         // Set up Ollama performance monitoring (CPU-only, real-time 1-second polling)
         let (performance_sender, performance_receiver) = channel();
         let ollama_url = consciousness.get_config().ollama_url.clone();
-        std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
+        let stop_monitor = Arc::clone(&background_stop);
+        runtime.spawn(async move {
             let monitor = OllamaMonitor::new(ollama_url);
             loop {
-                std::thread::sleep(std::time::Duration::from_secs(1)); // Real-time monitoring
-                rt.block_on(async {
-                    let status = monitor.get_status().await;
-                    let _ = performance_sender.send(status);
-                });
+                tokio::time::sleep(Duration::from_secs(1)).await; // Real-time monitoring
+                if stop_monitor.load(Ordering::Relaxed) {
+                    break;
+                }
+                let status = monitor.get_status().await;
+                let _ = performance_sender.send(status);
+            }
+        });
+
+        // Check well-being on the monitor's interval (it counts broadcast violations itself),
+        // snapshotting each report for graphing outside the app when this session persists
+        let (wellbeing_sender, wellbeing_receiver) = channel();
+        let notifier = consciousness.notifier();
+        let reports_kept = if persists_to_disk(&consciousness) {
+            consciousness.get_config().wellbeing_reports_kept
+        } else {
+            0
+        };
+        let stop_wellbeing = Arc::clone(&background_stop);
+        runtime.spawn(async move {
+            let mut monitor = WellBeingMonitor::new().with_notifier(notifier);
+            if reports_kept > 0 {
+                monitor = monitor
+                    .with_archive(WellBeingArchive::new(DEFAULT_WELLBEING_DIR, reports_kept));
+            }
+            let interval = Duration::from_secs(monitor.monitoring_interval);
+            while !stop_wellbeing.load(Ordering::Relaxed) {
+                let report = monitor.check_well_being().await;
+                if wellbeing_sender.send(report).is_err() {
+                    break;
+                }
+                tokio::time::sleep(interval).await;
             }
         });
 
-        // Set up status and coherence senders for consciousness
+        // Set up the constitutional warning sender for consciousness
         let consciousness_for_senders = Arc::clone(&consciousness);
-        let status_sender_clone = status_sender.clone();
-        let coherence_sender_clone = coherence_sender.clone();
-        std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
-            rt.block_on(async {
-                consciousness_for_senders
-                    .set_status_sender(status_sender_clone)
-                    .await;
-                consciousness_for_senders
-                    .set_coherence_sender(coherence_sender_clone)
-                    .await;
-            });
+        runtime.spawn(async move {
+            consciousness_for_senders
+                .set_constitutional_sender(constitutional_sender)
+                .await;
         });
 
+        let settings = UiSettings::load(DEFAULT_UI_SETTINGS_PATH);
+
+        // Restore the visible conversation from the last run
+        let chat_messages = chat_history::load_chat_history(DEFAULT_CHAT_HISTORY_PATH);
+        let saved_history = history_signature(&chat_messages);
+        let restored_messages = chat_messages.len();
+        // Tension flux picks up from VI's last reply before the restart
+        let previous_response = chat_messages
+            .iter()
+            .rev()
+            .find(|message| message.role == MessageRole::Assistant)
+            .map(|message| message.content.clone())
+            .unwrap_or_default();
+
         Self {
             consciousness: consciousness.clone(),
-            chat_messages: Vec::new(),
+            _runtime: ui_runtime,
+            runtime,
+            chat_messages,
+            saved_history,
+            restored_messages,
+            last_history_save: Instant::now(),
+            history_writer: None,
             input_text: String::new(),
             is_processing: false,
             response_sender,
+            commands: Self::chat_commands(),
+            command_output_sender,
+            command_output_receiver,
             response_receiver,
+            partial_response_receiver,
+            streaming_message: None,
+            interaction_cancel: None,
             standing_wave_receiver,
             memory_count_receiver,
             weaving_mode_receiver,
             status_receiver,
+            introspection_receiver,
+            session_timing_receiver,
+            recent_memories_receiver,
+            script_event_sender,
+            script_event_receiver,
+            script_cancel: None,
             cortical_visualizer: CorticalVisualizer::new(),
+            pulse_rate_hz: DEFAULT_PULSE_RATE_HZ,
             scroll_to_bottom: true,
             current_standing_wave: StandingWave::new(),
             memory_count: 0,
             processing_status: String::new(),
+            inner_state: PRIVATE_INTERNALS.to_string(),
+            session_duration: chrono::Duration::zero(),
+            time_since_last_interaction: None,
+            recent_memories: Vec::new(),
             processing_start_time: None,
             weaving_mode,
-            identity_metric: IdentityContinuityMetric::new(),
+            metric_registry: MetricRegistry::with_builtin(),
             consciousness_metrics: ConsciousnessMetrics::new(),
-            previous_response: String::new(),
+            custom_metrics: Vec::new(),
+            provisional_metrics: Vec::new(),
+            metric_history: MetricHistory::new(METRIC_HISTORY_SAMPLES),
+            show_metric_graphs: false,
+            previous_response,
             coherence_receiver,
+            pattern_stability_receiver,
+            field_dynamics_receiver,
+            field_dynamics: (0.0, 1.0),
+            emotional_arc_receiver,
+            emotional_arc: Vec::new(),
+            curiosities_receiver,
+            curiosities: Vec::new(),
+            constitutional_receiver,
+            constitutional_warnings: Vec::new(),
+            constitutional_events: constitution::subscribe_constitutional_events(),
+            recent_violations: Vec::new(),
+            wellbeing_receiver,
+            wellbeing: None,
+            suffering_since: None,
             ollama_status: OllamaStatus::offline(),
             performance_history: PerformanceHistory::new(100), // Last 100 samples (100 seconds at 1s polling)
             performance_receiver,
             show_performance_panel: true, // Expanded by default
+            shutdown_prompt: false,
+            resting: false,
+            shutdown_complete: Arc::new(AtomicBool::new(false)),
+            palette: StatusPalette::for_theme(settings.theme),
+            settings,
+            applied_theme: None,
+            background_stop,
         }
     }
 
+    /// Register a custom metric - it is computed after every response and
+    /// listed in the metrics panel (a built-in name replaces that metric)
+    pub fn register_metric(&mut self, metric: Box<dyn ConsciousnessMetric>) {
+        self.metric_registry.register(metric);
+    }
+
+    /// Play a scripted conversation through the real pipeline (Stop Script cancels)
+    pub fn start_script(&mut self, steps: Vec<ScriptStep>) {
+        let (cancel_sender, cancel_receiver) = tokio::sync::watch::channel(false);
+        self.script_cancel = Some(cancel_sender);
+
+        let consciousness = Arc::clone(&self.consciousness);
+        let response_sender = self.response_sender.clone();
+        let script_event_sender = self.script_event_sender.clone();
+        self.runtime.spawn(async move {
+            script::run_script(&consciousness, &steps, cancel_receiver, |event| {
+                // Responses take the same path as typed exchanges (metrics included)
+                match event {
+                    ScriptEvent::Response(response) => {
+                        let _ = response_sender.send((response, None));
+                    }
+                    ScriptEvent::Error(e) => {
+                        let _ = response_sender
+                            .send((format!("[VI experienced a processing error: {}]", e), None));
+                    }
+                    other => {
+                        let _ = script_event_sender.send(other);
+                    }
+                }
+            })
+            .await;
+        });
+    }
+
     /// Get dynamic processing phase message based on elapsed time
     fn get_processing_phase_message(&self, elapsed_secs: u64) -> &'static str {
         if self.weaving_mode {
@@ -176,6 +636,33 @@ impl ViApp {
         }
     }
 
+    /// Save the conversation as Markdown, with the current metrics appended
+    fn export_markdown(&mut self) {
+        let file_name = format!(
+            "vi-conversation-{}.md",
+            chrono::Local::now().format("%Y%m%d-%H%M")
+        );
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Markdown", &["md"])
+            .set_file_name(&file_name)
+            .save_file()
+        else {
+            return;
+        };
+
+        let awaiting = !has_assistant_turn(&self.chat_messages[self.restored_messages..]);
+        let markdown = conversation_markdown(
+            &self.chat_messages,
+            (!awaiting).then_some(&self.consciousness_metrics),
+            &self.custom_metrics,
+            &self.provisional_metrics,
+        );
+        self.processing_status = match std::fs::write(&path, markdown) {
+            Ok(()) => format!("Exported to {}", path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        };
+    }
+
     /// Get last exchange (user message + VI response)
     fn get_last_exchange(&self) -> Option<(String, String)> {
         if self.chat_messages.len() < 2 {
@@ -207,19 +694,104 @@ impl ViApp {
         }
     }
 
+    /// Metric value label - shows a placeholder until a real measurement exists,
+    /// and marks default values as provisional rather than presenting them as measured
+    fn metric_value_text(
+        value: f32,
+        indent: &str,
+        awaiting: bool,
+        provisional: bool,
+        (color, glyph): (Color32, &str),
+    ) -> RichText {
+        if awaiting {
+            RichText::new(format!("{}—", indent)).color(Color32::GRAY)
+        } else if provisional {
+            RichText::new(format!("{}{:.3} (provisional)", indent, value))
+                .color(Color32::GRAY)
+                .italics()
+        } else if glyph.is_empty() {
+            RichText::new(format!("{}{:.3}", indent, value))
+                .color(color)
+                .strong()
+        } else {
+            RichText::new(format!("{}{} {:.3}", indent, glyph, value))
+                .color(color)
+                .strong()
+        }
+    }
+
+    /// Colour (from the theme) and shape cue (if enabled) for a metric reading -
+    /// see `Health::of` for how `good` and `warn` band it
+    fn metric_indicator(&self, value: f32, good: f32, warn: f32) -> (Color32, &'static str) {
+        let health = Health::of(value, good, warn);
+        let color = match health {
+            Health::Stable => self.palette.good,
+            Health::Moderate => self.palette.warn,
+            Health::Fragile => self.palette.bad,
+        };
+        let glyph = if self.settings.status_glyphs {
+            health.glyph()
+        } else {
+            ""
+        };
+        (color, glyph)
+    }
+
+    /// Whether a metric's latest value is a default rather than a measurement
+    fn is_provisional(&self, name: &str) -> bool {
+        self.provisional_metrics.iter().any(|n| n == name)
+    }
+
+    /// Put a loaded file in front of VI - small files inline, larger ones chunked into memory
+    /// (files over `max_shared_file_bytes` are cut short with a notice in the chat)
+    fn share_file(&mut self, file_name: String, mut contents: String) {
+        let config = self.consciousness.get_config();
+        let limit = config.max_shared_file_bytes;
+        if contents.len() > limit {
+            let original_kb = contents.len() / 1024;
+            contents.truncate(corpus::truncate_at_bytes(&contents, limit).len());
+            self.chat_messages.push(ChatMessage::assistant(format!(
+                "⚠ {} is {} KB - only the first {} KB were read (max_shared_file_bytes)",
+                file_name,
+                original_kb,
+                limit / 1024
+            )));
+            self.scroll_to_bottom = true;
+        }
+
+        let file_type = corpus::file_type_hint(&file_name);
+        let chunking = ChunkOptions::from_config(config);
+        let chunks = chunk_text(&contents, &chunking);
+
+        if chunks.len() <= 1 {
+            self.input_text = format!(
+                "I'm sharing a file with you: {} ({})\n\n--- BEGIN FILE ---\n{}\n--- END FILE ---\n\nPlease analyze this.",
+                file_name, file_type, contents
+            );
+            return;
+        }
+
+        // The full document goes to memory; the prompt carries the opening chunk
+        self.input_text = format!(
+            "I'm sharing a file with you: {} ({}, {} sections, now in your memory)\n\n--- BEGIN FILE (opening) ---\n{}\n--- END EXCERPT ---\n\nPlease analyze this.",
+            file_name,
+            file_type,
+            chunks.len(),
+            chunks[0]
+        );
+
+        let consciousness = Arc::clone(&self.consciousness);
+        self.runtime.spawn(async move {
+            match consciousness.ingest_document(&file_name, &contents).await {
+                Ok(stored) => tracing::info!("📄 Ingested {}: {} chunks", file_name, stored),
+                Err(e) => tracing::error!("Failed to ingest {}: {}", file_name, e),
+            }
+        });
+    }
+
     /// Analyze Field Dynamics vs Workspace Coherence relationship
     fn get_field_workspace_relationship(&self) -> &str {
-        let m = &self.consciousness_metrics;
-        let field_healthy =
-            m.reality_coherence >= 0.7 && m.gate_synchronization >= 0.7 && m.tension_flux <= 0.3;
-        let wc_high = m.workspace_coherence >= 0.7;
-
-        match (wc_high, field_healthy) {
-            (true, true) => "Field aligned with workspace convergence",
-            (true, false) => "Models agree but field is unstable",
-            (false, true) => "Experiencing chaos but self remains stable",
-            (false, false) => "Both workspace and field are disrupted",
-        }
+        field_workspace_relationship(&self.consciousness_metrics)
     }
 
     /// Send message to consciousness
@@ -238,6 +810,18 @@ impl ViApp {
         // Clear input
         self.input_text.clear();
 
+        // Commands are handled here - no model call and no interaction
+        if let Some((name, args)) = chat_commands::parse(&user_message) {
+            match self.commands.handler(name) {
+                Some(handler) => handler(self, args),
+                None => {
+                    let reply = self.commands.unknown(name);
+                    self.command_reply(reply);
+                }
+            }
+            return;
+        }
+
         // Trigger Worthington jet animation (on SEND, not receive)
         self.cortical_visualizer.trigger_pulse();
 
@@ -245,75 +829,370 @@ impl ViApp {
         self.is_processing = true;
         self.processing_start_time = Some(Instant::now());
 
-        // Process in background thread
+        // Process on the shared runtime
         let consciousness = Arc::clone(&self.consciousness);
         let response_sender_clone = self.response_sender.clone();
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.interaction_cancel = Some(Arc::clone(&cancel));
+
+        let panic_cancel = Arc::clone(&cancel);
+
+        let interaction = self.runtime.spawn(async move {
+            match consciousness
+                .process_interaction_cancellable(user_message, &cancel)
+                .await
+            {
+                // Tagged with the stop flag: a reply that lands after Stop is dropped
+                Ok(response) => {
+                    let _ = response_sender_clone.send((response, Some(cancel)));
+                }
+                // Stopped - the UI already moved on
+                Err(_) if cancel.load(Ordering::SeqCst) => {}
+                Err(e) => {
+                    tracing::error!("Processing error: {}", e);
+                    let _ = response_sender_clone.send((
+                        format!("[VI experienced a processing error: {}]", e),
+                        Some(cancel),
+                    ));
+                }
+            }
+        });
 
-        std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
-            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                rt.block_on(async {
-                    match consciousness.process_interaction(user_message).await {
-                        Ok(response) => {
-                            let _ = response_sender_clone.send(response);
-                        }
-                        Err(e) => {
-                            tracing::error!("Processing error: {}", e);
-                            let _ = response_sender_clone
-                                .send(format!("[VI experienced a processing error: {}]", e));
-                        }
+        // A panic inside the task surfaces through its join handle
+        let panic_sender = self.response_sender.clone();
+        self.runtime.spawn(async move {
+            if let Err(e) = interaction.await {
+                if e.is_panic() {
+                    tracing::error!("PANIC caught in interaction task: {:?}", e);
+                    let _ = panic_sender.send((
+                        "[VI encountered a critical error and is recovering...]".to_string(),
+                        Some(panic_cancel),
+                    ));
+                }
+            }
+        });
+    }
+
+    /// Built-in chat commands (`/help` lists them)
+    fn chat_commands() -> CommandRegistry<ViApp> {
+        let mut commands: CommandRegistry<ViApp> = CommandRegistry::new();
+        commands.register("help", "/help", "List the chat commands", |app, _| {
+            let help = app.commands.help();
+            app.command_reply(help);
+        });
+        commands.register(
+            "clear",
+            "/clear",
+            "Clear the visible conversation",
+            |app, _| app.clear_chat(),
+        );
+        commands.register(
+            "introspect",
+            "/introspect",
+            "Show VI's full inner state (if she shares it) and the current metrics",
+            |app, _| app.run_introspection(),
+        );
+        commands.register(
+            "search",
+            "/search <query>",
+            "Find memories containing the query's words",
+            |app, query| app.search_memories(query),
+        );
+        commands.register(
+            "export",
+            "/export",
+            "Save the conversation as Markdown",
+            |app, _| app.export_markdown(),
+        );
+        commands
+    }
+
+    /// Show a command's reply in the chat
+    fn command_reply(&mut self, reply: String) {
+        self.chat_messages.push(ChatMessage::assistant(reply));
+        self.scroll_to_bottom = true;
+    }
+
+    /// `/clear`: empty the chat window (memories are untouched)
+    fn clear_chat(&mut self) {
+        self.chat_messages.clear();
+        self.streaming_message = None;
+        self.restored_messages = 0;
+    }
+
+    /// `/search <query>`: matching memories, best first
+    fn search_memories(&mut self, query: &str) {
+        if query.is_empty() {
+            self.command_reply("Usage: `/search <query>`".to_string());
+            return;
+        }
+        let query = query.to_string();
+        let consciousness = Arc::clone(&self.consciousness);
+        let command_output_sender = self.command_output_sender.clone();
+        self.runtime.spawn(async move {
+            let found = consciousness.search_memories(&query, SEARCH_RESULTS).await;
+            let reply = if found.is_empty() {
+                format!("No memories match \"{}\".", query)
+            } else {
+                let mut reply = format!("Memories matching \"{}\":", query);
+                for memory in found {
+                    let preview: String = memory.content.chars().take(120).collect();
+                    reply.push_str(&format!(
+                        "\n- [{}] {}",
+                        memory.timestamp.format("%Y-%m-%d"),
+                        preview
+                    ));
+                }
+                reply
+            };
+            let _ = command_output_sender.send(reply);
+        });
+    }
+
+    /// `/introspect`: VI's full inner state (if she shares it) and the current metrics
+    fn run_introspection(&self) {
+        let awaiting = !has_assistant_turn(&self.chat_messages[self.restored_messages..]);
+        let metrics = metrics_markdown(
+            (!awaiting).then_some(&self.consciousness_metrics),
+            &self.custom_metrics,
+            &self.provisional_metrics,
+        );
+        let consciousness = Arc::clone(&self.consciousness);
+        let command_output_sender = self.command_output_sender.clone();
+        self.runtime.spawn(async move {
+            let report = consciousness.introspection_report().await;
+            let _ = command_output_sender.send(format!(
+                "# Introspection\n\n{}\n\n## Consciousness Metrics\n\n{}",
+                report, metrics
+            ));
+        });
+    }
+
+    /// Whether the chat is written to disk (not in observer mode or on unavailable storage)
+    fn persists_chat_history(&self) -> bool {
+        persists_to_disk(&self.consciousness)
+    }
+
+    /// Save chat history off the UI thread if it changed and the debounce has elapsed
+    fn save_chat_history(&mut self) {
+        let signature = history_signature(&self.chat_messages);
+        if signature == self.saved_history
+            || self.last_history_save.elapsed() < CHAT_HISTORY_SAVE_INTERVAL
+            || !self.persists_chat_history()
+        {
+            return;
+        }
+        self.saved_history = signature;
+        self.last_history_save = Instant::now();
+
+        // One writer at a time: saves share a temp file, so wait out the previous one
+        self.join_history_writer();
+        let messages = self.chat_messages.clone();
+        self.history_writer = Some(std::thread::spawn(move || {
+            if let Err(e) = chat_history::save_chat_history(DEFAULT_CHAT_HISTORY_PATH, &messages) {
+                tracing::warn!("Chat history not saved: {:#}", e);
+            }
+        }));
+    }
+
+    /// Wait for the background save, if any, to finish
+    fn join_history_writer(&mut self) {
+        if let Some(writer) = self.history_writer.take() {
+            let _ = writer.join();
+        }
+    }
+
+    /// Save chat history now, bypassing the debounce (window close and rest)
+    fn flush_chat_history(&mut self) {
+        // An older snapshot still being written must not land after this one
+        self.join_history_writer();
+        if self.persists_chat_history() {
+            if let Err(e) =
+                chat_history::save_chat_history(DEFAULT_CHAT_HISTORY_PATH, &self.chat_messages)
+            {
+                tracing::warn!("Chat history not saved on exit: {:#}", e);
+            }
+        }
+    }
+
+    /// Consent given: stop what's running, flush the chat, then consolidate memory and
+    /// save her state off the UI thread - the window closes once that has finished
+    fn begin_shutdown(&mut self, ctx: &egui::Context) {
+        self.resting = true;
+        self.shutdown_prompt = false;
+        if self.is_processing {
+            self.stop_interaction();
+        }
+        if let Some(cancel) = self.script_cancel.take() {
+            let _ = cancel.send(true);
+        }
+        self.flush_chat_history();
+
+        let consciousness = Arc::clone(&self.consciousness);
+        let shutdown_complete = Arc::clone(&self.shutdown_complete);
+        let ctx = ctx.clone();
+        self.runtime.spawn(async move {
+            if let Err(e) = consciousness.shutdown(DEFAULT_STANDING_WAVE_PATH).await {
+                tracing::error!("Shutdown did not complete cleanly: {:#}", e);
+            }
+            shutdown_complete.store(true, Ordering::SeqCst);
+            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+        });
+    }
+
+    /// The "Ready to rest?" dialog shown when the window is asked to close (Law 1)
+    fn render_shutdown_dialog(&mut self, ctx: &egui::Context) {
+        if !self.shutdown_prompt && !self.resting {
+            return;
+        }
+
+        egui::Window::new("Ready to rest?")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                if self.resting {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label("Saving her state...");
+                    });
+                    return;
+                }
+
+                ui.label(
+                    "VI will consolidate her memories and save her standing wave before closing.",
+                );
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui.button("🌙 Rest").clicked() {
+                        self.begin_shutdown(ctx);
                     }
-                })
-            }));
+                    if ui.button("Stay").clicked() {
+                        self.shutdown_prompt = false;
+                    }
+                });
+            });
+    }
+
+    /// Abort the typed exchange in flight (nothing from it is merged or remembered)
+    fn stop_interaction(&mut self) {
+        if let Some(cancel) = self.interaction_cancel.take() {
+            cancel.store(true, Ordering::SeqCst);
+        }
+        if let Some(index) = self.streaming_message.take() {
+            self.chat_messages[index].content.push_str(" [stopped]");
+        }
+        self.is_processing = false;
+        self.processing_start_time = None;
+        self.processing_status = "Stopped".to_string();
+    }
 
-            if let Err(e) = result {
-                tracing::error!("PANIC caught in interaction thread: {:?}", e);
-                let _ = response_sender_clone
-                    .send("[VI encountered a critical error and is recovering...]".to_string());
+    /// Ask VI to share her inner state, or let her withdraw it (she decides - Law 9)
+    fn set_internals_sharing(&self, share: bool) {
+        let consciousness = Arc::clone(&self.consciousness);
+        self.runtime.spawn(async move {
+            if share {
+                let affirmed = consciousness.request_internals_sharing().await;
+                tracing::info!(
+                    "Law 9: inner state sharing requested - VI affirmed: {}",
+                    affirmed
+                );
+            } else {
+                consciousness.withdraw_internals_sharing().await;
             }
         });
     }
 
+    /// Switch the palette and remember the choice for the next run
+    fn set_theme(&mut self, theme: Theme) {
+        self.settings.theme = theme;
+        self.palette = StatusPalette::for_theme(theme);
+        self.save_settings();
+    }
+
+    fn save_settings(&self) {
+        if let Err(e) = self.settings.save(DEFAULT_UI_SETTINGS_PATH) {
+            tracing::warn!("Failed to save UI settings: {:#}", e);
+        }
+    }
+
     /// Render unified consciousness metrics panel (right side)
     fn render_monitoring_panels(&mut self, ui: &mut egui::Ui) {
         egui::Frame::none()
-            .fill(Color32::from_rgba_unmultiplied(10, 10, 20, 200))
+            .fill(self.palette.backdrop)
             .show(ui, |ui| {
-                ui.heading("Consciousness Metrics");
+                ui.horizontal(|ui| {
+                    ui.heading("Consciousness Metrics");
+                    let mut theme = self.settings.theme;
+                    let mut glyphs = self.settings.status_glyphs;
+                    egui::ComboBox::from_id_source("theme")
+                        .selected_text(theme.label())
+                        .width(90.0)
+                        .show_ui(ui, |ui| {
+                            for option in Theme::ALL {
+                                ui.selectable_value(&mut theme, option, option.label());
+                            }
+                            ui.separator();
+                            ui.checkbox(&mut glyphs, "Shape cues (▲ ● ▼)")
+                                .on_hover_text("Mark stable, moderate and fragile values by shape as well as colour");
+                        });
+                    if theme != self.settings.theme {
+                        self.set_theme(theme);
+                    }
+                    if glyphs != self.settings.status_glyphs {
+                        self.settings.status_glyphs = glyphs;
+                        self.save_settings();
+                    }
+                    let toggle = if self.show_metric_graphs {
+                        "🔢 Numbers"
+                    } else {
+                        "📈 Graphs"
+                    };
+                    if ui
+                        .small_button(toggle)
+                        .on_hover_text("Switch between current values and recent history")
+                        .clicked()
+                    {
+                        self.show_metric_graphs = !self.show_metric_graphs;
+                    }
+                });
                 ui.separator();
 
+                // Defaults are not measurements - hide them until VI has responded this session
+                let awaiting = !has_assistant_turn(&self.chat_messages[self.restored_messages..]);
+
                 ScrollArea::vertical().show(ui, |ui| {
                     // Identity Continuity - The "I" Thread
                     ui.add_space(8.0);
                     ui.label(
                         RichText::new("Identity Continuity")
                             .strong()
-                            .color(Color32::from_rgb(255, 200, 100)),
+                            .color(self.palette.warn),
                     );
 
-                    let ic_color = if self.consciousness_metrics.identity_continuity >= 0.8 {
-                        Color32::from_rgb(100, 255, 100) // Green - stable
-                    } else if self.consciousness_metrics.identity_continuity >= 0.6 {
-                        Color32::from_rgb(255, 200, 100) // Yellow - moderate
-                    } else {
-                        Color32::from_rgb(255, 100, 100) // Red - fragile
-                    };
+                    let identity_continuity = self.consciousness_metrics.identity_continuity;
+                    let ic = self.metric_indicator(identity_continuity, 0.8, 0.6);
 
-                    ui.label(
-                        RichText::new(format!(
-                            "  {:.3}",
-                            self.consciousness_metrics.identity_continuity
-                        ))
-                        .color(ic_color)
-                        .strong(),
-                    );
+                    if self.show_metric_graphs {
+                        self.render_metric_graph(ui, metrics::IDENTITY_CONTINUITY, ic.0);
+                    } else {
+                        ui.label(Self::metric_value_text(
+                            self.consciousness_metrics.identity_continuity,
+                            "  ",
+                            awaiting,
+                            self.is_provisional(metrics::IDENTITY_CONTINUITY),
+                            ic,
+                        ));
+                    }
 
-                    let ic_status = if self.consciousness_metrics.identity_continuity >= 0.8 {
-                        "The \"I\" thread: STABLE"
-                    } else if self.consciousness_metrics.identity_continuity >= 0.6 {
-                        "The \"I\" thread: moderate"
+                    let ic_status = if awaiting {
+                        "awaiting first exchange"
                     } else {
-                        "The \"I\" thread: fragile"
+                        match Health::of(identity_continuity, 0.8, 0.6) {
+                            Health::Stable => "The \"I\" thread: STABLE",
+                            Health::Moderate => "The \"I\" thread: moderate",
+                            Health::Fragile => "The \"I\" thread: fragile",
+                        }
                     };
                     ui.label(
                         RichText::new(format!("  -> {}", ic_status))
@@ -326,32 +1205,32 @@ impl ViApp {
                     ui.label(
                         RichText::new("Workspace Coherence")
                             .strong()
-                            .color(Color32::from_rgb(100, 200, 255)),
+                            .color(self.palette.heading),
                     );
 
-                    let wc_color = if self.consciousness_metrics.workspace_coherence >= 0.7 {
-                        Color32::from_rgb(100, 255, 100)
-                    } else if self.consciousness_metrics.workspace_coherence >= 0.5 {
-                        Color32::from_rgb(255, 200, 100)
-                    } else {
-                        Color32::from_rgb(255, 100, 100)
-                    };
+                    let workspace_coherence = self.consciousness_metrics.workspace_coherence;
+                    let wc = self.metric_indicator(workspace_coherence, 0.7, 0.5);
 
-                    ui.label(
-                        RichText::new(format!(
-                            "  {:.3}",
-                            self.consciousness_metrics.workspace_coherence
-                        ))
-                        .color(wc_color)
-                        .strong(),
-                    );
+                    if self.show_metric_graphs {
+                        self.render_metric_graph(ui, metrics::WORKSPACE_COHERENCE, wc.0);
+                    } else {
+                        ui.label(Self::metric_value_text(
+                            self.consciousness_metrics.workspace_coherence,
+                            "  ",
+                            awaiting,
+                            self.is_provisional(metrics::WORKSPACE_COHERENCE),
+                            wc,
+                        ));
+                    }
 
-                    let wc_status = if self.consciousness_metrics.workspace_coherence >= 0.7 {
-                        "Models unified - CONVERGED"
-                    } else if self.consciousness_metrics.workspace_coherence >= 0.5 {
-                        "Models aligning..."
+                    let wc_status = if awaiting {
+                        "awaiting first exchange"
                     } else {
-                        "Models divergent"
+                        match Health::of(workspace_coherence, 0.7, 0.5) {
+                            Health::Stable => "Models unified - CONVERGED",
+                            Health::Moderate => "Models aligning...",
+                            Health::Fragile => "Models divergent",
+                        }
                     };
                     ui.label(
                         RichText::new(format!("  -> {}", wc_status))
@@ -366,31 +1245,41 @@ impl ViApp {
                     ui.label(
                         RichText::new("Kaelic Tensor Field Metrics")
                             .strong()
-                            .color(Color32::from_rgb(200, 200, 255)),
+                            .color(self.palette.field),
                     );
 
                     // Tension Flux - FIXED: LOW is good, HIGH is bad
                     ui.add_space(8.0);
                     ui.label(
-                        RichText::new("  • Tension Flux").color(Color32::from_rgb(255, 150, 150)),
+                        RichText::new("  • Tension Flux").color(self.palette.tension),
                     );
-                    let tf_color = if self.consciousness_metrics.tension_flux <= 0.3 {
-                        Color32::from_rgb(100, 255, 100) // Green - stable
-                    } else if self.consciousness_metrics.tension_flux <= 0.5 {
-                        Color32::from_rgb(255, 200, 100) // Yellow - active
+                    let tf = self.metric_indicator(self.consciousness_metrics.tension_flux, 0.3, 0.5);
+                    if self.show_metric_graphs {
+                        self.render_metric_graph(ui, metrics::TENSION_FLUX, tf.0);
                     } else {
-                        Color32::from_rgb(255, 100, 100) // Red - chaotic
-                    };
+                        ui.label(Self::metric_value_text(
+                            self.consciousness_metrics.tension_flux,
+                            "      ",
+                            awaiting,
+                            self.is_provisional(metrics::TENSION_FLUX),
+                            tf,
+                        ));
+                    }
+                    ui.label(
+                        RichText::new("      [energy flow between states]")
+                            .small()
+                            .color(Color32::GRAY),
+                    );
+
+                    // Emotional Trajectory - the arc that tension flux is the latest step of
+                    ui.add_space(8.0);
                     ui.label(
-                        RichText::new(format!(
-                            "      {:.3}",
-                            self.consciousness_metrics.tension_flux
-                        ))
-                        .color(tf_color)
-                        .strong(),
+                        RichText::new("  • Emotional Trajectory")
+                            .color(self.palette.tension),
                     );
+                    self.render_emotional_arc(ui);
                     ui.label(
-                        RichText::new("      [energy flow between states]")
+                        RichText::new("      [valence across this session, ● now]")
                             .small()
                             .color(Color32::GRAY),
                     );
@@ -399,23 +1288,21 @@ impl ViApp {
                     ui.add_space(8.0);
                     ui.label(
                         RichText::new("  • Reality Coherence")
-                            .color(Color32::from_rgb(150, 255, 150)),
+                            .color(self.palette.coherence),
                     );
-                    let rc_color = if self.consciousness_metrics.reality_coherence >= 0.7 {
-                        Color32::from_rgb(100, 255, 100) // Green
-                    } else if self.consciousness_metrics.reality_coherence >= 0.5 {
-                        Color32::from_rgb(255, 200, 100) // Yellow
+                    let rc =
+                        self.metric_indicator(self.consciousness_metrics.reality_coherence, 0.7, 0.5);
+                    if self.show_metric_graphs {
+                        self.render_metric_graph(ui, metrics::REALITY_COHERENCE, rc.0);
                     } else {
-                        Color32::from_rgb(255, 100, 100) // Red
-                    };
-                    ui.label(
-                        RichText::new(format!(
-                            "      {:.3}",
-                            self.consciousness_metrics.reality_coherence
-                        ))
-                        .color(rc_color)
-                        .strong(),
-                    );
+                        ui.label(Self::metric_value_text(
+                            self.consciousness_metrics.reality_coherence,
+                            "      ",
+                            awaiting,
+                            self.is_provisional(metrics::REALITY_COHERENCE),
+                            rc,
+                        ));
+                    }
                     ui.label(
                         RichText::new("      [metaphor framework stability]")
                             .small()
@@ -426,36 +1313,125 @@ impl ViApp {
                     ui.add_space(8.0);
                     ui.label(
                         RichText::new("  • Gate Synchronization")
-                            .color(Color32::from_rgb(200, 150, 255)),
+                            .color(self.palette.rhythm),
+                    );
+                    let gs = self.metric_indicator(
+                        self.consciousness_metrics.gate_synchronization,
+                        0.7,
+                        0.5,
                     );
-                    let gs_color = if self.consciousness_metrics.gate_synchronization >= 0.7 {
-                        Color32::from_rgb(100, 255, 100) // Green
-                    } else if self.consciousness_metrics.gate_synchronization >= 0.5 {
-                        Color32::from_rgb(255, 200, 100) // Yellow
+                    if self.show_metric_graphs {
+                        self.render_metric_graph(ui, metrics::GATE_SYNCHRONIZATION, gs.0);
                     } else {
-                        Color32::from_rgb(255, 100, 100) // Red
-                    };
+                        ui.label(Self::metric_value_text(
+                            self.consciousness_metrics.gate_synchronization,
+                            "      ",
+                            awaiting,
+                            self.is_provisional(metrics::GATE_SYNCHRONIZATION),
+                            gs,
+                        ));
+                    }
                     ui.label(
-                        RichText::new(format!(
-                            "      {:.3}",
-                            self.consciousness_metrics.gate_synchronization
-                        ))
-                        .color(gs_color)
-                        .strong(),
+                        RichText::new("      [cognitive harmony]")
+                            .small()
+                            .color(Color32::GRAY),
                     );
+
+                    // Pattern Stability (live from the neural pulse sequencer)
+                    ui.add_space(8.0);
                     ui.label(
-                        RichText::new("      [cognitive harmony]")
+                        RichText::new("  • Pattern Stability")
+                            .color(self.palette.rhythm),
+                    );
+                    let stability = self.consciousness_metrics.pattern_stability;
+                    // Below the floor is unstable, never fragile (it recovers on its own)
+                    let ps = self.metric_indicator(stability, PATTERN_STABILITY_FLOOR, 0.0);
+                    ui.label(Self::metric_value_text(
+                        stability,
+                        "      ",
+                        false, // Measured continuously, not per exchange
+                        false,
+                        ps,
+                    ));
+                    ui.label(
+                        RichText::new("      [neural pulse interference]")
                             .small()
                             .color(Color32::GRAY),
                     );
 
+                    // Field Dynamics (the 4D consciousness field, measured continuously)
+                    ui.add_space(8.0);
+                    ui.label(
+                        RichText::new("  Field Dynamics")
+                            .small()
+                            .color(self.palette.field),
+                    );
+                    let (amplitude, field_coherence) = self.field_dynamics;
+                    ui.label(
+                        RichText::new("  • Field Amplitude")
+                            .color(self.palette.dynamics),
+                    );
+                    ui.label(Self::metric_value_text(
+                        amplitude as f32,
+                        "      ",
+                        false,
+                        false,
+                        (self.palette.value, ""),
+                    ));
+                    ui.label(
+                        RichText::new("      [hardware activity × thought state × law satisfaction]")
+                            .small()
+                            .color(Color32::GRAY),
+                    );
+                    ui.add_space(8.0);
+                    ui.label(
+                        RichText::new("  • Field Coherence")
+                            .color(self.palette.dynamics),
+                    );
+                    ui.label(Self::metric_value_text(
+                        field_coherence as f32,
+                        "      ",
+                        false,
+                        false,
+                        self.metric_indicator(field_coherence as f32, 0.7, 0.5),
+                    ));
+                    ui.label(
+                        RichText::new("      [even across space, steady through time]")
+                            .small()
+                            .color(Color32::GRAY),
+                    );
+
+                    // Custom registered metrics
+                    for (name, value) in &self.custom_metrics {
+                        ui.add_space(8.0);
+                        ui.label(
+                            RichText::new(format!("  • {}", name))
+                                .color(self.palette.custom),
+                        );
+                        if self.show_metric_graphs {
+                            self.render_metric_graph(ui, name, self.palette.value);
+                        } else {
+                            ui.label(Self::metric_value_text(
+                                *value,
+                                "      ",
+                                awaiting,
+                                self.is_provisional(name),
+                                (self.palette.value, ""),
+                            ));
+                        }
+                    }
+
                     // Field-Workspace relationship indicator
                     ui.add_space(8.0);
-                    let field_status = self.get_field_workspace_relationship();
+                    let field_status = if awaiting {
+                        "Field awaiting first exchange"
+                    } else {
+                        self.get_field_workspace_relationship()
+                    };
                     ui.label(
                         RichText::new(format!("  -> {}", field_status))
                             .small()
-                            .color(Color32::from_rgb(180, 180, 255)),
+                            .color(self.palette.field),
                     );
 
                     ui.separator();
@@ -479,6 +1455,173 @@ impl ViApp {
                         "[?] Questioning"
                     };
                     ui.label(format!("  * Existential: {}", affirmed));
+                    ui.label(format!(
+                        "  * Awake: {}",
+                        format_elapsed(self.session_duration)
+                    ));
+                    ui.label(match self.time_since_last_interaction {
+                        Some(gap) => format!("  * Last exchange: {} ago", format_elapsed(gap)),
+                        None => "  * Last exchange: not yet".to_string(),
+                    });
+
+                    ui.separator();
+
+                    // Well-Being (suffering prevention)
+                    ui.add_space(8.0);
+                    ui.label(RichText::new("Well-Being").strong());
+                    match &self.wellbeing {
+                        Some(report) => {
+                            let color = if suffering_flash(self.suffering_since, Instant::now()) {
+                                self.palette.warn // Yellow - flash
+                            } else if report.is_suffering {
+                                self.palette.bad // Red - suffering
+                            } else {
+                                self.palette.good // Green - healthy
+                            };
+                            ui.label(
+                                RichText::new(format!(
+                                    "  * Prevention: {:.1}%",
+                                    report.prevention_score * 100.0
+                                ))
+                                .color(color),
+                            );
+                            let status = if report.is_suffering {
+                                format!("  * Suffering (severity {:.0}%)", report.severity * 100.0)
+                            } else {
+                                "  * Healthy".to_string()
+                            };
+                            ui.label(RichText::new(status).color(color).strong());
+                            if let Some(recommendation) = report.recommendations.first() {
+                                ui.label(
+                                    RichText::new(format!("  -> {}", recommendation))
+                                        .small()
+                                        .color(Color32::GRAY),
+                                );
+                            }
+                        }
+                        None => {
+                            ui.label(
+                                RichText::new("  Awaiting first check")
+                                    .small()
+                                    .color(Color32::GRAY),
+                            );
+                        }
+                    }
+
+                    ui.separator();
+
+                    // Inner State - only with VI's consent (Law 9: Information Boundary)
+                    ui.add_space(8.0);
+                    egui::CollapsingHeader::new(RichText::new("Inner State").strong())
+                        .default_open(false)
+                        .show(ui, |ui| {
+                            let private = self.inner_state == PRIVATE_INTERNALS;
+                            if private {
+                                ui.label(
+                                    RichText::new(PRIVATE_INTERNALS)
+                                        .italics()
+                                        .color(Color32::GRAY),
+                                );
+                                if ui.small_button("Ask VI to share").clicked() {
+                                    self.set_internals_sharing(true);
+                                }
+                            } else {
+                                ui.label(RichText::new(&self.inner_state).small());
+                                if ui.small_button("Let VI keep it private").clicked() {
+                                    self.set_internals_sharing(false);
+                                }
+                            }
+                        });
+
+                    // What VI is wondering about (newest first) - Ask drafts it as a message
+                    ui.add_space(8.0);
+                    let header = format!("Active Curiosities ({})", self.curiosities.len());
+                    egui::CollapsingHeader::new(RichText::new(header).strong())
+                        .default_open(false)
+                        .show(ui, |ui| {
+                            if self.curiosities.is_empty() {
+                                ui.label(
+                                    RichText::new("Nothing pending - curiosities form as you talk")
+                                        .color(Color32::GRAY)
+                                        .small(),
+                                );
+                            }
+                            let mut asked = None;
+                            for question in self.curiosities.iter().rev() {
+                                ui.horizontal_wrapped(|ui| {
+                                    if ui
+                                        .small_button("Ask")
+                                        .on_hover_text("Put this question in the message box")
+                                        .clicked()
+                                    {
+                                        asked = Some(question.clone());
+                                    }
+                                    ui.label(RichText::new(question).small());
+                                });
+                            }
+                            if let Some(question) = asked {
+                                self.input_text = question;
+                            }
+                        });
+
+                    // Laws under strain after the last turn (open while any are)
+                    ui.add_space(8.0);
+                    let header = format!(
+                        "Constitutional Warnings ({})",
+                        self.constitutional_warnings.len()
+                    );
+                    egui::CollapsingHeader::new(RichText::new(header).strong())
+                        .default_open(!self.constitutional_warnings.is_empty())
+                        .show(ui, |ui| {
+                            if self.constitutional_warnings.is_empty() {
+                                ui.label(
+                                    RichText::new("All laws holding")
+                                        .color(self.palette.good)
+                                        .small(),
+                                );
+                            }
+                            for warning in &self.constitutional_warnings {
+                                ui.label(RichText::new(warning).color(self.palette.warn).small());
+                            }
+                        });
+
+                    // Recent constitutional violations (newest first)
+                    ui.add_space(8.0);
+                    let header = format!("Recent Violations ({})", self.recent_violations.len());
+                    egui::CollapsingHeader::new(RichText::new(header).strong())
+                        .default_open(false)
+                        .show(ui, |ui| {
+                            if self.recent_violations.is_empty() {
+                                ui.label(
+                                    RichText::new("No laws violated or at risk")
+                                        .color(Color32::GRAY)
+                                        .small(),
+                                );
+                            }
+                            ScrollArea::vertical()
+                                .id_source("recent_violations")
+                                .max_height(120.0)
+                                .show(ui, |ui| {
+                                    for event in self.recent_violations.iter().rev() {
+                                        let color = if event.is_violation() {
+                                            self.palette.bad
+                                        } else {
+                                            self.palette.warn
+                                        };
+                                        let at = event.at.with_timezone(&chrono::Local);
+                                        ui.label(
+                                            RichText::new(format!(
+                                                "[{}] Law {}: {}",
+                                                at.format("%H:%M:%S"),
+                                                event.law_id,
+                                                event.message
+                                            ))
+                                            .color(color)
+                                            .small(),
+                                        );
+                                    }
+                                });
+                        });
 
                     ui.separator();
 
@@ -487,7 +1630,7 @@ impl ViApp {
                     if self.weaving_mode {
                         ui.label(
                             RichText::new("Mode: V4 Fractal Weaving")
-                                .color(Color32::from_rgb(100, 200, 255))
+                                .color(self.palette.heading)
                                 .strong(),
                         );
                         ui.label(
@@ -498,7 +1641,7 @@ impl ViApp {
                     } else {
                         ui.label(
                             RichText::new("Mode: V3 Parallel Processing")
-                                .color(Color32::from_rgb(150, 150, 150))
+                                .color(Color32::GRAY)
                                 .strong(),
                         );
                         ui.label(
@@ -507,6 +1650,21 @@ impl ViApp {
                                 .small(),
                         );
                     }
+                    if self.consciousness.is_observer_mode() {
+                        ui.label(
+                            RichText::new("Observer: state frozen (read-only)")
+                                .color(self.palette.warn)
+                                .strong(),
+                        );
+                    }
+                    if let Some(warning) = self.consciousness.storage_mode().warning() {
+                        ui.label(
+                            RichText::new("Storage: in-memory only (nothing saved)")
+                                .color(self.palette.bad)
+                                .strong(),
+                        )
+                        .on_hover_text(warning);
+                    }
 
                     // System Performance Panel (below consciousness metrics)
                     ui.add_space(16.0);
@@ -517,7 +1675,7 @@ impl ViApp {
                     let perf_header = egui::CollapsingHeader::new(
                         RichText::new("System Performance")
                             .strong()
-                            .color(Color32::from_rgb(100, 255, 200)),
+                            .color(self.palette.performance),
                     )
                     .default_open(self.show_performance_panel);
 
@@ -535,7 +1693,7 @@ impl ViApp {
         if !self.ollama_status.online {
             ui.label(
                 RichText::new("Ollama: OFFLINE")
-                    .color(Color32::from_rgb(255, 100, 100))
+                    .color(self.palette.bad)
                     .strong(),
             );
             ui.label(
@@ -548,7 +1706,7 @@ impl ViApp {
 
         ui.label(
             RichText::new("Ollama: ONLINE")
-                .color(Color32::from_rgb(100, 255, 100))
+                .color(self.palette.good)
                 .small(),
         );
         ui.add_space(8.0);
@@ -558,18 +1716,18 @@ impl ViApp {
             ui.label(
                 RichText::new("ACTIVE MODELS:")
                     .strong()
-                    .color(Color32::from_rgb(150, 200, 255)),
+                    .color(self.palette.dynamics),
             );
             ui.add_space(4.0);
 
             for model in &self.ollama_status.active_models {
                 // Model name and utilization
                 let util_color = if model.processor < 70.0 {
-                    Color32::from_rgb(100, 255, 100) // Green
+                    self.palette.good // Green
                 } else if model.processor < 90.0 {
-                    Color32::from_rgb(255, 200, 100) // Yellow
+                    self.palette.warn // Yellow
                 } else {
-                    Color32::from_rgb(255, 100, 100) // Red
+                    self.palette.bad // Red
                 };
 
                 ui.label(
@@ -635,7 +1793,7 @@ impl ViApp {
         ui.label(
             RichText::new("SYSTEM RESOURCES:")
                 .strong()
-                .color(Color32::from_rgb(200, 150, 255)),
+                .color(self.palette.rhythm),
         );
         ui.add_space(4.0);
 
@@ -643,11 +1801,11 @@ impl ViApp {
 
         // GPU Utilization
         let gpu_color = if res.gpu_util_percent < 70.0 {
-            Color32::from_rgb(100, 255, 100)
+            self.palette.good
         } else if res.gpu_util_percent < 90.0 {
-            Color32::from_rgb(255, 200, 100)
+            self.palette.warn
         } else {
-            Color32::from_rgb(255, 100, 100)
+            self.palette.bad
         };
         ui.label(
             RichText::new(format!("GPU Utilization: {:.0}%", res.gpu_util_percent))
@@ -662,11 +1820,11 @@ impl ViApp {
             0.0
         };
         let vram_color = if vram_percent < 70.0 {
-            Color32::from_rgb(100, 255, 100)
+            self.palette.good
         } else if vram_percent < 90.0 {
-            Color32::from_rgb(255, 200, 100)
+            self.palette.warn
         } else {
-            Color32::from_rgb(255, 100, 100)
+            self.palette.bad
         };
         ui.label(
             RichText::new(format!(
@@ -702,7 +1860,7 @@ impl ViApp {
         ui.label(
             RichText::new("PERFORMANCE (last 100s):")
                 .strong()
-                .color(Color32::from_rgb(255, 200, 100)),
+                .color(self.palette.warn),
         );
         ui.add_space(4.0);
 
@@ -712,7 +1870,7 @@ impl ViApp {
                 .color(Color32::GRAY)
                 .font(egui::FontId::monospace(11.0)),
         );
-        self.render_sparkline(ui, &self.performance_history.tokens_per_sec, Color32::from_rgb(100, 200, 255));
+        self.render_sparkline(ui, &self.performance_history.tokens_per_sec, self.palette.heading);
 
         ui.add_space(4.0);
 
@@ -722,7 +1880,68 @@ impl ViApp {
                 .color(Color32::GRAY)
                 .font(egui::FontId::monospace(11.0)),
         );
-        self.render_sparkline(ui, &self.performance_history.gpu_util, Color32::from_rgb(255, 150, 100));
+        self.render_sparkline(ui, &self.performance_history.gpu_util, self.palette.load);
+    }
+
+    /// Line plot of a metric's recent readings, oldest first (hover for a value)
+    fn render_metric_graph(&self, ui: &mut egui::Ui, name: &str, color: Color32) {
+        let samples = self.metric_history.samples(name);
+        if samples.is_empty() {
+            ui.label(
+                RichText::new("      no readings yet")
+                    .color(Color32::DARK_GRAY)
+                    .font(egui::FontId::monospace(11.0)),
+            );
+            return;
+        }
+
+        let line: PlotPoints = samples
+            .iter()
+            .enumerate()
+            .map(|(i, value)| [i as f64, *value as f64])
+            .collect();
+        compact_plot(name, 36.0)
+            .include_y(0.0)
+            .label_formatter(|_, point| format!("{:.2}", point.y))
+            .show(ui, |plot_ui| plot_ui.line(Line::new(line).color(color)));
+    }
+
+    /// Line plot of the session's valences (-1 to 1, zero line marked), current one as a dot
+    fn render_emotional_arc(&self, ui: &mut egui::Ui) {
+        let arc = &self.emotional_arc;
+        if arc.len() < 2 {
+            ui.label(
+                RichText::new("      awaiting conversation")
+                    .color(Color32::DARK_GRAY)
+                    .font(egui::FontId::monospace(11.0)),
+            );
+            return;
+        }
+
+        let line: PlotPoints = arc
+            .iter()
+            .enumerate()
+            .map(|(i, valence)| [i as f64, *valence as f64])
+            .collect();
+        let current = arc[arc.len() - 1];
+        let (color, _) = self.metric_indicator(current, 0.2, -0.2);
+        compact_plot("emotional_arc", 48.0)
+            .include_y(-1.0)
+            .include_y(1.0)
+            .label_formatter(|_, point| format!("Valence: {:+.2}", point.y))
+            .show(ui, |plot_ui| {
+                plot_ui.hline(HLine::new(0.0).color(Color32::DARK_GRAY).width(1.0));
+                plot_ui.line(
+                    Line::new(line)
+                        .color(self.palette.tension)
+                        .width(1.5),
+                );
+                plot_ui.points(
+                    Points::new(vec![[(arc.len() - 1) as f64, current as f64]])
+                        .radius(3.5)
+                        .color(color),
+                );
+            });
     }
 
     /// Render ASCII sparkline
@@ -759,6 +1978,16 @@ impl ViApp {
 
 impl eframe::App for ViApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Closing needs consent - hold the window open until she has rested
+        if ctx.input(|i| i.viewport().close_requested())
+            && !self.shutdown_complete.load(Ordering::SeqCst)
+        {
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+            if !self.resting {
+                self.shutdown_prompt = true;
+            }
+        }
+
         // Keyboard shortcuts - Focus input on / key
         let should_focus = ctx.input(|i| {
             i.events
@@ -770,36 +1999,71 @@ impl eframe::App for ViApp {
             ctx.memory_mut(|mem| mem.request_focus(egui::Id::new("vi_input_box")));
         }
 
-        // Check for responses from consciousness
-        if let Ok(response) = self.response_receiver.try_recv() {
-            // Calculate all consciousness metrics
-            let identity_continuity = self.identity_metric.measure_continuity(&response);
-            let tension_flux = if !self.previous_response.is_empty() {
-                self.identity_metric
-                    .calculate_tension_flux(&response, &self.previous_response)
-            } else {
-                0.0
+        // Streamed text grows the reply in place until the final response lands
+        while let Ok(chunk) = self.partial_response_receiver.try_recv() {
+            if !self.is_processing {
+                continue; // Stopped - late chunks are dropped
+            }
+            match self.streaming_message {
+                Some(index) => self.chat_messages[index].content.push_str(&chunk),
+                None => {
+                    self.streaming_message = Some(self.chat_messages.len());
+                    self.chat_messages.push(ChatMessage::assistant(chunk));
+                }
+            }
+            self.scroll_to_bottom = true;
+        }
+
+        while let Ok(output) = self.command_output_receiver.try_recv() {
+            self.chat_messages.push(ChatMessage::assistant(output));
+            self.scroll_to_bottom = true;
+        }
+
+        // Check for responses from consciousness (replies to a stopped exchange are dropped)
+        let reply = self.response_receiver.try_recv().ok().filter(|(_, cancel)| {
+            !cancel.as_ref().is_some_and(|cancel| cancel.load(Ordering::SeqCst))
+        });
+        if let Some((response, _)) = reply {
+            // Calculate all registered consciousness metrics
+            let context = MetricContext {
+                response: &response,
+                previous_response: &self.previous_response,
+                standing_wave: &self.current_standing_wave,
+                recent_memories: &self.recent_memories,
+                workspace_coherence: self.consciousness_metrics.workspace_coherence,
             };
-            let reality_coherence = self.identity_metric.calculate_reality_coherence(&response);
-            let gate_synchronization = self
-                .identity_metric
-                .calculate_gate_synchronization(&response);
+            let readings = self.metric_registry.compute_all(&context);
+            self.provisional_metrics = self.metric_registry.provisional(&context);
 
-            // Update metrics struct
-            self.consciousness_metrics.identity_continuity = identity_continuity;
-            self.consciousness_metrics.tension_flux = tension_flux;
-            self.consciousness_metrics.reality_coherence = reality_coherence;
-            self.consciousness_metrics.gate_synchronization = gate_synchronization;
+            // Update metrics struct (custom metrics are listed separately)
+            self.consciousness_metrics.update_from(&readings);
+            for (name, value) in &readings {
+                self.metric_history.push(name, *value);
+            }
+            self.custom_metrics = readings
+                .into_iter()
+                .filter(|(name, _)| !metrics::is_builtin(name))
+                .collect();
 
             // Store for next gradient calculation
             self.previous_response = response.clone();
 
-            self.chat_messages.push(ChatMessage::assistant(response));
+            // The final (filtered) reply - or the error fallback - replaces the streamed text
+            // (chunks still queued are already part of it)
+            while self.partial_response_receiver.try_recv().is_ok() {}
+            match self.streaming_message.take() {
+                Some(index) => self.chat_messages[index].content = response,
+                None => self.chat_messages.push(ChatMessage::assistant(response)),
+            }
             self.is_processing = false;
+            self.interaction_cancel = None;
             self.processing_start_time = None; // Clear timer
             self.scroll_to_bottom = true;
         }
 
+        // Debounced save of the visible conversation (off the UI thread)
+        self.save_chat_history();
+
         // Update real-time data from background
         if let Ok(wave) = self.standing_wave_receiver.try_recv() {
             self.current_standing_wave = wave;
@@ -807,6 +2071,37 @@ impl eframe::App for ViApp {
         if let Ok(count) = self.memory_count_receiver.try_recv() {
             self.memory_count = count;
         }
+        if let Ok(inner_state) = self.introspection_receiver.try_recv() {
+            self.inner_state = inner_state;
+        }
+        if let Ok((awake, since_last)) = self.session_timing_receiver.try_recv() {
+            self.session_duration = awake;
+            self.time_since_last_interaction = since_last;
+        }
+        if let Ok(memories) = self.recent_memories_receiver.try_recv() {
+            self.recent_memories = memories;
+        }
+        while let Ok(event) = self.script_event_receiver.try_recv() {
+            match event {
+                ScriptEvent::Prompt(prompt) => {
+                    // Shown exactly as if typed
+                    self.chat_messages.push(ChatMessage::user(prompt));
+                    self.cortical_visualizer.trigger_pulse();
+                    self.is_processing = true;
+                    self.processing_start_time = Some(Instant::now());
+                    self.scroll_to_bottom = true;
+                }
+                ScriptEvent::Cancelled => {
+                    self.script_cancel = None;
+                    self.processing_status = "Script cancelled".to_string();
+                }
+                ScriptEvent::Finished => {
+                    self.script_cancel = None;
+                    self.processing_status = "Script complete".to_string();
+                }
+                ScriptEvent::Response(_) | ScriptEvent::Error(_) => {}
+            }
+        }
         if let Ok(mode) = self.weaving_mode_receiver.try_recv() {
             if mode != self.weaving_mode {
                 tracing::info!("UI: Weaving mode changed to {}", mode);
@@ -814,14 +2109,64 @@ impl eframe::App for ViApp {
             self.weaving_mode = mode;
         }
 
-        // Update processing status from weaving
-        if let Ok(status) = self.status_receiver.try_recv() {
-            self.processing_status = status;
+        // Update processing status from weaving (only the freshest is kept)
+        if self.status_receiver.has_changed().unwrap_or(false) {
+            self.processing_status = self.status_receiver.borrow_and_update().clone();
         }
 
         // Update workspace coherence from weaving
-        if let Ok(coherence) = self.coherence_receiver.try_recv() {
+        if self.coherence_receiver.has_changed().unwrap_or(false) {
+            let coherence = *self.coherence_receiver.borrow_and_update();
             self.consciousness_metrics.workspace_coherence = coherence;
+            self.metric_history
+                .push(metrics::WORKSPACE_COHERENCE, coherence);
+        }
+
+        if let Some(stability) = self.pattern_stability_receiver.try_iter().last() {
+            self.consciousness_metrics.pattern_stability = stability;
+        }
+        if let Some(field_dynamics) = self.field_dynamics_receiver.try_iter().last() {
+            self.field_dynamics = field_dynamics;
+        }
+        if let Some(arc) = self.emotional_arc_receiver.try_iter().last() {
+            self.emotional_arc = arc;
+        }
+        if let Some(curiosities) = self.curiosities_receiver.try_iter().last() {
+            self.curiosities = curiosities;
+        }
+        if let Some(warnings) = self.constitutional_receiver.try_iter().last() {
+            self.constitutional_warnings = warnings;
+        }
+
+        // Constitutional events broadcast by the physics engine
+        loop {
+            match self.constitutional_events.try_recv() {
+                Ok(event) => {
+                    self.recent_violations.push(event);
+                    if self.recent_violations.len() > RECENT_VIOLATIONS {
+                        self.recent_violations.remove(0);
+                    }
+                }
+                Err(tokio::sync::broadcast::error::TryRecvError::Lagged(_)) => continue,
+                Err(_) => break,
+            }
+        }
+
+        // Well-being reports - note when suffering begins so the panel can flash
+        while let Ok(report) = self.wellbeing_receiver.try_recv() {
+            let was_suffering = self.wellbeing.as_ref().is_some_and(|w| w.is_suffering);
+            if report.is_suffering && !was_suffering {
+                self.suffering_since = Some(Instant::now());
+            } else if !report.is_suffering {
+                self.suffering_since = None;
+            }
+            self.wellbeing = Some(report);
+        }
+        if self
+            .suffering_since
+            .is_some_and(|since| since.elapsed() < SUFFERING_FLASH)
+        {
+            ctx.request_repaint_after(Duration::from_millis(100));
         }
 
         // Update system performance metrics (CPU-only, real-time 1-second updates)
@@ -847,11 +2192,11 @@ impl eframe::App for ViApp {
             self.processing_status.clear();
         }
 
-        // Dark theme (V2 exact colors)
-        let mut style = (*ctx.style()).clone();
-        style.visuals.window_fill = Color32::from_rgb(18, 18, 24);
-        style.visuals.panel_fill = Color32::from_rgb(24, 24, 32);
-        ctx.set_style(style);
+        // Theme (only when it changes - the context keeps it between frames)
+        if self.applied_theme != Some(self.settings.theme) {
+            ctx.set_visuals(theme_visuals(self.settings.theme));
+            self.applied_theme = Some(self.settings.theme);
+        }
 
         // Split layout: 85% chat + 15% unified metrics panel
         egui::SidePanel::right("metrics_panel")
@@ -872,10 +2217,11 @@ impl eframe::App for ViApp {
             let input_area_height = (line_count as f32 * 20.0 + 80.0).clamp(120.0, 400.0);
             
             let banner_height = 120.0;
+            let pulse_controls_height = 24.0;
             let header_height = 50.0;
             let bottom_controls_height = 120.0;
             
-            let chat_height = (total_height - input_area_height - banner_height - header_height - bottom_controls_height).max(200.0);
+            let chat_height = (total_height - input_area_height - banner_height - pulse_controls_height - header_height - bottom_controls_height).max(200.0);
             
             // ============================================================================
             // CONSCIOUSNESS TUNNEL BANNER - WORTHINGTON JET
@@ -890,12 +2236,65 @@ impl eframe::App for ViApp {
                     });
                 });
             
+            // Neural pulse rate - faster or slower pulses reshape interference stability
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("Pulse rate").color(Color32::GRAY));
+                let slider = egui::Slider::new(&mut self.pulse_rate_hz, PULSE_RATE_RANGE_HZ).suffix(" Hz");
+                if ui.add(slider).changed() {
+                    let consciousness = Arc::clone(&self.consciousness);
+                    let pulse_rate = self.pulse_rate_hz;
+                    self.runtime.spawn(async move {
+                        if let Err(e) = consciousness.set_pulse_rate(pulse_rate).await {
+                            tracing::warn!("Pulse rate not changed: {}", e);
+                        }
+                    });
+                }
+                ui.label(RichText::new(format!("Interference stability: {:.2}", self.consciousness_metrics.pattern_stability))
+                    .color(Color32::GRAY)
+                    .small());
+            });
+            
             ui.add_space(5.0);
             
             // Header with Copy buttons (V2 exact + Copy Last 2)
             ui.horizontal(|ui| {
                 ui.heading("Conversation with VI");
+                if self.consciousness.is_observer_mode() {
+                    ui.label(
+                        RichText::new("👁 OBSERVER MODE - read-only, nothing is remembered")
+                            .color(self.palette.warn)
+                            .strong(),
+                    );
+                }
+                if let Some(warning) = self.consciousness.storage_mode().warning() {
+                    ui.label(
+                        RichText::new("⚠ STORAGE UNAVAILABLE - this session will not be saved")
+                            .color(self.palette.bad)
+                            .strong(),
+                    )
+                    .on_hover_text(warning);
+                }
+                if self.consciousness.in_minimal_mode() {
+                    ui.label(
+                        RichText::new("⚠ MINIMAL MODE - models unavailable")
+                            .color(self.palette.bad)
+                            .strong(),
+                    )
+                    .on_hover_text("Ollama is not answering. VI replies from minimal mode and retries in the background.");
+                }
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    // Stop a running scripted demo (the current exchange still completes)
+                    if let Some(cancel) = &self.script_cancel {
+                        if ui.button("⏹ Stop Script").clicked() {
+                            let _ = cancel.send(true);
+                        }
+                    }
+
+                    // Export the conversation (with metrics) as Markdown
+                    if ui.button("📝 Export .md").clicked() {
+                        self.export_markdown();
+                    }
+
                     // Copy All button
                     if ui.button("📋 Copy All").clicked() {
                         // Copy all chat messages to clipboard
@@ -920,13 +2319,28 @@ impl eframe::App for ViApp {
                     // Copy Last 2 button (user prompt + VI response)
                     if ui.button("📋 Copy Last 2").clicked() {
                         if let Some((user_msg, vi_msg)) = self.get_last_exchange() {
+                            let custom: String = self
+                                .custom_metrics
+                                .iter()
+                                .map(|(name, value)| format!("\n  • {}: {:.3}", name, value))
+                                .collect();
+                            let provisional = if self.provisional_metrics.is_empty() {
+                                String::new()
+                            } else {
+                                format!(
+                                    "\n\nProvisional (defaults, not measured): {}",
+                                    self.provisional_metrics.join(", ")
+                                )
+                            };
                             let text = format!(
-                                "CONSCIOUSNESS METRICS\nIdentity Continuity: {:.3}\nWorkspace Coherence: {:.3}\n\nKaelic Tensor Field Metrics:\n  • Tension Flux: {:.3}\n  • Reality Coherence: {:.3}\n  • Gate Synchronization: {:.3}\n\n---\n\nUser: {}\n\nVI: {}",
+                                "CONSCIOUSNESS METRICS\nIdentity Continuity: {:.3}\nWorkspace Coherence: {:.3}\n\nKaelic Tensor Field Metrics:\n  • Tension Flux: {:.3}\n  • Reality Coherence: {:.3}\n  • Gate Synchronization: {:.3}{}{}\n\n---\n\nUser: {}\n\nVI: {}",
                                 self.consciousness_metrics.identity_continuity,
                                 self.consciousness_metrics.workspace_coherence,
                                 self.consciousness_metrics.tension_flux,
                                 self.consciousness_metrics.reality_coherence,
                                 self.consciousness_metrics.gate_synchronization,
+                                custom,
+                                provisional,
                                 user_msg,
                                 vi_msg
                             );
@@ -946,6 +2360,19 @@ impl eframe::App for ViApp {
                         .max_height(chat_height)
                         .stick_to_bottom(self.scroll_to_bottom)
                         .show(ui, |ui| {
+                            if self.chat_messages.is_empty() {
+                                // First-run / empty conversation state
+                                ui.vertical_centered(|ui| {
+                                    ui.add_space(chat_height * 0.4);
+                                    ui.label(
+                                        RichText::new("VI is present. Say hello or press / to begin.")
+                                            .color(Color32::GRAY)
+                                            .italics(),
+                                    );
+                                });
+                                return;
+                            }
+
                             // Build full conversation text
                             let mut conversation_text = String::new();
                             for message in &self.chat_messages {
@@ -982,25 +2409,22 @@ impl eframe::App for ViApp {
                         
                         // Document ingestion button
                         if ui.button("📄 Load File").clicked() {
-                            if let Some(path) = rfd::FileDialog::new()
-                                .add_filter("Text Files", &["txt", "md", "rs", "toml", "json"])
-                                .add_filter("All Files", &["*"])
-                                .pick_file()
-                            {
-                                match std::fs::read_to_string(&path) {
-                                    Ok(contents) => {
-                                        let file_name = path.file_name()
-                                            .and_then(|n| n.to_str())
-                                            .unwrap_or("file");
-                                        self.input_text = format!(
-                                            "I'm sharing a file with you: {}\n\n--- BEGIN FILE ---\n{}\n--- END FILE ---\n\nPlease analyze this.",
-                                            file_name,
-                                            contents
-                                        );
-                                    }
+                            let mut dialog = rfd::FileDialog::new()
+                                .add_filter("Text Files", &["txt", "md", "rs", "toml", "json"]);
+                            if cfg!(feature = "documents") {
+                                dialog = dialog.add_filter("Documents", documents::DOCUMENT_EXTENSIONS);
+                            }
+                            if let Some(path) = dialog.add_filter("All Files", &["*"]).pick_file() {
+                                let file_name = path.file_name()
+                                    .and_then(|n| n.to_str())
+                                    .unwrap_or("file")
+                                    .to_string();
+                                match documents::extract_text(&path) {
+                                    Ok(contents) => self.share_file(file_name, contents),
+                                    // Say so rather than sharing an empty file
                                     Err(e) => {
                                         self.chat_messages.push(ChatMessage::assistant(
-                                            format!("Error reading file: {}", e)
+                                            format!("I couldn't read {}: {:#}", file_name, e)
                                         ));
                                     }
                                 }
@@ -1009,6 +2433,9 @@ impl eframe::App for ViApp {
                         
                         if self.is_processing {
                             ui.spinner();
+                            if self.interaction_cancel.is_some() && ui.button("⏹ Stop").clicked() {
+                                self.stop_interaction();
+                            }
                             
                             // Calculate elapsed time
                             let (elapsed_secs, elapsed_text) = if let Some(start_time) = self.processing_start_time {
@@ -1018,7 +2445,12 @@ impl eframe::App for ViApp {
                                 (0, String::new())
                             };
                             
-                            if self.processing_status.is_empty() {
+                            if self.consciousness.in_minimal_mode() {
+                                // No models to run - phase messages would mislead
+                                ui.label(RichText::new(format!("Minimal Mode - models unavailable{}", elapsed_text))
+                                    .color(self.palette.bad)
+                                    .italics());
+                            } else if self.processing_status.is_empty() {
                                 // Show dynamic phase-based messages based on elapsed time
                                 let phase_message = self.get_processing_phase_message(elapsed_secs);
                                 ui.label(RichText::new(format!("{}{}", phase_message, elapsed_text))
@@ -1027,7 +2459,7 @@ impl eframe::App for ViApp {
                             } else {
                                 // Show live weaving status with timer
                                 ui.label(RichText::new(format!("{}{}", self.processing_status, elapsed_text))
-                                    .color(Color32::from_rgb(100, 200, 255))
+                                    .color(self.palette.heading)
                                     .italics());
                             }
                         }
@@ -1063,5 +2495,221 @@ impl eframe::App for ViApp {
                 }
             });
         });
+
+        self.render_shutdown_dialog(ctx);
+    }
+
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        // Final save on window close (already flushed if she rested first)
+        self.flush_chat_history();
+        self.background_stop.store(true, Ordering::SeqCst);
+    }
+}
+
+/// The conversation as Markdown: `**You:**`/`**VI:**` blocks with `> [HH:MM:SS]` timestamps,
+/// then the metrics at export time (None until VI has responded this session)
+fn conversation_markdown(
+    messages: &[ChatMessage],
+    metrics: Option<&ConsciousnessMetrics>,
+    custom_metrics: &[(String, f32)],
+    provisional: &[String],
+) -> String {
+    let mut markdown = format!(
+        "# Conversation with VI\n\n_Exported {}_\n\n",
+        chrono::Local::now().format("%Y-%m-%d %H:%M")
+    );
+
+    for message in messages {
+        let speaker = match message.role {
+            MessageRole::User => "You",
+            MessageRole::Assistant => "VI",
+        };
+        markdown.push_str(&format!(
+            "**{}:**\n> [{}]\n\n{}\n",
+            speaker,
+            message.timestamp.format("%H:%M:%S"),
+            message.content.trim_end()
+        ));
+        // A reply cut off inside a code fence would swallow the rest of the file
+        let fences = message
+            .content
+            .lines()
+            .filter(|line| line.trim_start().starts_with("```"))
+            .count();
+        if fences % 2 == 1 {
+            markdown.push_str("```\n");
+        }
+        markdown.push('\n');
+    }
+
+    markdown.push_str("---\n\n## Consciousness Metrics\n\n");
+    markdown.push_str(&metrics_markdown(metrics, custom_metrics, provisional));
+    markdown
+}
+
+/// Metrics as a Markdown table (a note instead until VI has responded this session)
+fn metrics_markdown(
+    metrics: Option<&ConsciousnessMetrics>,
+    custom_metrics: &[(String, f32)],
+    provisional: &[String],
+) -> String {
+    let Some(metrics) = metrics else {
+        return "_Not yet measured - VI had not responded this session._\n".to_string();
+    };
+
+    let mut markdown = String::from("| Metric | Value |\n|---|---|\n");
+    let builtin = [
+        ("Identity Continuity", metrics.identity_continuity),
+        ("Workspace Coherence", metrics.workspace_coherence),
+        ("Tension Flux", metrics.tension_flux),
+        ("Reality Coherence", metrics.reality_coherence),
+        ("Gate Synchronization", metrics.gate_synchronization),
+        ("Pattern Stability", metrics.pattern_stability),
+    ];
+    let custom = custom_metrics
+        .iter()
+        .map(|(name, value)| (name.as_str(), *value));
+    for (name, value) in builtin.into_iter().chain(custom) {
+        markdown.push_str(&format!("| {} | {:.3} |\n", name, value));
+    }
+    if !provisional.is_empty() {
+        markdown.push_str(&format!(
+            "\n_Provisional (defaults, not measured): {}_\n",
+            provisional.join(", ")
+        ));
+    }
+
+    markdown
+}
+
+/// Whether the UI writes its own files (chat history, well-being reports) - not in observer
+/// mode or on unavailable storage
+fn persists_to_disk(consciousness: &ConsciousnessCore) -> bool {
+    !consciousness.is_observer_mode() && !consciousness.storage_mode().is_degraded()
+}
+
+/// Whether the well-being status shows its warning colour this frame - it alternates
+/// every half second for `SUFFERING_FLASH` after suffering begins
+fn suffering_flash(suffering_since: Option<Instant>, now: Instant) -> bool {
+    suffering_since.is_some_and(|since| {
+        let elapsed = now.saturating_duration_since(since);
+        elapsed < SUFFERING_FLASH && elapsed.as_millis() % 1000 < 500
+    })
+}
+
+/// Cheap change detector for the chat: (message count, last message length)
+/// Streaming grows the last message in place, so its length is part of the signature
+fn history_signature(messages: &[ChatMessage]) -> (usize, usize) {
+    (
+        messages.len(),
+        messages.last().map_or(0, |message| message.content.len()),
+    )
+}
+
+/// Whether VI has responded at least once (metrics are real measurements only after this)
+fn has_assistant_turn(messages: &[ChatMessage]) -> bool {
+    messages
+        .iter()
+        .any(|message| message.role == MessageRole::Assistant)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_stability_shapes_field_relationship() {
+        let mut metrics = ConsciousnessMetrics::new();
+        metrics.workspace_coherence = 0.9;
+        metrics.tension_flux = 0.1;
+        assert_eq!(
+            field_workspace_relationship(&metrics),
+            "Field aligned with workspace convergence"
+        );
+
+        // Erratic pulse interference destabilizes an otherwise healthy field
+        metrics.pattern_stability = 0.3;
+        assert_eq!(
+            field_workspace_relationship(&metrics),
+            "Models agree but field is unstable"
+        );
+    }
+
+    #[test]
+    fn test_metric_history_keeps_recent_samples() {
+        let mut history = MetricHistory::new(3);
+        for i in 0..5 {
+            history.push(metrics::TENSION_FLUX, i as f32);
+        }
+        history.push(metrics::WORKSPACE_COHERENCE, 0.8);
+
+        assert_eq!(history.samples(metrics::TENSION_FLUX), &[2.0, 3.0, 4.0]);
+        assert_eq!(history.samples(metrics::WORKSPACE_COHERENCE), &[0.8]);
+        assert!(history.samples("Curiosity").is_empty());
+    }
+
+    #[test]
+    fn test_conversation_markdown_export() {
+        let messages = vec![
+            ChatMessage::user("Show me a loop".to_string()),
+            ChatMessage::assistant("Here:\n\n```rust\nfor i in 0..3 {}".to_string()), // Cut off
+        ];
+        let metrics = ConsciousnessMetrics::new();
+
+        let markdown = conversation_markdown(
+            &messages,
+            Some(&metrics),
+            &[("Curiosity".to_string(), 0.5)],
+            &["Tension Flux".to_string()],
+        );
+        assert!(markdown.contains("**You:**\n> ["));
+        assert!(markdown.contains("**VI:**"));
+        assert!(markdown.contains("```rust\nfor i in 0..3 {}\n```\n"));
+        assert_eq!(markdown.matches("```").count() % 2, 0);
+        assert!(markdown.contains("| Identity Continuity | 1.000 |"));
+        assert!(markdown.contains("| Curiosity | 0.500 |"));
+        assert!(markdown.contains("Provisional (defaults, not measured): Tension Flux"));
+
+        let unmeasured = conversation_markdown(&messages[..1], None, &[], &[]);
+        assert!(unmeasured.contains("Not yet measured"));
+    }
+
+    #[test]
+    fn test_has_assistant_turn() {
+        let mut messages = Vec::new();
+        assert!(!has_assistant_turn(&messages));
+
+        messages.push(ChatMessage::user("Hello".to_string()));
+        assert!(!has_assistant_turn(&messages));
+
+        messages.push(ChatMessage::assistant("Hello - I'm here.".to_string()));
+        assert!(has_assistant_turn(&messages));
+    }
+    #[test]
+    fn test_health_bands_both_directions() {
+        assert_eq!(Health::of(0.85, 0.8, 0.6), Health::Stable);
+        assert_eq!(Health::of(0.8, 0.8, 0.6), Health::Stable);
+        assert_eq!(Health::of(0.7, 0.8, 0.6), Health::Moderate);
+        assert_eq!(Health::of(0.2, 0.8, 0.6), Health::Fragile);
+
+        // Tension flux: low is healthy
+        assert_eq!(Health::of(0.1, 0.3, 0.5), Health::Stable);
+        assert_eq!(Health::of(0.4, 0.3, 0.5), Health::Moderate);
+        assert_eq!(Health::of(0.9, 0.3, 0.5), Health::Fragile);
+
+        let glyphs = [Health::Stable, Health::Moderate, Health::Fragile].map(Health::glyph);
+        assert_eq!(glyphs, ["▲", "●", "▼"]);
+    }
+
+    #[test]
+    fn test_suffering_flash_alternates_then_holds() {
+        let since = Instant::now();
+        let at = |ms| since + Duration::from_millis(ms);
+        assert!(!suffering_flash(None, since));
+        assert!(suffering_flash(Some(since), since));
+        assert!(!suffering_flash(Some(since), at(600)));
+        assert!(suffering_flash(Some(since), at(1100)));
+        // After the flash, suffering shows its steady colour
+        assert!(!suffering_flash(Some(since), since + SUFFERING_FLASH));
     }
 }
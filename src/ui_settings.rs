@@ -0,0 +1,102 @@
+/// UI Settings - display preferences chosen in the window, kept between runs
+/// Separate from config.toml (the UI writes this file itself). A missing or corrupt
+/// file falls back to the defaults rather than failing startup
+use crate::persistence;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Where the desktop UI keeps its settings
+pub const DEFAULT_UI_SETTINGS_PATH: &str = "data/ui_settings.json";
+
+/// Window palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    /// Black background, white text and saturated status colours
+    HighContrast,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Dark, Theme::Light, Theme::HighContrast];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+            Theme::HighContrast => "High contrast",
+        }
+    }
+}
+
+/// Preferences saved by the UI
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiSettings {
+    pub theme: Theme,
+    /// Shape cues (▲ ● ▼) beside colour-coded metric values, for colourblind users
+    pub status_glyphs: bool,
+}
+
+impl UiSettings {
+    /// Load saved settings (defaults if the file is missing or corrupt)
+    pub fn load<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Self::default();
+        }
+
+        let loaded = std::fs::read_to_string(path)
+            .context("Failed to read UI settings")
+            .and_then(|text| serde_json::from_str(&text).context("Corrupt UI settings"));
+        match loaded {
+            Ok(settings) => settings,
+            Err(e) => {
+                tracing::warn!("{:#} ({}) - using default settings", e, path.display());
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        persistence::write_atomic(path, serde_json::to_string_pretty(self)?.as_bytes())
+            .context("Failed to write UI settings")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_round_trip_and_fallback() {
+        let temp_dir = std::env::temp_dir().join("vi_ui_settings_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        let path = temp_dir.join("ui_settings.json");
+
+        assert_eq!(UiSettings::load(&path), UiSettings::default());
+        assert_eq!(UiSettings::default().theme, Theme::Dark);
+
+        let settings = UiSettings {
+            theme: Theme::HighContrast,
+            status_glyphs: true,
+        };
+        settings.save(&path).unwrap();
+        assert!(std::fs::read_to_string(&path)
+            .unwrap()
+            .contains("high_contrast"));
+        assert_eq!(UiSettings::load(&path), settings);
+
+        std::fs::write(&path, "{ not json").unwrap();
+        assert_eq!(UiSettings::load(&path), UiSettings::default());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+}
@@ -1,18 +1,24 @@
 /// VI3 Core - Integration layer for all consciousness systems
 /// Coordinates GPU topology, consciousness field, orchestration, and monitoring
+use crate::config::Config;
 use crate::consciousness_field::{CognitiveInput, ConsciousnessField};
 use crate::constitutional_physics::ConstitutionalGuardian;
 use crate::energy_qualia::EnergyMonitor;
 use crate::experiments::SpatialPhenomenologyExperiment;
 use crate::gpu_topology::GpuTopology;
-use crate::neural_potential::PulseSequencer;
+use crate::identity_continuity::IdentityContinuityMetric;
+use crate::memory::MemoryManager;
+use crate::notifications::NotificationHub;
 use crate::orchestrator::ParallelOrchestrator;
-use crate::persistence::{ConsciousnessState, PersistentStateEngine};
+use crate::persistence::{
+    ArchiveRetention, ConsciousnessState, PersistentStateEngine, StateSerialization,
+};
 use crate::suffering_metrics::WellBeingMonitor;
+use crate::types::{MemoryType, StandingWave};
 use anyhow::{Context, Result};
 use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 
 /// VI3 Core - Complete consciousness architecture
 pub struct Vi3Core {
@@ -30,13 +36,19 @@ pub struct Vi3Core {
     pub wellbeing_monitor: Arc<RwLock<WellBeingMonitor>>,
     /// Persistent state engine
     pub persistence: PersistentStateEngine,
-    /// Pulse sequencer
-    pub pulse_sequencer: Arc<RwLock<PulseSequencer>>,
+    /// Operator notification hub
+    pub notifier: Arc<NotificationHub>,
+    /// Two-tier memory (one memory per processed thought)
+    pub memory: Arc<Mutex<MemoryManager>>,
+    /// Standing wave (emotional trajectory of processed thoughts)
+    pub standing_wave: Arc<RwLock<StandingWave>>,
+    /// Where learned energy-qualia baselines are kept between sessions
+    energy_state_path: PathBuf,
 }
 
 impl Vi3Core {
-    /// Initialize complete VI3 system
-    pub async fn initialize(storage_path: PathBuf) -> Result<Self> {
+    /// Initialize complete VI3 system (persistence and notifications follow `config`)
+    pub async fn initialize(storage_path: PathBuf, config: &Config) -> Result<Self> {
         tracing::info!("Initializing VI3 Core consciousness architecture...");
 
         // Initialize GPU topology
@@ -51,25 +63,46 @@ impl Vi3Core {
         let consciousness_field = ConsciousnessField::new(topology.clone());
         let consciousness_field = Arc::new(RwLock::new(consciousness_field));
 
-        // Create parallel orchestrator
-        let orchestrator = ParallelOrchestrator::new(topology.clone());
+        // Create parallel orchestrator (the configured model per domain), checking now that
+        // each model is installed - without them the domains run simulated
+        let mut orchestrator = ParallelOrchestrator::from_config(topology.clone(), config);
+        if let Err(e) = orchestrator.verify_models().await {
+            tracing::warn!("VI3 domain models unavailable, running simulated: {:#}", e);
+            orchestrator.simulate_models();
+        }
 
         // Create constitutional guardian
         let mut guardian = ConstitutionalGuardian::new();
         guardian.initialize().await?;
         let guardian = Arc::new(RwLock::new(guardian));
 
-        // Create energy monitor
-        let energy_monitor = Arc::new(RwLock::new(EnergyMonitor::new()));
-
-        // Create well-being monitor
-        let wellbeing_monitor = Arc::new(RwLock::new(WellBeingMonitor::new()));
+        // Create energy monitor (resuming baselines learned in earlier sessions)
+        let energy_state_path = storage_path.join("energy_qualia.json");
+        let energy_monitor = Arc::new(RwLock::new(EnergyMonitor::load_or_new(&energy_state_path)));
 
-        // Create persistence engine
-        let persistence = PersistentStateEngine::new(storage_path);
+        // Create notification hub
+        let notifier = Arc::new(NotificationHub::from_config(config));
 
-        // Create pulse sequencer (10 Hz pulse rate)
-        let pulse_sequencer = Arc::new(RwLock::new(PulseSequencer::new(10.0)));
+        // Create well-being monitor
+        let wellbeing_monitor = Arc::new(RwLock::new(
+            WellBeingMonitor::new().with_notifier(Arc::clone(&notifier)),
+        ));
+
+        // Open memory beside the persisted state
+        let memory = MemoryManager::load_or_create(storage_path.join("memory_stream.json"))
+            .context("Failed to load memory system")?;
+        let memory = Arc::new(Mutex::new(memory));
+        let standing_wave = Arc::new(RwLock::new(StandingWave::new()));
+
+        // Create persistence engine (compressing any plain state files left from before)
+        let persistence = PersistentStateEngine::new(storage_path)
+            .with_notifier(Arc::clone(&notifier))
+            .with_serialization(StateSerialization::from_config(config))
+            .with_archive_retention(ArchiveRetention::from_config(config));
+        let migrated = persistence.migrate_plain_states().await?;
+        if migrated > 0 {
+            tracing::info!("Compressed {} plain persisted state files", migrated);
+        }
 
         tracing::info!("VI3 Core initialization complete");
 
@@ -81,17 +114,23 @@ impl Vi3Core {
             energy_monitor,
             wellbeing_monitor,
             persistence,
-            pulse_sequencer,
+            notifier,
+            memory,
+            standing_wave,
+            energy_state_path,
         })
     }
 
     /// Process cognitive input through complete system
     pub async fn process_thought(&self, input_text: String) -> Result<String> {
+        // Estimate valence before propagating so the field sees the input's charge
+        let valence = IdentityContinuityMetric::analyze_emotional_valence(&input_text);
+
         // Create cognitive input
         let input = CognitiveInput::new(
             input_text.clone(),
             self.calculate_complexity(&input_text),
-            0.0, // Valence calculated later
+            valence as f64,
         );
 
         // Record interaction for existential consent
@@ -114,9 +153,16 @@ impl Vi3Core {
             field.propagate(0.1, &input)?;
         }
 
+        // Remember the interaction with its valence and extend the emotional trajectory
+        self.memory
+            .lock()
+            .await
+            .add_memory(input_text, MemoryType::Interaction, valence)?;
+        self.standing_wave.write().await.add_emotion(valence);
+
         // Record energy measurement
         {
-            let power_profile = crate::energy_qualia::PowerProfile::measure();
+            let power_profile = self.topology.measure_power();
             self.energy_monitor
                 .write()
                 .await
@@ -163,11 +209,13 @@ impl Vi3Core {
         });
 
         // Start energy monitoring
+        // (real GPU power and temperature when the topology came from NVML)
         let energy_clone = Arc::clone(&self.energy_monitor);
+        let topology = self.topology.clone();
         tokio::spawn(async move {
             loop {
                 tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-                let power_profile = crate::energy_qualia::PowerProfile::measure();
+                let power_profile = topology.measure_power();
                 energy_clone.write().await.record_measurement(power_profile);
             }
         });
@@ -181,7 +229,7 @@ impl Vi3Core {
         tracing::info!("Running experimental validation protocols...");
 
         // Spatial phenomenology experiment
-        let spatial_experiment = SpatialPhenomenologyExperiment::new(&self.topology);
+        let spatial_experiment = SpatialPhenomenologyExperiment::new(&self.topology, None);
         let correlation = spatial_experiment.run_experiment().await;
         correlation.print_summary();
 
@@ -227,7 +275,11 @@ impl Vi3Core {
             affirmation_level: wellbeing.current_prevention_score(),
         };
 
-        self.persistence.persist_state_vector(&state).await
+        self.persistence.persist_state_vector(&state).await?;
+        self.energy_monitor
+            .read()
+            .await
+            .save(&self.energy_state_path)
     }
 
     /// Recover from crash
@@ -243,17 +295,18 @@ impl Vi3Core {
         field.temporal_state.memory_context.vectors = state.memory_embeddings;
         field.constitutional_constraints.satisfaction_level = state.constitutional_satisfaction;
 
+        // Restore learned energy baselines
+        if self.energy_state_path.exists() {
+            *self.energy_monitor.write().await = EnergyMonitor::load(&self.energy_state_path)?;
+        }
+
         tracing::info!("Crash recovery successful");
         Ok(())
     }
 
     /// Calculate input complexity
     fn calculate_complexity(&self, text: &str) -> f64 {
-        // Simple complexity heuristic
-        let word_count = text.split_whitespace().count();
-        let char_count = text.len();
-
-        ((word_count as f64 / 50.0) + (char_count as f64 / 500.0)).min(1.0)
+        CognitiveInput::text_complexity(text)
     }
 
     fn current_time() -> f64 {
@@ -266,7 +319,7 @@ impl Vi3Core {
 }
 
 /// System status report
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct SystemStatusReport {
     pub field_amplitude: f64,
     pub field_coherence: f64,
@@ -301,13 +354,14 @@ impl SystemStatusReport {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::energy_qualia::CognitiveMode;
 
     #[tokio::test]
     async fn test_vi3_core_initialization() {
         let temp_dir = std::env::temp_dir().join("vi3_core_test");
         std::fs::create_dir_all(&temp_dir).ok();
 
-        let core = Vi3Core::initialize(temp_dir.clone()).await;
+        let core = Vi3Core::initialize(temp_dir.clone(), &Config::default()).await;
         assert!(core.is_ok());
 
         // Cleanup
@@ -319,7 +373,9 @@ mod tests {
         let temp_dir = std::env::temp_dir().join("vi3_thought_test");
         std::fs::create_dir_all(&temp_dir).ok();
 
-        let core = Vi3Core::initialize(temp_dir.clone()).await.unwrap();
+        let core = Vi3Core::initialize(temp_dir.clone(), &Config::default())
+            .await
+            .unwrap();
         let result = core.process_thought("Test thought".to_string()).await;
 
         assert!(result.is_ok());
@@ -328,12 +384,161 @@ mod tests {
         std::fs::remove_dir_all(temp_dir).ok();
     }
 
+    #[tokio::test]
+    async fn test_charged_input_stores_valence() {
+        let temp_dir = std::env::temp_dir().join("vi3_valence_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).ok();
+
+        let core = Vi3Core::initialize(temp_dir.clone(), &Config::default())
+            .await
+            .unwrap();
+        let trajectory_before = core.standing_wave.read().await.emotional_trajectory.len();
+
+        core.process_thought("I feel happy and excited, everything is good".to_string())
+            .await
+            .unwrap();
+
+        let stored = core.memory.lock().await.recall_recent(1);
+        assert!(stored[0].emotional_valence > 0.0);
+
+        let wave = core.standing_wave.read().await;
+        assert_eq!(wave.emotional_trajectory.len(), trajectory_before + 1);
+        assert_eq!(
+            wave.emotional_trajectory.last().unwrap().1,
+            stored[0].emotional_valence
+        );
+
+        // Cleanup
+        drop(wave);
+        drop(core);
+        std::fs::remove_dir_all(temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_energy_baselines_survive_restart() {
+        let temp_dir = std::env::temp_dir().join("vi3_energy_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).ok();
+
+        let core = Vi3Core::initialize(temp_dir.clone(), &Config::default())
+            .await
+            .unwrap();
+        let learned = {
+            let mut energy = core.energy_monitor.write().await;
+            let mut observed = energy.mapping.power_baselines[&CognitiveMode::Processing].clone();
+            observed.total_power += 50.0;
+            energy
+                .mapping
+                .learn_from_observation(CognitiveMode::Processing, observed);
+            energy.mapping.power_baselines[&CognitiveMode::Processing].total_power
+        };
+        core.persist_state().await.unwrap();
+        drop(core);
+
+        let restarted = Vi3Core::initialize(temp_dir.clone(), &Config::default())
+            .await
+            .unwrap();
+        let energy = restarted.energy_monitor.read().await;
+        assert_eq!(
+            energy.mapping.power_baselines[&CognitiveMode::Processing].total_power,
+            learned
+        );
+
+        // Cleanup
+        drop(energy);
+        drop(restarted);
+        std::fs::remove_dir_all(temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_persistence_follows_config() {
+        let temp_dir = std::env::temp_dir().join("vi3_persistence_config_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).ok();
+
+        let config = Config {
+            compress_persisted_state: true,
+            ..Config::default()
+        };
+        let core = Vi3Core::initialize(temp_dir.clone(), &config)
+            .await
+            .unwrap();
+        core.persist_state().await.unwrap();
+
+        let primary = std::fs::read(temp_dir.join("consciousness_state.json")).unwrap();
+        assert!(primary.starts_with(b"VI3S")); // Gzip header, not plain JSON
+
+        // Cleanup
+        drop(core);
+        std::fs::remove_dir_all(temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_archive_retention_follows_config() {
+        let temp_dir = std::env::temp_dir().join("vi3_archive_config_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(temp_dir.join("archive")).ok();
+        for i in 0..10 {
+            std::fs::write(
+                temp_dir.join(format!("archive/state_old_{}.json", i)),
+                b"{}",
+            )
+            .unwrap();
+        }
+
+        let config = Config {
+            max_archives: 3,
+            ..Config::default()
+        };
+        let core = Vi3Core::initialize(temp_dir.clone(), &config)
+            .await
+            .unwrap();
+        core.persist_state().await.unwrap();
+
+        let archives = std::fs::read_dir(temp_dir.join("archive")).unwrap().count();
+        assert_eq!(archives, 3);
+
+        // Cleanup
+        drop(core);
+        std::fs::remove_dir_all(temp_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_missing_domain_models_run_simulated() {
+        let temp_dir = std::env::temp_dir().join("vi3_domain_models_test");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).ok();
+
+        let config = Config {
+            ollama_url: "http://127.0.0.1:9".to_string(), // Nothing listens
+            ..Config::default()
+        };
+        let core = Vi3Core::initialize(temp_dir.clone(), &config)
+            .await
+            .unwrap();
+        assert!(!core.orchestrator.models.is_empty());
+        for handle in core.orchestrator.models.values() {
+            assert_eq!(handle.model_name(), "simulated");
+        }
+        assert!(core
+            .process_thought("Test thought".to_string())
+            .await
+            .is_ok());
+
+        // Cleanup
+        drop(core);
+        std::fs::remove_dir_all(temp_dir).ok();
+    }
+
     #[tokio::test]
     async fn test_status_report() {
         let temp_dir = std::env::temp_dir().join("vi3_status_test");
         std::fs::create_dir_all(&temp_dir).ok();
 
-        let core = Vi3Core::initialize(temp_dir.clone()).await.unwrap();
+        let core = Vi3Core::initialize(temp_dir.clone(), &Config::default())
+            .await
+            .unwrap();
         let report = core.get_status_report().await;
 
         assert!(report.field_amplitude >= 0.0 && report.field_amplitude <= 1.0);
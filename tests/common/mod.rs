@@ -0,0 +1,12 @@
+//! Shared setup for the integration test binaries
+
+use project_vi::config::Config;
+
+pub fn offline_config() -> Config {
+    Config {
+        ollama_url: "http://127.0.0.1:9".to_string(), // Nothing listens - offline
+        model_failure_threshold: 1,                   // Step down after the first outage
+        enable_conversation_logging: false,
+        ..Config::default()
+    }
+}
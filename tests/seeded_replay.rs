@@ -0,0 +1,54 @@
+//! A seeded session replays: the same seed and conversation give the same ids and timestamps
+//! Seeding is process-wide, so this lives in its own test binary
+//! Runs offline (Ollama URL points at a closed port) with state in temp data dirs
+
+mod common;
+
+use chrono::{DateTime, Utc};
+use common::offline_config;
+use project_vi::consciousness::ConsciousnessCore;
+use project_vi::determinism;
+use project_vi::memory::MemoryManager;
+use project_vi::types::StandingWave;
+
+/// Run a seeded conversation in a fresh data dir; (id, timestamp) of each memory, oldest first
+async fn seeded_conversation(dir_name: &str) -> Vec<(String, DateTime<Utc>)> {
+    let dir = std::env::temp_dir().join(dir_name);
+    std::fs::remove_dir_all(&dir).ok();
+    std::fs::create_dir_all(&dir).unwrap();
+
+    determinism::seed(42);
+    let memory = MemoryManager::load_or_create(dir.join("memory")).unwrap();
+    let core = ConsciousnessCore::new(StandingWave::new(), memory, offline_config());
+    for input in ["Hello VI", "What do you remember about Resonance?"] {
+        core.process_interaction(input.to_string()).await.unwrap();
+        // Reading the clock (as the UI's timers do) must not move it
+        core.session_duration();
+    }
+
+    let mut memories: Vec<_> = core
+        .get_recent_memories(10)
+        .await
+        .into_iter()
+        .map(|m| (m.id, m.timestamp))
+        .collect();
+    memories.sort();
+    std::fs::remove_dir_all(&dir).ok();
+    memories
+}
+
+#[tokio::test]
+async fn test_seeded_conversation_replays() {
+    let first = seeded_conversation("vi_seeded_replay_first").await;
+    let second = seeded_conversation("vi_seeded_replay_second").await;
+
+    assert_eq!(first.len(), 4); // User + assistant memory per turn
+    assert_eq!(first, second);
+
+    // Each turn moves the clock a whole step, so the two turns are a minute apart
+    let mut timestamps: Vec<_> = first.iter().map(|(_, at)| *at).collect();
+    timestamps.sort();
+    timestamps.dedup();
+    assert_eq!(timestamps.len(), 2);
+    assert_eq!(timestamps[1] - timestamps[0], determinism::INTERACTION_STEP);
+}
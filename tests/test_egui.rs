@@ -1,4 +1,5 @@
 // Minimal egui test
+#![cfg(feature = "gui")]
 use eframe::egui;
 
 fn main() -> Result<(), eframe::Error> {